@@ -0,0 +1,6 @@
+// editors start the language server as a command of its own
+fn main() {
+    let arguments = vec![String::from("lsp")];
+
+    std::process::exit(nia_interpreter_core::cli::run(arguments));
+}
@@ -0,0 +1,292 @@
+use std::path::PathBuf;
+
+pub const USAGE: &'static str = "\
+Usage: nia [--config PATH | --no-config | --image PATH] [COMMAND]
+
+Commands:
+    repl            Start an interactive session (default)
+    run SCRIPT      Execute script and exit
+    SCRIPT          Same as `run SCRIPT'
+    -e, --eval CODE Evaluate code, print its value and exit
+    daemon          Run the event loop without an interactive session
+    check [PATH]    Load configuration at PATH, or the selected one, and exit
+    fmt [--check] [PATH]...
+                    Format files at PATH in place, or stdin to stdout;
+                    with --check, only list files that are not formatted
+    lint [PATH]...  Report likely mistakes in files at PATH, or stdin, as
+                    `path:line:column: code: message' lines
+    lsp             Serve the language server protocol over stdio
+    save-image PATH Save the interpreter with the selected configuration
+                    as an image at PATH
+    -h, --help      Print this message
+
+Options:
+    --config PATH   Use configuration at PATH instead of the default one
+    --no-config     Do not load any configuration
+    --image PATH    Start from the interpreter image at PATH instead of
+                    loading configuration";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigSource {
+    Default,
+    Path(PathBuf),
+    Image(PathBuf),
+    None,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliCommand {
+    Repl,
+    Run(String),
+    Eval(String),
+    Daemon,
+    Check(Option<String>),
+    Format(Vec<String>, bool),
+    Lint(Vec<String>),
+    Lsp,
+    SaveImage(String),
+    Help,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CliArguments {
+    config_source: ConfigSource,
+    command: CliCommand,
+}
+
+fn take_value(
+    arguments: &mut std::vec::IntoIter<String>,
+    flag: &str,
+) -> Result<String, String> {
+    arguments
+        .next()
+        .ok_or_else(|| format!("Option `{}' requires a value.", flag))
+}
+
+impl CliArguments {
+    pub fn parse(arguments: Vec<String>) -> Result<CliArguments, String> {
+        let mut config_source = None;
+        let mut command = None;
+        let mut arguments = arguments.into_iter();
+
+        while let Some(argument) = arguments.next() {
+            let new_command = match argument.as_str() {
+                "--config" | "--no-config" | "--image" => {
+                    if config_source.is_some() {
+                        return Err(String::from(
+                            "Options `--config', `--no-config' and `--image' can be given only once.",
+                        ));
+                    }
+
+                    config_source = Some(match argument.as_str() {
+                        "--config" => ConfigSource::Path(PathBuf::from(
+                            take_value(&mut arguments, "--config")?,
+                        )),
+                        "--image" => ConfigSource::Image(PathBuf::from(
+                            take_value(&mut arguments, "--image")?,
+                        )),
+                        _ => ConfigSource::None,
+                    });
+
+                    continue;
+                },
+                "-h" | "--help" => CliCommand::Help,
+                "-e" | "--eval" => {
+                    CliCommand::Eval(take_value(&mut arguments, &argument)?)
+                },
+                "repl" => CliCommand::Repl,
+                "daemon" => CliCommand::Daemon,
+                "lsp" => CliCommand::Lsp,
+                "run" => CliCommand::Run(take_value(&mut arguments, "run")?),
+                "save-image" => CliCommand::SaveImage(take_value(
+                    &mut arguments,
+                    "save-image",
+                )?),
+                "check" => {
+                    let path = match arguments.as_slice().first() {
+                        Some(path) if !path.starts_with("-") => {
+                            arguments.next()
+                        },
+                        _ => None,
+                    };
+
+                    CliCommand::Check(path)
+                },
+                "fmt" => {
+                    let mut paths = Vec::new();
+                    let mut check = false;
+
+                    for argument in arguments.by_ref() {
+                        match argument.as_str() {
+                            "--check" => check = true,
+                            _ if argument.starts_with("-") => {
+                                return Err(format!(
+                                    "Unknown option `{}'.",
+                                    argument
+                                ));
+                            },
+                            _ => paths.push(argument),
+                        }
+                    }
+
+                    CliCommand::Format(paths, check)
+                },
+                "lint" => {
+                    let paths = arguments.by_ref().collect::<Vec<String>>();
+
+                    if let Some(option) =
+                        paths.iter().find(|path| path.starts_with("-"))
+                    {
+                        return Err(format!("Unknown option `{}'.", option));
+                    }
+
+                    CliCommand::Lint(paths)
+                },
+                _ if argument.starts_with("-") => {
+                    return Err(format!("Unknown option `{}'.", argument));
+                },
+                _ => CliCommand::Run(argument),
+            };
+
+            if command.is_some() {
+                return Err(String::from("Only one command can be given."));
+            }
+
+            command = Some(new_command);
+        }
+
+        Ok(CliArguments {
+            config_source: config_source.unwrap_or(ConfigSource::Default),
+            command: command.unwrap_or(CliCommand::Repl),
+        })
+    }
+
+    pub fn get_config_source(&self) -> &ConfigSource {
+        &self.config_source
+    }
+
+    pub fn get_command(&self) -> &CliCommand {
+        &self.command
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    fn parse(arguments: Vec<&str>) -> Result<CliArguments, String> {
+        CliArguments::parse(
+            arguments.into_iter().map(|s| String::from(s)).collect(),
+        )
+    }
+
+    #[test]
+    fn defaults_to_repl_with_default_config() {
+        let arguments = parse(vec![]).unwrap();
+
+        nia_assert_equal(&ConfigSource::Default, arguments.get_config_source());
+        nia_assert_equal(&CliCommand::Repl, arguments.get_command());
+    }
+
+    #[test]
+    fn parses_commands() {
+        let specs = vec![
+            (vec!["repl"], CliCommand::Repl),
+            (vec!["run", "a.nia"], CliCommand::Run(String::from("a.nia"))),
+            (vec!["a.nia"], CliCommand::Run(String::from("a.nia"))),
+            (
+                vec!["-e", "(+ 1 2)"],
+                CliCommand::Eval(String::from("(+ 1 2)")),
+            ),
+            (
+                vec!["--eval", "(+ 1 2)"],
+                CliCommand::Eval(String::from("(+ 1 2)")),
+            ),
+            (vec!["daemon"], CliCommand::Daemon),
+            (vec!["check"], CliCommand::Check(None)),
+            (
+                vec!["check", "config.nia"],
+                CliCommand::Check(Some(String::from("config.nia"))),
+            ),
+            (vec!["fmt"], CliCommand::Format(vec![], false)),
+            (
+                vec!["fmt", "--check", "a.nia", "b.nia"],
+                CliCommand::Format(
+                    vec![String::from("a.nia"), String::from("b.nia")],
+                    true,
+                ),
+            ),
+            (vec!["lint"], CliCommand::Lint(vec![])),
+            (
+                vec!["lint", "a.nia", "b.nia"],
+                CliCommand::Lint(vec![
+                    String::from("a.nia"),
+                    String::from("b.nia"),
+                ]),
+            ),
+            (vec!["lsp"], CliCommand::Lsp),
+            (
+                vec!["save-image", "nia.image"],
+                CliCommand::SaveImage(String::from("nia.image")),
+            ),
+            (vec!["-h"], CliCommand::Help),
+            (vec!["--help"], CliCommand::Help),
+        ];
+
+        for (arguments, expected) in specs {
+            nia_assert_equal(
+                &expected,
+                parse(arguments).unwrap().get_command(),
+            );
+        }
+    }
+
+    #[test]
+    fn parses_config_sources() {
+        let arguments = parse(vec!["--config", "a.nia", "daemon"]).unwrap();
+
+        nia_assert_equal(
+            &ConfigSource::Path(PathBuf::from("a.nia")),
+            arguments.get_config_source(),
+        );
+        nia_assert_equal(&CliCommand::Daemon, arguments.get_command());
+
+        let arguments = parse(vec!["check", "--no-config"]).unwrap();
+
+        nia_assert_equal(&ConfigSource::None, arguments.get_config_source());
+        nia_assert_equal(&CliCommand::Check(None), arguments.get_command());
+
+        let arguments = parse(vec!["--image", "nia.image"]).unwrap();
+
+        nia_assert_equal(
+            &ConfigSource::Image(PathBuf::from("nia.image")),
+            arguments.get_config_source(),
+        );
+        nia_assert_equal(&CliCommand::Repl, arguments.get_command());
+    }
+
+    #[test]
+    fn returns_error_on_invalid_arguments() {
+        let specs = vec![
+            vec!["--config"],
+            vec!["-e"],
+            vec!["run"],
+            vec!["--unknown"],
+            vec!["daemon", "repl"],
+            vec!["a.nia", "b.nia"],
+            vec!["--config", "a.nia", "--no-config"],
+            vec!["--image", "nia.image", "--config", "a.nia"],
+            vec!["--image"],
+            vec!["save-image"],
+            vec!["fmt", "--unknown"],
+            vec!["lint", "a.nia", "--fix"],
+        ];
+
+        for arguments in specs {
+            nia_assert(parse(arguments).is_err());
+        }
+    }
+}
@@ -0,0 +1,449 @@
+use std::io::Read;
+use std::path::PathBuf;
+
+use crate::library;
+use crate::EventLoop;
+use crate::Interpreter;
+
+use super::CliArguments;
+use super::CliCommand;
+use super::ConfigSource;
+use super::USAGE;
+
+const EXIT_SUCCESS: i32 = 0;
+const EXIT_FAILURE: i32 = 1;
+const EXIT_USAGE: i32 = 2;
+
+fn get_config_path(config_source: &ConfigSource) -> Option<PathBuf> {
+    match config_source {
+        ConfigSource::Default => {
+            crate::utils::get_default_configuration_file_path()
+        },
+        ConfigSource::Path(path) => Some(path.clone()),
+        ConfigSource::Image(_) | ConfigSource::None => None,
+    }
+}
+
+fn path_to_str(path: &PathBuf) -> Result<&str, String> {
+    path.to_str()
+        .ok_or_else(|| format!("Error resolving path: {:?}.", path))
+}
+
+fn load_image(image_path: &PathBuf) -> Result<Interpreter, String> {
+    Interpreter::load_image(path_to_str(image_path)?).map_err(|error| {
+        format!("Error loading image {:?}: {}.", image_path, error)
+    })
+}
+
+fn load_config(
+    interpreter: &mut Interpreter,
+    config_path: &PathBuf,
+) -> Result<(), String> {
+    let path_string = path_to_str(config_path)?;
+
+    interpreter.load_config(path_string).map_err(|error| {
+        format!("Error during configuration {:?}: {}.", config_path, error)
+    })
+}
+
+// unlike the repl, non-interactive modes refuse to run with a broken config
+fn make_interpreter(
+    config_source: &ConfigSource,
+) -> Result<Interpreter, String> {
+    if let ConfigSource::Image(image_path) = config_source {
+        return load_image(image_path);
+    }
+
+    let mut interpreter = Interpreter::new();
+
+    if let Some(config_path) = get_config_path(config_source) {
+        load_config(&mut interpreter, &config_path)?;
+    }
+
+    Ok(interpreter)
+}
+
+fn run_repl(config_source: &ConfigSource) -> Result<(), String> {
+    let interpreter = match config_source {
+        ConfigSource::Default => Interpreter::with_default_config(),
+        ConfigSource::Path(path) => Interpreter::with_config(path.clone()),
+        ConfigSource::Image(image_path) => load_image(image_path)?,
+        ConfigSource::None => Interpreter::new(),
+    };
+
+    crate::repl::run_with_interpreter(interpreter)
+        .map_err(|error| format!("Error: {}.", error))
+}
+
+fn run_script(
+    config_source: &ConfigSource,
+    script_path: &str,
+) -> Result<(), String> {
+    let mut interpreter = make_interpreter(config_source)?;
+
+    interpreter
+        .execute_file_in_main_environment(script_path)
+        .map(|_| ())
+        .map_err(|error| format!("Error: {}.", error))
+}
+
+fn run_eval(config_source: &ConfigSource, code: &str) -> Result<(), String> {
+    let mut interpreter = make_interpreter(config_source)?;
+
+    let value = interpreter
+        .execute_in_main_environment(code)
+        .map_err(|error| format!("Error: {}.", error))?;
+
+    // `nia:quit' exits without printing anything
+    if interpreter.is_quit_requested() {
+        return Ok(());
+    }
+
+    let string = library::value_to_readable_string(&interpreter, value)
+        .map_err(|error| format!("Error: {}.", error))?;
+
+    println!("{}", string);
+
+    Ok(())
+}
+
+fn run_daemon(config_source: &ConfigSource) -> Result<(), String> {
+    let interpreter = make_interpreter(config_source)?;
+    let event_loop_handle = EventLoop::run_event_loop(interpreter);
+
+    if event_loop_handle.wait_for_stop() {
+        Ok(())
+    } else {
+        Err(String::from("Event loop stopped unexpectedly."))
+    }
+}
+
+// an image saved once starts faster than evaluating configuration again
+fn run_save_image(
+    config_source: &ConfigSource,
+    image_path: &str,
+) -> Result<(), String> {
+    let interpreter = make_interpreter(config_source)?;
+
+    interpreter.save_image(image_path).map_err(|error| {
+        format!("Error saving image {:?}: {}.", image_path, error)
+    })
+}
+
+fn run_check(
+    config_source: &ConfigSource,
+    config_path: &Option<String>,
+) -> Result<(), String> {
+    let config_path = match config_path {
+        Some(config_path) => PathBuf::from(config_path),
+        None => get_config_path(config_source)
+            .ok_or_else(|| String::from("No configuration to check."))?,
+    };
+
+    load_config(&mut Interpreter::new(), &config_path)?;
+
+    println!("Configuration {:?} is valid.", config_path);
+
+    Ok(())
+}
+
+fn format_stdin(check: bool) -> Result<(), String> {
+    let mut code = String::new();
+
+    std::io::stdin()
+        .read_to_string(&mut code)
+        .map_err(|error| format!("Error reading stdin: {}.", error))?;
+
+    let formatted_code = crate::format_code(&code)
+        .map_err(|error| format!("Error formatting stdin: {}.", error))?;
+
+    if !check {
+        print!("{}", formatted_code);
+    } else if formatted_code != code {
+        return Err(String::from("Stdin is not formatted."));
+    }
+
+    Ok(())
+}
+
+// with `--check', files are left untouched and the ones that would change
+// are printed
+fn run_format(paths: &[String], check: bool) -> Result<(), String> {
+    if paths.is_empty() {
+        return format_stdin(check);
+    }
+
+    let mut unformatted_count = 0;
+
+    for path in paths {
+        let code = std::fs::read_to_string(path)
+            .map_err(|error| format!("Error reading {:?}: {}.", path, error))?;
+
+        let formatted_code = crate::format_code(&code).map_err(|error| {
+            format!("Error formatting {:?}: {}.", path, error)
+        })?;
+
+        if formatted_code == code {
+            continue;
+        }
+
+        if check {
+            println!("{}", path);
+            unformatted_count += 1;
+        } else {
+            std::fs::write(path, formatted_code).map_err(|error| {
+                format!("Error writing {:?}: {}.", path, error)
+            })?;
+        }
+    }
+
+    if unformatted_count > 0 {
+        Err(format!("{} file(s) are not formatted.", unformatted_count))
+    } else {
+        Ok(())
+    }
+}
+
+fn lint_source(path: &str, code: &str) -> Result<usize, String> {
+    let diagnostics = crate::lint_code(code)
+        .map_err(|error| format!("Error linting {:?}: {}.", path, error))?;
+
+    for diagnostic in &diagnostics {
+        println!("{}", diagnostic.format(path, code));
+    }
+
+    Ok(diagnostics.len())
+}
+
+// diagnostics go to stdout one per line, so the command fails only after
+// every file was linted
+fn run_lint(paths: &[String]) -> Result<(), String> {
+    let mut diagnostic_count = 0;
+
+    if paths.is_empty() {
+        let mut code = String::new();
+
+        std::io::stdin()
+            .read_to_string(&mut code)
+            .map_err(|error| format!("Error reading stdin: {}.", error))?;
+
+        diagnostic_count += lint_source("<stdin>", &code)?;
+    }
+
+    for path in paths {
+        let code = std::fs::read_to_string(path)
+            .map_err(|error| format!("Error reading {:?}: {}.", path, error))?;
+
+        diagnostic_count += lint_source(path, &code)?;
+    }
+
+    if diagnostic_count > 0 {
+        Err(format!("{} problem(s) found.", diagnostic_count))
+    } else {
+        Ok(())
+    }
+}
+
+// stdout carries the protocol, so nothing else may be printed there
+fn run_lsp() -> Result<(), String> {
+    let stdin = std::io::stdin();
+    let stdout = std::io::stdout();
+
+    crate::lsp::run_language_server(stdin.lock(), stdout.lock())
+}
+
+pub fn run(arguments: Vec<String>) -> i32 {
+    let arguments = match CliArguments::parse(arguments) {
+        Ok(arguments) => arguments,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            return EXIT_USAGE;
+        },
+    };
+
+    let config_source = arguments.get_config_source();
+
+    let result = match arguments.get_command() {
+        CliCommand::Repl => run_repl(config_source),
+        CliCommand::Run(script_path) => run_script(config_source, script_path),
+        CliCommand::Eval(code) => run_eval(config_source, code),
+        CliCommand::Daemon => run_daemon(config_source),
+        CliCommand::Check(config_path) => run_check(config_source, config_path),
+        CliCommand::Format(paths, check) => run_format(paths, *check),
+        CliCommand::Lint(paths) => run_lint(paths),
+        CliCommand::Lsp => run_lsp(),
+        CliCommand::SaveImage(image_path) => {
+            run_save_image(config_source, image_path)
+        },
+        CliCommand::Help => {
+            println!("{}", USAGE);
+            Ok(())
+        },
+    };
+
+    match result {
+        Ok(()) => EXIT_SUCCESS,
+        Err(message) => {
+            eprintln!("{}", message);
+            EXIT_FAILURE
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    use crate::utils;
+
+    fn run_with(arguments: Vec<&str>) -> i32 {
+        run(arguments.into_iter().map(|s| String::from(s)).collect())
+    }
+
+    #[test]
+    fn returns_usage_exit_code_on_invalid_arguments() {
+        nia_assert_equal(EXIT_USAGE, run_with(vec!["--unknown"]));
+    }
+
+    #[test]
+    fn evaluates_code() {
+        nia_assert_equal(
+            EXIT_SUCCESS,
+            run_with(vec!["--no-config", "-e", "(+ 1 2)"]),
+        );
+        nia_assert_equal(
+            EXIT_FAILURE,
+            run_with(vec!["--no-config", "-e", "(not-defined)"]),
+        );
+    }
+
+    #[test]
+    fn runs_scripts_and_checks_configs() {
+        utils::with_tempdir(|directory| {
+            let valid_path = format!("{}/valid.nia", directory);
+            let invalid_path = format!("{}/invalid.nia", directory);
+
+            std::fs::write(&valid_path, "(defv a 1)").unwrap();
+            std::fs::write(&invalid_path, "(not-defined)").unwrap();
+
+            let valid_path = valid_path.as_str();
+            let invalid_path = invalid_path.as_str();
+
+            nia_assert_equal(
+                EXIT_SUCCESS,
+                run_with(vec!["--no-config", "run", valid_path]),
+            );
+            nia_assert_equal(
+                EXIT_FAILURE,
+                run_with(vec!["--no-config", invalid_path]),
+            );
+            nia_assert_equal(EXIT_SUCCESS, run_with(vec!["check", valid_path]));
+            nia_assert_equal(
+                EXIT_FAILURE,
+                run_with(vec!["check", invalid_path]),
+            );
+            nia_assert_equal(
+                EXIT_FAILURE,
+                run_with(vec!["--config", invalid_path, "-e", "1"]),
+            );
+            nia_assert_equal(
+                EXIT_FAILURE,
+                run_with(vec!["--no-config", "check"]),
+            );
+        })
+    }
+
+    #[test]
+    fn saves_and_starts_from_images() {
+        utils::with_tempdir(|directory| {
+            let config_path = format!("{}/config.nia", directory);
+            let image_path = format!("{}/nia.image", directory);
+
+            std::fs::write(&config_path, "(defv nia-image-test 1)").unwrap();
+
+            let config_path = config_path.as_str();
+            let image_path = image_path.as_str();
+
+            nia_assert_equal(
+                EXIT_SUCCESS,
+                run_with(vec![
+                    "--config",
+                    config_path,
+                    "save-image",
+                    image_path,
+                ]),
+            );
+            nia_assert_equal(
+                EXIT_SUCCESS,
+                run_with(vec!["--image", image_path, "-e", "nia-image-test"]),
+            );
+            nia_assert_equal(
+                EXIT_FAILURE,
+                run_with(vec!["--image", config_path, "-e", "1"]),
+            );
+        })
+    }
+
+    #[test]
+    fn formats_files() {
+        utils::with_tempdir(|directory| {
+            let path = format!("{}/config.nia", directory);
+
+            std::fs::write(&path, "(defn f (a)\n(+ a   1))").unwrap();
+
+            nia_assert_equal(
+                EXIT_FAILURE,
+                run_with(vec!["fmt", "--check", path.as_str()]),
+            );
+            nia_assert_equal(
+                EXIT_SUCCESS,
+                run_with(vec!["fmt", path.as_str()]),
+            );
+            nia_assert_equal(
+                String::from("(defn f (a)\n  (+ a 1))\n"),
+                std::fs::read_to_string(&path).unwrap(),
+            );
+            nia_assert_equal(
+                EXIT_SUCCESS,
+                run_with(vec!["fmt", "--check", path.as_str()]),
+            );
+
+            std::fs::write(&path, "(defn f (a)").unwrap();
+
+            nia_assert_equal(
+                EXIT_FAILURE,
+                run_with(vec!["fmt", path.as_str()]),
+            );
+        })
+    }
+    #[test]
+    fn lints_files() {
+        utils::with_tempdir(|directory| {
+            let valid_path = format!("{}/valid.nia", directory);
+            let invalid_path = format!("{}/invalid.nia", directory);
+
+            std::fs::write(&valid_path, "(defn f (a) (+ a 1))\n(f 1)").unwrap();
+            std::fs::write(&invalid_path, "(defn f (a) (+ a b))").unwrap();
+
+            nia_assert_equal(
+                EXIT_SUCCESS,
+                run_with(vec!["lint", valid_path.as_str()]),
+            );
+            nia_assert_equal(
+                EXIT_FAILURE,
+                run_with(vec![
+                    "lint",
+                    valid_path.as_str(),
+                    invalid_path.as_str(),
+                ]),
+            );
+            nia_assert_equal(
+                EXIT_FAILURE,
+                run_with(vec!["lint", "not-existing.nia"]),
+            );
+        })
+    }
+}
@@ -0,0 +1,5 @@
+mod cli_arguments;
+mod commands;
+
+pub use cli_arguments::*;
+pub use commands::*;
@@ -0,0 +1,245 @@
+use std::path::{Path, PathBuf};
+
+use crate::Action;
+use crate::Capability;
+use crate::Error;
+use crate::ImageItem;
+use crate::ImageReader;
+use crate::ImageWriter;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Capabilities {
+    os_commands: bool,
+    uinput_actions: bool,
+    listening_control: bool,
+    // `None` allows importing files from anywhere
+    import_roots: Option<Vec<PathBuf>>,
+}
+
+impl Capabilities {
+    pub fn all() -> Capabilities {
+        Capabilities {
+            os_commands: true,
+            uinput_actions: true,
+            listening_control: true,
+            import_roots: None,
+        }
+    }
+
+    pub fn none() -> Capabilities {
+        Capabilities {
+            os_commands: false,
+            uinput_actions: false,
+            listening_control: false,
+            import_roots: Some(Vec::new()),
+        }
+    }
+
+    pub fn with_os_commands(mut self, allowed: bool) -> Capabilities {
+        self.os_commands = allowed;
+        self
+    }
+
+    pub fn with_uinput_actions(mut self, allowed: bool) -> Capabilities {
+        self.uinput_actions = allowed;
+        self
+    }
+
+    pub fn with_listening_control(mut self, allowed: bool) -> Capabilities {
+        self.listening_control = allowed;
+        self
+    }
+
+    pub fn with_import_root<P>(mut self, import_root: P) -> Capabilities
+    where
+        P: Into<PathBuf>,
+    {
+        let import_root = import_root.into();
+
+        match &mut self.import_roots {
+            Some(import_roots) => import_roots.push(import_root),
+            None => self.import_roots = Some(vec![import_root]),
+        }
+
+        self
+    }
+
+    pub fn is_allowed(&self, capability: Capability) -> bool {
+        match capability {
+            Capability::OsCommands => self.os_commands,
+            Capability::UInputActions => self.uinput_actions,
+            Capability::ListeningControl => self.listening_control,
+        }
+    }
+
+    pub fn is_import_allowed(&self, module_path: &str) -> bool {
+        let import_roots = match &self.import_roots {
+            Some(import_roots) => import_roots,
+            None => return true,
+        };
+
+        // paths are canonicalized, so that `..' cannot leave an import root
+        let module_path = match Path::new(module_path).canonicalize() {
+            Ok(module_path) => module_path,
+            Err(_) => return false,
+        };
+
+        import_roots.iter().any(|import_root| {
+            match import_root.canonicalize() {
+                Ok(import_root) => module_path.starts_with(import_root),
+                Err(_) => false,
+            }
+        })
+    }
+
+    pub fn check(&self, capability: Capability) -> Result<(), Error> {
+        if self.is_allowed(capability) {
+            Ok(())
+        } else {
+            Error::permission_denied_error(format!(
+                "Capability `{}' is not granted.",
+                capability.get_name()
+            ))
+            .into()
+        }
+    }
+
+    pub fn check_import(&self, module_path: &str) -> Result<(), Error> {
+        if self.is_import_allowed(module_path) {
+            Ok(())
+        } else {
+            Error::permission_denied_error(format!(
+                "Importing \"{}\" is not allowed.",
+                module_path
+            ))
+            .into()
+        }
+    }
+
+    // Composite actions are checked when their parts are dispatched.
+    pub fn check_action(&self, action: &Action) -> Result<(), Error> {
+        match action {
+            Action::KeyPress(_)
+            | Action::KeyClick(_)
+            | Action::KeyRelease(_)
+            | Action::MouseButtonPress(_)
+            | Action::MouseButtonClick(_)
+            | Action::MouseButtonRelease(_)
+            | Action::TextKeyClick(_)
+            | Action::NumberKeyClick(_)
+            | Action::FunctionKeyClick(_)
+            | Action::ControlKeyClick(_)
+            | Action::KPKeyClick(_)
+            | Action::MultimediaKeyClick(_)
+            | Action::MouseButtonKeyClick(_)
+            | Action::MouseAbsoluteMove(_, _)
+            | Action::MouseRelativeMove(_, _)
+            | Action::TextType(_) => self.check(Capability::UInputActions),
+            Action::ExecuteOSCommand(_) => self.check(Capability::OsCommands),
+            Action::ExecuteCode(_)
+            | Action::ExecuteFunction(_)
+            | Action::ExecuteNamedAction(_)
+            | Action::ExecuteFunctionValue(_)
+            | Action::Wait(_)
+            | Action::Sequence(_)
+            | Action::Repeat(_, _)
+            | Action::WithHeld(_, _) => Ok(()),
+        }
+    }
+}
+
+impl ImageItem for Capabilities {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write(&self.os_commands)?;
+        writer.write(&self.uinput_actions)?;
+        writer.write(&self.listening_control)?;
+        writer.write(&self.import_roots)
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        let os_commands = reader.read()?;
+        let uinput_actions = reader.read()?;
+        let listening_control = reader.read()?;
+        let import_roots = reader.read()?;
+
+        Ok(Capabilities {
+            os_commands,
+            uinput_actions,
+            listening_control,
+            import_roots,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[allow(unused_imports)]
+    use crate::utils;
+
+    #[test]
+    fn grants_capabilities() {
+        let capabilities = Capabilities::none()
+            .with_os_commands(true)
+            .with_listening_control(true);
+
+        nia_assert(capabilities.is_allowed(Capability::OsCommands));
+        nia_assert(!capabilities.is_allowed(Capability::UInputActions));
+        nia_assert(capabilities.is_allowed(Capability::ListeningControl));
+
+        nia_assert_is_ok(&capabilities.check(Capability::OsCommands));
+        utils::assert_permission_denied_error(
+            &capabilities.check(Capability::UInputActions),
+        );
+    }
+
+    #[test]
+    fn checks_actions() {
+        let capabilities = Capabilities::none().with_uinput_actions(true);
+
+        nia_assert_is_ok(&capabilities.check_action(&Action::KeyPress(1)));
+        nia_assert_is_ok(
+            &capabilities.check_action(&Action::TextType(String::from("a"))),
+        );
+        nia_assert_is_ok(&capabilities.check_action(&Action::Wait(1)));
+        utils::assert_permission_denied_error(
+            &capabilities
+                .check_action(&Action::ExecuteOSCommand(String::from("ls"))),
+        );
+
+        let capabilities = Capabilities::none();
+
+        utils::assert_permission_denied_error(
+            &capabilities.check_action(&Action::MouseRelativeMove(1, 1)),
+        );
+    }
+
+    #[test]
+    fn allows_imports_only_from_import_roots() {
+        utils::with_tempdir(|directory| {
+            let module_path = format!("{}/module.nia", directory);
+
+            std::fs::write(&module_path, "").unwrap();
+
+            nia_assert(Capabilities::all().is_import_allowed(&module_path));
+            nia_assert(!Capabilities::none().is_import_allowed(&module_path));
+
+            let capabilities =
+                Capabilities::none().with_import_root(directory.clone());
+
+            nia_assert(capabilities.is_import_allowed(&module_path));
+            nia_assert(
+                !capabilities
+                    .is_import_allowed(&format!("{}/../module.nia", directory)),
+            );
+            utils::assert_permission_denied_error(
+                &Capabilities::none().check_import(&module_path),
+            );
+        });
+    }
+}
@@ -0,0 +1,16 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Capability {
+    OsCommands,
+    UInputActions,
+    ListeningControl,
+}
+
+impl Capability {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Capability::OsCommands => "os-commands",
+            Capability::UInputActions => "uinput-actions",
+            Capability::ListeningControl => "listening-control",
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod capabilities;
+mod capability;
+
+pub use capabilities::*;
+pub use capability::*;
@@ -0,0 +1,387 @@
+use std::path::Path;
+
+use crate::EnvironmentId;
+use crate::Error;
+use crate::Interpreter;
+use crate::Value;
+
+use super::Completion;
+
+fn is_delimiter(character: char) -> bool {
+    character.is_whitespace()
+        || match character {
+            '(' | ')' | '{' | '}' | '[' | ']' | '\'' | '`' | ',' | '"' => true,
+            _ => false,
+        }
+}
+
+// returns the index right after the opening quote of the string literal
+// that is not closed before the end of the code
+fn find_open_string_start(code: &str) -> Option<usize> {
+    let mut string_start = None;
+    let mut escaped = false;
+
+    for (index, character) in code.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if character == '\\' && string_start.is_some() {
+            escaped = true;
+        } else if character == '"' {
+            string_start = match string_start {
+                Some(_) => None,
+                None => Some(index + 1),
+            };
+        }
+    }
+
+    string_start
+}
+
+fn is_inside_import(code_before_string: &str) -> bool {
+    match code_before_string.rfind("(import") {
+        Some(index) => !code_before_string[index..].contains(')'),
+        None => false,
+    }
+}
+
+fn push_environment_names(
+    interpreter: &Interpreter,
+    environment_id: EnvironmentId,
+    names: &mut Vec<String>,
+) -> Result<(), Error> {
+    let environment = interpreter
+        .get_environment_arena()
+        .get_environment(environment_id)?;
+
+    for symbol_id in environment
+        .get_functions()
+        .keys()
+        .chain(environment.get_variables().keys())
+    {
+        names.push(interpreter.get_symbol_name(*symbol_id)?.clone());
+    }
+
+    Ok(())
+}
+
+fn complete_names(
+    interpreter: &Interpreter,
+    prefix: &str,
+) -> Result<Vec<String>, Error> {
+    let mut names = Vec::new();
+
+    push_environment_names(
+        interpreter,
+        interpreter.get_main_environment_id(),
+        &mut names,
+    )?;
+    push_environment_names(
+        interpreter,
+        interpreter.get_root_environment_id(),
+        &mut names,
+    )?;
+
+    names.retain(|name| name.starts_with(prefix) && !name.starts_with("#"));
+
+    Ok(names)
+}
+
+fn complete_keywords(
+    interpreter: &Interpreter,
+    prefix: &str,
+) -> Result<Vec<String>, Error> {
+    let keyword_arena = interpreter.get_keyword_arena();
+    let mut keywords = Vec::new();
+
+    for keyword_id in keyword_arena.get_all_keyword_identifiers() {
+        let keyword_name = keyword_arena.get_keyword(keyword_id)?.get_name();
+
+        if keyword_name.starts_with(prefix) {
+            keywords.push(format!(":{}", keyword_name));
+        }
+    }
+
+    Ok(keywords)
+}
+
+fn complete_object_members(
+    interpreter: &mut Interpreter,
+    object_name: &str,
+    prefix: &str,
+) -> Result<Vec<String>, Error> {
+    let main_environment_id = interpreter.get_main_environment_id();
+    let object_symbol_id =
+        match interpreter.find_interned_symbol_id(object_name) {
+            Some(symbol_id) => symbol_id,
+            None => return Ok(Vec::new()),
+        };
+
+    let object_id = match interpreter
+        .lookup_variable(main_environment_id, object_symbol_id)?
+    {
+        Some(Value::Object(object_id)) => object_id,
+        _ => return Ok(Vec::new()),
+    };
+
+    let mut members = Vec::new();
+
+    for property_symbol_id in
+        interpreter.get_object(object_id)?.get_properties().keys()
+    {
+        let property_name = interpreter.get_symbol_name(*property_symbol_id)?;
+
+        if property_name.starts_with(prefix) {
+            members.push(format!("{}:{}", object_name, property_name));
+        }
+    }
+
+    Ok(members)
+}
+
+// paths are resolved the same way `import' resolves relative module paths
+fn complete_paths(
+    interpreter: &mut Interpreter,
+    prefix: &str,
+) -> Result<Vec<String>, Error> {
+    let (directory_part, file_prefix) = match prefix.rfind('/') {
+        Some(index) => (&prefix[..index + 1], &prefix[index + 1..]),
+        None => ("", prefix),
+    };
+
+    let current_module_path =
+        interpreter.get_current_module().get_path().clone();
+
+    let directory = crate::utils::resolve_path_with_current_module_path(
+        current_module_path,
+        String::from(directory_part),
+    )?;
+    let directory = if directory.is_empty() {
+        String::from(".")
+    } else {
+        directory
+    };
+
+    let entries = match Path::new(&directory).read_dir() {
+        Ok(entries) => entries,
+        Err(_) => return Ok(Vec::new()),
+    };
+
+    let mut paths = Vec::new();
+
+    for entry in entries.filter_map(|entry| entry.ok()) {
+        let file_name = match entry.file_name().into_string() {
+            Ok(file_name) => file_name,
+            Err(_) => continue,
+        };
+
+        if !file_name.starts_with(file_prefix)
+            || (file_name.starts_with('.') && !file_prefix.starts_with('.'))
+        {
+            continue;
+        }
+
+        let suffix = if entry.path().is_dir() { "/" } else { "" };
+
+        paths.push(format!("{}{}{}", directory_part, file_name, suffix));
+    }
+
+    Ok(paths)
+}
+
+pub fn complete_code(
+    interpreter: &mut Interpreter,
+    code: &str,
+    position: usize,
+) -> Result<Completion, Error> {
+    if position > code.len() || !code.is_char_boundary(position) {
+        return Error::invalid_argument_error(
+            "Completion position is not in the code.",
+        )
+        .into();
+    }
+
+    let code = &code[..position];
+
+    if let Some(string_start) = find_open_string_start(code) {
+        let candidates = if is_inside_import(&code[..string_start]) {
+            complete_paths(interpreter, &code[string_start..])?
+        } else {
+            Vec::new()
+        };
+
+        return Ok(make_completion(string_start, candidates));
+    }
+
+    let start = code
+        .char_indices()
+        .rev()
+        .find(|(_, character)| is_delimiter(*character))
+        .map(|(index, character)| index + character.len_utf8())
+        .unwrap_or(0);
+    let word = &code[start..];
+
+    let candidates = if word.starts_with(':') {
+        complete_keywords(interpreter, &word[1..])?
+    } else if let Some(separator_index) = word.find(':') {
+        complete_object_members(
+            interpreter,
+            &word[..separator_index],
+            &word[separator_index + 1..],
+        )?
+    } else if word.is_empty() {
+        Vec::new()
+    } else {
+        complete_names(interpreter, word)?
+    };
+
+    Ok(make_completion(start, candidates))
+}
+
+fn make_completion(start: usize, mut candidates: Vec<String>) -> Completion {
+    candidates.sort();
+    candidates.dedup();
+
+    Completion::new(start, candidates)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    use crate::utils;
+
+    fn assert_completes(
+        interpreter: &mut Interpreter,
+        code: &str,
+        expected_start: usize,
+        expected_candidate: &str,
+    ) {
+        let completion = complete_code(interpreter, code, code.len()).unwrap();
+
+        nia_assert_equal(expected_start, completion.get_start());
+        nia_assert(
+            completion
+                .get_candidates()
+                .contains(&String::from(expected_candidate)),
+        );
+    }
+
+    #[test]
+    fn completes_functions_and_variables() {
+        let mut interpreter = Interpreter::new();
+
+        interpreter
+            .execute_in_main_environment(
+                "(defv completion-variable 1) (defn completion-function () 1)",
+            )
+            .unwrap();
+
+        assert_completes(
+            &mut interpreter,
+            "(+ 1 complet",
+            5,
+            "completion-variable",
+        );
+        assert_completes(
+            &mut interpreter,
+            "(complet",
+            1,
+            "completion-function",
+        );
+        assert_completes(&mut interpreter, "(defn", 1, "defn");
+    }
+
+    #[test]
+    fn completes_members_of_builtin_objects() {
+        let mut interpreter = Interpreter::new();
+
+        assert_completes(&mut interpreter, "(string:jo", 1, "string:join");
+        assert_completes(&mut interpreter, "(list:", 1, "list:map");
+        assert_completes(&mut interpreter, "(device:", 1, "device:define");
+        assert_completes(
+            &mut interpreter,
+            "(action:",
+            1,
+            "action:send-key-press",
+        );
+    }
+
+    #[test]
+    fn does_not_intern_names_of_unknown_objects() {
+        let mut interpreter = Interpreter::new();
+
+        let completion =
+            complete_code(&mut interpreter, "(unknown-object:", 16).unwrap();
+
+        nia_assert(completion.get_candidates().is_empty());
+        nia_assert_equal(
+            None,
+            interpreter.find_interned_symbol_id("unknown-object"),
+        );
+    }
+
+    #[test]
+    fn completes_keywords() {
+        let mut interpreter = Interpreter::new();
+
+        interpreter
+            .execute_in_main_environment(":completion-keyword")
+            .unwrap();
+
+        assert_completes(
+            &mut interpreter,
+            "(f :complet",
+            3,
+            ":completion-keyword",
+        );
+    }
+
+    #[test]
+    fn completes_paths_inside_imports() {
+        utils::with_tempdir(|directory| {
+            let mut interpreter = Interpreter::new();
+
+            std::fs::create_dir(format!("{}/modules", directory)).unwrap();
+            std::fs::write(format!("{}/modules/keys.nia", directory), "")
+                .unwrap();
+
+            let code = format!("(import \"{}/mo", directory);
+            assert_completes(
+                &mut interpreter,
+                &code,
+                9,
+                &format!("{}/modules/", directory),
+            );
+
+            let code =
+                format!("(import #{{:a}} from \"{}/modules/k", directory);
+            assert_completes(
+                &mut interpreter,
+                &code,
+                20,
+                &format!("{}/modules/keys.nia", directory),
+            );
+        })
+    }
+
+    #[test]
+    fn does_not_complete_inside_other_strings() {
+        let mut interpreter = Interpreter::new();
+
+        let completion = complete_code(&mut interpreter, "(f \"de", 6).unwrap();
+
+        nia_assert_equal(Completion::empty(4), completion);
+    }
+
+    #[test]
+    fn returns_invalid_argument_error_when_position_is_out_of_code() {
+        let mut interpreter = Interpreter::new();
+
+        let result = complete_code(&mut interpreter, "(f", 10);
+
+        utils::assert_invalid_argument_error(&result);
+    }
+}
@@ -0,0 +1,27 @@
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Completion {
+    start: usize,
+    candidates: Vec<String>,
+}
+
+impl Completion {
+    pub fn new(start: usize, candidates: Vec<String>) -> Completion {
+        Completion { start, candidates }
+    }
+
+    pub fn empty(start: usize) -> Completion {
+        Completion::new(start, Vec::new())
+    }
+
+    pub fn get_start(&self) -> usize {
+        self.start
+    }
+
+    pub fn get_candidates(&self) -> &Vec<String> {
+        &self.candidates
+    }
+
+    pub fn take_candidates(self) -> Vec<String> {
+        self.candidates
+    }
+}
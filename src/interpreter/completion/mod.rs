@@ -0,0 +1,5 @@
+mod complete_code;
+mod completion;
+
+pub use complete_code::*;
+pub use completion::*;
@@ -1,4 +1,7 @@
 use crate::interpreter::error::Error;
+use crate::interpreter::image::ImageItem;
+use crate::interpreter::image::ImageReader;
+use crate::interpreter::image::ImageWriter;
 use crate::interpreter::value::SymbolId;
 use crate::interpreter::value::Value;
 use std::collections::HashMap;
@@ -57,6 +60,18 @@ impl Context {
     }
 }
 
+impl ImageItem for Context {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write(&self.values)
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        let values = reader.read()?;
+
+        Ok(Context { values })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,152 @@
+use super::builtin_functions::BUILTIN_FUNCTIONS_DOCUMENTATION;
+use super::builtin_objects::BUILTIN_OBJECTS_DOCUMENTATION;
+use super::core::CORE_DOCUMENTATION;
+use super::special_forms::SPECIAL_FORMS_DOCUMENTATION;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BuiltinDocumentation {
+    name: &'static str,
+    signature: &'static str,
+    documentation: &'static str,
+}
+
+impl BuiltinDocumentation {
+    fn new(
+        (name, signature, documentation): (
+            &'static str,
+            &'static str,
+            &'static str,
+        ),
+    ) -> BuiltinDocumentation {
+        BuiltinDocumentation {
+            name,
+            signature,
+            documentation,
+        }
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn get_signature(&self) -> &'static str {
+        self.signature
+    }
+
+    pub fn get_documentation(&self) -> &'static str {
+        self.documentation
+    }
+}
+
+pub fn get_builtin_documentations() -> Vec<BuiltinDocumentation> {
+    SPECIAL_FORMS_DOCUMENTATION
+        .iter()
+        .chain(BUILTIN_FUNCTIONS_DOCUMENTATION.iter())
+        .chain(BUILTIN_OBJECTS_DOCUMENTATION.iter())
+        .chain(CORE_DOCUMENTATION.iter())
+        .map(|entry| BuiltinDocumentation::new(*entry))
+        .collect()
+}
+
+pub fn get_builtin_documentation(name: &str) -> Option<BuiltinDocumentation> {
+    get_builtin_documentations()
+        .into_iter()
+        .find(|documentation| documentation.get_name() == name)
+}
+
+// core macros like `defn' and `if' read as special forms in code
+pub fn is_special_form_name(name: &str) -> bool {
+    SPECIAL_FORMS_DOCUMENTATION
+        .iter()
+        .chain(CORE_DOCUMENTATION.iter())
+        .any(|(documented_name, _, _)| {
+            *documented_name == name && !name.contains(':')
+        })
+}
+
+pub fn is_builtin_object_name(name: &str) -> bool {
+    BUILTIN_OBJECTS_DOCUMENTATION
+        .iter()
+        .any(|(documented_name, _, _)| {
+            documented_name.split(':').next() == Some(name)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    use crate::BuiltinRegistry;
+    use crate::Interpreter;
+
+    #[test]
+    fn finds_documentation_by_name() {
+        let documentation = get_builtin_documentation("list:nth").unwrap();
+
+        nia_assert_equal("list:nth", documentation.get_name());
+        nia_assert_equal(
+            "(list:nth index list)",
+            documentation.get_signature(),
+        );
+        nia_assert(get_builtin_documentation("not-a-builtin").is_none());
+    }
+
+    #[test]
+    fn recognizes_special_forms_and_builtin_objects() {
+        nia_assert(is_special_form_name("let"));
+        nia_assert(is_special_form_name("defn"));
+        nia_assert(!is_special_form_name("func:bind"));
+        nia_assert(!is_special_form_name("list:map"));
+
+        nia_assert(is_builtin_object_name("list"));
+        nia_assert(is_builtin_object_name("device"));
+        nia_assert(!is_builtin_object_name("list:map"));
+        nia_assert(!is_builtin_object_name("let"));
+    }
+
+    #[test]
+    fn names_are_unique() {
+        let documentations = get_builtin_documentations();
+
+        for (index, documentation) in documentations.iter().enumerate() {
+            nia_assert(
+                documentations[index + 1..]
+                    .iter()
+                    .all(|other| other.get_name() != documentation.get_name()),
+            );
+        }
+    }
+
+    #[test]
+    fn documents_every_builtin_function_and_special_form() {
+        let registry = BuiltinRegistry::from_stdlib();
+
+        for name in registry
+            .get_builtin_function_names()
+            .into_iter()
+            .chain(registry.get_special_form_names().into_iter())
+        {
+            if name.starts_with("#") {
+                continue;
+            }
+
+            nia_assert(get_builtin_documentation(name).is_some());
+        }
+    }
+
+    #[test]
+    fn every_documentation_names_a_definition() {
+        let mut interpreter = Interpreter::new();
+        let nil = interpreter.intern_nil_symbol_value();
+
+        for documentation in get_builtin_documentations() {
+            let code = format!("(describe \"{}\")", documentation.get_name());
+            let result = interpreter.execute_in_main_environment(&code);
+
+            nia_assert_nequal(nil, result.unwrap());
+        }
+    }
+}
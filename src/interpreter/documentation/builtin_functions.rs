@@ -0,0 +1,196 @@
+pub const BUILTIN_FUNCTIONS_DOCUMENTATION: &'static [(
+    &'static str,
+    &'static str,
+    &'static str,
+)] = &[
+    (
+        "!=",
+        "(!= value-1 value-2)",
+        "Returns #t when values are not identical. Alias of `neq?'.",
+    ),
+    (
+        "!==",
+        "(!== value-1 value-2)",
+        "Returns #t when values are not structurally equal. Alias of `nequal?'.",
+    ),
+    (
+        "%",
+        "(% dividend divisor)",
+        "Returns the remainder of dividing dividend by divisor.",
+    ),
+    (
+        "*",
+        "(* number-1 number-2 #rest numbers)",
+        "Returns the product of numbers.",
+    ),
+    (
+        "+",
+        "(+ number-1 number-2 #rest numbers)",
+        "Returns the sum of numbers.",
+    ),
+    (
+        "-",
+        "(- number-1 #opt number-2)",
+        "Returns the negation of number-1, or the difference of number-1 and number-2.",
+    ),
+    (
+        "/",
+        "(/ dividend divisor)",
+        "Returns dividend divided by divisor.",
+    ),
+    (
+        "<",
+        "(< number-1 number-2 #rest numbers)",
+        "Returns #t when numbers are strictly increasing.",
+    ),
+    (
+        "<=",
+        "(<= number-1 number-2 #rest numbers)",
+        "Returns #t when numbers are not decreasing.",
+    ),
+    (
+        "=",
+        "(= value-1 value-2)",
+        "Returns #t when values are identical. Alias of `eq?'.",
+    ),
+    (
+        "==",
+        "(== value-1 value-2)",
+        "Returns #t when values are structurally equal. Alias of `equal?'.",
+    ),
+    (
+        ">",
+        "(> number-1 number-2 #rest numbers)",
+        "Returns #t when numbers are strictly decreasing.",
+    ),
+    (
+        ">=",
+        "(>= number-1 number-2 #rest numbers)",
+        "Returns #t when numbers are not increasing.",
+    ),
+    (
+        "apropos",
+        "(apropos substring)",
+        "Returns the sorted list of names of functions, variables and members of built-in objects that contain substring.",
+    ),
+    (
+        "assert",
+        "(assert value)",
+        "Throws `assertion-error' when value is #f. Value must be a boolean.",
+    ),
+    (
+        "dec",
+        "(dec integer)",
+        "Returns integer decremented by one.",
+    ),
+    (
+        "describe",
+        "(describe name)",
+        "Returns an object with `:name', `:kind', `:signature' and `:doc' describing the function, special form or variable name.",
+    ),
+    (
+        "display",
+        "(display value)",
+        "Prints the human-readable representation of value, with strings unquoted.",
+    ),
+    (
+        "doc",
+        "(doc name-or-function)",
+        "Returns the documentation string of a function, special form or variable, or nil when it is not documented.",
+    ),
+    (
+        "eq?",
+        "(eq? value-1 value-2)",
+        "Returns #t when values are identical.",
+    ),
+    (
+        "equal?",
+        "(equal? value-1 value-2)",
+        "Returns #t when values are structurally equal.",
+    ),
+    (
+        "eval",
+        "(eval value)",
+        "Evaluates value in the current environment.",
+    ),
+    (
+        "flookup",
+        "(flookup symbol)",
+        "Returns the function bound to symbol in the current environment.",
+    ),
+    (
+        "gensym",
+        "(gensym #opt name)",
+        "Returns a new unique symbol, optionally based on name.",
+    ),
+    (
+        "inc",
+        "(inc integer)",
+        "Returns integer incremented by one.",
+    ),
+    (
+        "instance-of?",
+        "(instance-of? value class)",
+        "Returns #t when class is in the prototype chain of value.",
+    ),
+    (
+        "intern",
+        "(intern string)",
+        "Returns the symbol named string.",
+    ),
+    (
+        "lookup",
+        "(lookup symbol)",
+        "Returns the value of variable symbol in the current environment.",
+    ),
+    (
+        "neq?",
+        "(neq? value-1 value-2)",
+        "Returns #t when values are not identical.",
+    ),
+    (
+        "nequal?",
+        "(nequal? value-1 value-2)",
+        "Returns #t when values are not structurally equal.",
+    ),
+    (
+        "new",
+        "(new class #rest arguments)",
+        "Constructs an instance of class by calling its constructor with arguments.",
+    ),
+    (
+        "not",
+        "(not boolean)",
+        "Returns the negation of boolean.",
+    ),
+    (
+        "pprint",
+        "(pprint value #opt width)",
+        "Prints the readable representation of value, breaking lists and objects that do not fit into width columns across indented lines.",
+    ),
+    (
+        "print",
+        "(print value)",
+        "Prints the readable representation of value. Alias of `write'.",
+    ),
+    (
+        "println",
+        "(println format #rest arguments)",
+        "Prints the formatted string followed by a newline.",
+    ),
+    (
+        "string",
+        "(string #rest values)",
+        "Returns the concatenation of string representations of values.",
+    ),
+    (
+        "type",
+        "(type value)",
+        "Returns a string naming the type of value.",
+    ),
+    (
+        "write",
+        "(write value)",
+        "Prints the readable representation of value, which reads back into an equal value when possible.",
+    ),
+];
@@ -0,0 +1,815 @@
+pub const BUILTIN_OBJECTS_DOCUMENTATION: &'static [(
+    &'static str,
+    &'static str,
+    &'static str,
+)] = &[
+    (
+        "action:pressed-keys",
+        "(action:pressed-keys)",
+        "Returns the list of currently pressed keys.",
+    ),
+    (
+        "action:pressed-mouse-buttons",
+        "(action:pressed-mouse-buttons)",
+        "Returns the list of currently pressed mouse buttons.",
+    ),
+    (
+        "action:send-execute-os-command",
+        "(action:send-execute-os-command command)",
+        "Queues execution of the operating system command string.",
+    ),
+    (
+        "action:send-key-click",
+        "(action:send-key-click key)",
+        "Queues a press and a release of key.",
+    ),
+    (
+        "action:send-key-press",
+        "(action:send-key-press key)",
+        "Queues a press of key.",
+    ),
+    (
+        "action:send-key-release",
+        "(action:send-key-release key)",
+        "Queues a release of key.",
+    ),
+    (
+        "action:send-mouse-absolute-move",
+        "(action:send-mouse-absolute-move x y)",
+        "Queues a move of the mouse pointer to coordinates x and y.",
+    ),
+    (
+        "action:send-mouse-button-click",
+        "(action:send-mouse-button-click button)",
+        "Queues a press and a release of mouse button.",
+    ),
+    (
+        "action:send-mouse-button-press",
+        "(action:send-mouse-button-press button)",
+        "Queues a press of mouse button.",
+    ),
+    (
+        "action:send-mouse-button-release",
+        "(action:send-mouse-button-release button)",
+        "Queues a release of mouse button.",
+    ),
+    (
+        "action:send-mouse-relative-move",
+        "(action:send-mouse-relative-move x y)",
+        "Queues a move of the mouse pointer by x and y.",
+    ),
+    (
+        "action:send-text-type",
+        "(action:send-text-type text)",
+        "Queues typing of text.",
+    ),
+    (
+        "action:send-wait",
+        "(action:send-wait milliseconds)",
+        "Queues a pause of milliseconds between actions.",
+    ),
+    (
+        "alist:acons",
+        "(alist:acons alist key value)",
+        "Returns alist with the pair of key and value prepended.",
+    ),
+    (
+        "alist:acons!",
+        "(alist:acons! alist-symbol key value)",
+        "Prepends the pair of key and value to the alist stored in variable alist-symbol.",
+    ),
+    (
+        "alist:has-key?",
+        "(alist:has-key? alist key)",
+        "Returns #t when alist has a pair with key.",
+    ),
+    (
+        "alist:has-value?",
+        "(alist:has-value? alist value)",
+        "Returns #t when alist has a pair with value.",
+    ),
+    (
+        "alist:lookup",
+        "(alist:lookup alist key)",
+        "Returns the value of the first pair of alist with key, or nil.",
+    ),
+    ("alist:new", "(alist:new)", "Returns an empty alist."),
+    (
+        "bit:and",
+        "(bit:and integer-1 integer-2)",
+        "Returns the bitwise and of integers.",
+    ),
+    (
+        "bit:clear",
+        "(bit:clear index integer)",
+        "Returns integer with the bit at index cleared.",
+    ),
+    (
+        "bit:flip",
+        "(bit:flip index integer)",
+        "Returns integer with the bit at index flipped.",
+    ),
+    (
+        "bit:not",
+        "(bit:not integer)",
+        "Returns the bitwise negation of integer.",
+    ),
+    (
+        "bit:or",
+        "(bit:or integer-1 integer-2)",
+        "Returns the bitwise or of integers.",
+    ),
+    (
+        "bit:set",
+        "(bit:set index integer)",
+        "Returns integer with the bit at index set.",
+    ),
+    (
+        "bit:shift-left",
+        "(bit:shift-left shift integer)",
+        "Returns integer shifted left by shift bits.",
+    ),
+    (
+        "bit:shift-right",
+        "(bit:shift-right shift integer)",
+        "Returns integer shifted right by shift bits.",
+    ),
+    (
+        "bit:test",
+        "(bit:test index integer)",
+        "Returns #t when the bit of integer at index is set.",
+    ),
+    (
+        "bit:xor",
+        "(bit:xor integer-1 integer-2)",
+        "Returns the bitwise exclusive or of integers.",
+    ),
+    ("cons:car", "(cons:car cons)", "Returns the car of cons."),
+    ("cons:cdr", "(cons:cdr cons)", "Returns the cdr of cons."),
+    (
+        "cons:new",
+        "(cons:new car cdr)",
+        "Returns a new cons of car and cdr.",
+    ),
+    (
+        "cons:set-car!",
+        "(cons:set-car! cons value)",
+        "Sets the car of cons to value.",
+    ),
+    (
+        "cons:set-cdr!",
+        "(cons:set-cdr! cons value)",
+        "Sets the cdr of cons to value.",
+    ),
+    (
+        "device:define",
+        "(device:define id path name)",
+        "Defines a keyboard device with id, device file path and name.",
+    ),
+    (
+        "device:define-emergency-stop-chord",
+        "(device:define-emergency-stop-chord chord)",
+        "Sets the key chord that stops listening immediately.",
+    ),
+    (
+        "device:define-global-mapping",
+        "(device:define-global-mapping chords action)",
+        "Maps the key chord sequence string to action.",
+    ),
+    (
+        "device:define-modifier",
+        "(device:define-modifier key alias)",
+        "Defines key as a modifier with alias string.",
+    ),
+    (
+        "device:define-remap",
+        "(device:define-remap from to)",
+        "Remaps key from to key to in recognized key chords. Remapped keys are forwarded as clicks, so they can be neither held nor repeated.",
+    ),
+    (
+        "device:get-defined-remaps",
+        "(device:get-defined-remaps)",
+        "Returns the list of defined remaps.",
+    ),
+    (
+        "device:is-listening?",
+        "(device:is-listening?)",
+        "Returns #t when devices are being listened.",
+    ),
+    (
+        "device:remove-remap",
+        "(device:remove-remap from)",
+        "Removes the remap of key chord from.",
+    ),
+    (
+        "device:start-listening",
+        "(device:start-listening)",
+        "Starts listening defined devices.",
+    ),
+    (
+        "device:stop-listening",
+        "(device:stop-listening)",
+        "Stops listening defined devices.",
+    ),
+    (
+        "env:functions",
+        "(env:functions #opt environment)",
+        "Returns the sorted list of functions defined in the `:current', `:main' or `:root' environment. Defaults to `:current'.",
+    ),
+    (
+        "env:is-const-function?",
+        "(env:is-const-function? symbol)",
+        "Returns #t when function symbol is defined as constant.",
+    ),
+    (
+        "env:is-const-variable?",
+        "(env:is-const-variable? symbol)",
+        "Returns #t when variable symbol is defined as constant.",
+    ),
+    (
+        "env:lookup-function-environment",
+        "(env:lookup-function-environment symbol)",
+        "Returns `:root', `:main', `:module', `:current' or `:enclosing' for the environment that defines function symbol, or nil.",
+    ),
+    (
+        "env:lookup-variable-environment",
+        "(env:lookup-variable-environment symbol)",
+        "Returns `:root', `:main', `:module', `:current' or `:enclosing' for the environment that defines variable symbol, or nil.",
+    ),
+    (
+        "env:variables",
+        "(env:variables #opt environment)",
+        "Returns the sorted list of variables defined in the `:current', `:main' or `:root' environment. Defaults to `:current'.",
+    ),
+    (
+        "func:always",
+        "(func:always value)",
+        "Returns a function that always returns value.",
+    ),
+    (
+        "func:apply",
+        "(func:apply function arguments)",
+        "Calls function with the list of arguments.",
+    ),
+    (
+        "func:call",
+        "(func:call function #rest arguments)",
+        "Calls function with arguments.",
+    ),
+    (
+        "func:combine",
+        "(func:combine function #rest functions)",
+        "Returns the composition of functions. The last function is called first.",
+    ),
+    ("func:f", "(func:f)", "Returns #f."),
+    ("func:id", "(func:id value)", "Returns value."),
+    ("func:t", "(func:t)", "Returns #t."),
+    (
+        "is:atom?",
+        "(is:atom? value)",
+        "Returns #t when value is not a cons.",
+    ),
+    (
+        "is:boolean?",
+        "(is:boolean? value)",
+        "Returns #t when value is a boolean.",
+    ),
+    (
+        "is:builtin?",
+        "(is:builtin? value)",
+        "Returns #t when value is a built-in function.",
+    ),
+    (
+        "is:cons?",
+        "(is:cons? value)",
+        "Returns #t when value is a cons.",
+    ),
+    (
+        "is:even?",
+        "(is:even? integer)",
+        "Returns #t when integer is even.",
+    ),
+    (
+        "is:false?",
+        "(is:false? value)",
+        "Returns #t when value is #f.",
+    ),
+    (
+        "is:float?",
+        "(is:float? value)",
+        "Returns #t when value is a float.",
+    ),
+    (
+        "is:function?",
+        "(is:function? value)",
+        "Returns #t when value is a function.",
+    ),
+    (
+        "is:int?",
+        "(is:int? value)",
+        "Returns #t when value is an integer.",
+    ),
+    (
+        "is:interpreted?",
+        "(is:interpreted? value)",
+        "Returns #t when value is an interpreted function.",
+    ),
+    (
+        "is:keyword?",
+        "(is:keyword? value)",
+        "Returns #t when value is a keyword.",
+    ),
+    (
+        "is:list?",
+        "(is:list? value)",
+        "Returns #t when value is a proper list.",
+    ),
+    (
+        "is:macro?",
+        "(is:macro? value)",
+        "Returns #t when value is a macro.",
+    ),
+    (
+        "is:negative?",
+        "(is:negative? number)",
+        "Returns #t when number is less than zero.",
+    ),
+    ("is:nil?", "(is:nil? value)", "Returns #t when value is nil."),
+    (
+        "is:number?",
+        "(is:number? value)",
+        "Returns #t when value is an integer or a float.",
+    ),
+    (
+        "is:object?",
+        "(is:object? value)",
+        "Returns #t when value is an object.",
+    ),
+    ("is:odd?", "(is:odd? integer)", "Returns #t when integer is odd."),
+    (
+        "is:positive?",
+        "(is:positive? number)",
+        "Returns #t when number is greater than zero.",
+    ),
+    (
+        "is:special?",
+        "(is:special? value)",
+        "Returns #t when value is a special form.",
+    ),
+    (
+        "is:string?",
+        "(is:string? value)",
+        "Returns #t when value is a string.",
+    ),
+    (
+        "is:symbol?",
+        "(is:symbol? value)",
+        "Returns #t when value is a symbol.",
+    ),
+    ("is:true?", "(is:true? value)", "Returns #t when value is #t."),
+    (
+        "is:zero?",
+        "(is:zero? number)",
+        "Returns #t when number is zero.",
+    ),
+    (
+        "list:all?",
+        "(list:all? predicate list)",
+        "Returns #t when predicate returns #t for every item of list.",
+    ),
+    (
+        "list:any?",
+        "(list:any? predicate list)",
+        "Returns #t when predicate returns #t for an item of list.",
+    ),
+    (
+        "list:append",
+        "(list:append value list)",
+        "Returns list with value appended.",
+    ),
+    (
+        "list:aperture",
+        "(list:aperture size list)",
+        "Returns the list of consecutive sublists of list of length size.",
+    ),
+    (
+        "list:contains?",
+        "(list:contains? value list)",
+        "Returns #t when list has an item equal to value.",
+    ),
+    (
+        "list:filter",
+        "(list:filter predicate list)",
+        "Returns items of list for which predicate returns #t.",
+    ),
+    (
+        "list:fold",
+        "(list:fold function list initial)",
+        "Reduces list from the first item with function of accumulator and item, starting with initial.",
+    ),
+    (
+        "list:foldl",
+        "(list:foldl function list initial)",
+        "Reduces list from the last item with function of accumulator and item, starting with initial.",
+    ),
+    (
+        "list:head",
+        "(list:head list)",
+        "Returns the first item of list.",
+    ),
+    (
+        "list:init",
+        "(list:init list)",
+        "Returns list without its last item.",
+    ),
+    (
+        "list:join",
+        "(list:join list-1 list-2)",
+        "Returns the concatenation of lists.",
+    ),
+    (
+        "list:last",
+        "(list:last list)",
+        "Returns the last item of list.",
+    ),
+    (
+        "list:length",
+        "(list:length list)",
+        "Returns the count of items of list.",
+    ),
+    (
+        "list:map",
+        "(list:map function list)",
+        "Returns the list of results of calling function with items of list.",
+    ),
+    (
+        "list:new",
+        "(list:new #rest values)",
+        "Returns a new list of values.",
+    ),
+    (
+        "list:none?",
+        "(list:none? predicate list)",
+        "Returns #t when predicate returns #t for no item of list.",
+    ),
+    (
+        "list:nth",
+        "(list:nth index list)",
+        "Returns the item of list at index.",
+    ),
+    (
+        "list:remove",
+        "(list:remove index list)",
+        "Returns list without the item at index.",
+    ),
+    (
+        "list:repeat",
+        "(list:repeat count value)",
+        "Returns a list of value repeated count times.",
+    ),
+    (
+        "list:replace",
+        "(list:replace index value list)",
+        "Returns list with the item at index replaced by value.",
+    ),
+    (
+        "list:reverse",
+        "(list:reverse list)",
+        "Returns list in reverse order.",
+    ),
+    (
+        "list:set-nth!",
+        "(list:set-nth! value index list)",
+        "Sets the item of list at index to value.",
+    ),
+    (
+        "list:tail",
+        "(list:tail list)",
+        "Returns list without its first item.",
+    ),
+    (
+        "list:take",
+        "(list:take count list)",
+        "Returns the first count items of list.",
+    ),
+    (
+        "list:take-while",
+        "(list:take-while predicate list)",
+        "Returns the leading items of list for which predicate returns #t.",
+    ),
+    (
+        "list:unzip",
+        "(list:unzip list)",
+        "Splits a list of two-item lists into a list of two lists.",
+    ),
+    (
+        "list:zip",
+        "(list:zip list-1 list-2)",
+        "Returns the list of two-item lists of corresponding items of lists.",
+    ),
+    (
+        "logic:and",
+        "(logic:and boolean-1 boolean-2)",
+        "Returns the conjunction of booleans.",
+    ),
+    (
+        "logic:nand",
+        "(logic:nand boolean-1 boolean-2)",
+        "Returns the negated conjunction of booleans.",
+    ),
+    (
+        "logic:nor",
+        "(logic:nor boolean-1 boolean-2)",
+        "Returns the negated disjunction of booleans.",
+    ),
+    (
+        "logic:or",
+        "(logic:or boolean-1 boolean-2)",
+        "Returns the disjunction of booleans.",
+    ),
+    (
+        "logic:xor",
+        "(logic:xor boolean-1 boolean-2)",
+        "Returns the exclusive disjunction of booleans.",
+    ),
+    (
+        "math:abs",
+        "(math:abs number)",
+        "Returns the absolute value of number.",
+    ),
+    (
+        "math:ceil",
+        "(math:ceil number)",
+        "Returns the least integer not less than number.",
+    ),
+    (
+        "math:floor",
+        "(math:floor number)",
+        "Returns the greatest integer not greater than number.",
+    ),
+    (
+        "math:max",
+        "(math:max number #rest numbers)",
+        "Returns the greatest of numbers.",
+    ),
+    (
+        "math:min",
+        "(math:min number #rest numbers)",
+        "Returns the least of numbers.",
+    ),
+    (
+        "math:pow",
+        "(math:pow base exponent)",
+        "Returns base raised to exponent.",
+    ),
+    (
+        "math:round",
+        "(math:round number)",
+        "Returns number rounded to the nearest integer.",
+    ),
+    (
+        "module:exports",
+        "(module:exports #opt path)",
+        "Returns the sorted list of names exported by the loaded module at path, or by the current module.",
+    ),
+    (
+        "module:reload",
+        "(module:reload #opt path)",
+        "Reloads the module at path, or all loaded modules.",
+    ),
+    ("nia:quit", "(nia:quit)", "Stops the interpreter."),
+    (
+        "object:define-property!",
+        "(object:define-property! object descriptor)",
+        "Defines a property of object from descriptor with `:name' and either `:value' and `:writable', or `:get' and `:set'.",
+    ),
+    (
+        "object:delete-property!",
+        "(object:delete-property! object key)",
+        "Deletes property key of object.",
+    ),
+    (
+        "object:freeze!",
+        "(object:freeze! object)",
+        "Prevents any further changes of object.",
+    ),
+    (
+        "object:get",
+        "(object:get object key)",
+        "Returns the value of property key of object or its prototypes.",
+    ),
+    (
+        "object:get-property-descriptor",
+        "(object:get-property-descriptor object key)",
+        "Returns the descriptor of property key of object.",
+    ),
+    (
+        "object:get-proto",
+        "(object:get-proto object)",
+        "Returns the prototype of object, or nil.",
+    ),
+    (
+        "object:is-configurable?",
+        "(object:is-configurable? object key)",
+        "Returns #t when property key of object is configurable.",
+    ),
+    (
+        "object:is-enumerable?",
+        "(object:is-enumerable? object key)",
+        "Returns #t when property key of object is enumerable.",
+    ),
+    (
+        "object:is-frozen?",
+        "(object:is-frozen? object)",
+        "Returns #t when object is frozen.",
+    ),
+    (
+        "object:is-internable?",
+        "(object:is-internable? object key)",
+        "Returns #t when property key of object is internable.",
+    ),
+    (
+        "object:is-writable?",
+        "(object:is-writable? object key)",
+        "Returns #t when property key of object is writable.",
+    ),
+    (
+        "object:make",
+        "(object:make #rest keys-and-values)",
+        "Returns a new object with properties from alternating keys and values.",
+    ),
+    (
+        "object:new",
+        "(object:new #opt prototype)",
+        "Returns a new empty object with an optional prototype.",
+    ),
+    (
+        "object:observe!",
+        "(object:observe! object key-or-function #opt function)",
+        "Calls function with key, old and new value after property key, or any property, of object changes.",
+    ),
+    (
+        "object:set!",
+        "(object:set! object key value)",
+        "Sets property key of object to value.",
+    ),
+    (
+        "object:set-configurable!",
+        "(object:set-configurable! object key boolean)",
+        "Sets whether property key of object is configurable.",
+    ),
+    (
+        "object:set-enumerable!",
+        "(object:set-enumerable! object key boolean)",
+        "Sets whether property key of object is enumerable.",
+    ),
+    (
+        "object:set-internable!",
+        "(object:set-internable! object key boolean)",
+        "Sets whether property key of object is internable.",
+    ),
+    (
+        "object:set-proto!",
+        "(object:set-proto! object prototype)",
+        "Sets the prototype of object.",
+    ),
+    (
+        "object:set-writable!",
+        "(object:set-writable! object key boolean)",
+        "Sets whether property key of object is writable.",
+    ),
+    (
+        "object:unobserve!",
+        "(object:unobserve! object key-or-function #opt function)",
+        "Removes an observer added by `object:observe!'. Returns #t when it was removed.",
+    ),
+    (
+        "object:update-property!",
+        "(object:update-property! object descriptor)",
+        "Updates the property of object named in descriptor.",
+    ),
+    (
+        "rand:float",
+        "(rand:float #opt min max)",
+        "Returns a random float, optionally between min and max.",
+    ),
+    (
+        "rand:int",
+        "(rand:int #opt min max)",
+        "Returns a random integer, optionally between min and max.",
+    ),
+    (
+        "string:compare",
+        "(string:compare string-1 string-2)",
+        "Returns -1, 0 or 1 when string-1 is less than, equal to or greater than string-2.",
+    ),
+    (
+        "string:concat",
+        "(string:concat string #rest strings)",
+        "Returns the concatenation of strings.",
+    ),
+    (
+        "string:contains?",
+        "(string:contains? substring string)",
+        "Returns #t when string contains substring.",
+    ),
+    (
+        "string:equal?",
+        "(string:equal? string-1 string-2)",
+        "Returns #t when strings are equal.",
+    ),
+    (
+        "string:find",
+        "(string:find substring string)",
+        "Returns the index of the first occurrence of substring in string, or -1.",
+    ),
+    (
+        "string:format",
+        "(string:format format #rest arguments)",
+        "Returns format with `{}' placeholders replaced by arguments.",
+    ),
+    (
+        "string:greater?",
+        "(string:greater? string-1 string-2)",
+        "Returns #t when string-1 is greater than string-2.",
+    ),
+    (
+        "string:join",
+        "(string:join separator value #rest values)",
+        "Returns values joined with separator.",
+    ),
+    (
+        "string:left",
+        "(string:left count string)",
+        "Returns the first count characters of string.",
+    ),
+    (
+        "string:length",
+        "(string:length string)",
+        "Returns the count of characters of string.",
+    ),
+    (
+        "string:less?",
+        "(string:less? string-1 string-2)",
+        "Returns #t when string-1 is less than string-2.",
+    ),
+    (
+        "string:lower",
+        "(string:lower string)",
+        "Returns string in lower case.",
+    ),
+    (
+        "string:repeat",
+        "(string:repeat count string)",
+        "Returns string repeated count times.",
+    ),
+    (
+        "string:right",
+        "(string:right count string)",
+        "Returns the last count characters of string.",
+    ),
+    (
+        "string:split",
+        "(string:split separator string)",
+        "Returns the list of parts of string split by separator.",
+    ),
+    (
+        "string:substr",
+        "(string:substr index length string)",
+        "Returns length characters of string starting from index.",
+    ),
+    (
+        "string:trim",
+        "(string:trim string)",
+        "Returns string without leading and trailing whitespace.",
+    ),
+    (
+        "string:trim-left",
+        "(string:trim-left string)",
+        "Returns string without leading whitespace.",
+    ),
+    (
+        "string:trim-right",
+        "(string:trim-right string)",
+        "Returns string without trailing whitespace.",
+    ),
+    (
+        "string:upper",
+        "(string:upper string)",
+        "Returns string in upper case.",
+    ),
+    (
+        "to:boolean",
+        "(to:boolean value)",
+        "Converts value to a boolean.",
+    ),
+    ("to:float", "(to:float value)", "Converts value to a float."),
+    ("to:int", "(to:int value)", "Converts value to an integer."),
+    (
+        "to:keyword",
+        "(to:keyword value)",
+        "Converts value to a keyword.",
+    ),
+    ("to:string", "(to:string value)", "Converts value to a string."),
+    ("to:symbol", "(to:symbol value)", "Converts value to a symbol."),
+];
@@ -0,0 +1,76 @@
+pub const CORE_DOCUMENTATION: &'static [(
+    &'static str,
+    &'static str,
+    &'static str,
+)] = &[
+    (
+        "dec!",
+        "(dec! symbol)",
+        "Decrements the integer stored in variable symbol.",
+    ),
+    (
+        "defc",
+        "(defc name #opt value docstring)",
+        "Defines constant variable name with value, or nil, and an optional docstring.",
+    ),
+    (
+        "defm",
+        "(defm name (arguments) #rest forms)",
+        "Defines macro name.",
+    ),
+    (
+        "defn",
+        "(defn name (arguments) #rest forms)",
+        "Defines function name. A string followed by other forms is the docstring of the function.",
+    ),
+    (
+        "defon",
+        "(defon object name (arguments) #rest forms)",
+        "Defines function name as a property of object.",
+    ),
+    (
+        "defv",
+        "(defv name #opt value docstring)",
+        "Defines variable name with value, or nil, and an optional docstring.",
+    ),
+    (
+        "fn",
+        "(fn (arguments) #rest forms)",
+        "Constructs a function. A string followed by other forms is its docstring.",
+    ),
+    (
+        "func:bind",
+        "(func:bind function #rest arguments)",
+        "Returns function with leading arguments bound.",
+    ),
+    (
+        "func:curry",
+        "(func:curry function count)",
+        "Returns the curried form of function of count arguments. Throws when called with more arguments.",
+    ),
+    (
+        "func:curry*",
+        "(func:curry* function count)",
+        "Returns the curried form of function of count arguments. Passes extra arguments to function.",
+    ),
+    (
+        "if",
+        "(if condition then-clause else-clause)",
+        "Evaluates then-clause when condition is truthy, else-clause otherwise.",
+    ),
+    (
+        "inc!",
+        "(inc! symbol)",
+        "Increments the integer stored in variable symbol.",
+    ),
+    (
+        "unless",
+        "(unless condition else-clause)",
+        "Evaluates else-clause when condition is #f.",
+    ),
+    (
+        "when",
+        "(when condition then-clause)",
+        "Evaluates then-clause when condition is truthy.",
+    ),
+];
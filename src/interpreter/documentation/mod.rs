@@ -0,0 +1,9 @@
+mod builtin_documentation;
+mod builtin_functions;
+mod builtin_objects;
+mod core;
+mod name_description;
+mod special_forms;
+
+pub use builtin_documentation::*;
+pub use name_description::*;
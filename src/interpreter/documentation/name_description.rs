@@ -0,0 +1,422 @@
+use crate::EnvironmentId;
+use crate::Error;
+use crate::Function;
+use crate::FunctionArguments;
+use crate::FunctionId;
+use crate::Interpreter;
+use crate::SymbolId;
+use crate::Value;
+
+use super::get_builtin_documentation;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NameDescription {
+    name: String,
+    kind: &'static str,
+    signature: Option<String>,
+    documentation: Option<String>,
+}
+
+impl NameDescription {
+    pub fn get_name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn get_kind(&self) -> &'static str {
+        self.kind
+    }
+
+    pub fn get_signature(&self) -> Option<&String> {
+        self.signature.as_ref()
+    }
+
+    pub fn get_documentation(&self) -> Option<&String> {
+        self.documentation.as_ref()
+    }
+}
+
+enum Binding {
+    Function(Value),
+    Variable(SymbolId, Value),
+    Property(Value),
+}
+
+impl Binding {
+    fn get_value(&self) -> Value {
+        match self {
+            Binding::Function(value) => *value,
+            Binding::Variable(_, value) => *value,
+            Binding::Property(value) => *value,
+        }
+    }
+}
+
+// `object:member' names refer to properties of objects stored in variables,
+// other names refer to functions first and to variables then
+fn lookup_binding(
+    interpreter: &mut Interpreter,
+    environment_id: EnvironmentId,
+    name: &str,
+) -> Result<Option<Binding>, Error> {
+    let separator_index = name
+        .find(':')
+        .filter(|index| *index > 0 && *index < name.len() - 1);
+
+    if let Some(separator_index) = separator_index {
+        let object_symbol_id =
+            interpreter.intern_symbol_id(&name[..separator_index]);
+        let property_symbol_id =
+            interpreter.intern_symbol_id(&name[separator_index + 1..]);
+
+        let object_id = match interpreter
+            .lookup_variable(environment_id, object_symbol_id)?
+        {
+            Some(Value::Object(object_id)) => object_id,
+            _ => return Ok(None),
+        };
+
+        let value = interpreter
+            .get_object_property(object_id, property_symbol_id)?
+            .map(Binding::Property);
+
+        return Ok(value);
+    }
+
+    let symbol_id = interpreter.intern_symbol_id(name);
+
+    if let Some(value) =
+        interpreter.lookup_function(environment_id, symbol_id)?
+    {
+        return Ok(Some(Binding::Function(value)));
+    }
+
+    let binding = interpreter
+        .lookup_variable(environment_id, symbol_id)?
+        .map(|value| Binding::Variable(symbol_id, value));
+
+    Ok(binding)
+}
+
+fn get_kind(
+    interpreter: &Interpreter,
+    value: Value,
+) -> Result<&'static str, Error> {
+    let kind = match value {
+        Value::Function(function_id) => {
+            match interpreter.get_function(function_id)? {
+                Function::Builtin(_) => "builtin-function",
+                Function::SpecialForm(_) => "special-form",
+                Function::Interpreted(_) => "function",
+                Function::Macro(_) => "macro",
+            }
+        },
+        _ => "variable",
+    };
+
+    Ok(kind)
+}
+
+fn render_signature(name: &str, arguments: &FunctionArguments) -> String {
+    let mut parts = vec![String::from(name)];
+
+    parts.extend(arguments.get_ordinary_arguments().iter().cloned());
+
+    if arguments.get_optional_arguments().len() > 0 {
+        parts.push(String::from("#opt"));
+        parts.extend(
+            arguments
+                .get_optional_arguments()
+                .iter()
+                .map(|argument| argument.get_name().clone()),
+        );
+    }
+
+    if let Some(rest_argument) = arguments.get_rest_argument() {
+        parts.push(String::from("#rest"));
+        parts.push(rest_argument.clone());
+    }
+
+    if arguments.get_key_arguments().len() > 0 {
+        parts.push(String::from("#keys"));
+        parts.extend(
+            arguments
+                .get_key_arguments()
+                .iter()
+                .map(|argument| argument.get_name().clone()),
+        );
+    }
+
+    format!("({})", parts.join(" "))
+}
+
+fn describe_function_id(
+    interpreter: &Interpreter,
+    name: &str,
+    function_id: FunctionId,
+) -> Result<(Option<String>, Option<String>), Error> {
+    let description = match interpreter.get_function(function_id)? {
+        Function::Interpreted(interpreted_function) => (
+            Some(render_signature(name, interpreted_function.get_arguments())),
+            interpreted_function.get_documentation().cloned(),
+        ),
+        Function::Macro(macro_function) => (
+            Some(render_signature(name, macro_function.get_arguments())),
+            None,
+        ),
+        _ => (None, None),
+    };
+
+    Ok(description)
+}
+
+pub fn get_function_documentation(
+    interpreter: &Interpreter,
+    function_id: FunctionId,
+) -> Result<Option<String>, Error> {
+    let (_, documentation) =
+        describe_function_id(interpreter, "", function_id)?;
+
+    Ok(documentation)
+}
+
+pub fn describe_name(
+    interpreter: &mut Interpreter,
+    environment_id: EnvironmentId,
+    name: &str,
+) -> Result<Option<NameDescription>, Error> {
+    let binding = match lookup_binding(interpreter, environment_id, name)? {
+        Some(binding) => binding,
+        None => return Ok(None),
+    };
+    let value = binding.get_value();
+
+    // the table describes only definitions that were not shadowed
+    let root_environment_id = interpreter.get_root_environment_id();
+    let is_builtin =
+        match lookup_binding(interpreter, root_environment_id, name)? {
+            Some(root_binding) => root_binding.get_value() == value,
+            None => false,
+        };
+
+    let (mut signature, mut documentation) = match value {
+        Value::Function(function_id) => {
+            describe_function_id(interpreter, name, function_id)?
+        },
+        _ => (None, None),
+    };
+
+    if let Binding::Variable(symbol_id, _) = binding {
+        if let Some(variable_documentation) =
+            interpreter.get_variable_documentation(environment_id, symbol_id)?
+        {
+            documentation = Some(variable_documentation);
+        }
+    }
+
+    if let Some(builtin_documentation) =
+        get_builtin_documentation(name).filter(|_| is_builtin)
+    {
+        signature = Some(String::from(builtin_documentation.get_signature()));
+
+        if documentation.is_none() {
+            documentation =
+                Some(String::from(builtin_documentation.get_documentation()));
+        }
+    }
+
+    Ok(Some(NameDescription {
+        name: String::from(name),
+        kind: get_kind(interpreter, value)?,
+        signature,
+        documentation,
+    }))
+}
+
+fn push_environment_names(
+    interpreter: &Interpreter,
+    environment_id: EnvironmentId,
+    names: &mut Vec<String>,
+) -> Result<(), Error> {
+    let environment = interpreter
+        .get_environment_arena()
+        .get_environment(environment_id)?;
+
+    for symbol_id in environment
+        .get_functions()
+        .keys()
+        .chain(environment.get_variables().keys())
+    {
+        names.push(interpreter.get_symbol_name(*symbol_id)?.clone());
+    }
+
+    Ok(())
+}
+
+fn push_object_member_names(
+    interpreter: &Interpreter,
+    environment_id: EnvironmentId,
+    names: &mut Vec<String>,
+) -> Result<(), Error> {
+    let environment = interpreter
+        .get_environment_arena()
+        .get_environment(environment_id)?;
+
+    for (symbol_id, wrapper) in environment.get_variables() {
+        let object_id = match wrapper.force_get_value() {
+            Value::Object(object_id) => object_id,
+            _ => continue,
+        };
+        let object_name = interpreter.get_symbol_name(*symbol_id)?;
+
+        for property_symbol_id in
+            interpreter.get_object(object_id)?.get_properties().keys()
+        {
+            names.push(format!(
+                "{}:{}",
+                object_name,
+                interpreter.get_symbol_name(*property_symbol_id)?
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn collect_documented_names(
+    interpreter: &Interpreter,
+    environment_id: EnvironmentId,
+) -> Result<Vec<String>, Error> {
+    let mut names = Vec::new();
+    let mut current_environment_id = Some(environment_id);
+
+    while let Some(environment_id) = current_environment_id {
+        push_environment_names(interpreter, environment_id, &mut names)?;
+
+        current_environment_id = interpreter
+            .get_environment_arena()
+            .get_parent(environment_id)?;
+    }
+
+    push_object_member_names(
+        interpreter,
+        interpreter.get_root_environment_id(),
+        &mut names,
+    )?;
+
+    names.extend(
+        super::get_builtin_documentations()
+            .into_iter()
+            .map(|documentation| String::from(documentation.get_name())),
+    );
+
+    names.retain(|name| !name.starts_with("#"));
+    names.sort();
+    names.dedup();
+
+    Ok(names)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[test]
+    fn describes_builtin_functions_with_documentation_table() {
+        let mut interpreter = Interpreter::new();
+        let environment_id = interpreter.get_main_environment_id();
+
+        let description =
+            describe_name(&mut interpreter, environment_id, "string:join")
+                .unwrap()
+                .unwrap();
+
+        nia_assert_equal("builtin-function", description.get_kind());
+        nia_assert_equal(
+            Some(&String::from("(string:join separator value #rest values)")),
+            description.get_signature(),
+        );
+    }
+
+    #[test]
+    fn renders_signature_of_interpreted_functions() {
+        let mut interpreter = Interpreter::new();
+        let environment_id = interpreter.get_main_environment_id();
+
+        interpreter
+            .execute_in_main_environment(
+                "(defn f (a #opt b #rest c) \"Doc.\" a)",
+            )
+            .unwrap();
+
+        let description = describe_name(&mut interpreter, environment_id, "f")
+            .unwrap()
+            .unwrap();
+
+        nia_assert_equal("function", description.get_kind());
+        nia_assert_equal(
+            Some(&String::from("(f a #opt b #rest c)")),
+            description.get_signature(),
+        );
+        nia_assert_equal(
+            Some(&String::from("Doc.")),
+            description.get_documentation(),
+        );
+    }
+
+    #[test]
+    fn ignores_documentation_table_for_shadowed_definitions() {
+        let mut interpreter = Interpreter::new();
+        let environment_id = interpreter.get_main_environment_id();
+
+        interpreter
+            .execute_in_main_environment("(defn not (a b) a)")
+            .unwrap();
+
+        let description =
+            describe_name(&mut interpreter, environment_id, "not")
+                .unwrap()
+                .unwrap();
+
+        nia_assert_equal(
+            Some(&String::from("(not a b)")),
+            description.get_signature(),
+        );
+        nia_assert_equal(None, description.get_documentation());
+    }
+
+    #[test]
+    fn returns_none_for_unknown_names() {
+        let mut interpreter = Interpreter::new();
+        let environment_id = interpreter.get_main_environment_id();
+
+        nia_assert_equal(
+            Ok(None),
+            describe_name(&mut interpreter, environment_id, "not-defined"),
+        );
+        nia_assert_equal(
+            Ok(None),
+            describe_name(&mut interpreter, environment_id, "list:not-defined"),
+        );
+    }
+
+    #[test]
+    fn collects_names_of_environments_and_builtin_objects() {
+        let mut interpreter = Interpreter::new();
+        let environment_id = interpreter.get_main_environment_id();
+
+        interpreter
+            .execute_in_main_environment("(defv main-variable 1)")
+            .unwrap();
+
+        let names =
+            collect_documented_names(&interpreter, environment_id).unwrap();
+
+        nia_assert(names.contains(&String::from("main-variable")));
+        nia_assert(names.contains(&String::from("list:map")));
+        nia_assert(names.contains(&String::from("defn")));
+        nia_assert(names.iter().all(|name| !name.starts_with("#")));
+    }
+}
@@ -0,0 +1,161 @@
+pub const SPECIAL_FORMS_DOCUMENTATION: &'static [(
+    &'static str,
+    &'static str,
+    &'static str,
+)] = &[
+    (
+        "and",
+        "(and #rest forms)",
+        "Evaluates forms from left to right until one of them is falsy. Returns the value of the last evaluated form, or #t when no forms were provided.",
+    ),
+    (
+        "block",
+        "(block #rest forms)",
+        "Evaluates forms in order and returns the list of their values.",
+    ),
+    (
+        "call-with-this",
+        "(call-with-this object function #rest arguments)",
+        "Calls function with arguments while `this' is bound to object. Used by the `object:method' invocation syntax.",
+    ),
+    (
+        "cond",
+        "(cond #rest (condition #rest forms))",
+        "Evaluates conditions in order and evaluates forms of the first clause whose condition is truthy. Returns nil when no clause matches.",
+    ),
+    (
+        "defclass",
+        "(defclass name (#opt parent) #rest definitions)",
+        "Defines a class object. Definitions are `(constructor (arguments) forms...)', `(method name (arguments) forms...)' and `(static name value)'. Methods can refer to the parent class as `super'.",
+    ),
+    (
+        "define-function",
+        "(define-function name #opt value :const)",
+        "Defines function name in the current environment. Value is evaluated and must be a function. Keyword `:const' makes the definition constant.",
+    ),
+    (
+        "define-variable",
+        "(define-variable name #opt value :const docstring)",
+        "Defines variable name in the current environment with the evaluated value, or nil. Keyword `:const' makes the variable constant, an optional string documents it.",
+    ),
+    (
+        "doitems",
+        "(doitems (key value object) #rest forms)",
+        "Evaluates forms for every enumerable property of object with key and value bound. Forms may call `break' and `continue'.",
+    ),
+    (
+        "dokeys",
+        "(dokeys (key object) #rest forms)",
+        "Evaluates forms for every enumerable property name of object bound to key. Forms may call `break' and `continue'.",
+    ),
+    (
+        "dolist",
+        "(dolist (item list) #rest forms)",
+        "Evaluates forms for every item of list. Forms may call `break' and `continue'.",
+    ),
+    (
+        "dotimes",
+        "(dotimes (index count) #rest forms)",
+        "Evaluates forms count times with index bound to 0, 1 and so on. Forms may call `break' and `continue'.",
+    ),
+    (
+        "dovalues",
+        "(dovalues (value object) #rest forms)",
+        "Evaluates forms for every enumerable property value of object. Forms may call `break' and `continue'.",
+    ),
+    (
+        "export",
+        "(export #rest specifiers)",
+        "Exports definitions of the current module. Supports `(export name)', `(export #{:a :b})', `(export name as alias)', `(export default name)' and re-exports with `from \"path\"'.",
+    ),
+    (
+        "flet",
+        "(flet ((name (arguments) #rest forms) ...) #rest body)",
+        "Evaluates body with local functions defined. Definitions do not see each other.",
+    ),
+    (
+        "flet*",
+        "(flet* ((name (arguments) #rest forms) ...) #rest body)",
+        "Evaluates body with local functions defined sequentially, so that later definitions see earlier ones.",
+    ),
+    (
+        "fset!",
+        "(fset! name value)",
+        "Sets function name to the evaluated value in the environment that defines it.",
+    ),
+    (
+        "function",
+        "(function (lambda|macro (arguments) #rest forms))",
+        "Constructs a function or a macro. A string followed by other forms in the body of a lambda is its docstring.",
+    ),
+    (
+        "import",
+        "(import #rest specifiers)",
+        "Imports definitions from a module. Supports `(import \"path\")', `(import name from \"path\")', `(import #{:a :b} from \"path\")' and `(import name as alias from \"path\")'.",
+    ),
+    (
+        "let",
+        "(let ((name value) ...) #rest body)",
+        "Evaluates body with local variables bound to evaluated values. Bindings do not see each other.",
+    ),
+    (
+        "let*",
+        "(let* ((name value) ...) #rest body)",
+        "Evaluates body with local variables bound sequentially, so that later values see earlier bindings.",
+    ),
+    (
+        "match",
+        "(match value #rest (pattern #rest forms))",
+        "Matches value against patterns in order and evaluates forms of the first matching clause with pattern variables bound.",
+    ),
+    (
+        "mlet",
+        "(mlet ((name (arguments) #rest forms) ...) #rest body)",
+        "Evaluates body with local macros defined. Definitions do not see each other.",
+    ),
+    (
+        "mlet*",
+        "(mlet* ((name (arguments) #rest forms) ...) #rest body)",
+        "Evaluates body with local macros defined sequentially, so that later definitions see earlier ones.",
+    ),
+    (
+        "or",
+        "(or #rest forms)",
+        "Evaluates forms from left to right until one of them is truthy. Returns the value of the last evaluated form, or #f when no forms were provided.",
+    ),
+    (
+        "progn",
+        "(progn #rest forms)",
+        "Evaluates forms in order and returns the value of the last one, or nil.",
+    ),
+    (
+        "quote",
+        "(quote value)",
+        "Returns value without evaluating it. `'value' is a shorthand.",
+    ),
+    (
+        "set!",
+        "(set! name value)",
+        "Sets variable name to the evaluated value in the environment that defines it.",
+    ),
+    (
+        "throw",
+        "(throw #opt symbol message)",
+        "Throws an error identified by symbol with an optional message string. Symbol defaults to `generic-error'.",
+    ),
+    (
+        "try",
+        "(try form #rest (catch symbol #rest forms))",
+        "Evaluates form. When it throws an error identified by symbol of one of the catch clauses, evaluates forms of that clause instead.",
+    ),
+    (
+        "while",
+        "(while condition #rest forms)",
+        "Evaluates forms while condition evaluates to #t.",
+    ),
+    (
+        "with-this",
+        "(with-this object #rest forms)",
+        "Evaluates forms with `this' bound to object.",
+    ),
+];
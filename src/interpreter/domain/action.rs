@@ -29,6 +29,10 @@ pub enum Action {
     ExecuteNamedAction(String),
     Wait(i32),
 
+    Sequence(Vec<Action>),
+    Repeat(i32, Box<Action>),
+    WithHeld(Vec<i32>, Box<Action>),
+
     ExecuteFunctionValue(Value), // specified only for interpreter
 }
 
@@ -39,4 +43,161 @@ impl Action {
     {
         NamedAction::new(self, name.into())
     }
+
+    pub fn is_composite(&self) -> bool {
+        match self {
+            Action::Sequence(_)
+            | Action::Repeat(_, _)
+            | Action::WithHeld(_, _) => true,
+            _ => false,
+        }
+    }
+
+    pub fn contains_execute_function_value(&self) -> bool {
+        match self {
+            Action::ExecuteFunctionValue(_) => true,
+            Action::Sequence(actions) => actions
+                .iter()
+                .any(|action| action.contains_execute_function_value()),
+            Action::Repeat(_, action) => {
+                action.contains_execute_function_value()
+            },
+            Action::WithHeld(_, action) => {
+                action.contains_execute_function_value()
+            },
+            _ => false,
+        }
+    }
+
+    // Expands composite action by one level. `Repeat' is unrolled lazily,
+    // so that huge repeat counts do not allocate all steps at once.
+    pub fn expand(self) -> Vec<Action> {
+        match self {
+            Action::Sequence(actions) => actions,
+            Action::Repeat(count, action) => {
+                if count <= 0 {
+                    Vec::new()
+                } else if count == 1 {
+                    vec![*action]
+                } else {
+                    let step = (*action).clone();
+
+                    vec![step, Action::Repeat(count - 1, action)]
+                }
+            },
+            Action::WithHeld(key_codes, action) => {
+                let mut actions = Vec::new();
+
+                for key_code in key_codes.iter() {
+                    actions.push(Action::KeyPress(*key_code));
+                }
+
+                actions.push(*action);
+
+                for key_code in key_codes.iter().rev() {
+                    actions.push(Action::KeyRelease(*key_code));
+                }
+
+                actions
+            },
+            action => vec![action],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[test]
+    fn expands_sequence_to_its_items() {
+        let action = Action::Sequence(vec![
+            Action::KeyClick(1),
+            Action::Wait(100),
+            Action::KeyClick(2),
+        ]);
+
+        nia_assert_equal(
+            vec![Action::KeyClick(1), Action::Wait(100), Action::KeyClick(2)],
+            action.expand(),
+        );
+    }
+
+    #[test]
+    fn expands_repeat_lazily() {
+        let specs = vec![
+            (Action::Repeat(0, Box::new(Action::KeyClick(1))), vec![]),
+            (
+                Action::Repeat(1, Box::new(Action::KeyClick(1))),
+                vec![Action::KeyClick(1)],
+            ),
+            (
+                Action::Repeat(3, Box::new(Action::KeyClick(1))),
+                vec![
+                    Action::KeyClick(1),
+                    Action::Repeat(2, Box::new(Action::KeyClick(1))),
+                ],
+            ),
+        ];
+
+        for (action, expected) in specs {
+            nia_assert_equal(expected, action.expand());
+        }
+    }
+
+    #[test]
+    fn expands_with_held_to_presses_and_releases() {
+        let action =
+            Action::WithHeld(vec![29, 42], Box::new(Action::KeyClick(30)));
+
+        nia_assert_equal(
+            vec![
+                Action::KeyPress(29),
+                Action::KeyPress(42),
+                Action::KeyClick(30),
+                Action::KeyRelease(42),
+                Action::KeyRelease(29),
+            ],
+            action.expand(),
+        );
+    }
+
+    #[test]
+    fn detects_nested_execute_function_value() {
+        let specs = vec![
+            (Action::KeyClick(1), false),
+            (Action::ExecuteFunctionValue(Value::Integer(1)), true),
+            (
+                Action::Sequence(vec![
+                    Action::KeyClick(1),
+                    Action::ExecuteFunctionValue(Value::Integer(1)),
+                ]),
+                true,
+            ),
+            (
+                Action::Repeat(
+                    2,
+                    Box::new(Action::WithHeld(
+                        vec![1],
+                        Box::new(Action::ExecuteFunctionValue(Value::Integer(
+                            1,
+                        ))),
+                    )),
+                ),
+                true,
+            ),
+            (Action::Repeat(2, Box::new(Action::Sequence(vec![]))), false),
+        ];
+
+        for (action, expected) in specs {
+            nia_assert_equal(
+                expected,
+                action.contains_execute_function_value(),
+            );
+        }
+    }
 }
@@ -0,0 +1,162 @@
+use crate::Key;
+use crate::KeyChord;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KeyRemap {
+    from: Key,
+    to: Key,
+}
+
+impl KeyRemap {
+    pub fn new(from: Key, to: Key) -> KeyRemap {
+        KeyRemap { from, to }
+    }
+
+    pub fn get_from(&self) -> Key {
+        self.from
+    }
+
+    pub fn get_to(&self) -> Key {
+        self.to
+    }
+
+    // A lone key remap target keeps the device of the remapped key, so device
+    // specific mappings still see where the key came from.
+    pub fn remap_key(&self, key: Key) -> Option<Key> {
+        if self.from != key {
+            return None;
+        }
+
+        let remapped_key = match (key, self.to) {
+            (Key::DeviceKey(device_key), Key::LoneKey(lone_key)) => {
+                nia_key!(device_key.get_device_id(), lone_key.get_key_id())
+            },
+            (_, to) => to,
+        };
+
+        Some(remapped_key)
+    }
+
+    pub fn remap_key_with(remaps: &Vec<KeyRemap>, key: Key) -> Key {
+        // device key remaps take priority over lone key ones
+        let device_key_remaps = remaps
+            .iter()
+            .filter(|remap| remap.from.get_device_id().is_some());
+        let lone_key_remaps = remaps
+            .iter()
+            .filter(|remap| remap.from.get_device_id().is_none());
+
+        for remap in device_key_remaps.chain(lone_key_remaps) {
+            if let Some(remapped_key) = remap.remap_key(key) {
+                return remapped_key;
+            }
+        }
+
+        key
+    }
+
+    pub fn remap_key_chord_with(
+        remaps: &Vec<KeyRemap>,
+        key_chord: &KeyChord,
+    ) -> KeyChord {
+        let modifiers = key_chord
+            .get_modifiers()
+            .iter()
+            .map(|modifier| KeyRemap::remap_key_with(remaps, *modifier))
+            .collect();
+        let key = KeyRemap::remap_key_with(remaps, key_chord.get_key());
+
+        KeyChord::new(modifiers, key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[test]
+    fn remaps_keys() {
+        let specs = vec![
+            (
+                KeyRemap::new(nia_key!(58), nia_key!(29)),
+                nia_key!(58),
+                Some(nia_key!(29)),
+            ),
+            (
+                KeyRemap::new(nia_key!(58), nia_key!(29)),
+                nia_key!(1, 58),
+                Some(nia_key!(1, 29)),
+            ),
+            (
+                KeyRemap::new(nia_key!(58), nia_key!(2, 29)),
+                nia_key!(1, 58),
+                Some(nia_key!(2, 29)),
+            ),
+            (
+                KeyRemap::new(nia_key!(1, 58), nia_key!(29)),
+                nia_key!(1, 58),
+                Some(nia_key!(1, 29)),
+            ),
+            (
+                KeyRemap::new(nia_key!(1, 58), nia_key!(29)),
+                nia_key!(2, 58),
+                None,
+            ),
+            (
+                KeyRemap::new(nia_key!(58), nia_key!(29)),
+                nia_key!(30),
+                None,
+            ),
+        ];
+
+        for (remap, key, expected) in specs {
+            let result = remap.remap_key(key);
+
+            nia_assert_equal(expected.is_some(), result.is_some());
+
+            if let (Some(expected), Some(result)) = (expected, result) {
+                nia_assert(Key::keys_are_same(expected, result));
+            }
+        }
+    }
+
+    #[test]
+    fn prefers_device_key_remaps() {
+        let remaps = vec![
+            KeyRemap::new(nia_key!(58), nia_key!(1)),
+            KeyRemap::new(nia_key!(2, 58), nia_key!(29)),
+        ];
+
+        nia_assert(Key::keys_are_same(
+            nia_key!(2, 29),
+            KeyRemap::remap_key_with(&remaps, nia_key!(2, 58)),
+        ));
+        nia_assert(Key::keys_are_same(
+            nia_key!(3, 1),
+            KeyRemap::remap_key_with(&remaps, nia_key!(3, 58)),
+        ));
+        nia_assert(Key::keys_are_same(
+            nia_key!(3, 30),
+            KeyRemap::remap_key_with(&remaps, nia_key!(3, 30)),
+        ));
+    }
+
+    #[test]
+    fn remaps_every_key_of_key_chord() {
+        let remaps = vec![
+            KeyRemap::new(nia_key!(58), nia_key!(29)),
+            KeyRemap::new(nia_key!(30), nia_key!(31)),
+        ];
+
+        let key_chord = KeyChord::new(vec![nia_key!(1, 58)], nia_key!(1, 30));
+        let expected = KeyChord::new(vec![nia_key!(1, 29)], nia_key!(1, 31));
+
+        let result = KeyRemap::remap_key_chord_with(&remaps, &key_chord);
+
+        nia_assert(KeyChord::key_chords_are_same(&expected, &result));
+    }
+}
@@ -0,0 +1,488 @@
+use crate::Action;
+
+const KEY_LEFT_CTRL: i32 = 29;
+const KEY_LEFT_SHIFT: i32 = 42;
+const KEY_RIGHT_ALT: i32 = 100;
+const KEY_SPACE: i32 = 57;
+
+// Rows of a layout. Each row starts at the given key code, and the n-th
+// character of a row is produced by the key `start + n`. Null characters mark
+// keys that do not produce a character (e.g. dead keys).
+type LayoutRow = (i32, &'static str, &'static str);
+
+const COMMON_KEYS: &[(char, i32)] = &[(' ', 57), ('\n', 28), ('\t', 15)];
+
+const US_ROWS: &[LayoutRow] = &[
+    (2, "1234567890-=", "!@#$%^&*()_+"),
+    (16, "qwertyuiop[]", "QWERTYUIOP{}"),
+    (30, "asdfghjkl;'`", "ASDFGHJKL:\"~"),
+    (43, "\\zxcvbnm,./", "|ZXCVBNM<>?"),
+];
+const US_ALT_GR_KEYS: &[(char, i32)] = &[];
+
+const DE_ROWS: &[LayoutRow] = &[
+    (2, "1234567890ß\0", "!\"§$%&/()=?\0"),
+    (16, "qwertzuiopü+", "QWERTZUIOPÜ*"),
+    (30, "asdfghjklöä\0", "ASDFGHJKLÖÄ°"),
+    (43, "#yxcvbnm,.-", "'YXCVBNM;:_"),
+    (86, "<", ">"),
+];
+const DE_ALT_GR_KEYS: &[(char, i32)] = &[
+    ('²', 3),
+    ('³', 4),
+    ('{', 8),
+    ('[', 9),
+    (']', 10),
+    ('}', 11),
+    ('\\', 12),
+    ('@', 16),
+    ('€', 18),
+    ('~', 27),
+    ('µ', 50),
+    ('|', 86),
+];
+
+const FR_ROWS: &[LayoutRow] = &[
+    (2, "&é\"'(-è_çà)=", "1234567890°+"),
+    (16, "azertyuiop\0$", "AZERTYUIOP\0£"),
+    (30, "qsdfghjklmù²", "QSDFGHJKLM%\0"),
+    (43, "*wxcvbn,;:!", "µWXCVBN?./§"),
+    (86, "<", ">"),
+];
+const FR_ALT_GR_KEYS: &[(char, i32)] = &[
+    ('#', 4),
+    ('{', 5),
+    ('[', 6),
+    ('|', 7),
+    ('\\', 9),
+    ('^', 10),
+    ('@', 11),
+    (']', 12),
+    ('}', 13),
+    ('€', 18),
+];
+
+const RU_ROWS: &[LayoutRow] = &[
+    (2, "1234567890-=", "!\"№;%:?*()_+"),
+    (16, "йцукенгшщзхъ", "ЙЦУКЕНГШЩЗХЪ"),
+    (30, "фывапролджэё", "ФЫВАПРОЛДЖЭЁ"),
+    (43, "\\ячсмитьбю.", "/ЯЧСМИТЬБЮ,"),
+];
+const RU_ALT_GR_KEYS: &[(char, i32)] = &[];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KeyStroke {
+    key_code: i32,
+    shift: bool,
+    alt_gr: bool,
+}
+
+impl KeyStroke {
+    pub fn new(key_code: i32, shift: bool, alt_gr: bool) -> KeyStroke {
+        KeyStroke {
+            key_code,
+            shift,
+            alt_gr,
+        }
+    }
+
+    pub fn get_key_code(&self) -> i32 {
+        self.key_code
+    }
+
+    pub fn is_shifted(&self) -> bool {
+        self.shift
+    }
+
+    pub fn is_alt_gr(&self) -> bool {
+        self.alt_gr
+    }
+
+    pub fn to_actions(&self) -> Vec<Action> {
+        let mut modifiers = Vec::new();
+
+        if self.shift {
+            modifiers.push(KEY_LEFT_SHIFT);
+        }
+
+        if self.alt_gr {
+            modifiers.push(KEY_RIGHT_ALT);
+        }
+
+        let mut actions = Vec::new();
+
+        for modifier in modifiers.iter() {
+            actions.push(Action::KeyPress(*modifier));
+        }
+
+        actions.push(Action::KeyClick(self.key_code));
+
+        for modifier in modifiers.iter().rev() {
+            actions.push(Action::KeyRelease(*modifier));
+        }
+
+        actions
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyboardLayout {
+    Us,
+    De,
+    Fr,
+    Ru,
+}
+
+impl KeyboardLayout {
+    pub fn from_name(name: &str) -> Option<KeyboardLayout> {
+        match name {
+            "us" => Some(KeyboardLayout::Us),
+            "de" => Some(KeyboardLayout::De),
+            "fr" => Some(KeyboardLayout::Fr),
+            "ru" => Some(KeyboardLayout::Ru),
+            _ => None,
+        }
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            KeyboardLayout::Us => "us",
+            KeyboardLayout::De => "de",
+            KeyboardLayout::Fr => "fr",
+            KeyboardLayout::Ru => "ru",
+        }
+    }
+
+    fn get_rows(&self) -> &'static [LayoutRow] {
+        match self {
+            KeyboardLayout::Us => US_ROWS,
+            KeyboardLayout::De => DE_ROWS,
+            KeyboardLayout::Fr => FR_ROWS,
+            KeyboardLayout::Ru => RU_ROWS,
+        }
+    }
+
+    fn get_alt_gr_keys(&self) -> &'static [(char, i32)] {
+        match self {
+            KeyboardLayout::Us => US_ALT_GR_KEYS,
+            KeyboardLayout::De => DE_ALT_GR_KEYS,
+            KeyboardLayout::Fr => FR_ALT_GR_KEYS,
+            KeyboardLayout::Ru => RU_ALT_GR_KEYS,
+        }
+    }
+
+    pub fn get_key_stroke(&self, character: char) -> Option<KeyStroke> {
+        if character == '\0' {
+            return None;
+        }
+
+        for (common_character, key_code) in COMMON_KEYS {
+            if *common_character == character {
+                return Some(KeyStroke::new(*key_code, false, false));
+            }
+        }
+
+        for (start_key_code, normal, shifted) in self.get_rows() {
+            for (index, row_character) in normal.chars().enumerate() {
+                if row_character == character {
+                    let key_code = start_key_code + index as i32;
+
+                    return Some(KeyStroke::new(key_code, false, false));
+                }
+            }
+
+            for (index, row_character) in shifted.chars().enumerate() {
+                if row_character == character {
+                    let key_code = start_key_code + index as i32;
+
+                    return Some(KeyStroke::new(key_code, true, false));
+                }
+            }
+        }
+
+        for (alt_gr_character, key_code) in self.get_alt_gr_keys() {
+            if *alt_gr_character == character {
+                return Some(KeyStroke::new(*key_code, false, true));
+            }
+        }
+
+        None
+    }
+
+    // The unicode input method is typed with the keys that produce `u' and
+    // the hex digits, so it is only available on layouts that have latin
+    // letters, not on e.g. `ru'.
+    pub fn supports_unicode_input(&self) -> bool {
+        "u0123456789abcdef"
+            .chars()
+            .all(|character| self.get_key_stroke(character).is_some())
+    }
+
+    // Characters that cannot be typed with the layout are entered with the
+    // `Ctrl+Shift+U <hex code> Space' unicode input method.
+    fn unicode_input_actions(&self, character: char) -> Option<Vec<Action>> {
+        if !self.supports_unicode_input() {
+            return None;
+        }
+
+        let mut actions = vec![
+            Action::KeyPress(KEY_LEFT_CTRL),
+            Action::KeyPress(KEY_LEFT_SHIFT),
+            Action::KeyClick(self.get_key_stroke('u')?.get_key_code()),
+            Action::KeyRelease(KEY_LEFT_SHIFT),
+            Action::KeyRelease(KEY_LEFT_CTRL),
+        ];
+
+        for hex_digit in format!("{:x}", character as u32).chars() {
+            actions.extend(self.get_key_stroke(hex_digit)?.to_actions());
+        }
+
+        actions.push(Action::KeyClick(KEY_SPACE));
+
+        Some(actions)
+    }
+
+    // returns the first character that can be typed neither with the layout
+    // nor with the unicode input method
+    pub fn text_to_actions(&self, text: &str) -> Result<Vec<Action>, char> {
+        let mut actions = Vec::new();
+
+        for character in text.chars() {
+            match self.get_key_stroke(character) {
+                Some(key_stroke) => actions.extend(key_stroke.to_actions()),
+                None => actions.extend(
+                    self.unicode_input_actions(character).ok_or(character)?,
+                ),
+            }
+        }
+
+        Ok(actions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[test]
+    fn converts_layout_names() {
+        let specs = vec![
+            ("us", KeyboardLayout::Us),
+            ("de", KeyboardLayout::De),
+            ("fr", KeyboardLayout::Fr),
+            ("ru", KeyboardLayout::Ru),
+        ];
+
+        for (name, layout) in specs {
+            nia_assert_equal(Some(layout), KeyboardLayout::from_name(name));
+            nia_assert_equal(name, layout.get_name());
+        }
+
+        nia_assert_equal(None, KeyboardLayout::from_name("xx"));
+    }
+
+    #[test]
+    fn returns_key_strokes_of_characters() {
+        let specs = vec![
+            (
+                KeyboardLayout::Us,
+                'a',
+                Some(KeyStroke::new(30, false, false)),
+            ),
+            (
+                KeyboardLayout::Us,
+                'A',
+                Some(KeyStroke::new(30, true, false)),
+            ),
+            (
+                KeyboardLayout::Us,
+                '@',
+                Some(KeyStroke::new(3, true, false)),
+            ),
+            (
+                KeyboardLayout::Us,
+                '|',
+                Some(KeyStroke::new(43, true, false)),
+            ),
+            (
+                KeyboardLayout::Us,
+                ' ',
+                Some(KeyStroke::new(57, false, false)),
+            ),
+            (KeyboardLayout::Us, 'ä', None),
+            (
+                KeyboardLayout::De,
+                'z',
+                Some(KeyStroke::new(21, false, false)),
+            ),
+            (
+                KeyboardLayout::De,
+                'y',
+                Some(KeyStroke::new(44, false, false)),
+            ),
+            (
+                KeyboardLayout::De,
+                'Ä',
+                Some(KeyStroke::new(40, true, false)),
+            ),
+            (
+                KeyboardLayout::De,
+                '@',
+                Some(KeyStroke::new(16, false, true)),
+            ),
+            (
+                KeyboardLayout::De,
+                '€',
+                Some(KeyStroke::new(18, false, true)),
+            ),
+            (
+                KeyboardLayout::Fr,
+                'a',
+                Some(KeyStroke::new(16, false, false)),
+            ),
+            (
+                KeyboardLayout::Fr,
+                'q',
+                Some(KeyStroke::new(30, false, false)),
+            ),
+            (
+                KeyboardLayout::Fr,
+                '1',
+                Some(KeyStroke::new(2, true, false)),
+            ),
+            (
+                KeyboardLayout::Fr,
+                'é',
+                Some(KeyStroke::new(3, false, false)),
+            ),
+            (
+                KeyboardLayout::Fr,
+                '@',
+                Some(KeyStroke::new(11, false, true)),
+            ),
+            (
+                KeyboardLayout::Ru,
+                'ф',
+                Some(KeyStroke::new(30, false, false)),
+            ),
+            (
+                KeyboardLayout::Ru,
+                'Я',
+                Some(KeyStroke::new(44, true, false)),
+            ),
+            (
+                KeyboardLayout::Ru,
+                '№',
+                Some(KeyStroke::new(4, true, false)),
+            ),
+            (KeyboardLayout::Ru, 'a', None),
+        ];
+
+        for (layout, character, expected) in specs {
+            nia_assert_equal(expected, layout.get_key_stroke(character));
+        }
+    }
+
+    #[test]
+    fn does_not_return_key_strokes_of_dead_keys() {
+        nia_assert_equal(None, KeyboardLayout::De.get_key_stroke('\0'));
+        nia_assert_equal(None, KeyboardLayout::De.get_key_stroke('^'));
+    }
+
+    #[test]
+    fn converts_text_to_actions() {
+        let expected = vec![
+            Action::KeyPress(42),
+            Action::KeyClick(35),
+            Action::KeyRelease(42),
+            Action::KeyClick(23),
+            Action::KeyPress(100),
+            Action::KeyClick(16),
+            Action::KeyRelease(100),
+        ];
+
+        nia_assert_equal(
+            Ok(expected),
+            KeyboardLayout::De.text_to_actions("Hi@"),
+        );
+    }
+
+    #[test]
+    fn uses_unicode_input_for_unrepresentable_characters() {
+        let expected = vec![
+            Action::KeyPress(29),
+            Action::KeyPress(42),
+            Action::KeyClick(22),
+            Action::KeyRelease(42),
+            Action::KeyRelease(29),
+            Action::KeyClick(18),
+            Action::KeyClick(5),
+            Action::KeyClick(57),
+        ];
+
+        // U+00E4 is typed as `e4'
+        nia_assert_equal(Ok(expected), KeyboardLayout::Us.text_to_actions("ä"));
+    }
+
+    #[test]
+    fn uses_unicode_input_with_positions_of_the_layout() {
+        let expected = vec![
+            Action::KeyPress(29),
+            Action::KeyPress(42),
+            Action::KeyClick(22),
+            Action::KeyRelease(42),
+            Action::KeyRelease(29),
+            Action::KeyClick(3),
+            Action::KeyClick(2),
+            Action::KeyClick(10),
+            Action::KeyClick(3),
+            Action::KeyClick(57),
+        ];
+
+        // U+2192 is typed as `2192', digits are shifted in fr
+        let expected_fr = vec![
+            Action::KeyPress(29),
+            Action::KeyPress(42),
+            Action::KeyClick(22),
+            Action::KeyRelease(42),
+            Action::KeyRelease(29),
+            Action::KeyPress(42),
+            Action::KeyClick(3),
+            Action::KeyRelease(42),
+            Action::KeyPress(42),
+            Action::KeyClick(2),
+            Action::KeyRelease(42),
+            Action::KeyPress(42),
+            Action::KeyClick(10),
+            Action::KeyRelease(42),
+            Action::KeyPress(42),
+            Action::KeyClick(3),
+            Action::KeyRelease(42),
+            Action::KeyClick(57),
+        ];
+
+        nia_assert_equal(Ok(expected), KeyboardLayout::De.text_to_actions("→"));
+        nia_assert_equal(
+            Ok(expected_fr),
+            KeyboardLayout::Fr.text_to_actions("→"),
+        );
+    }
+
+    #[test]
+    fn supports_unicode_input_only_on_latin_layouts() {
+        nia_assert(KeyboardLayout::Us.supports_unicode_input());
+        nia_assert(KeyboardLayout::De.supports_unicode_input());
+        nia_assert(KeyboardLayout::Fr.supports_unicode_input());
+        nia_assert(!KeyboardLayout::Ru.supports_unicode_input());
+    }
+
+    #[test]
+    fn rejects_characters_that_layout_cannot_type() {
+        nia_assert_equal(Err('z'), KeyboardLayout::Ru.text_to_actions("фz"));
+        nia_assert_equal(Err('→'), KeyboardLayout::Ru.text_to_actions("→"));
+    }
+}
@@ -10,6 +10,8 @@ mod action;
 mod device_info;
 mod device_key;
 mod key_chord;
+mod key_remap;
+mod keyboard_layout;
 mod lone_key;
 mod mapping;
 mod named_action;
@@ -20,6 +22,8 @@ pub use device_info::*;
 pub use device_key::*;
 pub use key::*;
 pub use key_chord::*;
+pub use key_remap::*;
+pub use keyboard_layout::*;
 pub use lone_key::*;
 pub use mapping::*;
 pub use modifier_description::*;
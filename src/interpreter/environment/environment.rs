@@ -3,6 +3,9 @@ use std::collections::HashMap;
 use crate::interpreter::environment::EnvironmentId;
 use crate::interpreter::environment::EnvironmentValueWrapper;
 use crate::interpreter::error::Error;
+use crate::interpreter::image::ImageItem;
+use crate::interpreter::image::ImageReader;
+use crate::interpreter::image::ImageWriter;
 use crate::interpreter::value::SymbolId;
 use crate::interpreter::value::Value;
 
@@ -10,6 +13,7 @@ use crate::interpreter::value::Value;
 pub struct LexicalEnvironment {
     variables: HashMap<SymbolId, EnvironmentValueWrapper>,
     functions: HashMap<SymbolId, EnvironmentValueWrapper>,
+    variable_documentation: HashMap<SymbolId, String>,
     parent: Option<EnvironmentId>,
 }
 
@@ -18,6 +22,7 @@ impl LexicalEnvironment {
         LexicalEnvironment {
             variables: HashMap::new(),
             functions: HashMap::new(),
+            variable_documentation: HashMap::new(),
             parent: None,
         }
     }
@@ -195,6 +200,42 @@ impl LexicalEnvironment {
         })
     }
 
+    pub fn get_variables(
+        &self,
+    ) -> &HashMap<SymbolId, EnvironmentValueWrapper> {
+        &self.variables
+    }
+
+    pub fn get_functions(
+        &self,
+    ) -> &HashMap<SymbolId, EnvironmentValueWrapper> {
+        &self.functions
+    }
+
+    pub fn get_variable_documentation(
+        &self,
+        symbol_id: SymbolId,
+    ) -> Option<&String> {
+        self.variable_documentation.get(&symbol_id)
+    }
+
+    pub fn set_variable_documentation(
+        &mut self,
+        symbol_id: SymbolId,
+        documentation: String,
+    ) -> Result<(), Error> {
+        if !self.has_variable(symbol_id) {
+            return Error::generic_execution_error(
+                "Cannot document not defined variable.",
+            )
+            .into();
+        }
+
+        self.variable_documentation.insert(symbol_id, documentation);
+
+        Ok(())
+    }
+
     pub fn get_gc_items(&self) -> Vec<Value> {
         let mut result = self
             .variables
@@ -228,6 +269,29 @@ impl LexicalEnvironment {
     }
 }
 
+impl ImageItem for LexicalEnvironment {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write(&self.variables)?;
+        writer.write(&self.functions)?;
+        writer.write(&self.variable_documentation)?;
+        writer.write(&self.parent)
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        let variables = reader.read()?;
+        let functions = reader.read()?;
+        let variable_documentation = reader.read()?;
+        let parent = reader.read()?;
+
+        Ok(LexicalEnvironment {
+            variables,
+            functions,
+            variable_documentation,
+            parent,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -3,6 +3,9 @@ use std::collections::HashMap;
 use crate::interpreter::environment::EnvironmentId;
 use crate::interpreter::environment::LexicalEnvironment;
 use crate::interpreter::error::Error;
+use crate::interpreter::image::ImageItem;
+use crate::interpreter::image::ImageReader;
+use crate::interpreter::image::ImageWriter;
 use crate::interpreter::value::SymbolId;
 use crate::interpreter::value::Value;
 
@@ -39,6 +42,13 @@ impl EnvironmentArena {
         }
     }
 
+    pub fn get_environment(
+        &self,
+        environment_id: EnvironmentId,
+    ) -> Result<&LexicalEnvironment, Error> {
+        self.get(environment_id)
+    }
+
     pub fn get_parent(
         &self,
         environment_id: EnvironmentId,
@@ -246,6 +256,32 @@ impl EnvironmentArena {
         }
     }
 
+    pub fn get_variable_documentation(
+        &self,
+        environment_id: EnvironmentId,
+        symbol_id: SymbolId,
+    ) -> Result<Option<String>, Error> {
+        match self.lookup_environment_by_variable(environment_id, symbol_id)? {
+            Some(defining_environment_id) => {
+                let env = self.get(defining_environment_id)?;
+
+                Ok(env.get_variable_documentation(symbol_id).cloned())
+            },
+            None => Ok(None),
+        }
+    }
+
+    pub fn set_variable_documentation(
+        &mut self,
+        environment_id: EnvironmentId,
+        symbol_id: SymbolId,
+        documentation: String,
+    ) -> Result<(), Error> {
+        let env = self.get_mut(environment_id)?;
+
+        env.set_variable_documentation(symbol_id, documentation)
+    }
+
     pub fn lookup_environment_by_variable(
         &self,
         environment_id: EnvironmentId,
@@ -309,6 +345,20 @@ impl EnvironmentArena {
     }
 }
 
+impl ImageItem for EnvironmentArena {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write(&self.arena)?;
+        writer.write(&self.next_id)
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        let arena = reader.read()?;
+        let next_id = reader.read()?;
+
+        Ok(EnvironmentArena { arena, next_id })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,4 +1,7 @@
 use crate::Error;
+use crate::ImageItem;
+use crate::ImageReader;
+use crate::ImageWriter;
 use crate::Value;
 
 const ENVIRONMENT_VALUE_WRAPPER_FLAG_INTERNABLE: u8 = 0x1;
@@ -116,6 +119,20 @@ impl EnvironmentValueWrapper {
     }
 }
 
+impl ImageItem for EnvironmentValueWrapper {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write(&self.value)?;
+        writer.write(&self.flags)
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        let value = reader.read()?;
+        let flags = reader.read()?;
+
+        Ok(EnvironmentValueWrapper { value, flags })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
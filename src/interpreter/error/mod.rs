@@ -18,6 +18,8 @@ pub const SYMBOL_NAME_STACK_OVERFLOW_ERROR: &'static str = "stack-overflow";
 pub const SYMBOL_NAME_ASSERTION_ERROR: &'static str = "assertion-error";
 pub const SYMBOL_NAME_BREAK_ERROR: &'static str = "break-error";
 pub const SYMBOL_NAME_CONTINUE_ERROR: &'static str = "continue-error";
+pub const SYMBOL_NAME_PERMISSION_DENIED_ERROR: &'static str =
+    "permission-denied";
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ErrorKind {
@@ -39,6 +41,8 @@ pub enum ErrorKind {
     Assertion,
     Break,
     Continue,
+
+    PermissionDenied,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -309,6 +313,18 @@ impl Error {
             String::from(SYMBOL_NAME_CONTINUE_ERROR),
         )
     }
+
+    pub fn permission_denied_error<S>(message: S) -> Error
+    where
+        S: Into<String>,
+    {
+        Error::from(
+            None,
+            ErrorKind::PermissionDenied,
+            message,
+            String::from(SYMBOL_NAME_PERMISSION_DENIED_ERROR),
+        )
+    }
 }
 
 impl fmt::Display for Error {
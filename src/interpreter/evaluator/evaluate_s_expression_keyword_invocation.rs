@@ -7,6 +7,8 @@ use crate::KeywordId;
 use crate::Value;
 use crate::{ConsId, SymbolId};
 
+use crate::library;
+
 fn evaluate_s_expression_keyword_get(
     interpreter: &mut Interpreter,
     environment_id: EnvironmentId,
@@ -20,9 +22,12 @@ fn evaluate_s_expression_keyword_get(
         evaluate_value(interpreter, environment_id, object_value)?;
 
     let value = match evaluated_argument {
-        Value::Object(object_id) => {
-            interpreter.get_object_property(object_id, property_symbol_id)?
-        }
+        Value::Object(object_id) => library::get_object_property(
+            interpreter,
+            environment_id,
+            object_id,
+            property_symbol_id,
+        )?,
         _ => {
             return Error::generic_execution_error(
                 "Cannot get an item of not an object.",
@@ -55,7 +60,9 @@ fn evaluate_s_expression_keyword_set(
         evaluate_value(interpreter, environment_id, property_new_value)?;
 
     match evaluated_object_value {
-        Value::Object(object_id) => interpreter.set_object_property(
+        Value::Object(object_id) => library::set_object_property(
+            interpreter,
+            environment_id,
             object_id,
             property_symbol_id,
             evaluated_new_value,
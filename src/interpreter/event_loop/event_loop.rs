@@ -1,19 +1,28 @@
+use std::panic;
+use std::panic::AssertUnwindSafe;
 use std::sync::mpsc;
 use std::thread;
 
 use std::time::Duration;
+use std::time::Instant;
 
 use nia_events::UInputWorkerCommand;
+use nia_events::WorkerHandle;
 use nia_events::XorgWorkerCommand;
 use nia_events::{ButtonId, Command, KeyId};
 
 use crate::{Action, ActionDeque, ActionResult, GarbageCollectorWrapper};
+use crate::ActionGuard;
 
 use crate::EventLoopHandle;
 use crate::NiaActionListener;
 use crate::NiaActionListenerHandle;
 use crate::NiaChangeMappingCommand;
 use crate::NiaChangeMappingCommandResult;
+use crate::NiaCollectGarbageCommand;
+use crate::NiaCollectGarbageCommandResult;
+use crate::NiaCompleteCodeCommand;
+use crate::NiaCompleteCodeCommandResult;
 use crate::NiaDefineActionCommand;
 use crate::NiaDefineActionCommandResult;
 use crate::NiaDefineDeviceCommand;
@@ -22,17 +31,29 @@ use crate::NiaDefineMappingCommand;
 use crate::NiaDefineMappingCommandResult;
 use crate::NiaDefineModifierCommand;
 use crate::NiaDefineModifierCommandResult;
+use crate::NiaDefineRemapCommand;
+use crate::NiaDefineRemapCommandResult;
 use crate::NiaExecuteCodeCommand;
 use crate::NiaExecuteCodeCommandResult;
 use crate::NiaGetDefinedActionsCommand;
 use crate::NiaGetDefinedActionsCommandResult;
+use crate::NiaGetDefinedDevicesCommand;
+use crate::NiaGetDefinedDevicesCommandResult;
 use crate::NiaGetDefinedMappingsCommand;
 use crate::NiaGetDefinedMappingsCommandResult;
 use crate::NiaGetDefinedModifiersCommand;
 use crate::NiaGetDefinedModifiersCommandResult;
+use crate::NiaGetDefinedRemapsCommand;
+use crate::NiaGetDefinedRemapsCommandResult;
+use crate::NiaGetReadableMappingsCommand;
+use crate::NiaGetReadableMappingsCommandResult;
 use crate::NiaInterpreterCommand;
 use crate::NiaInterpreterCommandResult;
 use crate::NiaIsListeningCommandResult;
+use crate::NiaLoadFileCommand;
+use crate::NiaLoadFileCommandResult;
+use crate::NiaReloadCommand;
+use crate::NiaReloadCommandResult;
 use crate::NiaRemoveActionCommand;
 use crate::NiaRemoveActionCommandResult;
 use crate::NiaRemoveDeviceByIdCommand;
@@ -45,16 +66,24 @@ use crate::NiaRemoveMappingCommand;
 use crate::NiaRemoveMappingCommandResult;
 use crate::NiaRemoveModifierCommand;
 use crate::NiaRemoveModifierCommandResult;
+use crate::NiaRemoveRemapCommand;
+use crate::NiaRemoveRemapCommandResult;
+use crate::NiaTimeCodeCommand;
+use crate::NiaTimeCodeCommandResult;
 use crate::NiaWorker;
 use crate::StateMachineAction;
 
 use crate::Error;
 use crate::Interpreter;
 
+use crate::interpreter::DEFAULT_MAX_ACTIONS_PER_SECOND;
+use crate::interpreter::DEFAULT_MAX_NAMED_ACTION_DEPTH;
 use crate::interpreter::PRIMITIVE_ACTIONS_VARIABLE_NAME;
 
 use crate::library;
 
+const MODULES_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
 pub struct EventLoop {}
 
 const GARBAGE_COLLECTOR_PERIOD: u64 = 120000;
@@ -62,7 +91,7 @@ const GARBAGE_COLLECTOR_PERIOD: u64 = 120000;
 mod do_command {
     pub use super::*;
     use crate::{
-        Mapping, NiaIsListeningCommand, NiaRemoveDeviceByIdCommand,
+        KeyChord, Mapping, NiaIsListeningCommand, NiaRemoveDeviceByIdCommand,
         NiaStartListeningCommand, NiaStartListeningCommandResult,
         NiaStopListeningCommand, NiaStopListeningCommandResult,
     };
@@ -102,7 +131,11 @@ mod do_command {
             interpreter.execute_in_main_environment(command.get_code());
 
         let result = match result {
-            Ok(value) => library::value_to_string(interpreter, value),
+            Ok(value) => library::value_to_pretty_string(
+                interpreter,
+                value,
+                library::DEFAULT_PRETTY_PRINT_WIDTH,
+            ),
             Err(error) => Err(error),
         };
 
@@ -185,7 +218,7 @@ mod do_command {
         let action_name = action.get_action_name();
 
         let result = match action.get_action() {
-            Action::ExecuteFunctionValue(_) => Error::failure(
+            action if action.contains_execute_function_value() => Error::failure(
                 "Invariant violation Action::ExecuteFunctionValue must not be able to be defined from server request."
             ).into(),
 
@@ -313,6 +346,151 @@ mod do_command {
         NiaStopListeningCommandResult::from(result).into()
     }
 
+    fn do_command_define_remap(
+        interpreter: &mut Interpreter,
+        command: NiaDefineRemapCommand,
+    ) -> NiaInterpreterCommandResult {
+        let result =
+            library::define_key_remap(interpreter, command.get_key_remap());
+        let result = result.map(|_| String::from("Success"));
+
+        NiaDefineRemapCommandResult::from(result).into()
+    }
+
+    fn do_command_get_defined_remaps(
+        interpreter: &mut Interpreter,
+        _command: NiaGetDefinedRemapsCommand,
+    ) -> NiaInterpreterCommandResult {
+        let result = library::get_defined_key_remaps(interpreter);
+
+        NiaGetDefinedRemapsCommandResult::from(result).into()
+    }
+
+    fn do_command_remove_remap(
+        interpreter: &mut Interpreter,
+        command: NiaRemoveRemapCommand,
+    ) -> NiaInterpreterCommandResult {
+        let result = library::remove_key_remap(interpreter, command.get_key());
+        let result = result.map(|_| String::from("Success"));
+
+        NiaRemoveRemapCommandResult::from(result).into()
+    }
+
+    fn do_command_reload(
+        interpreter: &mut Interpreter,
+        command: NiaReloadCommand,
+    ) -> NiaInterpreterCommandResult {
+        let result = match command.get_module_path() {
+            Some(module_path) => interpreter.reload_module(module_path),
+            None => interpreter.reload_changed_modules(),
+        };
+
+        NiaReloadCommandResult::from(result).into()
+    }
+
+    fn do_command_complete_code(
+        interpreter: &mut Interpreter,
+        command: NiaCompleteCodeCommand,
+    ) -> NiaInterpreterCommandResult {
+        let result = crate::complete_code(
+            interpreter,
+            command.get_code(),
+            command.get_position(),
+        );
+
+        NiaCompleteCodeCommandResult::from(result).into()
+    }
+
+    fn do_command_load_file(
+        interpreter: &mut Interpreter,
+        command: NiaLoadFileCommand,
+    ) -> NiaInterpreterCommandResult {
+        let result = interpreter
+            .execute_file_in_main_environment(command.get_file_path());
+
+        let result = match result {
+            Ok(value) => library::value_to_pretty_string(
+                interpreter,
+                value,
+                library::DEFAULT_PRETTY_PRINT_WIDTH,
+            ),
+            Err(error) => Err(error),
+        };
+
+        NiaLoadFileCommandResult::from(result).into()
+    }
+
+    fn do_command_collect_garbage(
+        interpreter: &mut Interpreter,
+        _command: NiaCollectGarbageCommand,
+    ) -> NiaInterpreterCommandResult {
+        let result = crate::collect_garbage(interpreter);
+
+        NiaCollectGarbageCommandResult::from(result).into()
+    }
+
+    fn do_command_get_defined_devices(
+        interpreter: &mut Interpreter,
+        _command: NiaGetDefinedDevicesCommand,
+    ) -> NiaInterpreterCommandResult {
+        let result = library::get_defined_devices_info(interpreter);
+
+        NiaGetDefinedDevicesCommandResult::from(result).into()
+    }
+
+    // only the execution is measured, printing the value is not
+    fn do_command_time_code(
+        interpreter: &mut Interpreter,
+        command: NiaTimeCodeCommand,
+    ) -> NiaInterpreterCommandResult {
+        let start = Instant::now();
+        let result =
+            interpreter.execute_in_main_environment(command.get_code());
+        let elapsed = start.elapsed();
+
+        let result = match result {
+            Ok(value) => library::value_to_pretty_string(
+                interpreter,
+                value,
+                library::DEFAULT_PRETTY_PRINT_WIDTH,
+            ),
+            Err(error) => Err(error),
+        };
+
+        NiaTimeCodeCommandResult::new(
+            NiaExecuteCodeCommandResult::from(result),
+            elapsed,
+        )
+        .into()
+    }
+
+    // actions are rendered here because their function values belong to
+    // this interpreter
+    fn do_command_get_readable_mappings(
+        interpreter: &mut Interpreter,
+        _command: NiaGetReadableMappingsCommand,
+    ) -> NiaInterpreterCommandResult {
+        let mappings = match library::get_defined_mappings(interpreter) {
+            Ok(mappings) => mappings,
+            Err(error) => {
+                return NiaGetReadableMappingsCommandResult::from(error).into()
+            }
+        };
+
+        let result = mappings
+            .into_iter()
+            .map(|mapping| {
+                let (key_chords, action) = mapping.take();
+                let action =
+                    library::action_to_readable_string(interpreter, &action)?;
+
+                Ok((key_chords, action))
+            })
+            .collect::<Result<Vec<(Vec<KeyChord>, String)>, Error>>();
+
+        NiaGetReadableMappingsCommandResult::from(result).into()
+    }
+
     pub fn do_command(
         interpreter: &mut Interpreter,
         command: NiaInterpreterCommand,
@@ -372,6 +550,36 @@ mod do_command {
             NiaInterpreterCommand::StopListening(command) => {
                 do_command_stop_listening(interpreter, command)
             }
+            NiaInterpreterCommand::DefineRemap(command) => {
+                do_command_define_remap(interpreter, command)
+            }
+            NiaInterpreterCommand::GetDefinedRemaps(command) => {
+                do_command_get_defined_remaps(interpreter, command)
+            }
+            NiaInterpreterCommand::RemoveRemap(command) => {
+                do_command_remove_remap(interpreter, command)
+            }
+            NiaInterpreterCommand::Reload(command) => {
+                do_command_reload(interpreter, command)
+            }
+            NiaInterpreterCommand::CompleteCode(command) => {
+                do_command_complete_code(interpreter, command)
+            }
+            NiaInterpreterCommand::LoadFile(command) => {
+                do_command_load_file(interpreter, command)
+            }
+            NiaInterpreterCommand::CollectGarbage(command) => {
+                do_command_collect_garbage(interpreter, command)
+            }
+            NiaInterpreterCommand::GetDefinedDevices(command) => {
+                do_command_get_defined_devices(interpreter, command)
+            }
+            NiaInterpreterCommand::TimeCode(command) => {
+                do_command_time_code(interpreter, command)
+            }
+            NiaInterpreterCommand::GetReadableMappings(command) => {
+                do_command_get_readable_mappings(interpreter, command)
+            }
         }
     }
 }
@@ -410,6 +618,8 @@ impl EventLoop {
         interpreter: &mut Interpreter,
         action: Action,
     ) -> Result<ActionResult, Error> {
+        interpreter.get_capabilities().check_action(&action)?;
+
         let result = match action {
             Action::KeyPress(key_code) => Command::UInput(
                 UInputWorkerCommand::KeyDown(KeyId::new(key_code as u16)),
@@ -481,7 +691,18 @@ impl EventLoop {
             )
             .into(),
             Action::TextType(text) => {
-                Command::Xorg(XorgWorkerCommand::TextType(text)).into()
+                let keyboard_layout = library::get_keyboard_layout(interpreter)?;
+
+                let actions =
+                    keyboard_layout.text_to_actions(&text).map_err(|character| {
+                        Error::invalid_argument_error(format!(
+                            "Character {:?} cannot be typed with keyboard layout `{}'.",
+                            character,
+                            keyboard_layout.get_name()
+                        ))
+                    })?;
+
+                ActionResult::PushSteps(Action::Sequence(actions))
             }
             Action::ExecuteOSCommand(os_command) => {
                 Command::Spawn(os_command).into()
@@ -527,11 +748,81 @@ impl EventLoop {
                 ActionResult::Nothing
             }
             Action::Wait(_) => ActionResult::Nothing,
+            action @ Action::Sequence(_)
+            | action @ Action::Repeat(_, _)
+            | action @ Action::WithHeld(_, _) => {
+                ActionResult::PushAction(action)
+            }
         };
 
         Ok(result)
     }
 
+    fn send_action(
+        interpreter: &mut Interpreter,
+        worker_handle: &WorkerHandle,
+        action: Action,
+    ) {
+        match EventLoop::handle_action(interpreter, action) {
+            Ok(ActionResult::SendCommand(command)) => {
+                match worker_handle.send_command(command) {
+                    Ok(_) => {}
+                    Err(_) => {
+                        // worker is dead
+                    }
+                }
+            }
+            Ok(_) => {}
+            Err(error) => {
+                println!("{:?}", error);
+            }
+        }
+    }
+
+    fn release_pressed_inputs(
+        interpreter: &mut Interpreter,
+        worker_handle: &WorkerHandle,
+    ) {
+        let release_actions =
+            interpreter.get_pressed_inputs_mut().take_release_actions();
+
+        for action in release_actions {
+            EventLoop::send_action(interpreter, worker_handle, action);
+        }
+    }
+
+    fn emergency_stop(
+        interpreter: &mut Interpreter,
+        worker_handle: &WorkerHandle,
+        action_deque: &mut ActionDeque,
+    ) {
+        action_deque.clear();
+
+        let nil = interpreter.intern_nil_symbol_value();
+
+        match library::set_root_variable(
+            interpreter,
+            PRIMITIVE_ACTIONS_VARIABLE_NAME,
+            nil,
+        ) {
+            Ok(()) => {}
+            Err(error) => {
+                println!("{:?}", error);
+            }
+        }
+
+        EventLoop::release_pressed_inputs(interpreter, worker_handle);
+
+        if interpreter.is_listening() {
+            match interpreter.stop_listening() {
+                Ok(()) => {}
+                Err(error) => {
+                    println!("{:?}", error);
+                }
+            }
+        }
+    }
+
     pub fn run_event_loop(interpreter: Interpreter) -> EventLoopHandle {
         let mut interpreter = interpreter;
         let mut gc = GarbageCollectorWrapper::new(GARBAGE_COLLECTOR_PERIOD);
@@ -544,6 +835,8 @@ impl EventLoop {
             interpreter_command_result_receiver,
         ) = mpsc::channel::<NiaInterpreterCommandResult>();
 
+        let (quit_sender, quit_receiver) = mpsc::channel::<bool>();
+
         thread::spawn(move || {
             let worker_handle = NiaWorker::new().start_sending().expect("");
             // todo: change
@@ -551,20 +844,37 @@ impl EventLoop {
                 None;
 
             let mut action_deque = ActionDeque::new();
+            let mut action_guard = ActionGuard::new();
+            let mut last_modules_check = Instant::now();
 
-            loop {
+            // the loop is guarded, so that synthetic keys are released even
+            // if it panics
+            let loop_result = panic::catch_unwind(AssertUnwindSafe(|| loop {
                 // execute command that was received with channel
                 match interpreter_command_receiver.try_recv() {
                     Ok(command) => {
                         let command_result =
                             do_command::do_command(&mut interpreter, command);
 
+                        // callers are told about `nia:quit' before they get
+                        // the result of the command that called it
+                        if interpreter.is_quit_requested() {
+                            match quit_sender.send(true) {
+                                Ok(()) => {}
+                                Err(_) => {}
+                            }
+                        }
+
                         match interpreter_command_result_sender
                             .send(command_result)
                         {
                             Ok(()) => {}
                             Err(_) => break,
                         }
+
+                        if interpreter.is_quit_requested() {
+                            break;
+                        }
                     }
                     Err(mpsc::TryRecvError::Disconnected) => {
                         break;
@@ -572,6 +882,55 @@ impl EventLoop {
                     Err(mpsc::TryRecvError::Empty) => {}
                 };
 
+                // `nia:quit' was called by an action
+                if interpreter.is_quit_requested() {
+                    match quit_sender.send(true) {
+                        Ok(()) => {}
+                        Err(_) => {}
+                    }
+
+                    break;
+                }
+
+                // reload modules which files were changed
+                if last_modules_check.elapsed() >= MODULES_CHECK_INTERVAL {
+                    last_modules_check = Instant::now();
+
+                    if library::get_watch_modules(&mut interpreter)
+                        .unwrap_or(false)
+                    {
+                        match interpreter.reload_changed_modules() {
+                            Ok(module_paths) => {
+                                for module_path in module_paths {
+                                    println!("Reloaded module: {}.", module_path);
+                                }
+                            }
+                            Err(error) => {
+                                println!("{:?}", error);
+                            }
+                        }
+                    }
+                }
+
+                // stop key remapping threads when reloaded modules changed
+                // key configuration, they are constructed again below
+                if interpreter.take_listener_restart_request() {
+                    match &action_listener_handle {
+                        Some(handle) => match handle.stop() {
+                            Ok(_) => {}
+                            Err(_) => {}
+                        },
+                        None => {}
+                    }
+
+                    action_listener_handle = None;
+
+                    EventLoop::release_pressed_inputs(
+                        &mut interpreter,
+                        &worker_handle,
+                    );
+                }
+
                 // construct/stop key remapping threads
                 if interpreter.is_listening()
                     && action_listener_handle.is_none()
@@ -607,6 +966,11 @@ impl EventLoop {
                     }
 
                     action_listener_handle = None;
+
+                    EventLoop::release_pressed_inputs(
+                        &mut interpreter,
+                        &worker_handle,
+                    );
                 }
 
                 // add actions to queue from state machine
@@ -618,6 +982,18 @@ impl EventLoop {
                                 StateMachineAction::Execute(action) => {
                                     action_deque.push_action(action);
                                 }
+                                StateMachineAction::EmergencyStop => {
+                                    println!("Emergency stop.");
+
+                                    EventLoop::emergency_stop(
+                                        &mut interpreter,
+                                        &worker_handle,
+                                        &mut action_deque,
+                                    );
+                                    action_guard.reset();
+
+                                    break;
+                                }
                             },
                             Err(mpsc::TryRecvError::Empty) => {
                                 break;
@@ -640,25 +1016,90 @@ impl EventLoop {
                 action_deque.push_actions(actions);
 
                 // handle actions from queue
-                while let Some(action) = action_deque.take_action() {
+                let max_actions_per_second =
+                    library::get_max_actions_per_second(&mut interpreter)
+                        .unwrap_or(DEFAULT_MAX_ACTIONS_PER_SECOND as usize);
+                let max_named_action_depth =
+                    library::get_max_named_action_depth(&mut interpreter)
+                        .unwrap_or(DEFAULT_MAX_NAMED_ACTION_DEPTH as usize);
+
+                while let Some((action, depth, is_counted)) =
+                    action_deque.take_counted_action_with_depth()
+                {
+                    let is_runaway = if is_counted
+                        && !action_guard.register_action(max_actions_per_second)
+                    {
+                        println!(
+                            "Emergency stop: more than {} actions per second.",
+                            max_actions_per_second
+                        );
+                        true
+                    } else if let Action::ExecuteNamedAction(_) = &action {
+                        if !ActionGuard::named_action_depth_is_allowed(
+                            depth,
+                            max_named_action_depth,
+                        ) {
+                            println!(
+                                "Emergency stop: named action depth exceeds {}.",
+                                max_named_action_depth
+                            );
+                            true
+                        } else {
+                            false
+                        }
+                    } else {
+                        false
+                    };
+
+                    if is_runaway {
+                        EventLoop::emergency_stop(
+                            &mut interpreter,
+                            &worker_handle,
+                            &mut action_deque,
+                        );
+                        action_guard.reset();
+
+                        break;
+                    }
+
+                    let nested_depth = match &action {
+                        Action::ExecuteNamedAction(_) => depth + 1,
+                        _ => depth,
+                    };
+
+                    // inputs are tracked only once they were sent, so that
+                    // denied or failed actions are never released later
+                    let sent_action = action.clone();
+
                     match EventLoop::handle_action(&mut interpreter, action) {
                         Ok(ActionResult::SendCommand(command)) => {
                             match worker_handle.send_command(command) {
-                                Ok(_) => {}
+                                Ok(_) => {
+                                    interpreter
+                                        .get_pressed_inputs_mut()
+                                        .register_action(&sent_action);
+                                }
                                 Err(_) => {
                                     // worker is dead
                                 }
                             }
                         }
                         Ok(ActionResult::PushAction(action)) => {
-                            action_deque.push_action_front(action);
+                            action_deque
+                                .push_nested_action_front(action, nested_depth);
+                        }
+                        Ok(ActionResult::PushSteps(action)) => {
+                            action_deque.push_steps_front(action, nested_depth);
                         }
                         Ok(ActionResult::Nothing) => {}
                         Err(error) => {
                             println!("{:?}", error);
 
                             if error.is_failure() {
-                                // handle failure
+                                EventLoop::release_pressed_inputs(
+                                    &mut interpreter,
+                                    &worker_handle,
+                                );
                             }
                         }
                     };
@@ -672,7 +1113,10 @@ impl EventLoop {
                         println!("{:?}", error);
 
                         if error.is_failure() {
-                            // handle failure
+                            EventLoop::release_pressed_inputs(
+                                &mut interpreter,
+                                &worker_handle,
+                            );
                         }
                         false
                     }
@@ -681,7 +1125,9 @@ impl EventLoop {
                 if !was_collected {
                     thread::sleep(Duration::from_millis(10));
                 }
-            }
+            }));
+
+            EventLoop::release_pressed_inputs(&mut interpreter, &worker_handle);
 
             match action_listener_handle {
                 Some(handle) => match handle.stop() {
@@ -695,11 +1141,16 @@ impl EventLoop {
                 Ok(()) => {}
                 Err(()) => {}
             }
+
+            if let Err(panic_payload) = loop_result {
+                panic::resume_unwind(panic_payload);
+            }
         });
 
         let event_loop_handle = EventLoopHandle::new(
             interpreter_command_sender,
             interpreter_command_result_receiver,
+            quit_receiver,
         );
 
         event_loop_handle
@@ -1,3 +1,4 @@
+use std::cell::Cell;
 use std::sync::mpsc;
 
 use crate::NiaInterpreterCommand;
@@ -7,6 +8,8 @@ pub struct EventLoopHandle {
     interpreter_command_sender: mpsc::Sender<NiaInterpreterCommand>,
     interpreter_command_result_receiver:
         mpsc::Receiver<NiaInterpreterCommandResult>,
+    quit_receiver: mpsc::Receiver<bool>,
+    is_quitting: Cell<bool>,
 }
 
 impl EventLoopHandle {
@@ -15,10 +18,13 @@ impl EventLoopHandle {
         interpreter_command_result_receiver: mpsc::Receiver<
             NiaInterpreterCommandResult,
         >,
+        quit_receiver: mpsc::Receiver<bool>,
     ) -> EventLoopHandle {
         EventLoopHandle {
             interpreter_command_sender,
             interpreter_command_result_receiver,
+            quit_receiver,
+            is_quitting: Cell::new(false),
         }
     }
 
@@ -38,4 +44,25 @@ impl EventLoopHandle {
             Err(_) => Err(()),
         }
     }
+
+    // the event loop tells about `nia:quit' before it sends the result of
+    // the command that called it, so this is true once that result is
+    // received
+    pub fn is_quitting(&self) -> bool {
+        if !self.is_quitting.get() {
+            self.is_quitting
+                .set(self.quit_receiver.try_recv().unwrap_or(false));
+        }
+
+        self.is_quitting.get()
+    }
+
+    // results are not sent after the event loop thread exits, so receiving
+    // blocks until then; returns true when the loop stopped because of
+    // `nia:quit', and false when it stopped otherwise or panicked
+    pub fn wait_for_stop(&self) -> bool {
+        while self.interpreter_command_result_receiver.recv().is_ok() {}
+
+        self.is_quitting.get() || self.quit_receiver.recv().unwrap_or(false)
+    }
 }
@@ -13,8 +13,10 @@ fn get_current_time() -> Duration {
         .expect("Time went backwards")
 }
 
+// queued actions keep the depth of named actions they were executed by, and
+// whether they count towards the rate guard
 pub struct ActionDeque {
-    actions: VecDeque<Action>,
+    actions: VecDeque<(Action, usize, bool)>,
     last_checked_time: Duration,
 }
 
@@ -29,33 +31,73 @@ impl ActionDeque {
     }
 
     pub fn push_action(&mut self, action: Action) {
-        self.actions.push_back(action);
+        self.actions.push_back((action, 0, true));
     }
 
     pub fn push_actions(&mut self, actions: Vec<Action>) {
         for action in actions {
-            self.actions.push_back(action);
+            self.actions.push_back((action, 0, true));
         }
     }
 
     pub fn push_action_front(&mut self, action: Action) {
-        self.actions.push_front(action);
+        self.actions.push_front((action, 0, true));
     }
 
     pub fn push_actions_front(&mut self, actions: Vec<Action>) {
         for action in actions {
-            self.actions.push_front(action);
+            self.actions.push_front((action, 0, true));
         }
     }
 
-    pub fn take_action(&mut self) -> Option<Action> {
+    pub fn push_nested_action_front(&mut self, action: Action, depth: usize) {
+        self.actions.push_front((action, depth, true));
+    }
+
+    pub fn push_steps_front(&mut self, action: Action, depth: usize) {
+        self.actions.push_front((action, depth, false));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.actions.clear();
+    }
+
+    fn expand_front_action(&mut self) {
+        loop {
+            let is_composite = match self.actions.front() {
+                Some((action, _, _)) => action.is_composite(),
+                None => false,
+            };
+
+            if !is_composite {
+                break;
+            }
+
+            if let Some((action, depth, is_counted)) = self.actions.pop_front()
+            {
+                for action in action.expand().into_iter().rev() {
+                    self.actions.push_front((action, depth, is_counted));
+                }
+            }
+        }
+    }
+
+    pub fn take_counted_action_with_depth(
+        &mut self,
+    ) -> Option<(Action, usize, bool)> {
+        self.expand_front_action();
+
         let current_time = get_current_time();
         let diff = current_time - self.last_checked_time;
 
         self.last_checked_time = current_time;
 
         let take = match self.actions.front_mut() {
-            Some(Action::Wait(remaining_ms)) => {
+            Some((Action::Wait(remaining_ms), _, _)) => {
                 let diff_as_ms = diff.as_millis();
 
                 if diff_as_ms >= (*remaining_ms as u128) {
@@ -64,7 +106,7 @@ impl ActionDeque {
                     *remaining_ms -= diff_as_ms as i32;
                     false
                 }
-            }
+            },
             Some(_) => true,
             None => false,
         };
@@ -75,6 +117,167 @@ impl ActionDeque {
             None
         }
     }
+
+    pub fn take_action_with_depth(&mut self) -> Option<(Action, usize)> {
+        self.take_counted_action_with_depth()
+            .map(|(action, depth, _)| (action, depth))
+    }
+
+    pub fn take_action(&mut self) -> Option<Action> {
+        self.take_action_with_depth().map(|(action, _)| action)
+    }
 }
 
-// todo: tests here
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    use crate::ActionGuard;
+    use crate::KeyboardLayout;
+
+    fn take_all_actions(action_deque: &mut ActionDeque) -> Vec<Action> {
+        let mut actions = Vec::new();
+
+        while let Some(action) = action_deque.take_action() {
+            actions.push(action);
+        }
+
+        actions
+    }
+
+    #[test]
+    fn returns_actions_in_order() {
+        let mut action_deque = ActionDeque::new();
+
+        action_deque.push_action(Action::KeyClick(1));
+        action_deque.push_action(Action::KeyClick(2));
+        action_deque.push_action_front(Action::KeyClick(3));
+
+        nia_assert_equal(
+            vec![
+                Action::KeyClick(3),
+                Action::KeyClick(1),
+                Action::KeyClick(2),
+            ],
+            take_all_actions(&mut action_deque),
+        );
+    }
+
+    #[test]
+    fn expands_composite_actions() {
+        let mut action_deque = ActionDeque::new();
+
+        action_deque.push_action(Action::Sequence(vec![
+            Action::KeyClick(1),
+            Action::Repeat(2, Box::new(Action::KeyClick(2))),
+            Action::WithHeld(
+                vec![29],
+                Box::new(Action::Sequence(vec![Action::KeyClick(3)])),
+            ),
+        ]));
+        action_deque.push_action(Action::KeyClick(4));
+
+        nia_assert_equal(
+            vec![
+                Action::KeyClick(1),
+                Action::KeyClick(2),
+                Action::KeyClick(2),
+                Action::KeyPress(29),
+                Action::KeyClick(3),
+                Action::KeyRelease(29),
+                Action::KeyClick(4),
+            ],
+            take_all_actions(&mut action_deque),
+        );
+    }
+
+    #[test]
+    fn keeps_depth_of_nested_actions() {
+        let mut action_deque = ActionDeque::new();
+
+        action_deque.push_action(Action::KeyClick(1));
+        action_deque.push_nested_action_front(
+            Action::Sequence(vec![Action::KeyClick(2), Action::KeyClick(3)]),
+            2,
+        );
+
+        nia_assert_equal(
+            Some((Action::KeyClick(2), 2)),
+            action_deque.take_action_with_depth(),
+        );
+        nia_assert_equal(
+            Some((Action::KeyClick(3), 2)),
+            action_deque.take_action_with_depth(),
+        );
+        nia_assert_equal(
+            Some((Action::KeyClick(1), 0)),
+            action_deque.take_action_with_depth(),
+        );
+        nia_assert_equal(None, action_deque.take_action_with_depth());
+    }
+
+    #[test]
+    fn counts_steps_of_typed_text_once() {
+        let mut action_deque = ActionDeque::new();
+        let mut action_guard = ActionGuard::new();
+        let max_actions_per_second = 100;
+        let text = "a".repeat(max_actions_per_second * 3);
+        let mut typed_actions = 0;
+
+        action_deque.push_action(Action::TextType(text));
+
+        while let Some((action, depth, is_counted)) =
+            action_deque.take_counted_action_with_depth()
+        {
+            nia_assert(
+                !is_counted
+                    || action_guard.register_action(max_actions_per_second),
+            );
+
+            match action {
+                Action::TextType(text) => {
+                    let actions =
+                        KeyboardLayout::Us.text_to_actions(&text).unwrap();
+
+                    action_deque
+                        .push_steps_front(Action::Sequence(actions), depth);
+                },
+                _ => typed_actions += 1,
+            }
+        }
+
+        nia_assert(typed_actions >= max_actions_per_second * 3);
+    }
+
+    #[test]
+    fn clears_actions() {
+        let mut action_deque = ActionDeque::new();
+
+        action_deque.push_action(Action::KeyClick(1));
+        action_deque
+            .push_action(Action::Repeat(100, Box::new(Action::KeyClick(2))));
+
+        nia_assert(!action_deque.is_empty());
+        action_deque.clear();
+        nia_assert(action_deque.is_empty());
+        nia_assert_equal(None, action_deque.take_action());
+    }
+
+    #[test]
+    fn skips_empty_composite_actions() {
+        let mut action_deque = ActionDeque::new();
+
+        action_deque.push_action(Action::Sequence(vec![]));
+        action_deque
+            .push_action(Action::Sequence(vec![Action::Sequence(vec![])]));
+
+        nia_assert_equal(
+            Vec::<Action>::new(),
+            take_all_actions(&mut action_deque),
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use std::time::Duration;
+use std::time::Instant;
+
+const RATE_WINDOW: Duration = Duration::from_secs(1);
+
+pub struct ActionGuard {
+    window_start: Instant,
+    actions_in_window: usize,
+}
+
+impl ActionGuard {
+    pub fn new() -> ActionGuard {
+        ActionGuard {
+            window_start: Instant::now(),
+            actions_in_window: 0,
+        }
+    }
+
+    fn register_action_at(
+        &mut self,
+        now: Instant,
+        max_actions_per_second: usize,
+    ) -> bool {
+        if now.duration_since(self.window_start) >= RATE_WINDOW {
+            self.window_start = now;
+            self.actions_in_window = 0;
+        }
+
+        self.actions_in_window += 1;
+
+        self.actions_in_window <= max_actions_per_second
+    }
+
+    pub fn register_action(&mut self, max_actions_per_second: usize) -> bool {
+        self.register_action_at(Instant::now(), max_actions_per_second)
+    }
+
+    pub fn reset(&mut self) {
+        self.window_start = Instant::now();
+        self.actions_in_window = 0;
+    }
+
+    pub fn named_action_depth_is_allowed(
+        depth: usize,
+        max_named_action_depth: usize,
+    ) -> bool {
+        depth < max_named_action_depth
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[test]
+    fn allows_actions_up_to_the_cap() {
+        let mut action_guard = ActionGuard::new();
+        let now = action_guard.window_start;
+
+        nia_assert(action_guard.register_action_at(now, 3));
+        nia_assert(action_guard.register_action_at(now, 3));
+        nia_assert(action_guard.register_action_at(now, 3));
+        nia_assert(!action_guard.register_action_at(now, 3));
+    }
+
+    #[test]
+    fn starts_new_window_after_a_second() {
+        let mut action_guard = ActionGuard::new();
+        let now = action_guard.window_start;
+
+        nia_assert(action_guard.register_action_at(now, 1));
+        nia_assert(!action_guard.register_action_at(now, 1));
+        nia_assert(action_guard.register_action_at(now + RATE_WINDOW, 1));
+    }
+
+    #[test]
+    fn checks_named_action_depth() {
+        nia_assert(ActionGuard::named_action_depth_is_allowed(0, 2));
+        nia_assert(ActionGuard::named_action_depth_is_allowed(1, 2));
+        nia_assert(!ActionGuard::named_action_depth_is_allowed(2, 2));
+    }
+}
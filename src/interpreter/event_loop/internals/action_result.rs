@@ -5,6 +5,9 @@ use nia_events::Command;
 pub enum ActionResult {
     SendCommand(Command),
     PushAction(Action),
+    // steps of an action that was already counted by the rate guard, like
+    // the keys of typed text
+    PushSteps(Action),
     Nothing,
 }
 
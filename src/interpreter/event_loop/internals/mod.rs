@@ -1,13 +1,17 @@
 mod action_deque;
+mod action_guard;
 mod action_result;
 mod nia_action_listener;
 mod nia_action_listener_handle;
 mod nia_worker;
+mod pressed_inputs;
 mod state_machine_action;
 
 pub use action_deque::*;
+pub use action_guard::*;
 pub use action_result::*;
 pub use nia_action_listener::*;
 pub use nia_action_listener_handle::*;
 pub use nia_worker::*;
+pub use pressed_inputs::*;
 pub use state_machine_action::*;
@@ -16,6 +16,7 @@ use nia_state_machine::StateMachineResult;
 use crate::Interpreter;
 use crate::Key;
 use crate::KeyChord;
+use crate::KeyRemap;
 use crate::NiaActionListenerHandle;
 use crate::StateMachineAction;
 use crate::{Convertable, Error};
@@ -27,6 +28,8 @@ pub struct NiaActionListener {
     devices: Vec<DeviceInfo>,
     modifiers: Vec<ModifierDescription>,
     mappings: Vec<Mapping>,
+    key_remaps: Vec<KeyRemap>,
+    emergency_stop_key_chord: Option<KeyChord>,
 }
 
 impl NiaActionListener {
@@ -35,6 +38,8 @@ impl NiaActionListener {
             devices: Vec::new(),
             modifiers: Vec::new(),
             mappings: Vec::new(),
+            key_remaps: Vec::new(),
+            emergency_stop_key_chord: None,
         }
     }
 
@@ -44,11 +49,16 @@ impl NiaActionListener {
         let devices_info = library::get_defined_devices_info(interpreter)?;
         let modifiers = library::get_defined_modifiers(interpreter)?;
         let mappings = library::get_defined_mappings(interpreter)?;
+        let key_remaps = library::get_defined_key_remaps(interpreter)?;
+        let emergency_stop_key_chord =
+            library::get_emergency_stop_key_chord(interpreter)?;
 
         let nia_action_listener = NiaActionListener {
             devices: devices_info,
             modifiers,
             mappings,
+            key_remaps,
+            emergency_stop_key_chord,
         };
 
         Ok(nia_action_listener)
@@ -64,8 +74,28 @@ impl NiaActionListener {
             );
         }
 
-        for modifier in &self.modifiers {
-            match modifier.get_key() {
+        // todo: rewrite key presses, releases and repeats 1:1 once the
+        //  listener of nia_events reports them, until then remaps apply to
+        //  recognized chords and keys remapped to modifiers are listened as
+        //  modifiers
+        let remapped_modifier_keys = self
+            .key_remaps
+            .iter()
+            .filter(|key_remap| {
+                self.modifiers
+                    .iter()
+                    .any(|modifier| modifier.get_key() == key_remap.get_to())
+            })
+            .map(|key_remap| key_remap.get_from());
+
+        let modifier_keys = self
+            .modifiers
+            .iter()
+            .map(|modifier| modifier.get_key())
+            .chain(remapped_modifier_keys);
+
+        for modifier_key in modifier_keys {
+            match modifier_key {
                 Key::LoneKey(lone_key) => {
                     settings_builder = settings_builder
                         .add_modifier_1(lone_key.get_key_id() as u16);
@@ -111,6 +141,8 @@ impl NiaActionListener {
         let listener_handle = listener.start_listening();
 
         let mut state_machine = self.construct_state_machine()?;
+        let emergency_stop_key_chord = self.emergency_stop_key_chord.clone();
+        let key_remaps = self.key_remaps.clone();
 
         let (state_machine_action_sender, state_machine_action_receiver) =
             mpsc::channel();
@@ -137,6 +169,28 @@ impl NiaActionListener {
                                     &key_chord_et,
                                 );
 
+                            // remaps rewrite keys before anything else sees
+                            // them, so fallbacks forward the remapped keys
+                            let key_chord = KeyRemap::remap_key_chord_with(
+                                &key_remaps,
+                                &key_chord,
+                            );
+
+                            // emergency stop is checked before the state
+                            // machine, so no mapping can shadow it
+                            if emergency_stop_key_chord.as_ref()
+                                == Some(&key_chord)
+                            {
+                                match action_sender
+                                    .send(StateMachineAction::EmergencyStop)
+                                {
+                                    Ok(_) => {}
+                                    Err(_) => {}
+                                }
+
+                                continue;
+                            }
+
                             match state_machine.excite(key_chord) {
                                 StateMachineResult::Excited(action) => {
                                     match action_sender.send(action) {
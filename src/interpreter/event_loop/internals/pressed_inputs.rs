@@ -0,0 +1,117 @@
+use std::collections::BTreeSet;
+
+use crate::Action;
+
+#[derive(Clone, Debug)]
+pub struct PressedInputs {
+    key_codes: BTreeSet<i32>,
+    button_codes: BTreeSet<i32>,
+}
+
+impl PressedInputs {
+    pub fn new() -> PressedInputs {
+        PressedInputs {
+            key_codes: BTreeSet::new(),
+            button_codes: BTreeSet::new(),
+        }
+    }
+
+    pub fn register_action(&mut self, action: &Action) {
+        match action {
+            Action::KeyPress(key_code) => {
+                self.key_codes.insert(*key_code);
+            },
+            Action::KeyRelease(key_code) => {
+                self.key_codes.remove(key_code);
+            },
+            Action::MouseButtonPress(button_code) => {
+                self.button_codes.insert(*button_code);
+            },
+            Action::MouseButtonRelease(button_code) => {
+                self.button_codes.remove(button_code);
+            },
+            _ => {},
+        }
+    }
+
+    pub fn get_key_codes(&self) -> Vec<i32> {
+        self.key_codes.iter().map(|key_code| *key_code).collect()
+    }
+
+    pub fn get_button_codes(&self) -> Vec<i32> {
+        self.button_codes
+            .iter()
+            .map(|button_code| *button_code)
+            .collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.key_codes.is_empty() && self.button_codes.is_empty()
+    }
+
+    pub fn take_release_actions(&mut self) -> Vec<Action> {
+        let mut actions = Vec::new();
+
+        for key_code in self.key_codes.iter().rev() {
+            actions.push(Action::KeyRelease(*key_code));
+        }
+
+        for button_code in self.button_codes.iter().rev() {
+            actions.push(Action::MouseButtonRelease(*button_code));
+        }
+
+        self.key_codes.clear();
+        self.button_codes.clear();
+
+        actions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[test]
+    fn tracks_pressed_keys_and_buttons() {
+        let mut pressed_inputs = PressedInputs::new();
+
+        pressed_inputs.register_action(&Action::KeyPress(29));
+        pressed_inputs.register_action(&Action::KeyPress(42));
+        pressed_inputs.register_action(&Action::KeyClick(30));
+        pressed_inputs.register_action(&Action::KeyRelease(29));
+        pressed_inputs.register_action(&Action::MouseButtonPress(272));
+        pressed_inputs.register_action(&Action::MouseButtonClick(273));
+
+        nia_assert_equal(vec![42], pressed_inputs.get_key_codes());
+        nia_assert_equal(vec![272], pressed_inputs.get_button_codes());
+        nia_assert(!pressed_inputs.is_empty());
+    }
+
+    #[test]
+    fn makes_release_actions_and_forgets_inputs() {
+        let mut pressed_inputs = PressedInputs::new();
+
+        pressed_inputs.register_action(&Action::KeyPress(29));
+        pressed_inputs.register_action(&Action::KeyPress(42));
+        pressed_inputs.register_action(&Action::MouseButtonPress(272));
+
+        nia_assert_equal(
+            vec![
+                Action::KeyRelease(42),
+                Action::KeyRelease(29),
+                Action::MouseButtonRelease(272),
+            ],
+            pressed_inputs.take_release_actions(),
+        );
+
+        nia_assert(pressed_inputs.is_empty());
+        nia_assert_equal(
+            Vec::<Action>::new(),
+            pressed_inputs.take_release_actions(),
+        );
+    }
+}
@@ -4,6 +4,7 @@ use crate::Action;
 pub enum StateMachineAction {
     Empty,
     Execute(Action),
+    EmergencyStop,
 }
 
 impl From<Action> for StateMachineAction {
@@ -0,0 +1,50 @@
+use crate::Error;
+use crate::GarbageCollectionStatistics;
+
+#[derive(Clone, Debug)]
+pub enum NiaCollectGarbageCommandResult {
+    Success(GarbageCollectionStatistics),
+    Error(String),
+    Failure(String),
+}
+
+impl From<Error> for NiaCollectGarbageCommandResult {
+    fn from(error: Error) -> Self {
+        let message = error.to_string();
+
+        if error.is_failure() {
+            NiaCollectGarbageCommandResult::Failure(message)
+        } else {
+            NiaCollectGarbageCommandResult::Error(message)
+        }
+    }
+}
+
+impl From<Result<GarbageCollectionStatistics, Error>>
+    for NiaCollectGarbageCommandResult
+{
+    fn from(result: Result<GarbageCollectionStatistics, Error>) -> Self {
+        match result {
+            Ok(statistics) => {
+                NiaCollectGarbageCommandResult::Success(statistics)
+            },
+            Err(error) => NiaCollectGarbageCommandResult::from(error),
+        }
+    }
+}
+
+impl std::fmt::Display for NiaCollectGarbageCommandResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NiaCollectGarbageCommandResult::Success(statistics) => {
+                write!(f, "Success: {}", statistics)
+            },
+            NiaCollectGarbageCommandResult::Error(execution_result) => {
+                write!(f, "Error: {}.", execution_result)
+            },
+            NiaCollectGarbageCommandResult::Failure(execution_result) => {
+                write!(f, "Failure: {}.", execution_result)
+            },
+        }
+    }
+}
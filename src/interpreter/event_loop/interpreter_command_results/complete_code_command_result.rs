@@ -0,0 +1,46 @@
+use crate::Completion;
+use crate::Error;
+
+#[derive(Clone, Debug)]
+pub enum NiaCompleteCodeCommandResult {
+    Success(Completion),
+    Error(String),
+    Failure(String),
+}
+
+impl From<Error> for NiaCompleteCodeCommandResult {
+    fn from(error: Error) -> Self {
+        let message = error.to_string();
+
+        if error.is_failure() {
+            NiaCompleteCodeCommandResult::Failure(message)
+        } else {
+            NiaCompleteCodeCommandResult::Error(message)
+        }
+    }
+}
+
+impl From<Result<Completion, Error>> for NiaCompleteCodeCommandResult {
+    fn from(result: Result<Completion, Error>) -> Self {
+        match result {
+            Ok(completion) => NiaCompleteCodeCommandResult::Success(completion),
+            Err(error) => NiaCompleteCodeCommandResult::from(error),
+        }
+    }
+}
+
+impl std::fmt::Display for NiaCompleteCodeCommandResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NiaCompleteCodeCommandResult::Success(completion) => {
+                write!(f, "Success: {:?}.", completion.get_candidates())
+            },
+            NiaCompleteCodeCommandResult::Error(execution_result) => {
+                write!(f, "Error: {}.", execution_result)
+            },
+            NiaCompleteCodeCommandResult::Failure(execution_result) => {
+                write!(f, "Failure: {}.", execution_result)
+            },
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use crate::Error;
+
+#[derive(Clone, Debug)]
+pub enum NiaDefineRemapCommandResult {
+    Success(),
+    Error(String),
+    Failure(String),
+}
+
+impl From<Error> for NiaDefineRemapCommandResult {
+    fn from(error: Error) -> Self {
+        let message = error.to_string();
+
+        if error.is_failure() {
+            NiaDefineRemapCommandResult::Failure(message)
+        } else {
+            NiaDefineRemapCommandResult::Error(message)
+        }
+    }
+}
+
+impl<T> From<Result<T, Error>> for NiaDefineRemapCommandResult
+where
+    T: Into<String>,
+{
+    fn from(result: Result<T, Error>) -> Self {
+        match result {
+            Ok(_) => NiaDefineRemapCommandResult::Success(),
+            Err(error) => NiaDefineRemapCommandResult::from(error),
+        }
+    }
+}
+
+impl std::fmt::Display for NiaDefineRemapCommandResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NiaDefineRemapCommandResult::Success() => write!(f, "Success."),
+            NiaDefineRemapCommandResult::Error(execution_result) => {
+                write!(f, "Error: {}.", execution_result)
+            },
+            NiaDefineRemapCommandResult::Failure(execution_result) => {
+                write!(f, "Failure: {}.", execution_result)
+            },
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use crate::DeviceInfo;
+use crate::Error;
+
+#[derive(Clone, Debug)]
+pub enum NiaGetDefinedDevicesCommandResult {
+    Success(Vec<DeviceInfo>),
+    Error(String),
+    Failure(String),
+}
+
+impl From<Error> for NiaGetDefinedDevicesCommandResult {
+    fn from(error: Error) -> Self {
+        let message = error.to_string();
+
+        if error.is_failure() {
+            NiaGetDefinedDevicesCommandResult::Failure(message)
+        } else {
+            NiaGetDefinedDevicesCommandResult::Error(message)
+        }
+    }
+}
+
+impl From<Result<Vec<DeviceInfo>, Error>>
+    for NiaGetDefinedDevicesCommandResult
+{
+    fn from(result: Result<Vec<DeviceInfo>, Error>) -> Self {
+        match result {
+            Ok(devices) => NiaGetDefinedDevicesCommandResult::Success(devices),
+            Err(error) => NiaGetDefinedDevicesCommandResult::from(error),
+        }
+    }
+}
+
+impl std::fmt::Display for NiaGetDefinedDevicesCommandResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NiaGetDefinedDevicesCommandResult::Success(devices) => {
+                write!(f, "Success: {:?}.", devices)
+            },
+            NiaGetDefinedDevicesCommandResult::Error(execution_result) => {
+                write!(f, "Error: {}.", execution_result)
+            },
+            NiaGetDefinedDevicesCommandResult::Failure(execution_result) => {
+                write!(f, "Failure: {}.", execution_result)
+            },
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::{Error, KeyRemap};
+
+#[derive(Clone, Debug)]
+pub enum NiaGetDefinedRemapsCommandResult {
+    Success(Vec<KeyRemap>),
+    Error(String),
+    Failure(String),
+}
+
+impl From<Error> for NiaGetDefinedRemapsCommandResult {
+    fn from(error: Error) -> Self {
+        let message = error.to_string();
+
+        if error.is_failure() {
+            NiaGetDefinedRemapsCommandResult::Failure(message)
+        } else {
+            NiaGetDefinedRemapsCommandResult::Error(message)
+        }
+    }
+}
+
+impl From<Result<Vec<KeyRemap>, Error>> for NiaGetDefinedRemapsCommandResult {
+    fn from(result: Result<Vec<KeyRemap>, Error>) -> Self {
+        match result {
+            Ok(key_remaps) => {
+                NiaGetDefinedRemapsCommandResult::Success(key_remaps)
+            },
+            Err(error) => NiaGetDefinedRemapsCommandResult::from(error),
+        }
+    }
+}
+
+impl std::fmt::Display for NiaGetDefinedRemapsCommandResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NiaGetDefinedRemapsCommandResult::Success(defined_key_remaps) => {
+                write!(f, "Success: {:?}.", defined_key_remaps)
+            },
+            NiaGetDefinedRemapsCommandResult::Error(execution_result) => {
+                write!(f, "Error: {}.", execution_result)
+            },
+            NiaGetDefinedRemapsCommandResult::Failure(execution_result) => {
+                write!(f, "Failure: {}.", execution_result)
+            },
+        }
+    }
+}
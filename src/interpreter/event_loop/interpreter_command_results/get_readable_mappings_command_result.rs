@@ -0,0 +1,50 @@
+use crate::Error;
+use crate::KeyChord;
+
+#[derive(Clone, Debug)]
+pub enum NiaGetReadableMappingsCommandResult {
+    Success(Vec<(Vec<KeyChord>, String)>),
+    Error(String),
+    Failure(String),
+}
+
+impl From<Error> for NiaGetReadableMappingsCommandResult {
+    fn from(error: Error) -> Self {
+        let message = error.to_string();
+
+        if error.is_failure() {
+            NiaGetReadableMappingsCommandResult::Failure(message)
+        } else {
+            NiaGetReadableMappingsCommandResult::Error(message)
+        }
+    }
+}
+
+impl From<Result<Vec<(Vec<KeyChord>, String)>, Error>>
+    for NiaGetReadableMappingsCommandResult
+{
+    fn from(result: Result<Vec<(Vec<KeyChord>, String)>, Error>) -> Self {
+        match result {
+            Ok(mappings) => {
+                NiaGetReadableMappingsCommandResult::Success(mappings)
+            },
+            Err(error) => NiaGetReadableMappingsCommandResult::from(error),
+        }
+    }
+}
+
+impl std::fmt::Display for NiaGetReadableMappingsCommandResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NiaGetReadableMappingsCommandResult::Success(mappings) => {
+                write!(f, "Success: {:?}.", mappings)
+            },
+            NiaGetReadableMappingsCommandResult::Error(execution_result) => {
+                write!(f, "Error: {}.", execution_result)
+            },
+            NiaGetReadableMappingsCommandResult::Failure(execution_result) => {
+                write!(f, "Failure: {}.", execution_result)
+            },
+        }
+    }
+}
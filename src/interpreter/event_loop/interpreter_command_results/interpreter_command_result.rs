@@ -21,6 +21,16 @@ pub enum NiaInterpreterCommandResult {
     IsListening(NiaIsListeningCommandResult),
     StartListening(NiaStartListeningCommandResult),
     StopListening(NiaStopListeningCommandResult),
+    DefineRemap(NiaDefineRemapCommandResult),
+    GetDefinedRemaps(NiaGetDefinedRemapsCommandResult),
+    RemoveRemap(NiaRemoveRemapCommandResult),
+    Reload(NiaReloadCommandResult),
+    CompleteCode(NiaCompleteCodeCommandResult),
+    LoadFile(NiaLoadFileCommandResult),
+    CollectGarbage(NiaCollectGarbageCommandResult),
+    GetDefinedDevices(NiaGetDefinedDevicesCommandResult),
+    TimeCode(NiaTimeCodeCommandResult),
+    GetReadableMappings(NiaGetReadableMappingsCommandResult),
 }
 
 macro_rules! make_from_impl {
@@ -105,6 +115,43 @@ make_from_impl!(
     NiaStopListeningCommandResult,
     NiaInterpreterCommandResult::StopListening
 );
+make_from_impl!(
+    NiaDefineRemapCommandResult,
+    NiaInterpreterCommandResult::DefineRemap
+);
+make_from_impl!(
+    NiaGetDefinedRemapsCommandResult,
+    NiaInterpreterCommandResult::GetDefinedRemaps
+);
+make_from_impl!(
+    NiaRemoveRemapCommandResult,
+    NiaInterpreterCommandResult::RemoveRemap
+);
+make_from_impl!(NiaReloadCommandResult, NiaInterpreterCommandResult::Reload);
+make_from_impl!(
+    NiaCompleteCodeCommandResult,
+    NiaInterpreterCommandResult::CompleteCode
+);
+make_from_impl!(
+    NiaLoadFileCommandResult,
+    NiaInterpreterCommandResult::LoadFile
+);
+make_from_impl!(
+    NiaCollectGarbageCommandResult,
+    NiaInterpreterCommandResult::CollectGarbage
+);
+make_from_impl!(
+    NiaGetDefinedDevicesCommandResult,
+    NiaInterpreterCommandResult::GetDefinedDevices
+);
+make_from_impl!(
+    NiaTimeCodeCommandResult,
+    NiaInterpreterCommandResult::TimeCode
+);
+make_from_impl!(
+    NiaGetReadableMappingsCommandResult,
+    NiaInterpreterCommandResult::GetReadableMappings
+);
 
 impl std::fmt::Display for NiaInterpreterCommandResult {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
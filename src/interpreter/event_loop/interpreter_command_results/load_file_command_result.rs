@@ -0,0 +1,45 @@
+use crate::Error;
+
+#[derive(Clone, Debug)]
+pub enum NiaLoadFileCommandResult {
+    Success(String),
+    Error(String),
+    Failure(String),
+}
+
+impl From<Error> for NiaLoadFileCommandResult {
+    fn from(error: Error) -> Self {
+        let message = error.to_string();
+
+        if error.is_failure() {
+            NiaLoadFileCommandResult::Failure(message)
+        } else {
+            NiaLoadFileCommandResult::Error(message)
+        }
+    }
+}
+
+impl From<Result<String, Error>> for NiaLoadFileCommandResult {
+    fn from(result: Result<String, Error>) -> Self {
+        match result {
+            Ok(value) => NiaLoadFileCommandResult::Success(value),
+            Err(error) => NiaLoadFileCommandResult::from(error),
+        }
+    }
+}
+
+impl std::fmt::Display for NiaLoadFileCommandResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NiaLoadFileCommandResult::Success(value) => {
+                write!(f, "Success: {}.", value)
+            },
+            NiaLoadFileCommandResult::Error(execution_result) => {
+                write!(f, "Error: {}.", execution_result)
+            },
+            NiaLoadFileCommandResult::Failure(execution_result) => {
+                write!(f, "Failure: {}.", execution_result)
+            },
+        }
+    }
+}
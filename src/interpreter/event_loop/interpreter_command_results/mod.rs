@@ -1,39 +1,59 @@
 mod change_mapping_command_result;
+mod collect_garbage_command_result;
+mod complete_code_command_result;
 mod define_action_command_result;
 mod define_device_command_result;
 mod define_mapping_command_result;
 mod define_modifier_command_result;
+mod define_remap_command_result;
 mod execute_code_command_result;
 mod get_defined_actions_result;
+mod get_defined_devices_command_result;
 mod get_defined_mappings_command_result;
 mod get_defined_modifiers_command_result;
+mod get_defined_remaps_command_result;
+mod get_readable_mappings_command_result;
 mod interpreter_command_result;
 mod is_listening_command_result;
+mod load_file_command_result;
+mod reload_command_result;
 mod remove_action_command_result;
 mod remove_device_by_id_command_result;
 mod remove_device_by_name_command_result;
 mod remove_device_by_path_command_result;
 mod remove_mapping_command_result;
 mod remove_modifier_command_result;
+mod remove_remap_command_result;
 mod start_listening_command_result;
 mod stop_listening_command_result;
+mod time_code_command_result;
 
 pub use change_mapping_command_result::*;
+pub use collect_garbage_command_result::*;
+pub use complete_code_command_result::*;
 pub use define_action_command_result::*;
 pub use define_device_command_result::*;
 pub use define_mapping_command_result::*;
 pub use define_modifier_command_result::*;
+pub use define_remap_command_result::*;
 pub use execute_code_command_result::*;
 pub use get_defined_actions_result::*;
+pub use get_defined_devices_command_result::*;
 pub use get_defined_mappings_command_result::*;
 pub use get_defined_modifiers_command_result::*;
+pub use get_defined_remaps_command_result::*;
+pub use get_readable_mappings_command_result::*;
 pub use interpreter_command_result::*;
 pub use is_listening_command_result::*;
+pub use load_file_command_result::*;
+pub use reload_command_result::*;
 pub use remove_action_command_result::*;
 pub use remove_device_by_id_command_result::*;
 pub use remove_device_by_name_command_result::*;
 pub use remove_device_by_path_command_result::*;
 pub use remove_mapping_command_result::*;
 pub use remove_modifier_command_result::*;
+pub use remove_remap_command_result::*;
 pub use start_listening_command_result::*;
 pub use stop_listening_command_result::*;
+pub use time_code_command_result::*;
@@ -0,0 +1,45 @@
+use crate::Error;
+
+#[derive(Clone, Debug)]
+pub enum NiaReloadCommandResult {
+    Success(Vec<String>),
+    Error(String),
+    Failure(String),
+}
+
+impl From<Error> for NiaReloadCommandResult {
+    fn from(error: Error) -> Self {
+        let message = error.to_string();
+
+        if error.is_failure() {
+            NiaReloadCommandResult::Failure(message)
+        } else {
+            NiaReloadCommandResult::Error(message)
+        }
+    }
+}
+
+impl From<Result<Vec<String>, Error>> for NiaReloadCommandResult {
+    fn from(result: Result<Vec<String>, Error>) -> Self {
+        match result {
+            Ok(module_paths) => NiaReloadCommandResult::Success(module_paths),
+            Err(error) => NiaReloadCommandResult::from(error),
+        }
+    }
+}
+
+impl std::fmt::Display for NiaReloadCommandResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NiaReloadCommandResult::Success(module_paths) => {
+                write!(f, "Success: {:?}.", module_paths)
+            },
+            NiaReloadCommandResult::Error(execution_result) => {
+                write!(f, "Error: {}.", execution_result)
+            },
+            NiaReloadCommandResult::Failure(execution_result) => {
+                write!(f, "Failure: {}.", execution_result)
+            },
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use crate::Error;
+
+#[derive(Clone, Debug)]
+pub enum NiaRemoveRemapCommandResult {
+    Success(),
+    Error(String),
+    Failure(String),
+}
+
+impl From<Error> for NiaRemoveRemapCommandResult {
+    fn from(error: Error) -> Self {
+        let message = error.to_string();
+
+        if error.is_failure() {
+            NiaRemoveRemapCommandResult::Failure(message)
+        } else {
+            NiaRemoveRemapCommandResult::Error(message)
+        }
+    }
+}
+
+impl<T> From<Result<T, Error>> for NiaRemoveRemapCommandResult
+where
+    T: Into<String>,
+{
+    fn from(result: Result<T, Error>) -> Self {
+        match result {
+            Ok(_) => NiaRemoveRemapCommandResult::Success(),
+            Err(error) => NiaRemoveRemapCommandResult::from(error),
+        }
+    }
+}
+
+impl std::fmt::Display for NiaRemoveRemapCommandResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NiaRemoveRemapCommandResult::Success() => write!(f, "Success."),
+            NiaRemoveRemapCommandResult::Error(execution_result) => {
+                write!(f, "Error: {}.", execution_result)
+            },
+            NiaRemoveRemapCommandResult::Failure(execution_result) => {
+                write!(f, "Failure: {}.", execution_result)
+            },
+        }
+    }
+}
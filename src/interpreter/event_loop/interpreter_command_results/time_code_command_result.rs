@@ -0,0 +1,36 @@
+use std::time::Duration;
+
+use crate::NiaExecuteCodeCommandResult;
+
+#[derive(Clone, Debug)]
+pub struct NiaTimeCodeCommandResult {
+    result: NiaExecuteCodeCommandResult,
+    elapsed: Duration,
+}
+
+impl NiaTimeCodeCommandResult {
+    pub fn new(
+        result: NiaExecuteCodeCommandResult,
+        elapsed: Duration,
+    ) -> NiaTimeCodeCommandResult {
+        NiaTimeCodeCommandResult { result, elapsed }
+    }
+
+    pub fn get_result(&self) -> &NiaExecuteCodeCommandResult {
+        &self.result
+    }
+
+    pub fn get_elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    pub fn take(self) -> (NiaExecuteCodeCommandResult, Duration) {
+        (self.result, self.elapsed)
+    }
+}
+
+impl std::fmt::Display for NiaTimeCodeCommandResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} Elapsed: {:?}.", self.result, self.elapsed)
+    }
+}
@@ -0,0 +1,8 @@
+#[derive(Clone, Debug)]
+pub struct NiaCollectGarbageCommand {}
+
+impl NiaCollectGarbageCommand {
+    pub fn new() -> NiaCollectGarbageCommand {
+        NiaCollectGarbageCommand {}
+    }
+}
@@ -0,0 +1,25 @@
+#[derive(Clone, Debug)]
+pub struct NiaCompleteCodeCommand {
+    code: String,
+    position: usize,
+}
+
+impl NiaCompleteCodeCommand {
+    pub fn new<S>(code: S, position: usize) -> NiaCompleteCodeCommand
+    where
+        S: Into<String>,
+    {
+        NiaCompleteCodeCommand {
+            code: code.into(),
+            position,
+        }
+    }
+
+    pub fn get_code(&self) -> &String {
+        &self.code
+    }
+
+    pub fn get_position(&self) -> usize {
+        self.position
+    }
+}
@@ -0,0 +1,16 @@
+use crate::KeyRemap;
+
+#[derive(Clone, Debug)]
+pub struct NiaDefineRemapCommand {
+    key_remap: KeyRemap,
+}
+
+impl NiaDefineRemapCommand {
+    pub fn new(key_remap: KeyRemap) -> NiaDefineRemapCommand {
+        NiaDefineRemapCommand { key_remap }
+    }
+
+    pub fn get_key_remap(&self) -> &KeyRemap {
+        &self.key_remap
+    }
+}
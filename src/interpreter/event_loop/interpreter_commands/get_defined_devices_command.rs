@@ -0,0 +1,8 @@
+#[derive(Clone, Debug)]
+pub struct NiaGetDefinedDevicesCommand {}
+
+impl NiaGetDefinedDevicesCommand {
+    pub fn new() -> NiaGetDefinedDevicesCommand {
+        NiaGetDefinedDevicesCommand {}
+    }
+}
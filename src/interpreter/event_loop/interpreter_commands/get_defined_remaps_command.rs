@@ -0,0 +1,8 @@
+#[derive(Clone, Debug)]
+pub struct NiaGetDefinedRemapsCommand {}
+
+impl NiaGetDefinedRemapsCommand {
+    pub fn new() -> NiaGetDefinedRemapsCommand {
+        NiaGetDefinedRemapsCommand {}
+    }
+}
@@ -0,0 +1,8 @@
+#[derive(Clone, Debug)]
+pub struct NiaGetReadableMappingsCommand {}
+
+impl NiaGetReadableMappingsCommand {
+    pub fn new() -> NiaGetReadableMappingsCommand {
+        NiaGetReadableMappingsCommand {}
+    }
+}
@@ -1,7 +1,7 @@
 use crate::interpreter::event_loop::interpreter_commands::*;
 
 use crate::{Action, Key, Mapping, NamedAction};
-use crate::{KeyChord, ModifierDescription};
+use crate::{KeyChord, KeyRemap, ModifierDescription};
 
 #[derive(Clone, Debug)]
 pub enum NiaInterpreterCommand {
@@ -23,6 +23,16 @@ pub enum NiaInterpreterCommand {
     IsListening(NiaIsListeningCommand),
     StartListening(NiaStartListeningCommand),
     StopListening(NiaStopListeningCommand),
+    DefineRemap(NiaDefineRemapCommand),
+    GetDefinedRemaps(NiaGetDefinedRemapsCommand),
+    RemoveRemap(NiaRemoveRemapCommand),
+    Reload(NiaReloadCommand),
+    CompleteCode(NiaCompleteCodeCommand),
+    LoadFile(NiaLoadFileCommand),
+    CollectGarbage(NiaCollectGarbageCommand),
+    GetDefinedDevices(NiaGetDefinedDevicesCommand),
+    TimeCode(NiaTimeCodeCommand),
+    GetReadableMappings(NiaGetReadableMappingsCommand),
 }
 
 impl NiaInterpreterCommand {
@@ -172,4 +182,83 @@ impl NiaInterpreterCommand {
 
         NiaInterpreterCommand::StopListening(stop_listening_command)
     }
+
+    pub fn make_define_remap_command(
+        key_remap: KeyRemap,
+    ) -> NiaInterpreterCommand {
+        let define_remap_command = NiaDefineRemapCommand::new(key_remap);
+
+        NiaInterpreterCommand::DefineRemap(define_remap_command)
+    }
+
+    pub fn make_get_defined_remaps_command() -> NiaInterpreterCommand {
+        let get_defined_remaps_command = NiaGetDefinedRemapsCommand::new();
+
+        NiaInterpreterCommand::GetDefinedRemaps(get_defined_remaps_command)
+    }
+
+    pub fn make_remove_remap_command(key: Key) -> NiaInterpreterCommand {
+        let remove_remap_command = NiaRemoveRemapCommand::new(key);
+
+        NiaInterpreterCommand::RemoveRemap(remove_remap_command)
+    }
+
+    pub fn make_reload_command(
+        module_path: Option<String>,
+    ) -> NiaInterpreterCommand {
+        let reload_command = NiaReloadCommand::new(module_path);
+
+        NiaInterpreterCommand::Reload(reload_command)
+    }
+
+    pub fn make_complete_code_command<S>(
+        code: S,
+        position: usize,
+    ) -> NiaInterpreterCommand
+    where
+        S: Into<String>,
+    {
+        let complete_code_command = NiaCompleteCodeCommand::new(code, position);
+
+        NiaInterpreterCommand::CompleteCode(complete_code_command)
+    }
+
+    pub fn make_load_file_command<S>(file_path: S) -> NiaInterpreterCommand
+    where
+        S: Into<String>,
+    {
+        let load_file_command = NiaLoadFileCommand::new(file_path);
+
+        NiaInterpreterCommand::LoadFile(load_file_command)
+    }
+
+    pub fn make_collect_garbage_command() -> NiaInterpreterCommand {
+        let collect_garbage_command = NiaCollectGarbageCommand::new();
+
+        NiaInterpreterCommand::CollectGarbage(collect_garbage_command)
+    }
+
+    pub fn make_get_defined_devices_command() -> NiaInterpreterCommand {
+        let get_defined_devices_command = NiaGetDefinedDevicesCommand::new();
+
+        NiaInterpreterCommand::GetDefinedDevices(get_defined_devices_command)
+    }
+
+    pub fn make_time_code_command<S>(code: S) -> NiaInterpreterCommand
+    where
+        S: Into<String>,
+    {
+        let time_code_command = NiaTimeCodeCommand::new(code);
+
+        NiaInterpreterCommand::TimeCode(time_code_command)
+    }
+
+    pub fn make_get_readable_mappings_command() -> NiaInterpreterCommand {
+        let get_readable_mappings_command =
+            NiaGetReadableMappingsCommand::new();
+
+        NiaInterpreterCommand::GetReadableMappings(
+            get_readable_mappings_command,
+        )
+    }
 }
@@ -0,0 +1,19 @@
+#[derive(Clone, Debug)]
+pub struct NiaLoadFileCommand {
+    file_path: String,
+}
+
+impl NiaLoadFileCommand {
+    pub fn new<S>(file_path: S) -> NiaLoadFileCommand
+    where
+        S: Into<String>,
+    {
+        NiaLoadFileCommand {
+            file_path: file_path.into(),
+        }
+    }
+
+    pub fn get_file_path(&self) -> &String {
+        &self.file_path
+    }
+}
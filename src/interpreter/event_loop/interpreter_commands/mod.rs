@@ -1,39 +1,59 @@
 mod change_mapping_command;
+mod collect_garbage_command;
+mod complete_code_command;
 mod define_action_command;
 mod define_device_command;
 mod define_mapping_command;
 mod define_modifier_command;
+mod define_remap_command;
 mod execute_code_command;
 mod get_defined_actions;
+mod get_defined_devices_command;
 mod get_defined_mappings_command;
 mod get_defined_modifiers_command;
+mod get_defined_remaps_command;
+mod get_readable_mappings_command;
 mod interpreter_command;
 mod is_listening_command;
+mod load_file_command;
+mod reload_command;
 mod remove_action_command;
 mod remove_device_by_id_command;
 mod remove_device_by_name_command;
 mod remove_device_by_path_command;
 mod remove_mapping_command;
 mod remove_modifier_command;
+mod remove_remap_command;
 mod start_listening_command;
 mod stop_listening_command;
+mod time_code_command;
 
 pub use change_mapping_command::*;
+pub use collect_garbage_command::*;
+pub use complete_code_command::*;
 pub use define_action_command::*;
 pub use define_device_command::*;
 pub use define_mapping_command::*;
 pub use define_modifier_command::*;
+pub use define_remap_command::*;
 pub use execute_code_command::*;
 pub use get_defined_actions::*;
+pub use get_defined_devices_command::*;
 pub use get_defined_mappings_command::*;
 pub use get_defined_modifiers_command::*;
+pub use get_defined_remaps_command::*;
+pub use get_readable_mappings_command::*;
 pub use interpreter_command::*;
 pub use is_listening_command::*;
+pub use load_file_command::*;
+pub use reload_command::*;
 pub use remove_action_command::*;
 pub use remove_device_by_id_command::*;
 pub use remove_device_by_name_command::*;
 pub use remove_device_by_path_command::*;
 pub use remove_mapping_command::*;
 pub use remove_modifier_command::*;
+pub use remove_remap_command::*;
 pub use start_listening_command::*;
 pub use stop_listening_command::*;
+pub use time_code_command::*;
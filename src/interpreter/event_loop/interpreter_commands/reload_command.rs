@@ -0,0 +1,14 @@
+#[derive(Clone, Debug)]
+pub struct NiaReloadCommand {
+    module_path: Option<String>,
+}
+
+impl NiaReloadCommand {
+    pub fn new(module_path: Option<String>) -> NiaReloadCommand {
+        NiaReloadCommand { module_path }
+    }
+
+    pub fn get_module_path(&self) -> &Option<String> {
+        &self.module_path
+    }
+}
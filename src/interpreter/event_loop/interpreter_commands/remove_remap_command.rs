@@ -0,0 +1,16 @@
+use crate::Key;
+
+#[derive(Clone, Debug)]
+pub struct NiaRemoveRemapCommand {
+    key: Key,
+}
+
+impl NiaRemoveRemapCommand {
+    pub fn new(key: Key) -> NiaRemoveRemapCommand {
+        NiaRemoveRemapCommand { key }
+    }
+
+    pub fn get_key(&self) -> Key {
+        self.key
+    }
+}
@@ -0,0 +1,17 @@
+#[derive(Clone, Debug)]
+pub struct NiaTimeCodeCommand {
+    code: String,
+}
+
+impl NiaTimeCodeCommand {
+    pub fn new<S>(code: S) -> NiaTimeCodeCommand
+    where
+        S: Into<String>,
+    {
+        NiaTimeCodeCommand { code: code.into() }
+    }
+
+    pub fn get_code(&self) -> &String {
+        &self.code
+    }
+}
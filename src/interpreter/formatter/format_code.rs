@@ -0,0 +1,310 @@
+use crate::interpreter::parser::parse_syntax_tree;
+use crate::interpreter::parser::ParseError;
+use crate::interpreter::parser::SyntaxNode;
+use crate::interpreter::parser::TokenKind;
+
+const MAX_LINE_BREAKS: usize = 2;
+
+const BODY_FORM_NAMES: &[&str] = &[
+    "block",
+    "call-with-this",
+    "cond",
+    "defclass",
+    "doitems",
+    "dokeys",
+    "dolist",
+    "dotimes",
+    "dovalues",
+    "export",
+    "flet",
+    "flet*",
+    "fn",
+    "function",
+    "if",
+    "lambda",
+    "let",
+    "let*",
+    "macro",
+    "match",
+    "mlet",
+    "mlet*",
+    "progn",
+    "try",
+    "unless",
+    "when",
+    "while",
+];
+
+// forms with a body are indented by two spaces, whatever stands on the
+// first line, so `(defn f (a)' is followed by its body at `  '
+fn is_body_form_name(name: &str) -> bool {
+    BODY_FORM_NAMES.contains(&name)
+        || name.starts_with("def")
+        || name.starts_with("with-")
+}
+
+fn get_width(text: &str) -> usize {
+    text.chars().count()
+}
+
+// returns the children that are not line breaks, each with a flag telling
+// whether it starts a new line
+fn get_items(children: &[SyntaxNode]) -> Vec<(&SyntaxNode, bool)> {
+    let mut items = Vec::new();
+    let mut starts_line = false;
+
+    for child in children {
+        match child {
+            SyntaxNode::LineBreaks(_) => starts_line = true,
+            _ => {
+                items.push((child, starts_line));
+                starts_line = false;
+            },
+        }
+    }
+
+    items
+}
+
+fn get_indentation(
+    opening: &str,
+    children: &[SyntaxNode],
+    column: usize,
+) -> usize {
+    let items = get_items(children);
+    let base = column + get_width(opening);
+
+    if opening.ends_with('{') {
+        return base;
+    }
+
+    match items.as_slice() {
+        [(SyntaxNode::Atom(TokenKind::Symbol, name, _), _), ..]
+            if is_body_form_name(name) =>
+        {
+            base + 1
+        },
+        [(SyntaxNode::Atom(TokenKind::Symbol, name, _), _), (_, false), ..] => {
+            base + get_width(name) + 1
+        },
+        [(SyntaxNode::Atom(TokenKind::Symbol, _, _), _), ..] => base + 1,
+        _ => base,
+    }
+}
+
+// keys of an object literal written one pair per line are padded to the
+// widest key, so the values line up
+fn get_key_width(opening: &str, children: &[SyntaxNode]) -> Option<usize> {
+    let items = get_items(children);
+
+    if opening != "{" || items.len() < 4 || items.len() % 2 != 0 {
+        return None;
+    }
+
+    let mut key_width = 0;
+
+    for (index, pair) in items.chunks(2).enumerate() {
+        let (key, key_starts_line) = pair[0];
+        let (_, value_starts_line) = pair[1];
+
+        let key = match key {
+            SyntaxNode::Atom(TokenKind::Keyword, key, _) => key,
+            _ => return None,
+        };
+
+        if value_starts_line || (index > 0 && !key_starts_line) {
+            return None;
+        }
+
+        key_width = key_width.max(get_width(key));
+    }
+
+    Some(key_width)
+}
+
+struct Formatter {
+    output: String,
+}
+
+impl Formatter {
+    fn new() -> Formatter {
+        Formatter {
+            output: String::new(),
+        }
+    }
+
+    fn get_column(&self) -> usize {
+        match self.output.rfind('\n') {
+            Some(index) => get_width(&self.output[index + 1..]),
+            None => get_width(&self.output),
+        }
+    }
+
+    fn push_line_breaks(&mut self, count: usize, indentation: usize) {
+        let count = count.min(MAX_LINE_BREAKS);
+
+        self.output.extend(std::iter::repeat('\n').take(count));
+        self.output.extend(std::iter::repeat(' ').take(indentation));
+    }
+
+    fn format_node(&mut self, node: &SyntaxNode) {
+        match node {
+            SyntaxNode::Atom(_, text, _) | SyntaxNode::Comment(text) => {
+                self.output.push_str(text)
+            },
+            SyntaxNode::LineBreaks(_) => {},
+            SyntaxNode::Prefixed(prefix, node, _) => {
+                self.output.push_str(prefix);
+                self.format_node(node);
+            },
+            SyntaxNode::Sequence(opening, children, closing, _) => {
+                let column = self.get_column();
+                let indentation = get_indentation(opening, children, column);
+                let key_width = get_key_width(opening, children);
+
+                self.output.push_str(opening);
+                self.format_children(children, indentation, key_width, true);
+                self.output.push_str(closing);
+            },
+        }
+    }
+
+    // the line breaks of the original code are kept, the whitespace around
+    // them is not: items are separated by one space or by line breaks
+    // followed by the indentation
+    fn format_children(
+        &mut self,
+        children: &[SyntaxNode],
+        indentation: usize,
+        key_width: Option<usize>,
+        is_inside_sequence: bool,
+    ) {
+        let mut line_breaks = 0;
+        let mut item_index = 0;
+        let mut last_item = None;
+
+        for child in children {
+            if let SyntaxNode::LineBreaks(count) = child {
+                line_breaks += count;
+                continue;
+            }
+
+            if item_index > 0 {
+                if line_breaks > 0 {
+                    self.push_line_breaks(line_breaks, indentation);
+                } else {
+                    self.output.push(' ');
+                }
+            }
+
+            self.format_node(child);
+
+            if let (Some(key_width), SyntaxNode::Atom(_, key, _)) =
+                (key_width, child)
+            {
+                if item_index % 2 == 0 {
+                    let padding = key_width - get_width(key);
+
+                    self.output.extend(std::iter::repeat(' ').take(padding));
+                }
+            }
+
+            line_breaks = 0;
+            item_index += 1;
+            last_item = Some(child);
+        }
+
+        if let Some(SyntaxNode::Comment(_)) = last_item {
+            if is_inside_sequence {
+                self.push_line_breaks(1, indentation);
+            }
+        }
+    }
+}
+
+pub fn format_code(code: &str) -> Result<String, ParseError> {
+    let nodes = parse_syntax_tree(code)?;
+    let mut formatter = Formatter::new();
+
+    formatter.format_children(&nodes, 0, None, false);
+
+    let mut result = String::from(formatter.output.trim_end());
+
+    if !result.is_empty() {
+        result.push('\n');
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    fn assert_formats(code: &str, expected: &str) {
+        let result = format_code(code).unwrap();
+
+        nia_assert_equal(expected, &result);
+        nia_assert_equal(expected, &format_code(&result).unwrap());
+    }
+
+    #[test]
+    fn normalizes_whitespace() {
+        assert_formats("(+   1\t2 )", "(+ 1 2)\n");
+        assert_formats("\n\n(f  '( a  b ) )\n\n\n", "(f '(a b))\n");
+        assert_formats("(a)\n\n\n\n(b)", "(a)\n\n(b)\n");
+        assert_formats("", "");
+    }
+
+    #[test]
+    fn indents_bodies_of_special_forms() {
+        assert_formats(
+            "(defn f (a)\n(let ((b 1)\n(c 2))\n(+ a b c)))",
+            "(defn f (a)\n  (let ((b 1)\n        (c 2))\n    (+ a b c)))\n",
+        );
+        assert_formats(
+            "(cond\n((= a 1) 'one)\n(#t 'other))",
+            "(cond\n  ((= a 1) 'one)\n  (#t 'other))\n",
+        );
+        assert_formats(
+            "(match value\n(() 'nothing)\n((a) a))",
+            "(match value\n  (() 'nothing)\n  ((a) a))\n",
+        );
+    }
+
+    #[test]
+    fn aligns_arguments_of_calls() {
+        assert_formats(
+            "(list:map #(+ %1 1)\n'(1 2 3))",
+            "(list:map #(+ %1 1)\n          '(1 2 3))\n",
+        );
+        assert_formats("(f\na\nb)", "(f\n  a\n  b)\n");
+        assert_formats("'(1\n2\n3)", "'(1\n  2\n  3)\n");
+    }
+
+    #[test]
+    fn aligns_object_literals() {
+        assert_formats(
+            "{:a 1\n:long-key 2\n   :b {:c 3}}",
+            "{:a        1\n :long-key 2\n :b        {:c 3}}\n",
+        );
+        assert_formats("{:a 1 :long-key 2}", "{:a 1 :long-key 2}\n");
+    }
+
+    #[test]
+    fn preserves_comments() {
+        assert_formats(
+            ";; keys\n(defv a 1)   ; first\n\n\n; last",
+            ";; keys\n(defv a 1) ; first\n\n; last\n",
+        );
+    }
+
+    #[test]
+    fn returns_error_for_invalid_code() {
+        nia_assert(format_code("(defn f (a)").is_err());
+        nia_assert(format_code("(f))").is_err());
+    }
+}
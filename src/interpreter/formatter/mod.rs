@@ -0,0 +1,3 @@
+mod format_code;
+
+pub use format_code::*;
@@ -0,0 +1,90 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GarbageCollectionStatistics {
+    environments: usize,
+    strings: usize,
+    keywords: usize,
+    symbols: usize,
+    cons_cells: usize,
+    objects: usize,
+    functions: usize,
+}
+
+impl GarbageCollectionStatistics {
+    pub fn new(
+        environments: usize,
+        strings: usize,
+        keywords: usize,
+        symbols: usize,
+        cons_cells: usize,
+        objects: usize,
+        functions: usize,
+    ) -> GarbageCollectionStatistics {
+        GarbageCollectionStatistics {
+            environments,
+            strings,
+            keywords,
+            symbols,
+            cons_cells,
+            objects,
+            functions,
+        }
+    }
+
+    pub fn get_environments(&self) -> usize {
+        self.environments
+    }
+
+    pub fn get_strings(&self) -> usize {
+        self.strings
+    }
+
+    pub fn get_keywords(&self) -> usize {
+        self.keywords
+    }
+
+    pub fn get_symbols(&self) -> usize {
+        self.symbols
+    }
+
+    pub fn get_cons_cells(&self) -> usize {
+        self.cons_cells
+    }
+
+    pub fn get_objects(&self) -> usize {
+        self.objects
+    }
+
+    pub fn get_functions(&self) -> usize {
+        self.functions
+    }
+
+    pub fn get_total(&self) -> usize {
+        self.environments
+            + self.strings
+            + self.keywords
+            + self.symbols
+            + self.cons_cells
+            + self.objects
+            + self.functions
+    }
+}
+
+impl fmt::Display for GarbageCollectionStatistics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Freed {} items: {} environments, {} strings, {} keywords, \
+             {} symbols, {} cons cells, {} objects, {} functions.",
+            self.get_total(),
+            self.environments,
+            self.strings,
+            self.keywords,
+            self.symbols,
+            self.cons_cells,
+            self.objects,
+            self.functions
+        )
+    }
+}
@@ -1,5 +1,6 @@
 use crate::interpreter::environment::EnvironmentId;
 use crate::interpreter::error::Error;
+use crate::interpreter::garbage_collector::GarbageCollectionStatistics;
 use crate::interpreter::interpreter::Interpreter;
 use crate::interpreter::value::Value;
 
@@ -257,7 +258,20 @@ impl GarbageCollector {
         Ok(())
     }
 
-    fn free(self, interpreter: &mut Interpreter) -> Result<(), Error> {
+    fn free(
+        self,
+        interpreter: &mut Interpreter,
+    ) -> Result<GarbageCollectionStatistics, Error> {
+        let statistics = GarbageCollectionStatistics::new(
+            self.candidate_environment_ids.len(),
+            self.candidate_string_ids.len(),
+            self.candidate_keyword_ids.len(),
+            self.candidate_symbol_ids.len(),
+            self.candidate_cons_ids.len(),
+            self.candidate_object_ids.len(),
+            self.candidate_function_ids.len(),
+        );
+
         interpreter.free_environments(self.candidate_environment_ids)?;
 
         interpreter.free_strings(self.candidate_string_ids)?;
@@ -267,27 +281,26 @@ impl GarbageCollector {
         interpreter.free_objects(self.candidate_object_ids)?;
         interpreter.free_functions(self.candidate_function_ids)?;
 
-        Ok(())
+        Ok(statistics)
     }
 
     pub fn collect(
         mut self,
         interpreter: &mut Interpreter,
-    ) -> Result<(), Error> {
+    ) -> Result<GarbageCollectionStatistics, Error> {
         self.collect_context_items(interpreter)?;
         self.collect_ordinary_items(interpreter)?;
-        self.free(interpreter)?;
 
-        Ok(())
+        self.free(interpreter)
     }
 }
 
-pub fn collect_garbage(interpreter: &mut Interpreter) -> Result<(), Error> {
+pub fn collect_garbage(
+    interpreter: &mut Interpreter,
+) -> Result<GarbageCollectionStatistics, Error> {
     let gc = GarbageCollector::new(interpreter);
 
-    gc.collect(interpreter)?;
-
-    Ok(())
+    gc.collect(interpreter)
 }
 
 #[cfg(test)]
@@ -338,6 +351,32 @@ mod tests {
         nia_assert(interpreter.get_string(string_id).is_err());
     }
 
+    #[test]
+    fn returns_statistics_of_freed_items() {
+        let mut interpreter = Interpreter::new();
+
+        collect_garbage(&mut interpreter).unwrap();
+
+        interpreter
+            .execute_in_main_environment("\"string\" :some-unused-keyword")
+            .unwrap();
+
+        let statistics = collect_garbage(&mut interpreter).unwrap();
+
+        nia_assert(statistics.get_strings() >= 1);
+        nia_assert(statistics.get_keywords() >= 1);
+        nia_assert_equal(
+            statistics.get_total(),
+            statistics.get_environments()
+                + statistics.get_strings()
+                + statistics.get_keywords()
+                + statistics.get_symbols()
+                + statistics.get_cons_cells()
+                + statistics.get_objects()
+                + statistics.get_functions(),
+        );
+    }
+
     #[test]
     fn collects_keywords() {
         let mut interpreter = Interpreter::new();
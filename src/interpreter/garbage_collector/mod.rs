@@ -1,5 +1,7 @@
+mod garbage_collection_statistics;
 mod garbage_collector;
 mod garbage_collector_wrapper;
 
+pub use garbage_collection_statistics::*;
 pub use garbage_collector::*;
 pub use garbage_collector_wrapper::*;
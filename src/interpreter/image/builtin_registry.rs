@@ -0,0 +1,225 @@
+use std::collections::HashMap;
+
+use crate::BuiltinFunctionType;
+use crate::SpecialFormFunctionType;
+use crate::BUILTIN_FUNCTIONS;
+use crate::BUILTIN_OBJECTS;
+use crate::INTERNAL_FUNCTIONS;
+use crate::SPECIAL_FORMS;
+
+const INTERNAL_FUNCTION_NAME_PREFIX: &'static str = "#internal:";
+
+// Maps built-in functions and special forms to stable names. Names are taken
+// from the tables the standard library is infected from, so they do not
+// depend on what the image's interpreter rebound before it was saved.
+pub struct BuiltinRegistry {
+    builtin_functions: HashMap<String, BuiltinFunctionType>,
+    builtin_function_names: HashMap<usize, String>,
+    special_forms: HashMap<String, SpecialFormFunctionType>,
+    special_form_names: HashMap<usize, String>,
+}
+
+impl BuiltinRegistry {
+    fn new() -> BuiltinRegistry {
+        BuiltinRegistry {
+            builtin_functions: HashMap::new(),
+            builtin_function_names: HashMap::new(),
+            special_forms: HashMap::new(),
+            special_form_names: HashMap::new(),
+        }
+    }
+
+    fn register_builtin_function(
+        &mut self,
+        name: String,
+        func: BuiltinFunctionType,
+    ) {
+        self.builtin_function_names
+            .entry(func as usize)
+            .or_insert(name.clone());
+        self.builtin_functions.insert(name, func);
+    }
+
+    fn register_special_form(
+        &mut self,
+        name: String,
+        func: SpecialFormFunctionType,
+    ) {
+        self.special_form_names
+            .entry(func as usize)
+            .or_insert(name.clone());
+        self.special_forms.insert(name, func);
+    }
+
+    pub fn from_stdlib() -> BuiltinRegistry {
+        let mut registry = BuiltinRegistry::new();
+
+        for (name, func) in SPECIAL_FORMS {
+            registry.register_special_form(String::from(*name), *func);
+        }
+
+        for (name, func) in BUILTIN_FUNCTIONS {
+            registry.register_builtin_function(String::from(*name), *func);
+        }
+
+        for (object_name, functions) in BUILTIN_OBJECTS {
+            for (name, func) in functions.iter() {
+                registry.register_builtin_function(
+                    format!("{}:{}", object_name, name),
+                    *func,
+                );
+            }
+        }
+
+        for (name, func) in INTERNAL_FUNCTIONS {
+            registry.register_builtin_function(
+                format!("{}{}", INTERNAL_FUNCTION_NAME_PREFIX, name),
+                *func,
+            );
+        }
+
+        registry
+    }
+
+    pub fn get_builtin_function(
+        &self,
+        name: &str,
+    ) -> Option<BuiltinFunctionType> {
+        self.builtin_functions.get(name).map(|func| *func)
+    }
+
+    pub fn get_builtin_function_name(
+        &self,
+        func: BuiltinFunctionType,
+    ) -> Option<&String> {
+        self.builtin_function_names.get(&(func as usize))
+    }
+
+    pub fn get_builtin_function_names(&self) -> Vec<&String> {
+        self.builtin_functions.keys().collect()
+    }
+
+    pub fn get_special_form_names(&self) -> Vec<&String> {
+        self.special_forms.keys().collect()
+    }
+
+    pub fn get_special_form(
+        &self,
+        name: &str,
+    ) -> Option<SpecialFormFunctionType> {
+        self.special_forms.get(name).map(|func| *func)
+    }
+
+    pub fn get_special_form_name(
+        &self,
+        func: SpecialFormFunctionType,
+    ) -> Option<&String> {
+        self.special_form_names.get(&(func as usize))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    use crate::Function;
+    use crate::Interpreter;
+    use crate::Value;
+
+    #[test]
+    fn collects_names_of_builtin_functions_and_special_forms() {
+        let registry = BuiltinRegistry::from_stdlib();
+
+        nia_assert(registry.get_builtin_function("list:new").is_some());
+        nia_assert(registry.get_builtin_function("#internal:break").is_some());
+        nia_assert(registry.get_special_form("quote").is_some());
+        nia_assert(registry.get_builtin_function("not-a-builtin").is_none());
+    }
+
+    #[test]
+    fn names_are_reversible() {
+        let registry = BuiltinRegistry::from_stdlib();
+
+        let func = registry.get_builtin_function("list:new").unwrap();
+        let name = registry.get_builtin_function_name(func).unwrap();
+
+        nia_assert_equal(
+            func as usize,
+            registry.get_builtin_function(name).unwrap() as usize,
+        );
+
+        let special_form = registry.get_special_form("quote").unwrap();
+
+        nia_assert_equal(
+            &String::from("quote"),
+            registry.get_special_form_name(special_form).unwrap(),
+        );
+    }
+
+    fn lookup_builtin_function(
+        interpreter: &mut Interpreter,
+        name: &str,
+    ) -> BuiltinFunctionType {
+        let root_environment_id = interpreter.get_root_environment_id();
+
+        let value = match name.find(':') {
+            Some(index) => {
+                let object_symbol_id =
+                    interpreter.intern_symbol_id(&name[..index]);
+                let property_symbol_id =
+                    interpreter.intern_symbol_id(&name[index + 1..]);
+
+                match interpreter
+                    .lookup_variable(root_environment_id, object_symbol_id)
+                {
+                    Ok(Some(Value::Object(object_id))) => interpreter
+                        .get_object_property(object_id, property_symbol_id)
+                        .unwrap(),
+                    _ => None,
+                }
+            },
+            None => {
+                let symbol_id = interpreter.intern_symbol_id(name);
+
+                interpreter
+                    .lookup_function(root_environment_id, symbol_id)
+                    .unwrap()
+            },
+        };
+
+        match value {
+            Some(Value::Function(function_id)) => {
+                match interpreter.get_function(function_id).unwrap() {
+                    Function::Builtin(builtin_function) => {
+                        *builtin_function.get_func()
+                    },
+                    _ => panic!("`{}' is not a built-in function", name),
+                }
+            },
+            _ => panic!("Cannot find `{}'", name),
+        }
+    }
+
+    #[test]
+    fn knows_every_builtin_of_fresh_interpreter() {
+        let mut interpreter = Interpreter::new();
+        let registry = BuiltinRegistry::from_stdlib();
+
+        for name in registry.get_builtin_function_names() {
+            if name.starts_with(INTERNAL_FUNCTION_NAME_PREFIX) {
+                continue;
+            }
+
+            let func = lookup_builtin_function(&mut interpreter, name);
+
+            nia_assert_equal(
+                func as usize,
+                registry.get_builtin_function(name).unwrap() as usize,
+            );
+        }
+    }
+}
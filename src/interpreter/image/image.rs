@@ -0,0 +1,35 @@
+use crate::Error;
+use crate::ImageReader;
+use crate::ImageWriter;
+
+use crate::make_corrupted_image_error;
+
+pub const IMAGE_MAGIC: &'static [u8] = b"NIAIMAGE";
+pub const IMAGE_VERSION: u32 = 4;
+
+pub fn write_image_header(writer: &mut ImageWriter) {
+    writer.write_bytes(IMAGE_MAGIC);
+    writer.write_u32(IMAGE_VERSION);
+}
+
+pub fn read_image_header(reader: &mut ImageReader) -> Result<(), Error> {
+    let magic = reader
+        .read_bytes(IMAGE_MAGIC.len())
+        .map_err(|_| make_corrupted_image_error("missing header"))?;
+
+    if magic != IMAGE_MAGIC {
+        return Error::generic_execution_error("File is not an image.").into();
+    }
+
+    let version = reader.read_u32()?;
+
+    if version != IMAGE_VERSION {
+        return Error::generic_execution_error(format!(
+            "Unsupported image version: {}, expected: {}.",
+            version, IMAGE_VERSION
+        ))
+        .into();
+    }
+
+    Ok(())
+}
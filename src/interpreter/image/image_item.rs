@@ -0,0 +1,358 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::Error;
+use crate::ImageReader;
+use crate::ImageWriter;
+
+use crate::make_corrupted_image_error;
+
+// Items that can be stored in an interpreter image.
+pub trait ImageItem: Sized {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error>;
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error>;
+}
+
+impl ImageItem for u8 {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write_u8(*self);
+
+        Ok(())
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        reader.read_u8()
+    }
+}
+
+impl ImageItem for bool {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write_u8(if *self { 1 } else { 0 });
+
+        Ok(())
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        match reader.read_u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(make_corrupted_image_error("invalid boolean")),
+        }
+    }
+}
+
+impl ImageItem for u32 {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write_u32(*self);
+
+        Ok(())
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        reader.read_u32()
+    }
+}
+
+impl ImageItem for u64 {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write_u64(*self);
+
+        Ok(())
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        reader.read_u64()
+    }
+}
+
+impl ImageItem for usize {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write_u64(*self as u64);
+
+        Ok(())
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        Ok(reader.read_u64()? as usize)
+    }
+}
+
+impl ImageItem for i32 {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write_u32(*self as u32);
+
+        Ok(())
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        Ok(reader.read_u32()? as i32)
+    }
+}
+
+impl ImageItem for i64 {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write_u64(*self as u64);
+
+        Ok(())
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        Ok(reader.read_u64()? as i64)
+    }
+}
+
+impl ImageItem for f64 {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write_u64(self.to_bits());
+
+        Ok(())
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        Ok(f64::from_bits(reader.read_u64()?))
+    }
+}
+
+impl ImageItem for String {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write(&self.len())?;
+        writer.write_bytes(self.as_bytes());
+
+        Ok(())
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        let length: usize = reader.read()?;
+        let bytes = reader.read_bytes(length)?;
+
+        String::from_utf8(bytes.to_vec())
+            .map_err(|_| make_corrupted_image_error("invalid string"))
+    }
+}
+
+impl ImageItem for PathBuf {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        let path = self.to_str().ok_or_else(|| {
+            Error::generic_execution_error("Cannot save non UTF-8 path.")
+        })?;
+
+        writer.write(&String::from(path))
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        let path: String = reader.read()?;
+
+        Ok(PathBuf::from(path))
+    }
+}
+
+impl ImageItem for SystemTime {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        let duration = self.duration_since(UNIX_EPOCH).map_err(|_| {
+            Error::generic_execution_error("Cannot save time before epoch.")
+        })?;
+
+        writer.write(&duration.as_secs())?;
+        writer.write(&duration.subsec_nanos())
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        let seconds: u64 = reader.read()?;
+        let nanoseconds: u32 = reader.read()?;
+
+        if nanoseconds >= 1_000_000_000 {
+            return Err(make_corrupted_image_error("invalid time"));
+        }
+
+        UNIX_EPOCH
+            .checked_add(Duration::new(seconds, nanoseconds))
+            .ok_or_else(|| make_corrupted_image_error("invalid time"))
+    }
+}
+
+impl<T: ImageItem> ImageItem for Box<T> {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write(self.as_ref())
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        Ok(Box::new(reader.read()?))
+    }
+}
+
+impl<T: ImageItem> ImageItem for Option<T> {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        match self {
+            Some(item) => {
+                writer.write_u8(1);
+                writer.write(item)
+            },
+            None => {
+                writer.write_u8(0);
+
+                Ok(())
+            },
+        }
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        match reader.read_u8()? {
+            0 => Ok(None),
+            1 => Ok(Some(reader.read()?)),
+            _ => Err(make_corrupted_image_error("invalid option")),
+        }
+    }
+}
+
+impl<T: ImageItem> ImageItem for Vec<T> {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write(&self.len())?;
+
+        for item in self {
+            writer.write(item)?;
+        }
+
+        Ok(())
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        let length: usize = reader.read()?;
+        let mut items = Vec::new();
+
+        for _ in 0..length {
+            items.push(reader.read()?);
+        }
+
+        Ok(items)
+    }
+}
+
+impl<K, V> ImageItem for HashMap<K, V>
+where
+    K: ImageItem + Eq + Hash,
+    V: ImageItem,
+{
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write(&self.len())?;
+
+        for (key, value) in self {
+            writer.write(key)?;
+            writer.write(value)?;
+        }
+
+        Ok(())
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        let length: usize = reader.read()?;
+        let mut map = HashMap::new();
+
+        for _ in 0..length {
+            let key = reader.read()?;
+            let value = reader.read()?;
+
+            map.insert(key, value);
+        }
+
+        Ok(map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    fn make_registry() -> crate::BuiltinRegistry {
+        crate::BuiltinRegistry::from_stdlib()
+    }
+
+    fn write_and_read<T: ImageItem>(item: &T) -> T {
+        let registry = make_registry();
+        let mut writer = ImageWriter::new(&registry);
+
+        writer.write(item).unwrap();
+
+        let bytes = writer.into_bytes();
+        let mut reader = ImageReader::new(&bytes, &registry);
+        let result = reader.read().unwrap();
+
+        nia_assert(reader.is_finished());
+
+        result
+    }
+
+    #[test]
+    fn writes_and_reads_primitive_items() {
+        nia_assert_equal(true, write_and_read(&true));
+        nia_assert_equal(-42i32, write_and_read(&-42i32));
+        nia_assert_equal(-42i64, write_and_read(&-42i64));
+        nia_assert_equal(4.5f64, write_and_read(&4.5f64));
+        nia_assert_equal(
+            String::from("猫a"),
+            write_and_read(&String::from("猫a")),
+        );
+        nia_assert_equal(
+            PathBuf::from("/a/b"),
+            write_and_read(&PathBuf::from("/a/b")),
+        );
+        nia_assert_equal(
+            UNIX_EPOCH + Duration::new(10, 20),
+            write_and_read(&(UNIX_EPOCH + Duration::new(10, 20))),
+        );
+    }
+
+    #[test]
+    fn writes_and_reads_collections() {
+        let mut map = HashMap::new();
+
+        map.insert(String::from("a"), vec![Some(1usize), None]);
+        map.insert(String::from("b"), Vec::new());
+
+        nia_assert_equal(map.clone(), write_and_read(&map));
+    }
+
+    #[test]
+    fn returns_error_when_image_ended_unexpectedly() {
+        let registry = make_registry();
+        let bytes = vec![1, 2, 3];
+        let mut reader = ImageReader::new(&bytes, &registry);
+
+        crate::utils::assert_generic_execution_error(&reader.read::<String>());
+    }
+
+    #[test]
+    fn returns_error_when_time_is_invalid() {
+        let registry = make_registry();
+        let specs = vec![(0u64, 1_000_000_000u32), (u64::MAX, 999_999_999u32)];
+
+        for (seconds, nanoseconds) in specs {
+            let mut writer = ImageWriter::new(&registry);
+
+            writer.write(&seconds).unwrap();
+            writer.write(&nanoseconds).unwrap();
+
+            let bytes = writer.into_bytes();
+            let mut reader = ImageReader::new(&bytes, &registry);
+
+            crate::utils::assert_generic_execution_error(
+                &reader.read::<SystemTime>(),
+            );
+        }
+    }
+
+    #[test]
+    fn returns_error_when_builtin_function_has_no_stable_name() {
+        let registry = make_registry();
+        let mut writer = ImageWriter::new(&registry);
+
+        crate::utils::assert_generic_execution_error(
+            &writer.write_builtin_function(crate::utils::stub_function),
+        );
+    }
+}
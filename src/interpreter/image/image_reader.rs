@@ -0,0 +1,97 @@
+use crate::BuiltinFunctionType;
+use crate::BuiltinRegistry;
+use crate::Error;
+use crate::ImageItem;
+use crate::SpecialFormFunctionType;
+
+pub struct ImageReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    registry: &'a BuiltinRegistry,
+}
+
+impl<'a> ImageReader<'a> {
+    pub fn new(
+        bytes: &'a [u8],
+        registry: &'a BuiltinRegistry,
+    ) -> ImageReader<'a> {
+        ImageReader {
+            bytes,
+            position: 0,
+            registry,
+        }
+    }
+
+    pub fn read_bytes(&mut self, count: usize) -> Result<&'a [u8], Error> {
+        if self.bytes.len() - self.position < count {
+            return Err(make_corrupted_image_error("unexpected end of image"));
+        }
+
+        let bytes = &self.bytes[self.position..self.position + count];
+
+        self.position += count;
+
+        Ok(bytes)
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, Error> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    pub fn read_u32(&mut self) -> Result<u32, Error> {
+        let mut bytes = [0; 4];
+
+        bytes.copy_from_slice(self.read_bytes(4)?);
+
+        Ok(u32::from_le_bytes(bytes))
+    }
+
+    pub fn read_u64(&mut self) -> Result<u64, Error> {
+        let mut bytes = [0; 8];
+
+        bytes.copy_from_slice(self.read_bytes(8)?);
+
+        Ok(u64::from_le_bytes(bytes))
+    }
+
+    pub fn read<T: ImageItem>(&mut self) -> Result<T, Error> {
+        T::read_from_image(self)
+    }
+
+    pub fn read_builtin_function(
+        &mut self,
+    ) -> Result<BuiltinFunctionType, Error> {
+        let name: String = self.read()?;
+
+        self.registry.get_builtin_function(&name).ok_or_else(|| {
+            Error::generic_execution_error(format!(
+                "Image refers to unknown built-in function: {}.",
+                name
+            ))
+        })
+    }
+
+    pub fn read_special_form(
+        &mut self,
+    ) -> Result<SpecialFormFunctionType, Error> {
+        let name: String = self.read()?;
+
+        self.registry.get_special_form(&name).ok_or_else(|| {
+            Error::generic_execution_error(format!(
+                "Image refers to unknown special form: {}.",
+                name
+            ))
+        })
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.position == self.bytes.len()
+    }
+}
+
+pub fn make_corrupted_image_error(description: &str) -> Error {
+    Error::generic_execution_error(format!(
+        "Image is corrupted: {}.",
+        description
+    ))
+}
@@ -0,0 +1,79 @@
+use crate::BuiltinFunctionType;
+use crate::BuiltinRegistry;
+use crate::Error;
+use crate::ImageItem;
+use crate::SpecialFormFunctionType;
+
+pub struct ImageWriter<'a> {
+    bytes: Vec<u8>,
+    registry: &'a BuiltinRegistry,
+}
+
+impl<'a> ImageWriter<'a> {
+    pub fn new(registry: &'a BuiltinRegistry) -> ImageWriter<'a> {
+        ImageWriter {
+            bytes: Vec::new(),
+            registry,
+        }
+    }
+
+    pub fn write_u8(&mut self, value: u8) {
+        self.bytes.push(value);
+    }
+
+    pub fn write_u32(&mut self, value: u32) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    pub fn write_u64(&mut self, value: u64) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        self.bytes.extend_from_slice(bytes);
+    }
+
+    pub fn write<T: ImageItem>(&mut self, item: &T) -> Result<(), Error> {
+        item.write_to_image(self)
+    }
+
+    // function pointers do not survive between program runs, so built-in
+    // functions are stored by their names
+    pub fn write_builtin_function(
+        &mut self,
+        func: BuiltinFunctionType,
+    ) -> Result<(), Error> {
+        let name = self
+            .registry
+            .get_builtin_function_name(func)
+            .cloned()
+            .ok_or_else(|| {
+                Error::generic_execution_error(
+                    "Cannot save built-in function that has no stable name.",
+                )
+            })?;
+
+        self.write(&name)
+    }
+
+    pub fn write_special_form(
+        &mut self,
+        func: SpecialFormFunctionType,
+    ) -> Result<(), Error> {
+        let name = self
+            .registry
+            .get_special_form_name(func)
+            .cloned()
+            .ok_or_else(|| {
+                Error::generic_execution_error(
+                    "Cannot save special form that has no stable name.",
+                )
+            })?;
+
+        self.write(&name)
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
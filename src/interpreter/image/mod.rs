@@ -0,0 +1,12 @@
+mod builtin_registry;
+mod image;
+mod image_item;
+mod image_reader;
+mod image_writer;
+mod value_items;
+
+pub use builtin_registry::*;
+pub use image::*;
+pub use image_item::*;
+pub use image_reader::*;
+pub use image_writer::*;
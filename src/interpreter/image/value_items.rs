@@ -0,0 +1,322 @@
+use crate::BuiltinFunction;
+use crate::Cons;
+use crate::ConsId;
+use crate::EnvironmentId;
+use crate::Error;
+use crate::Function;
+use crate::FunctionId;
+use crate::ImageItem;
+use crate::ImageReader;
+use crate::ImageWriter;
+use crate::InterpretedFunction;
+use crate::KeyArgument;
+use crate::Keyword;
+use crate::KeywordId;
+use crate::MacroFunction;
+use crate::ModuleId;
+use crate::ModuleState;
+use crate::NiaString;
+use crate::ObjectId;
+use crate::ObjectObserver;
+use crate::ObjectValueWrapper;
+use crate::OptionalArgument;
+use crate::SpecialFormFunction;
+use crate::StringId;
+use crate::Symbol;
+use crate::SymbolId;
+use crate::Value;
+
+use crate::make_corrupted_image_error;
+
+macro_rules! make_id_image_item {
+    ($id_type: ident) => {
+        impl ImageItem for $id_type {
+            fn write_to_image(
+                &self,
+                writer: &mut ImageWriter,
+            ) -> Result<(), Error> {
+                writer.write(&self.get_id())
+            }
+
+            fn read_from_image(
+                reader: &mut ImageReader,
+            ) -> Result<Self, Error> {
+                Ok($id_type::new(reader.read()?))
+            }
+        }
+    };
+}
+
+make_id_image_item!(ConsId);
+make_id_image_item!(EnvironmentId);
+make_id_image_item!(FunctionId);
+make_id_image_item!(KeywordId);
+make_id_image_item!(ModuleId);
+make_id_image_item!(ObjectId);
+make_id_image_item!(StringId);
+make_id_image_item!(SymbolId);
+
+impl ImageItem for Value {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        match self {
+            Value::Integer(value) => {
+                writer.write_u8(0);
+                writer.write(value)
+            },
+            Value::Float(value) => {
+                writer.write_u8(1);
+                writer.write(value)
+            },
+            Value::Boolean(value) => {
+                writer.write_u8(2);
+                writer.write(value)
+            },
+            Value::String(string_id) => {
+                writer.write_u8(3);
+                writer.write(string_id)
+            },
+            Value::Keyword(keyword_id) => {
+                writer.write_u8(4);
+                writer.write(keyword_id)
+            },
+            Value::Symbol(symbol_id) => {
+                writer.write_u8(5);
+                writer.write(symbol_id)
+            },
+            Value::Cons(cons_id) => {
+                writer.write_u8(6);
+                writer.write(cons_id)
+            },
+            Value::Object(object_id) => {
+                writer.write_u8(7);
+                writer.write(object_id)
+            },
+            Value::Function(function_id) => {
+                writer.write_u8(8);
+                writer.write(function_id)
+            },
+        }
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        let value = match reader.read_u8()? {
+            0 => Value::Integer(reader.read()?),
+            1 => Value::Float(reader.read()?),
+            2 => Value::Boolean(reader.read()?),
+            3 => Value::String(reader.read()?),
+            4 => Value::Keyword(reader.read()?),
+            5 => Value::Symbol(reader.read()?),
+            6 => Value::Cons(reader.read()?),
+            7 => Value::Object(reader.read()?),
+            8 => Value::Function(reader.read()?),
+            _ => return Err(make_corrupted_image_error("invalid value")),
+        };
+
+        Ok(value)
+    }
+}
+
+impl ImageItem for Symbol {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write(self.get_name())?;
+        writer.write(&self.get_gensym_id())
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        let name = reader.read()?;
+        let gensym_id = reader.read()?;
+
+        Ok(Symbol::new(name, gensym_id))
+    }
+}
+
+impl ImageItem for NiaString {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write(self.get_string())
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        Ok(NiaString::new(reader.read()?))
+    }
+}
+
+impl ImageItem for Keyword {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write(self.get_name())
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        Ok(Keyword::new(reader.read()?))
+    }
+}
+
+impl ImageItem for Cons {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write(&self.get_car())?;
+        writer.write(&self.get_cdr())
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        let car = reader.read()?;
+        let cdr = reader.read()?;
+
+        Ok(Cons::new(car, cdr))
+    }
+}
+
+impl ImageItem for ObjectValueWrapper {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write(&self.force_get_value())?;
+        writer.write(&self.get_getter())?;
+        writer.write(&self.get_setter())?;
+        writer.write(&self.get_flags())
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        let value = reader.read()?;
+        let getter = reader.read()?;
+        let setter = reader.read()?;
+        let flags = reader.read()?;
+
+        let mut object_value_wrapper =
+            ObjectValueWrapper::with_flags(value, flags);
+        object_value_wrapper.force_set_accessors(getter, setter);
+
+        Ok(object_value_wrapper)
+    }
+}
+
+impl ImageItem for ObjectObserver {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write(&self.get_property_symbol_id())?;
+        writer.write(&self.get_function_id())
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        let property_symbol_id = reader.read()?;
+        let function_id = reader.read()?;
+
+        Ok(ObjectObserver::new(property_symbol_id, function_id))
+    }
+}
+
+impl ImageItem for OptionalArgument {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write(self.get_name())?;
+        writer.write(&self.get_default())?;
+        writer.write(&self.get_provided().cloned())
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        let name = reader.read()?;
+        let default = reader.read()?;
+        let provided = reader.read()?;
+
+        Ok(OptionalArgument::new(name, default, provided))
+    }
+}
+
+impl ImageItem for KeyArgument {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write(self.get_name())?;
+        writer.write(&self.get_default())?;
+        writer.write(&self.get_provided().cloned())
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        let name = reader.read()?;
+        let default = reader.read()?;
+        let provided = reader.read()?;
+
+        Ok(KeyArgument::new(name, default, provided))
+    }
+}
+
+impl ImageItem for Function {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        match self {
+            Function::Builtin(builtin_function) => {
+                writer.write_u8(0);
+                writer.write_builtin_function(*builtin_function.get_func())
+            },
+            Function::Interpreted(interpreted_function) => {
+                writer.write_u8(1);
+                writer.write(&interpreted_function.get_environment())?;
+                writer.write(interpreted_function.get_arguments())?;
+                writer.write(interpreted_function.get_code())?;
+                writer.write(&interpreted_function.get_documentation().cloned())
+            },
+            Function::Macro(macro_function) => {
+                writer.write_u8(2);
+                writer.write(&macro_function.get_environment())?;
+                writer.write(macro_function.get_arguments())?;
+                writer.write(macro_function.get_code())
+            },
+            Function::SpecialForm(special_form_function) => {
+                writer.write_u8(3);
+                writer.write_special_form(*special_form_function.get_func())
+            },
+        }
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        let function = match reader.read_u8()? {
+            0 => Function::Builtin(BuiltinFunction::new(
+                reader.read_builtin_function()?,
+            )),
+            1 => {
+                let environment_id = reader.read()?;
+                let arguments = reader.read()?;
+                let code = reader.read()?;
+                let documentation = reader.read()?;
+
+                Function::Interpreted(InterpretedFunction::with_documentation(
+                    environment_id,
+                    arguments,
+                    code,
+                    documentation,
+                ))
+            },
+            2 => {
+                let environment_id = reader.read()?;
+                let arguments = reader.read()?;
+                let code = reader.read()?;
+
+                Function::Macro(MacroFunction::new(
+                    environment_id,
+                    arguments,
+                    code,
+                ))
+            },
+            3 => Function::SpecialForm(SpecialFormFunction::new(
+                reader.read_special_form()?,
+            )),
+            _ => return Err(make_corrupted_image_error("invalid function")),
+        };
+
+        Ok(function)
+    }
+}
+
+impl ImageItem for ModuleState {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        let tag = match self {
+            ModuleState::Loading => 0,
+            ModuleState::Loaded => 1,
+            ModuleState::Failed => 2,
+        };
+
+        writer.write_u8(tag);
+
+        Ok(())
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        match reader.read_u8()? {
+            0 => Ok(ModuleState::Loading),
+            1 => Ok(ModuleState::Loaded),
+            2 => Ok(ModuleState::Failed),
+            _ => Err(make_corrupted_image_error("invalid module state")),
+        }
+    }
+}
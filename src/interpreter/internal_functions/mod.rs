@@ -1,5 +1,10 @@
+use crate::interpreter::value::BuiltinFunctionType;
+
 mod _break;
 mod _continue;
 
 pub use _break::*;
 pub use _continue::*;
+
+pub const INTERNAL_FUNCTIONS: &[(&str, BuiltinFunctionType)] =
+    &[("break", _break), ("continue", _continue)];
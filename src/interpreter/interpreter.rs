@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 
 use crate::interpreter::evaluate_value;
 use crate::interpreter::evaluate_values;
@@ -7,6 +8,7 @@ use crate::interpreter::parser::parse;
 use crate::interpreter::reader::read_elements;
 
 use crate::BuiltinFunction;
+use crate::BuiltinRegistry;
 use crate::CallStack;
 use crate::ConsArena;
 use crate::ConsId;
@@ -17,6 +19,8 @@ use crate::Error;
 use crate::Function;
 use crate::FunctionArena;
 use crate::FunctionId;
+use crate::ImageReader;
+use crate::ImageWriter;
 use crate::InterpretedFunction;
 use crate::Keyword;
 use crate::KeywordArena;
@@ -24,11 +28,16 @@ use crate::KeywordId;
 use crate::Module;
 use crate::ModuleArena;
 use crate::ModuleId;
+use crate::ModuleState;
+use crate::{Capabilities, Capability};
+use crate::{get_std_module_path, get_std_module_source, is_std_module_path};
+use crate::{make_corrupted_image_error, read_image_header, write_image_header};
 use crate::NiaString;
 use crate::Object;
 use crate::ObjectArena;
 use crate::ObjectId;
 use crate::ObjectValueWrapper;
+use crate::PressedInputs;
 use crate::SpecialVariableFunction;
 use crate::StringArena;
 use crate::StringId;
@@ -40,7 +49,12 @@ use crate::Value;
 use crate::interpreter::evaluator::evaluate_builtin_function_invocation;
 use crate::interpreter::evaluator::evaluate_interpreted_function_invocation;
 
+use crate::interpreter::internal_functions::INTERNAL_FUNCTIONS;
 use crate::interpreter::stdlib::infect_stdlib;
+use crate::interpreter::stdlib::KEY_CONFIGURATION_ROOT_VARIABLE_NAMES;
+use crate::interpreter::stdlib::LISTENER_CONFIGURATION_ROOT_VARIABLE_NAMES;
+
+use crate::library;
 
 #[derive(Clone)]
 pub struct Interpreter {
@@ -67,6 +81,14 @@ pub struct Interpreter {
     call_stack: CallStack,
     this_object: Option<ObjectId>,
     is_listening: bool,
+    pressed_inputs: PressedInputs,
+    listener_restart_requested: bool,
+    quit_requested: bool,
+    // modification times of module files that failed to reload, so that
+    // they are not evaluated again until they are changed
+    failed_reload_modified_times: HashMap<ModuleId, Option<SystemTime>>,
+    loading_module_ids: Vec<ModuleId>,
+    capabilities: Capabilities,
 }
 
 impl Interpreter {
@@ -104,6 +126,12 @@ impl Interpreter {
             let call_stack = CallStack::new();
             let this_object = None;
             let is_listening = false;
+            let pressed_inputs = PressedInputs::new();
+            let listener_restart_requested = false;
+            let quit_requested = false;
+            let failed_reload_modified_times = HashMap::new();
+            let loading_module_ids = Vec::new();
+            let capabilities = Capabilities::all();
 
             // construct interpreter
             Interpreter {
@@ -130,6 +158,12 @@ impl Interpreter {
                 call_stack,
                 this_object,
                 is_listening,
+                pressed_inputs,
+                listener_restart_requested,
+                quit_requested,
+                failed_reload_modified_times,
+                loading_module_ids,
+                capabilities,
             }
         };
 
@@ -146,24 +180,15 @@ impl Interpreter {
             )
             .expect("Cannot define `nil' symbol.");
 
-        // break
-        let break_function = Function::Builtin(BuiltinFunction::new(
-            crate::interpreter::internal_functions::_break,
-        ));
-        let break_function_id = interpreter.register_function(break_function);
-        interpreter
-            .internal_functions
-            .insert(String::from("break"), break_function_id);
-
-        // continue
-        let continue_function = Function::Builtin(BuiltinFunction::new(
-            crate::interpreter::internal_functions::_continue,
-        ));
-        let continue_function_id =
-            interpreter.register_function(continue_function);
-        interpreter
-            .internal_functions
-            .insert(String::from("continue"), continue_function_id);
+        // internal functions
+        for (name, func) in INTERNAL_FUNCTIONS {
+            let function = Function::Builtin(BuiltinFunction::new(*func));
+            let function_id = interpreter.register_function(function);
+
+            interpreter
+                .internal_functions
+                .insert(String::from(*name), function_id);
+        }
 
         // special variables
         let this_symbol_id = interpreter.intern_symbol_id("this");
@@ -193,51 +218,55 @@ impl Interpreter {
         interpreter
     }
 
+    pub fn with_capabilities(capabilities: Capabilities) -> Interpreter {
+        let mut interpreter = Interpreter::new();
+
+        interpreter.capabilities = capabilities;
+
+        interpreter
+    }
+
+    // for evaluation of untrusted code
+    pub fn sandboxed() -> Interpreter {
+        Interpreter::with_capabilities(Capabilities::none())
+    }
+
     pub fn with_config(config_path: PathBuf) -> Interpreter {
         let mut interpreter = Interpreter::new();
 
-        let module_id = match config_path.to_str() {
-            Some(path_string) => match interpreter.load_module(path_string) {
-                Ok(module_id) => {
+        match config_path.to_str() {
+            Some(path_string) => match interpreter.load_config(path_string) {
+                Ok(()) => {
                     println!(
                         "Successfully loaded configuration: {:?}.",
                         config_path
                     );
-
-                    Some(module_id)
                 }
                 Err(error) => {
                     println!("Error during configuration: {}.", error);
-                    None
                 }
             },
             None => {
                 println!("Error resolving path: {:?}.", config_path);
-                None
             }
-        };
+        }
 
-        // if loaded with config, then config module becomes main module
-        match module_id {
-            Some(module_id) => {
-                let current_module_id = interpreter.current_module;
-                let current_module_path = interpreter
-                    .get_module(current_module_id)
-                    .map(|module| module.get_path().clone());
+        interpreter
+    }
 
-                if let Ok(current_module_path) = current_module_path {
-                    interpreter.main_module_id = module_id;
-                    interpreter.current_module = module_id;
+    // if loaded with config, then config module becomes main module
+    pub fn load_config(&mut self, config_path: &str) -> Result<(), Error> {
+        let module_id = self.load_module(config_path)?;
 
-                    if let Ok(module) = interpreter.get_module_mut(module_id) {
-                        module.set_path(current_module_path)
-                    }
-                }
-            }
-            None => {}
-        }
+        let current_module_id = self.current_module;
+        let current_module_path =
+            self.get_module(current_module_id)?.get_path().clone();
 
-        interpreter
+        self.main_module_id = module_id;
+        self.current_module = module_id;
+        self.get_module_mut(module_id)?.set_path(current_module_path);
+
+        Ok(())
     }
 
     pub fn with_default_config() -> Interpreter {
@@ -299,6 +328,8 @@ impl Interpreter {
     }
 
     pub fn start_listening(&mut self) -> Result<(), Error> {
+        self.check_capability(Capability::ListeningControl)?;
+
         if self.is_listening {
             return Error::generic_execution_error(
                 "Interpreter is already listening events.",
@@ -312,6 +343,8 @@ impl Interpreter {
     }
 
     pub fn stop_listening(&mut self) -> Result<(), Error> {
+        self.check_capability(Capability::ListeningControl)?;
+
         if !self.is_listening {
             return Error::generic_execution_error(
                 "Interpreter is already not listening events.",
@@ -323,6 +356,45 @@ impl Interpreter {
 
         Ok(())
     }
+
+    pub fn get_pressed_inputs(&self) -> &PressedInputs {
+        &self.pressed_inputs
+    }
+
+    pub fn get_pressed_inputs_mut(&mut self) -> &mut PressedInputs {
+        &mut self.pressed_inputs
+    }
+
+    pub fn get_capabilities(&self) -> &Capabilities {
+        &self.capabilities
+    }
+
+    pub fn check_capability(
+        &self,
+        capability: Capability,
+    ) -> Result<(), Error> {
+        self.capabilities.check(capability)
+    }
+
+    pub fn request_listener_restart(&mut self) {
+        self.listener_restart_requested = true;
+    }
+
+    pub fn take_listener_restart_request(&mut self) -> bool {
+        let listener_restart_requested = self.listener_restart_requested;
+
+        self.listener_restart_requested = false;
+
+        listener_restart_requested
+    }
+
+    pub fn request_quit(&mut self) {
+        self.quit_requested = true;
+    }
+
+    pub fn is_quit_requested(&self) -> bool {
+        self.quit_requested
+    }
 }
 
 impl Interpreter {
@@ -427,6 +499,13 @@ impl Interpreter {
         self.symbol_arena.intern(symbol_name)
     }
 
+    pub fn find_interned_symbol_id(
+        &self,
+        symbol_name: &str,
+    ) -> Option<SymbolId> {
+        self.symbol_arena.find_interned(symbol_name)
+    }
+
     pub fn intern_symbol(&mut self, symbol_name: &str) -> &Symbol {
         let symbol_id = self.symbol_arena.intern(symbol_name);
 
@@ -689,6 +768,15 @@ impl Interpreter {
             .get_property_value(object_id, key_symbol_id)
     }
 
+    pub fn get_object_property_value_wrapper(
+        &self,
+        object_id: ObjectId,
+        key_symbol_id: SymbolId,
+    ) -> Result<Option<ObjectValueWrapper>, Error> {
+        self.object_arena
+            .get_property_value_wrapper(object_id, key_symbol_id)
+    }
+
     pub fn set_object_property(
         &mut self,
         object_id: ObjectId,
@@ -763,6 +851,10 @@ impl Interpreter {
         self.function_arena.get_function(function_id)
     }
 
+    pub fn get_internal_functions(&self) -> &HashMap<String, FunctionId> {
+        &self.internal_functions
+    }
+
     pub fn get_internal_function(
         &self,
         name: &str,
@@ -811,6 +903,28 @@ impl Interpreter {
             .lookup_environment_by_variable(environment_id, variable_symbol_id)
     }
 
+    pub fn get_variable_documentation(
+        &self,
+        environment_id: EnvironmentId,
+        variable_symbol_id: SymbolId,
+    ) -> Result<Option<String>, Error> {
+        self.environment_arena
+            .get_variable_documentation(environment_id, variable_symbol_id)
+    }
+
+    pub fn set_variable_documentation(
+        &mut self,
+        environment_id: EnvironmentId,
+        variable_symbol_id: SymbolId,
+        documentation: String,
+    ) -> Result<(), Error> {
+        self.environment_arena.set_variable_documentation(
+            environment_id,
+            variable_symbol_id,
+            documentation,
+        )
+    }
+
     pub fn lookup_environment_by_function(
         &mut self,
         environment_id: EnvironmentId,
@@ -1025,6 +1139,38 @@ impl Interpreter {
         self.module_arena.get_module_mut(module_id)
     }
 
+    fn read_module_from_path(
+        &mut self,
+        module_path: &str,
+    ) -> Result<Vec<Value>, Error> {
+        match get_std_module_source(module_path) {
+            Some(module_content) => {
+                self.read_module_content(module_path, module_content)
+            }
+            None => self.read_file_from_path(module_path),
+        }
+    }
+
+    fn read_module_content(
+        &mut self,
+        module_path: &str,
+        module_content: &str,
+    ) -> Result<Vec<Value>, Error> {
+        let code = parse(module_content).map_err(|error| {
+            Error::parse_error(&format!(
+                "Cannot parse input file: \"{}\". Message: \"{}\"",
+                module_path, error
+            ))
+        })?;
+
+        let values =
+            read_elements(self, code.get_elements()).map_err(|_| {
+                Error::generic_execution_error("Error reading module.")
+            })?;
+
+        Ok(values)
+    }
+
     fn read_file_from_path(
         &mut self,
         module_path: &str,
@@ -1061,180 +1207,584 @@ impl Interpreter {
             ))
         })?;
 
-        let code = parse(&module_content).map_err(|error| {
-            Error::parse_error(&format!(
-                "Cannot parse input file: \"{}\". Message: \"{}\"",
-                module_path, error
-            ))
-        })?;
-
-        let values =
-            read_elements(self, code.get_elements()).map_err(|_| {
-                Error::generic_execution_error("Error reading module.")
-            })?;
-
-        Ok(values)
+        self.read_module_content(module_path, &module_content)
     }
 
     fn load_module(&mut self, module_path: &str) -> Result<ModuleId, Error> {
-        let values = self.read_file_from_path(module_path)?;
+        let values = self.read_module_from_path(module_path)?;
 
         let root_environment_id = self.get_root_environment_id();
         let module_environment_id =
             self.make_environment(root_environment_id)?;
 
-        let module_id =
-            self.make_module(String::from(module_path), module_environment_id);
-        let previous_current_module_id = self.current_module;
+        let module_id = match self.module_arena.get_module_id(module_path) {
+            // a module that failed to load is evaluated again from scratch
+            Some(module_id) => {
+                self.get_module_mut(module_id)?
+                    .reset(module_environment_id);
 
-        self.current_module = module_id;
-        evaluate_values(self, module_environment_id, &values)?;
-        self.current_module = previous_current_module_id;
+                module_id
+            }
+            None => self
+                .make_module(String::from(module_path), module_environment_id),
+        };
+
+        self.evaluate_module(module_id, module_path, &values)?;
 
         Ok(module_id)
     }
 
-    // for config file
-    pub fn execute_file_in_main_environment(
-        &mut self,
-        module_path: &str,
-    ) -> Result<Value, Error> {
-        let main_environment_id = self.get_main_environment_id();
-        let values = self.read_file_from_path(module_path)?;
+    fn get_root_variable_values(&mut self, names: &[&str]) -> Vec<Value> {
+        let root_environment_id = self.get_root_environment_id();
+        let mut values = Vec::new();
+
+        for name in names {
+            let symbol_id = self.intern_symbol_id(name);
+            let value = match self.lookup_variable(root_environment_id, symbol_id)
+            {
+                Ok(Some(value)) => value,
+                _ => self.intern_nil_symbol_value(),
+            };
 
-        match evaluate_values(self, main_environment_id, &values)? {
-            Some(value) => Ok(value),
-            None => Ok(self.intern_nil_symbol_value()),
+            values.push(value);
         }
+
+        values
     }
 
-    pub fn intern_module(
+    fn evaluate_module(
         &mut self,
+        module_id: ModuleId,
         module_path: &str,
-    ) -> Result<ModuleId, Error> {
-        let module_id = match self.module_arena.get_module_id(module_path) {
-            Some(module_id) => module_id,
-            None => self.load_module(module_path)?,
-        };
+        values: &Vec<Value>,
+    ) -> Result<(), Error> {
+        let module_environment_id =
+            self.get_module(module_id)?.get_environment_id();
+        let key_configuration_before =
+            self.get_root_variable_values(&KEY_CONFIGURATION_ROOT_VARIABLE_NAMES);
+        let modified_time = read_modified_time(module_path);
 
-        Ok(module_id)
-    }
+        let previous_current_module_id = self.current_module;
 
-    pub fn resolve_with_current_module_path(
-        &self,
-        path: String,
-    ) -> Result<String, Error> {
-        let current_module_path =
-            self.get_module(self.current_module)?.get_path().clone();
+        self.get_module_mut(module_id)?
+            .set_state(ModuleState::Loading);
+        self.loading_module_ids.push(module_id);
 
-        let resolved_path = crate::utils::resolve_path_with_current_module_path(
-            current_module_path,
-            path,
-        );
+        self.current_module = module_id;
+        let result = evaluate_values(self, module_environment_id, values);
+        self.current_module = previous_current_module_id;
 
-        resolved_path
-    }
-}
+        self.loading_module_ids.pop();
 
-impl Interpreter {
-    pub fn get_context(&self) -> &Context {
-        &self.context
+        if let Err(error) = result {
+            self.get_module_mut(module_id)?
+                .set_state(ModuleState::Failed);
+
+            return Err(error);
+        }
+
+        // a module that touched key configuration must be evaluated again
+        // whenever key configuration is rebuilt
+        let key_configuration_after =
+            self.get_root_variable_values(&KEY_CONFIGURATION_ROOT_VARIABLE_NAMES);
+        let defines_key_configuration =
+            key_configuration_before != key_configuration_after;
+
+        let module = self.get_module_mut(module_id)?;
+
+        module.set_modified_time(modified_time);
+        module.set_defines_key_configuration(defines_key_configuration);
+        module.set_state(ModuleState::Loaded);
+
+        Ok(())
     }
 
-    pub fn get_context_mut(&mut self) -> &mut Context {
-        &mut self.context
+    fn make_cyclic_import_error(&self, module_id: ModuleId) -> Error {
+        let position = self
+            .loading_module_ids
+            .iter()
+            .position(|loading_module_id| *loading_module_id == module_id)
+            .unwrap_or(0);
+
+        let mut module_paths = Vec::new();
+
+        for loading_module_id in self.loading_module_ids[position..]
+            .iter()
+            .chain(std::iter::once(&module_id))
+        {
+            let module_path = match self.get_module(*loading_module_id) {
+                Ok(module) => module.get_path().clone(),
+                Err(_) => loading_module_id.to_string(),
+            };
+
+            module_paths.push(module_path);
+        }
+
+        Error::generic_execution_error(format!(
+            "Cyclic import: {}.",
+            module_paths.join(" -> ")
+        ))
     }
 
-    pub fn get_context_value(
+    fn sort_modules_by_dependencies(
         &self,
-        symbol_id: SymbolId,
-    ) -> Result<Value, Error> {
-        self.context.get_value(symbol_id)
-    }
+        module_ids: Vec<ModuleId>,
+    ) -> Result<Vec<ModuleId>, Error> {
+        let mut remaining_module_ids = module_ids;
+        let mut sorted_module_ids = Vec::new();
+
+        remaining_module_ids.sort_by_key(|module_id| module_id.get_id());
+
+        while remaining_module_ids.len() > 0 {
+            let mut position = None;
+
+            for (index, module_id) in remaining_module_ids.iter().enumerate() {
+                let dependencies_are_sorted = self
+                    .get_module(*module_id)?
+                    .get_dependencies()
+                    .iter()
+                    .all(|dependency_id| {
+                        dependency_id == module_id
+                            || !remaining_module_ids.contains(dependency_id)
+                    });
+
+                if dependencies_are_sorted {
+                    position = Some(index);
+                    break;
+                }
+            }
 
-    pub fn set_context_value(
-        &mut self,
-        symbol_id: SymbolId,
-        value: Value,
-    ) -> Result<(), Error> {
-        self.context.set_value(symbol_id, value)
-    }
-}
+            // modules that depend on each other are taken in load order
+            let position = position.unwrap_or(0);
 
-impl Interpreter {
-    pub fn push_named_call(
-        &mut self,
-        function_id: FunctionId,
-        function_name_symbol_id: SymbolId,
-        arguments: Vec<Value>,
-    ) {
-        self.call_stack.push_named_function_invocation(
-            function_id,
-            function_name_symbol_id,
-            arguments,
-        )
+            sorted_module_ids.push(remaining_module_ids.remove(position));
+        }
+
+        Ok(sorted_module_ids)
     }
 
-    pub fn push_anonymous_call(
+    fn evaluate_reloaded_modules(
         &mut self,
-        function_id: FunctionId,
-        arguments: Vec<Value>,
-    ) {
-        self.call_stack
-            .push_anonymous_function_invocation(function_id, arguments)
-    }
+        reloaded_module_ids: &Vec<ModuleId>,
+    ) -> Result<Vec<String>, Error> {
+        let root_environment_id = self.get_root_environment_id();
 
-    pub fn pop_call(&mut self) {
-        self.call_stack.pop();
-    }
+        for name in KEY_CONFIGURATION_ROOT_VARIABLE_NAMES.iter() {
+            let nil = self.intern_nil_symbol_value();
 
-    pub fn clear_call_stack(&mut self) {
-        self.call_stack.clear()
-    }
+            library::set_root_variable(self, name, nil)?;
+        }
 
-    pub fn is_overflow(&self) -> bool {
-        self.call_stack.len() > 100
-    }
-}
+        let mut reloaded_module_paths = Vec::new();
 
-impl Interpreter {
-    fn execute_code(
-        &mut self,
-        execution_environment_id: EnvironmentId,
-        code: &str,
-    ) -> Result<Value, Error> {
-        // first step: parse code
-        let code = parse(code).map_err(|err| {
-            Error::parse_error(
-                format!("Error while parsing code: {:?}", err).as_str(),
-            )
-        })?;
+        for module_id in reloaded_module_ids.iter().copied() {
+            let module_path = self
+                .module_arena
+                .get_module_file_path(module_id)
+                .ok_or_else(|| {
+                    Error::failure(format!(
+                        "Cannot find path of module: {}",
+                        module_id
+                    ))
+                })?;
 
-        // second step: read forms
-        let values = read_elements(self, code.get_elements())?;
+            let values = self.read_module_from_path(&module_path)?;
+            let module_environment_id =
+                self.make_environment(root_environment_id)?;
 
-        // third step: evaluate
-        let mut results: Vec<Value> = Vec::new();
+            // the configuration module is evaluated with its own path, even
+            // though it acts as the main module
+            let previous_module_path =
+                self.get_module(module_id)?.get_path().clone();
 
-        for value in values {
-            let result = self.execute_value(execution_environment_id, value)?;
+            let module = self.get_module_mut(module_id)?;
 
-            results.push(result);
-        }
+            module.reset(module_environment_id);
+            module.set_path(module_path.clone());
 
-        let last_result = match results.last() {
-            Some(result) => *result,
-            None => self.intern_nil_symbol_value(),
-        };
+            let result = self.evaluate_module(module_id, &module_path, &values);
 
-        Ok(last_result)
+            self.get_module_mut(module_id)?
+                .set_path(previous_module_path);
+
+            result?;
+
+            reloaded_module_paths.push(module_path);
+        }
+
+        Ok(reloaded_module_paths)
     }
 
-    pub fn execute_value(
+    fn reload_modules(
         &mut self,
-        environment_id: EnvironmentId,
-        value: Value,
-    ) -> Result<Value, Error> {
+        module_ids: Vec<ModuleId>,
+    ) -> Result<Vec<String>, Error> {
+        // key configuration is rebuilt from scratch, so the modules that
+        // defined it are evaluated again together with the changed ones
+        let mut pending_module_ids = module_ids;
+
+        for module_id in self.module_arena.get_file_module_ids() {
+            if self.get_module(module_id)?.defines_key_configuration() {
+                pending_module_ids.push(module_id);
+            }
+        }
+
+        let mut reloaded_module_ids = Vec::new();
+
+        while let Some(module_id) = pending_module_ids.pop() {
+            if reloaded_module_ids.contains(&module_id) {
+                continue;
+            }
+
+            reloaded_module_ids.push(module_id);
+            pending_module_ids
+                .extend(self.module_arena.get_dependents(module_id));
+        }
+
+        reloaded_module_ids.retain(|module_id| {
+            self.module_arena.get_module_file_path(*module_id).is_some()
+        });
+
+        let reloaded_module_ids =
+            self.sort_modules_by_dependencies(reloaded_module_ids)?;
+
+        let listener_configuration_before = self.get_root_variable_values(
+            &LISTENER_CONFIGURATION_ROOT_VARIABLE_NAMES,
+        );
+
+        // a module that fails to reload must not wipe the configuration that
+        // worked before, so everything reloading changes is restored then
+        let key_configuration_before =
+            self.get_root_variable_values(&KEY_CONFIGURATION_ROOT_VARIABLE_NAMES);
+        let mut modules_before = Vec::new();
+
+        for module_id in reloaded_module_ids.iter() {
+            modules_before.push(self.get_module(*module_id)?.clone());
+        }
+
+        let reloaded_module_paths =
+            match self.evaluate_reloaded_modules(&reloaded_module_ids) {
+                Ok(reloaded_module_paths) => reloaded_module_paths,
+                Err(error) => {
+                    for (module_id, module) in reloaded_module_ids
+                        .into_iter()
+                        .zip(modules_before.into_iter())
+                    {
+                        *self.get_module_mut(module_id)? = module;
+                    }
+
+                    for (name, value) in KEY_CONFIGURATION_ROOT_VARIABLE_NAMES
+                        .iter()
+                        .zip(key_configuration_before.into_iter())
+                    {
+                        library::set_root_variable(self, name, value)?;
+                    }
+
+                    return Err(error);
+                }
+            };
+
+        let listener_configuration_after = self.get_root_variable_values(
+            &LISTENER_CONFIGURATION_ROOT_VARIABLE_NAMES,
+        );
+
+        let mut listener_configuration_changed = false;
+
+        for (before, after) in listener_configuration_before
+            .into_iter()
+            .zip(listener_configuration_after.into_iter())
+        {
+            if !library::deep_equal(self, before, after)? {
+                listener_configuration_changed = true;
+                break;
+            }
+        }
+
+        if listener_configuration_changed && self.is_listening {
+            self.request_listener_restart();
+        }
+
+        Ok(reloaded_module_paths)
+    }
+
+    pub fn reload_module(
+        &mut self,
+        module_path: &str,
+    ) -> Result<Vec<String>, Error> {
+        let module_id = match self.module_arena.get_module_id(module_path) {
+            Some(module_id) if module_path.len() > 0 => module_id,
+            _ => {
+                return Error::generic_execution_error(&format!(
+                    "Module \"{}\" was not loaded.",
+                    module_path
+                ))
+                .into();
+            }
+        };
+
+        self.reload_modules(vec![module_id])
+    }
+
+    pub fn reload_changed_modules(&mut self) -> Result<Vec<String>, Error> {
+        let mut changed_modules = Vec::new();
+
+        for module_id in self.module_arena.get_file_module_ids() {
+            let module_path =
+                match self.module_arena.get_module_file_path(module_id) {
+                    Some(module_path) => module_path,
+                    None => continue,
+                };
+
+            let modified_time = read_modified_time(&module_path);
+
+            if modified_time != self.get_module(module_id)?.get_modified_time()
+            {
+                changed_modules.push((module_id, modified_time));
+            }
+        }
+
+        // files that failed to reload are tried again once any of the
+        // changed files is changed again
+        let is_retry =
+            changed_modules.iter().all(|(module_id, modified_time)| {
+                self.failed_reload_modified_times.get(module_id)
+                    == Some(modified_time)
+            });
+
+        if is_retry {
+            return Ok(Vec::new());
+        }
+
+        let changed_module_ids = changed_modules
+            .iter()
+            .map(|(module_id, _)| *module_id)
+            .collect();
+
+        match self.reload_modules(changed_module_ids) {
+            Ok(reloaded_module_paths) => {
+                for (module_id, _) in changed_modules {
+                    self.failed_reload_modified_times.remove(&module_id);
+                }
+
+                Ok(reloaded_module_paths)
+            }
+            Err(error) => {
+                for (module_id, modified_time) in changed_modules {
+                    self.failed_reload_modified_times
+                        .insert(module_id, modified_time);
+                }
+
+                Err(error)
+            }
+        }
+    }
+
+    // for config file
+    pub fn execute_file_in_main_environment(
+        &mut self,
+        module_path: &str,
+    ) -> Result<Value, Error> {
+        let main_environment_id = self.get_main_environment_id();
+        let values = self.read_file_from_path(module_path)?;
+        let mut last_result = self.intern_nil_symbol_value();
+
+        // forms after `nia:quit' are not evaluated
+        for value in values {
+            last_result = evaluate_value(self, main_environment_id, value)?;
+
+            if self.quit_requested {
+                break;
+            }
+        }
+
+        Ok(last_result)
+    }
+
+    pub fn intern_module(
+        &mut self,
+        module_path: &str,
+    ) -> Result<ModuleId, Error> {
+        if !is_std_module_path(module_path) {
+            self.capabilities.check_import(module_path)?;
+        }
+
+        let module_id = match self.module_arena.get_module_id(module_path) {
+            Some(module_id) => match self.get_module(module_id)?.get_state() {
+                ModuleState::Loaded => module_id,
+                ModuleState::Loading => {
+                    return Err(self.make_cyclic_import_error(module_id));
+                }
+                ModuleState::Failed => self.load_module(module_path)?,
+            },
+            None => self.load_module(module_path)?,
+        };
+
+        let current_module_id = self.current_module;
+
+        if module_id != current_module_id {
+            self.get_module_mut(current_module_id)?
+                .add_dependency(module_id);
+        }
+
+        Ok(module_id)
+    }
+
+    fn find_module_in_search_path(
+        &mut self,
+        module_name: &str,
+    ) -> Result<Option<String>, Error> {
+        let mut candidates = vec![PathBuf::from(module_name)];
+
+        if !module_name.ends_with(".nia") {
+            candidates.push(PathBuf::from(format!("{}.nia", module_name)));
+        }
+
+        for directory in library::get_module_search_path(self)? {
+            for candidate in candidates.iter() {
+                let mut path = PathBuf::from(&directory);
+                path.push(candidate);
+
+                if path.is_file() {
+                    if let Some(path) = path.to_str() {
+                        return Ok(Some(String::from(path)));
+                    }
+                }
+            }
+        }
+
+        Ok(get_std_module_path(module_name))
+    }
+
+    pub fn resolve_with_current_module_path(
+        &mut self,
+        path: String,
+    ) -> Result<String, Error> {
+        let current_module_path =
+            self.get_module(self.current_module)?.get_path().clone();
+
+        let resolved_path = crate::utils::resolve_path_with_current_module_path(
+            current_module_path,
+            path.clone(),
+        )?;
+
+        // bare specifiers that do not name a file near the current module are
+        // looked up in the search path and among the standard modules
+        if !crate::utils::is_bare_module_specifier(&path)
+            || Path::new(&resolved_path).is_file()
+        {
+            return Ok(resolved_path);
+        }
+
+        match self.find_module_in_search_path(&path)? {
+            Some(module_path) => Ok(module_path),
+            None => Ok(resolved_path),
+        }
+    }
+}
+
+impl Interpreter {
+    pub fn get_context(&self) -> &Context {
+        &self.context
+    }
+
+    pub fn get_context_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+
+    pub fn get_context_value(
+        &self,
+        symbol_id: SymbolId,
+    ) -> Result<Value, Error> {
+        self.context.get_value(symbol_id)
+    }
+
+    pub fn set_context_value(
+        &mut self,
+        symbol_id: SymbolId,
+        value: Value,
+    ) -> Result<(), Error> {
+        self.context.set_value(symbol_id, value)
+    }
+}
+
+impl Interpreter {
+    pub fn push_named_call(
+        &mut self,
+        function_id: FunctionId,
+        function_name_symbol_id: SymbolId,
+        arguments: Vec<Value>,
+    ) {
+        self.call_stack.push_named_function_invocation(
+            function_id,
+            function_name_symbol_id,
+            arguments,
+        )
+    }
+
+    pub fn push_anonymous_call(
+        &mut self,
+        function_id: FunctionId,
+        arguments: Vec<Value>,
+    ) {
+        self.call_stack
+            .push_anonymous_function_invocation(function_id, arguments)
+    }
+
+    pub fn pop_call(&mut self) {
+        self.call_stack.pop();
+    }
+
+    pub fn clear_call_stack(&mut self) {
+        self.call_stack.clear()
+    }
+
+    pub fn is_overflow(&self) -> bool {
+        self.call_stack.len() > 100
+    }
+}
+
+impl Interpreter {
+    fn execute_code(
+        &mut self,
+        execution_environment_id: EnvironmentId,
+        code: &str,
+    ) -> Result<Value, Error> {
+        // first step: parse code
+        let code = parse(code).map_err(|err| {
+            Error::parse_error(
+                format!("Error while parsing code: {:?}", err).as_str(),
+            )
+        })?;
+
+        // second step: read forms
+        let values = read_elements(self, code.get_elements())?;
+
+        // third step: evaluate, forms after `nia:quit' are not
+        let mut results: Vec<Value> = Vec::new();
+
+        for value in values {
+            let result = self.execute_value(execution_environment_id, value)?;
+
+            results.push(result);
+
+            if self.quit_requested {
+                break;
+            }
+        }
+
+        let last_result = match results.last() {
+            Some(result) => *result,
+            None => self.intern_nil_symbol_value(),
+        };
+
+        Ok(last_result)
+    }
+
+    pub fn execute_value(
+        &mut self,
+        environment_id: EnvironmentId,
+        value: Value,
+    ) -> Result<Value, Error> {
         evaluate_value(self, environment_id, value)
     }
 
@@ -1328,42 +1878,184 @@ impl Interpreter {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl Interpreter {
+    pub fn to_image_bytes(&self) -> Result<Vec<u8>, Error> {
+        let registry = BuiltinRegistry::from_stdlib();
+        let mut writer = ImageWriter::new(&registry);
 
-    #[allow(unused_imports)]
-    use nia_basic_assertions::*;
+        write_image_header(&mut writer);
 
-    #[allow(unused_imports)]
-    use crate::utils;
+        writer.write(&self.environment_arena)?;
 
-    use crate::FunctionArguments;
+        writer.write(&self.module_arena)?;
+        writer.write(&self.root_module_id)?;
+        writer.write(&self.main_module_id)?;
+        writer.write(&self.current_module)?;
 
-    #[cfg(test)]
-    mod evaluation {
-        use super::*;
-        use crate::SpecialFormFunction;
+        writer.write(&self.string_arena)?;
+        writer.write(&self.keyword_arena)?;
+        writer.write(&self.symbol_arena)?;
+        writer.write(&self.cons_arena)?;
+        writer.write(&self.object_arena)?;
+        writer.write(&self.function_arena)?;
 
-        use std::convert::TryInto;
+        writer.write(&self.context)?;
+        writer.write(&self.exclusive_nil)?;
+        writer.write(&self.internal_functions)?;
+        writer.write(&self.capabilities)?;
 
-        macro_rules! assert_execution_result_eq {
-            ( $ expected: expr, $ code: expr) => {
-                let mut interpreter = Interpreter::new();
-                let result = interpreter.execute_in_main_environment($code);
+        Ok(writer.into_bytes())
+    }
 
-                nia_assert_equal($expected, result.unwrap())
-            };
-        }
+    pub fn from_image_bytes(bytes: &[u8]) -> Result<Interpreter, Error> {
+        let registry = BuiltinRegistry::from_stdlib();
+        let mut reader = ImageReader::new(bytes, &registry);
 
-        #[test]
-        fn executes_integer_correctly() {
-            assert_execution_result_eq!(Value::Integer(1), "1");
-        }
+        read_image_header(&mut reader)?;
 
-        #[test]
-        fn executes_float_correctly() {
-            assert_execution_result_eq!(Value::Float(1.2), "1.2");
+        let environment_arena = reader.read()?;
+
+        let module_arena = reader.read()?;
+        let root_module_id = reader.read()?;
+        let main_module_id = reader.read()?;
+        let current_module = reader.read()?;
+
+        let string_arena = reader.read()?;
+        let keyword_arena = reader.read()?;
+        let symbol_arena = reader.read()?;
+        let cons_arena = reader.read()?;
+        let object_arena = reader.read()?;
+        let function_arena = reader.read()?;
+
+        let context = reader.read()?;
+        let exclusive_nil = reader.read()?;
+        let internal_functions = reader.read()?;
+        let capabilities = reader.read()?;
+
+        if !reader.is_finished() {
+            return Err(make_corrupted_image_error("trailing data"));
+        }
+
+        // runtime state is not saved, listener and call stack always start
+        // from scratch
+        let mut interpreter = Interpreter {
+            environment_arena,
+
+            module_arena,
+            root_module_id,
+            main_module_id,
+            current_module,
+
+            string_arena,
+            keyword_arena,
+            symbol_arena,
+            cons_arena,
+            object_arena,
+            function_arena,
+
+            context,
+            exclusive_nil,
+            exclusive_nil_value: Value::Symbol(exclusive_nil),
+            internal_functions,
+            special_variables: HashMap::new(),
+
+            call_stack: CallStack::new(),
+            this_object: None,
+            is_listening: false,
+            pressed_inputs: PressedInputs::new(),
+            listener_restart_requested: false,
+            quit_requested: false,
+            failed_reload_modified_times: HashMap::new(),
+            loading_module_ids: Vec::new(),
+            capabilities,
+        };
+
+        let this_symbol_id = interpreter.intern_symbol_id("this");
+        let super_symbol_id = interpreter.intern_symbol_id("super");
+
+        interpreter.special_variables.insert(
+            this_symbol_id,
+            crate::interpreter::special_variables::_this,
+        );
+        interpreter.special_variables.insert(
+            super_symbol_id,
+            crate::interpreter::special_variables::_super,
+        );
+
+        Ok(interpreter)
+    }
+
+    pub fn save_image(&self, image_path: &str) -> Result<(), Error> {
+        let bytes = self.to_image_bytes()?;
+
+        // writing to a temporary file first, so that a crash in the middle
+        // of saving does not destroy the previous image
+        let temporary_path = format!("{}.tmp", image_path);
+
+        std::fs::write(&temporary_path, bytes)
+            .and_then(|_| std::fs::rename(&temporary_path, image_path))
+            .map_err(|error| {
+                Error::generic_execution_error(format!(
+                    "Cannot save image to {}: {}.",
+                    image_path, error
+                ))
+            })
+    }
+
+    pub fn load_image(image_path: &str) -> Result<Interpreter, Error> {
+        let bytes = std::fs::read(image_path).map_err(|error| {
+            Error::generic_execution_error(format!(
+                "Cannot read image {}: {}.",
+                image_path, error
+            ))
+        })?;
+
+        Interpreter::from_image_bytes(&bytes)
+    }
+}
+
+fn read_modified_time(module_path: &str) -> Option<SystemTime> {
+    std::fs::metadata(module_path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[allow(unused_imports)]
+    use crate::utils;
+
+    use crate::FunctionArguments;
+
+    #[cfg(test)]
+    mod evaluation {
+        use super::*;
+        use crate::SpecialFormFunction;
+
+        use std::convert::TryInto;
+
+        macro_rules! assert_execution_result_eq {
+            ( $ expected: expr, $ code: expr) => {
+                let mut interpreter = Interpreter::new();
+                let result = interpreter.execute_in_main_environment($code);
+
+                nia_assert_equal($expected, result.unwrap())
+            };
+        }
+
+        #[test]
+        fn executes_integer_correctly() {
+            assert_execution_result_eq!(Value::Integer(1), "1");
+        }
+
+        #[test]
+        fn executes_float_correctly() {
+            assert_execution_result_eq!(Value::Float(1.2), "1.2");
         }
 
         #[test]
@@ -1985,6 +2677,821 @@ mod tests {
         }
     }
 
+    #[cfg(test)]
+    mod capabilities {
+        use super::*;
+
+        #[test]
+        fn grants_all_capabilities_by_default() {
+            let interpreter = Interpreter::new();
+
+            nia_assert_equal(&Capabilities::all(), interpreter.get_capabilities());
+        }
+
+        #[test]
+        fn denies_listening_control_in_sandbox() {
+            let mut interpreter = Interpreter::sandboxed();
+
+            utils::assert_permission_denied_error(&interpreter.start_listening());
+            utils::assert_permission_denied_error(&interpreter.stop_listening());
+        }
+
+        #[test]
+        fn allows_imports_only_from_import_roots() {
+            utils::with_tempdir(|directory| {
+                let module_path = format!("{}/module.nia", directory);
+
+                std::fs::write(&module_path, "(defc value 1) (export value)")
+                    .unwrap();
+
+                let code = format!(
+                    r#"(import #{{:value}} from "{}") value"#,
+                    module_path
+                );
+
+                let mut interpreter = Interpreter::sandboxed();
+                let result = interpreter.execute_in_main_environment(&code);
+
+                utils::assert_permission_denied_error(&result);
+
+                let mut interpreter = Interpreter::with_capabilities(
+                    Capabilities::none().with_import_root(directory.clone()),
+                );
+                let pairs = vec![(code.as_str(), "1")];
+
+                utils::assert_results_are_equal(&mut interpreter, pairs);
+            });
+        }
+
+        #[test]
+        fn allows_std_module_imports_in_sandbox() {
+            let mut interpreter = Interpreter::sandboxed();
+
+            let pairs = vec![(
+                r#"(import #{:key-code} from "std/keys") (key-code :tab)"#,
+                "15",
+            )];
+
+            utils::assert_results_are_equal(&mut interpreter, pairs);
+        }
+
+        #[test]
+        fn permission_denied_errors_can_be_caught() {
+            let mut interpreter = Interpreter::sandboxed();
+
+            let pairs = vec![(
+                r#"(try (action:send-execute-os-command "ls") (catch 'permission-denied #t))"#,
+                "#t",
+            )];
+
+            utils::assert_results_are_equal(&mut interpreter, pairs);
+        }
+    }
+
+    #[cfg(test)]
+    mod module_loading {
+        use super::*;
+
+        fn import_module(
+            interpreter: &mut Interpreter,
+            module_path: &str,
+        ) -> Result<Value, Error> {
+            interpreter.execute_in_main_environment(&format!(
+                "(import \"{}\")",
+                module_path
+            ))
+        }
+
+        fn assert_module_state(
+            interpreter: &Interpreter,
+            module_path: &str,
+            expected: ModuleState,
+        ) {
+            let module_id =
+                interpreter.module_arena.get_module_id(module_path).unwrap();
+            let module = interpreter.get_module(module_id).unwrap();
+
+            nia_assert_equal(expected, module.get_state());
+        }
+
+        #[test]
+        fn marks_loaded_modules() {
+            utils::with_tempdir(|directory| {
+                let module_path = format!("{}/module.nia", directory);
+                let mut interpreter = Interpreter::new();
+
+                std::fs::write(&module_path, "(defc value 1)").unwrap();
+
+                nia_assert_is_ok(&import_module(&mut interpreter, &module_path));
+                assert_module_state(
+                    &interpreter,
+                    &module_path,
+                    ModuleState::Loaded,
+                );
+            });
+        }
+
+        #[test]
+        fn returns_error_that_names_import_chain_when_imports_are_cyclic() {
+            utils::with_tempdir(|directory| {
+                let first_path = format!("{}/a.nia", directory);
+                let second_path = format!("{}/b.nia", directory);
+                let mut interpreter = Interpreter::new();
+
+                std::fs::write(&first_path, r#"(import "./b.nia")"#).unwrap();
+                std::fs::write(&second_path, r#"(import "./a.nia")"#).unwrap();
+
+                let error =
+                    import_module(&mut interpreter, &first_path).unwrap_err();
+                let expected = format!(
+                    "Cyclic import: {} -> {} -> {}.",
+                    first_path, second_path, first_path
+                );
+
+                nia_assert_equal(
+                    &expected,
+                    error.get_total_cause().get_message(),
+                );
+                nia_assert_equal(0, interpreter.loading_module_ids.len());
+                assert_module_state(
+                    &interpreter,
+                    &first_path,
+                    ModuleState::Failed,
+                );
+                assert_module_state(
+                    &interpreter,
+                    &second_path,
+                    ModuleState::Failed,
+                );
+            });
+        }
+
+        #[test]
+        fn returns_error_when_module_imports_itself() {
+            utils::with_tempdir(|directory| {
+                let module_path = format!("{}/module.nia", directory);
+                let mut interpreter = Interpreter::new();
+
+                std::fs::write(&module_path, r#"(import "./module.nia")"#)
+                    .unwrap();
+
+                let error =
+                    import_module(&mut interpreter, &module_path).unwrap_err();
+                let expected = format!(
+                    "Cyclic import: {} -> {}.",
+                    module_path, module_path
+                );
+
+                nia_assert_equal(
+                    &expected,
+                    error.get_total_cause().get_message(),
+                );
+            });
+        }
+
+        #[test]
+        fn loads_failed_modules_again() {
+            utils::with_tempdir(|directory| {
+                let module_path = format!("{}/module.nia", directory);
+                let mut interpreter = Interpreter::new();
+
+                std::fs::write(
+                    &module_path,
+                    "(defc value 1) (export value) nia-undefined-variable",
+                )
+                .unwrap();
+
+                nia_assert(import_module(&mut interpreter, &module_path).is_err());
+                assert_module_state(
+                    &interpreter,
+                    &module_path,
+                    ModuleState::Failed,
+                );
+
+                std::fs::write(&module_path, "(defc value 2) (export value)")
+                    .unwrap();
+
+                let code = format!(
+                    r#"(import #{{:value}} from "{}") value"#,
+                    module_path
+                );
+                let pairs = vec![(code.as_str(), "2")];
+
+                utils::assert_results_are_equal(&mut interpreter, pairs);
+                assert_module_state(
+                    &interpreter,
+                    &module_path,
+                    ModuleState::Loaded,
+                );
+            });
+        }
+    }
+
+    #[cfg(test)]
+    mod module_search_path {
+        use super::*;
+
+        fn set_module_search_path(
+            interpreter: &mut Interpreter,
+            directory: &str,
+        ) {
+            interpreter
+                .execute_in_main_environment(&format!(
+                    "(set! nia-module-search-path '(\"{}\"))",
+                    directory
+                ))
+                .unwrap();
+        }
+
+        #[test]
+        fn imports_std_modules() {
+            let mut interpreter = Interpreter::new();
+
+            let pairs = vec![
+                (
+                    r#"(import #{:key-code} from "std/keys") (key-code :escape)"#,
+                    "1",
+                ),
+                (
+                    r#"(import #{:key-codes} from "std/keys.nia") key-codes:caps-lock"#,
+                    "58",
+                ),
+                (
+                    r#"(import #{:starts-with? :ends-with? :blank?} from "std/strings")
+                       (list:new (starts-with? "ab" "abc") (ends-with? "ab" "abc") (blank? "  "))"#,
+                    "'(#t #f #t)",
+                ),
+            ];
+
+            utils::assert_results_are_equal(&mut interpreter, pairs);
+        }
+
+        #[test]
+        fn imports_modules_from_search_path() {
+            utils::with_tempdir(|directory| {
+                let mut interpreter = Interpreter::new();
+
+                std::fs::create_dir(format!("{}/lib", directory)).unwrap();
+                std::fs::write(
+                    format!("{}/lib/helpers.nia", directory),
+                    "(defc value 1) (export value)",
+                )
+                .unwrap();
+                set_module_search_path(&mut interpreter, &directory);
+
+                let pairs = vec![
+                    (r#"(import #{:value} from "lib/helpers") value"#, "1"),
+                    (r#"(import #{:value} from "lib/helpers.nia") value"#, "1"),
+                ];
+
+                utils::assert_results_are_equal(&mut interpreter, pairs);
+            });
+        }
+
+        #[test]
+        fn prefers_search_path_over_std_modules() {
+            utils::with_tempdir(|directory| {
+                let mut interpreter = Interpreter::new();
+
+                std::fs::create_dir(format!("{}/std", directory)).unwrap();
+                std::fs::write(
+                    format!("{}/std/keys.nia", directory),
+                    "(defc key-code 42) (export key-code)",
+                )
+                .unwrap();
+                set_module_search_path(&mut interpreter, &directory);
+
+                let pairs =
+                    vec![(r#"(import #{:key-code} from "std/keys") key-code"#, "42")];
+
+                utils::assert_results_are_equal(&mut interpreter, pairs);
+            });
+        }
+
+        #[test]
+        fn prefers_files_near_current_module_over_search_path() {
+            utils::with_tempdir(|module_directory| {
+                utils::with_tempdir(|search_directory| {
+                    let mut interpreter = Interpreter::new();
+
+                    std::fs::write(
+                        format!("{}/helpers.nia", module_directory),
+                        "(defc value 1) (export value)",
+                    )
+                    .unwrap();
+                    std::fs::write(
+                        format!("{}/helpers.nia", search_directory),
+                        "(defc value 2) (export value)",
+                    )
+                    .unwrap();
+                    std::fs::write(
+                        format!("{}/main.nia", module_directory),
+                        r#"(import #{:value} from "helpers.nia") (export value)"#,
+                    )
+                    .unwrap();
+                    set_module_search_path(&mut interpreter, &search_directory);
+
+                    let code = format!(
+                        r#"(import #{{:value}} from "{}/main.nia") value"#,
+                        module_directory
+                    );
+                    let pairs = vec![(code.as_str(), "1")];
+
+                    utils::assert_results_are_equal(&mut interpreter, pairs);
+                });
+            });
+        }
+
+        #[test]
+        fn returns_error_when_module_cannot_be_found() {
+            let mut interpreter = Interpreter::new();
+
+            let result =
+                interpreter.execute_in_main_environment(r#"(import "std/unknown")"#);
+
+            nia_assert(result.is_err());
+        }
+    }
+
+    #[cfg(test)]
+    mod module_reloading {
+        use super::*;
+
+        fn import_module(interpreter: &mut Interpreter, module_path: &str) {
+            interpreter
+                .execute_in_main_environment(&format!(
+                    "(import \"{}\")",
+                    module_path
+                ))
+                .unwrap();
+        }
+
+        fn assert_root_variable_equal(
+            interpreter: &mut Interpreter,
+            name: &str,
+            code: &str,
+        ) {
+            let expected =
+                interpreter.execute_in_main_environment(code).unwrap();
+            let result = library::get_root_variable(interpreter, name).unwrap();
+
+            utils::assert_deep_equal(interpreter, expected, result);
+        }
+
+        #[test]
+        fn reloads_module() {
+            utils::with_tempdir(|directory| {
+                let module_path = format!("{}/module.nia", directory);
+                let mut interpreter = Interpreter::new();
+
+                interpreter
+                    .execute_in_root_environment("(defv nia-test 0)")
+                    .unwrap();
+
+                std::fs::write(&module_path, "(set! nia-test 1)").unwrap();
+                import_module(&mut interpreter, &module_path);
+                assert_root_variable_equal(&mut interpreter, "nia-test", "1");
+
+                std::fs::write(&module_path, "(set! nia-test 2)").unwrap();
+                let result = interpreter.reload_module(&module_path).unwrap();
+
+                nia_assert_equal(vec![module_path.clone()], result);
+                assert_root_variable_equal(&mut interpreter, "nia-test", "2");
+            });
+        }
+
+        #[test]
+        fn reloads_dependents_after_dependencies() {
+            utils::with_tempdir(|directory| {
+                let dependency_path = format!("{}/dependency.nia", directory);
+                let dependent_path = format!("{}/dependent.nia", directory);
+                let mut interpreter = Interpreter::new();
+
+                interpreter
+                    .execute_in_root_environment("(defv nia-test 0)")
+                    .unwrap();
+
+                std::fs::write(
+                    &dependency_path,
+                    "(defc value 1) (export value as default)",
+                )
+                .unwrap();
+                std::fs::write(
+                    &dependent_path,
+                    format!(
+                        "(import value from \"{}\") (set! nia-test value)",
+                        dependency_path
+                    ),
+                )
+                .unwrap();
+
+                import_module(&mut interpreter, &dependent_path);
+                assert_root_variable_equal(&mut interpreter, "nia-test", "1");
+
+                std::fs::write(
+                    &dependency_path,
+                    "(defc value 2) (export value as default)",
+                )
+                .unwrap();
+                let result =
+                    interpreter.reload_module(&dependency_path).unwrap();
+
+                nia_assert_equal(
+                    vec![dependency_path.clone(), dependent_path.clone()],
+                    result,
+                );
+                assert_root_variable_equal(&mut interpreter, "nia-test", "2");
+            });
+        }
+
+        #[test]
+        fn rebuilds_key_configuration() {
+            utils::with_tempdir(|directory| {
+                let keys_path = format!("{}/keys.nia", directory);
+                let module_path = format!("{}/module.nia", directory);
+                let mut interpreter = Interpreter::new();
+
+                interpreter
+                    .execute_in_root_environment("(defv nia-test 0)")
+                    .unwrap();
+
+                std::fs::write(
+                    &keys_path,
+                    "(device:define-modifier \"CtrlL\" \"Control\")",
+                )
+                .unwrap();
+                std::fs::write(&module_path, "(set! nia-test 1)").unwrap();
+
+                import_module(&mut interpreter, &keys_path);
+                import_module(&mut interpreter, &module_path);
+
+                std::fs::write(&module_path, "(set! nia-test 2)").unwrap();
+                let result = interpreter.reload_module(&module_path).unwrap();
+
+                nia_assert_equal(
+                    vec![keys_path.clone(), module_path.clone()],
+                    result,
+                );
+                assert_root_variable_equal(&mut interpreter, "nia-test", "2");
+                assert_root_variable_equal(
+                    &mut interpreter,
+                    "nia-defined-modifiers",
+                    "'((29 \"Control\"))",
+                );
+            });
+        }
+
+        #[test]
+        fn requests_listener_restart_only_when_key_configuration_changed() {
+            utils::with_tempdir(|directory| {
+                let keys_path = format!("{}/keys.nia", directory);
+                let mut interpreter = Interpreter::new();
+
+                std::fs::write(
+                    &keys_path,
+                    "(device:define-modifier \"CtrlL\" \"Control\")",
+                )
+                .unwrap();
+                import_module(&mut interpreter, &keys_path);
+                interpreter.start_listening().unwrap();
+
+                interpreter.reload_module(&keys_path).unwrap();
+                nia_assert(!interpreter.take_listener_restart_request());
+
+                std::fs::write(
+                    &keys_path,
+                    "(device:define-modifier \"CtrlL\" \"Ctrl\")",
+                )
+                .unwrap();
+                interpreter.reload_module(&keys_path).unwrap();
+                nia_assert(interpreter.take_listener_restart_request());
+                nia_assert(!interpreter.take_listener_restart_request());
+            });
+        }
+
+        #[test]
+        fn keeps_configuration_when_reloaded_module_is_broken() {
+            utils::with_tempdir(|directory| {
+                let keys_path = format!("{}/keys.nia", directory);
+                let module_path = format!("{}/module.nia", directory);
+                let mut interpreter = Interpreter::new();
+
+                std::fs::write(
+                    &keys_path,
+                    "(device:define-modifier \"CtrlL\" \"Control\")",
+                )
+                .unwrap();
+                std::fs::write(&module_path, "(defc value 1) (export value)")
+                    .unwrap();
+
+                import_module(&mut interpreter, &keys_path);
+                import_module(&mut interpreter, &module_path);
+
+                let specs = vec![
+                    (&keys_path, "(device:define-modifier \"CtrlL\""),
+                    (
+                        &keys_path,
+                        "(device:define-modifier \"CtrlL\" \"Ctrl\") (unknown)",
+                    ),
+                    (&module_path, "(unknown)"),
+                ];
+
+                for (path, code) in specs {
+                    std::fs::write(path, code).unwrap();
+
+                    nia_assert(interpreter.reload_module(path).is_err());
+                    assert_root_variable_equal(
+                        &mut interpreter,
+                        "nia-defined-modifiers",
+                        "'((29 \"Control\"))",
+                    );
+                }
+
+                let code = format!(
+                    r#"(import #{{:value}} from "{}") value"#,
+                    module_path
+                );
+                let pairs = vec![(code.as_str(), "1")];
+
+                utils::assert_results_are_equal(&mut interpreter, pairs);
+            });
+        }
+
+        #[test]
+        fn does_not_retry_broken_modules_until_they_are_changed() {
+            utils::with_tempdir(|directory| {
+                let module_path = format!("{}/module.nia", directory);
+                let mut interpreter = Interpreter::new();
+
+                std::fs::write(&module_path, "(defc value 1)").unwrap();
+                import_module(&mut interpreter, &module_path);
+                std::fs::write(&module_path, "(unknown)").unwrap();
+
+                let module_id = interpreter
+                    .module_arena
+                    .get_module_id(&module_path)
+                    .unwrap();
+
+                // the file counts as changed whatever the resolution of
+                // modification times is
+                interpreter
+                    .get_module_mut(module_id)
+                    .unwrap()
+                    .set_modified_time(None);
+
+                nia_assert(interpreter.reload_changed_modules().is_err());
+                nia_assert_equal(
+                    Ok(Vec::<String>::new()),
+                    interpreter
+                        .reload_changed_modules()
+                        .map_err(|error| error.to_string()),
+                );
+
+                interpreter
+                    .failed_reload_modified_times
+                    .insert(module_id, None);
+                std::fs::write(&module_path, "(defc value 2)").unwrap();
+
+                nia_assert_equal(
+                    Ok(vec![module_path.clone()]),
+                    interpreter
+                        .reload_changed_modules()
+                        .map_err(|error| error.to_string()),
+                );
+                nia_assert(interpreter.failed_reload_modified_times.is_empty());
+            });
+        }
+
+        #[test]
+        fn does_not_reload_unchanged_modules() {
+            utils::with_tempdir(|directory| {
+                let module_path = format!("{}/module.nia", directory);
+                let mut interpreter = Interpreter::new();
+
+                std::fs::write(&module_path, "(defc value 1)").unwrap();
+                import_module(&mut interpreter, &module_path);
+
+                let result = interpreter.reload_changed_modules().unwrap();
+
+                nia_assert_equal(Vec::<String>::new(), result);
+            });
+        }
+
+        #[test]
+        fn returns_generic_execution_error_when_module_was_not_loaded() {
+            let mut interpreter = Interpreter::new();
+
+            let result = interpreter.reload_module("/not/loaded/module.nia");
+
+            utils::assert_generic_execution_error(&result);
+        }
+    }
+
+    #[cfg(test)]
+    mod image {
+        use super::*;
+
+        fn assert_image_round_trip_preserves(
+            interpreter: &mut Interpreter,
+            pairs: Vec<(&str, &str)>,
+        ) {
+            let bytes = interpreter.to_image_bytes().unwrap();
+            let mut restored = Interpreter::from_image_bytes(&bytes).unwrap();
+
+            utils::assert_results_are_equal(&mut restored, pairs);
+        }
+
+        #[test]
+        fn preserves_definitions() {
+            let mut interpreter = Interpreter::new();
+
+            interpreter
+                .execute_in_main_environment(
+                    "(defv a 1) (defc b \"str\") (defv c '(1 :b c 2.5))",
+                )
+                .unwrap();
+
+            assert_image_round_trip_preserves(
+                &mut interpreter,
+                vec![("a", "1"), ("b", "\"str\""), ("c", "'(1 :b c 2.5)")],
+            );
+        }
+
+        #[test]
+        fn preserves_functions_and_macros() {
+            let mut interpreter = Interpreter::new();
+
+            interpreter
+                .execute_in_main_environment(
+                    "(defn add (a #opt (b 2)) (+ a b)) \
+                     (defm twice (x) (list:new 'progn x x)) \
+                     (defv counter 0)",
+                )
+                .unwrap();
+
+            assert_image_round_trip_preserves(
+                &mut interpreter,
+                vec![
+                    ("(add 1)", "3"),
+                    ("(list:new 1 2)", "'(1 2)"),
+                    (
+                        "(progn (twice (set! counter (inc counter))) counter)",
+                        "2",
+                    ),
+                    ("(cond (nil 1) (#t 2))", "2"),
+                ],
+            );
+        }
+
+        #[test]
+        fn preserves_objects() {
+            let mut interpreter = Interpreter::new();
+
+            interpreter
+                .execute_in_main_environment(
+                    "(defv obj {:a 1 :f (fn () (+ this:a 1))})",
+                )
+                .unwrap();
+
+            assert_image_round_trip_preserves(
+                &mut interpreter,
+                vec![
+                    ("obj:a", "1"),
+                    ("(obj:f)", "2"),
+                    ("(object:get obj :a)", "1"),
+                ],
+            );
+        }
+
+        #[test]
+        fn preserves_accessor_properties() {
+            let mut interpreter = Interpreter::new();
+
+            interpreter
+                .execute_in_main_environment(
+                    "(defv obj {:a 1}) \
+                     (object:define-property! obj {:name :b \
+                       :get (fn () (* this:a 10)) \
+                       :set (fn (value) (object:set! this :a value))})",
+                )
+                .unwrap();
+
+            assert_image_round_trip_preserves(
+                &mut interpreter,
+                vec![
+                    ("obj:b", "10"),
+                    ("(progn (object:set! obj :b 2) obj:a)", "2"),
+                ],
+            );
+        }
+
+        #[test]
+        fn preserves_object_observers() {
+            let mut interpreter = Interpreter::new();
+
+            interpreter
+                .execute_in_main_environment(
+                    "(defv changes '()) \
+                     (defv obj {:a 1}) \
+                     (object:observe! obj :a \
+                       (fn (key old new) (set! changes (cons:new new changes))))",
+                )
+                .unwrap();
+
+            assert_image_round_trip_preserves(
+                &mut interpreter,
+                vec![("(progn (object:set! obj :a 2) changes)", "'(2)")],
+            );
+        }
+
+        #[test]
+        fn preserves_modules() {
+            utils::with_tempdir(|directory| {
+                let module_path = format!("{}/module.nia", directory);
+                let mut interpreter = Interpreter::new();
+
+                std::fs::write(&module_path, "(defc value 3) (export value)")
+                    .unwrap();
+                interpreter
+                    .execute_in_main_environment(&format!(
+                        "(import #{{:value}} from \"{}\")",
+                        module_path
+                    ))
+                    .unwrap();
+
+                let bytes = interpreter.to_image_bytes().unwrap();
+                let restored = Interpreter::from_image_bytes(&bytes).unwrap();
+                let module_id = restored
+                    .get_module_arena()
+                    .get_module_id(&module_path)
+                    .unwrap();
+
+                nia_assert_equal(
+                    ModuleState::Loaded,
+                    restored.get_module(module_id).unwrap().get_state(),
+                );
+            });
+        }
+
+        #[test]
+        fn preserves_capabilities() {
+            let interpreter = Interpreter::sandboxed();
+
+            let bytes = interpreter.to_image_bytes().unwrap();
+            let restored = Interpreter::from_image_bytes(&bytes).unwrap();
+
+            nia_assert_equal(
+                &Capabilities::none(),
+                restored.get_capabilities(),
+            );
+        }
+
+        #[test]
+        fn saves_and_loads_image_files() {
+            utils::with_tempdir(|directory| {
+                let image_path = format!("{}/nia.image", directory);
+                let mut interpreter = Interpreter::new();
+
+                interpreter.execute_in_main_environment("(defv a 1)").unwrap();
+                interpreter.save_image(&image_path).unwrap();
+
+                let mut restored =
+                    Interpreter::load_image(&image_path).unwrap();
+                let result = restored.execute_in_main_environment("a");
+
+                nia_assert_equal(Value::Integer(1), result.unwrap());
+            });
+        }
+
+        #[test]
+        fn returns_generic_execution_error_when_image_is_invalid() {
+            let mut bytes = Interpreter::new().to_image_bytes().unwrap();
+
+            let result = Interpreter::from_image_bytes(b"NOTIMAGE");
+            utils::assert_generic_execution_error(&result);
+
+            let result =
+                Interpreter::from_image_bytes(&bytes[0..bytes.len() / 2]);
+            utils::assert_generic_execution_error(&result);
+
+            bytes.push(0);
+            let result = Interpreter::from_image_bytes(&bytes);
+            utils::assert_generic_execution_error(&result);
+        }
+
+        #[test]
+        fn returns_generic_execution_error_when_version_differs() {
+            let mut bytes = Interpreter::new().to_image_bytes().unwrap();
+            let version_position = crate::IMAGE_MAGIC.len();
+
+            bytes[version_position] = bytes[version_position].wrapping_add(1);
+
+            let result = Interpreter::from_image_bytes(&bytes);
+
+            utils::assert_generic_execution_error(&result);
+        }
+    }
+
     #[test]
     fn handles_stack_overflow() {
         let mut interpreter = Interpreter::new();
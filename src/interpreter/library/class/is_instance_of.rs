@@ -0,0 +1,92 @@
+use crate::Error;
+use crate::Interpreter;
+use crate::ObjectId;
+
+pub fn is_instance_of(
+    interpreter: &Interpreter,
+    object_id: ObjectId,
+    class_id: ObjectId,
+) -> Result<bool, Error> {
+    let mut current_object_id = object_id;
+    // prototypes can be set to anything, so the chain may be cyclic
+    let mut visited_object_ids = vec![object_id];
+
+    while let Some(prototype_id) =
+        interpreter.get_object_prototype(current_object_id)?
+    {
+        if prototype_id == class_id {
+            return Ok(true);
+        }
+
+        if visited_object_ids.contains(&prototype_id) {
+            break;
+        }
+
+        visited_object_ids.push(prototype_id);
+        current_object_id = prototype_id;
+    }
+
+    Ok(false)
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[test]
+    fn returns_true_when_class_is_in_prototype_chain() {
+        let mut interpreter = Interpreter::new();
+
+        let parent_id = interpreter.make_object();
+        let class_id = interpreter.make_child_object(parent_id);
+        let instance_id = interpreter.make_child_object(class_id);
+
+        nia_assert(
+            is_instance_of(&interpreter, instance_id, class_id).unwrap(),
+        );
+        nia_assert(
+            is_instance_of(&interpreter, instance_id, parent_id).unwrap(),
+        );
+        nia_assert(is_instance_of(&interpreter, class_id, parent_id).unwrap());
+    }
+
+    #[test]
+    fn returns_false_when_class_is_not_in_prototype_chain() {
+        let mut interpreter = Interpreter::new();
+
+        let class_id = interpreter.make_object();
+        let other_class_id = interpreter.make_object();
+        let instance_id = interpreter.make_child_object(class_id);
+
+        nia_assert(
+            !is_instance_of(&interpreter, instance_id, other_class_id).unwrap(),
+        );
+        nia_assert(
+            !is_instance_of(&interpreter, instance_id, instance_id).unwrap(),
+        );
+        nia_assert(
+            !is_instance_of(&interpreter, class_id, instance_id).unwrap(),
+        );
+    }
+
+    #[test]
+    fn stops_at_cyclic_prototype_chains() {
+        let mut interpreter = Interpreter::new();
+
+        let first_id = interpreter.make_object();
+        let second_id = interpreter.make_child_object(first_id);
+        let other_id = interpreter.make_object();
+
+        interpreter
+            .set_object_prototype(first_id, second_id)
+            .unwrap();
+
+        nia_assert(is_instance_of(&interpreter, first_id, second_id).unwrap());
+        nia_assert(is_instance_of(&interpreter, first_id, first_id).unwrap());
+        nia_assert(!is_instance_of(&interpreter, first_id, other_id).unwrap());
+    }
+}
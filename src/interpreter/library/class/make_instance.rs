@@ -0,0 +1,121 @@
+use crate::EnvironmentId;
+use crate::Error;
+use crate::Interpreter;
+use crate::ObjectId;
+use crate::Value;
+
+use crate::library;
+
+pub const CONSTRUCTOR_PROPERTY_NAME: &'static str = "constructor";
+
+pub fn make_instance(
+    interpreter: &mut Interpreter,
+    environment_id: EnvironmentId,
+    class_id: ObjectId,
+    arguments: Vec<Value>,
+) -> Result<ObjectId, Error> {
+    let instance_id = interpreter.make_child_object(class_id);
+    let constructor_symbol_id =
+        interpreter.intern_symbol_id(CONSTRUCTOR_PROPERTY_NAME);
+
+    // constructor is looked up through prototype chain, so classes without
+    // own constructor use the one of their parent
+    let constructor =
+        interpreter.get_object_property(class_id, constructor_symbol_id)?;
+
+    let function_id = match constructor {
+        Some(value) => library::read_as_function_id(value)?,
+        None => {
+            if arguments.len() > 0 {
+                return Error::invalid_argument_count_error(
+                    "Class without constructor cannot take arguments.",
+                )
+                .into();
+            }
+
+            return Ok(instance_id);
+        },
+    };
+
+    library::execute_function_with_this(
+        interpreter,
+        environment_id,
+        instance_id,
+        function_id,
+        arguments,
+    )?;
+
+    Ok(instance_id)
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[allow(unused_imports)]
+    use crate::utils;
+
+    #[test]
+    fn makes_instance_and_calls_constructor() {
+        let mut interpreter = Interpreter::new();
+        let environment_id = interpreter.get_main_environment_id();
+
+        let class_value = interpreter
+            .execute_in_main_environment(
+                "{:constructor (fn (a) (object:set! this :a a))}",
+            )
+            .unwrap();
+        let class_id = library::read_as_object_id(class_value).unwrap();
+
+        let instance_id = make_instance(
+            &mut interpreter,
+            environment_id,
+            class_id,
+            vec![Value::Integer(1)],
+        )
+        .unwrap();
+
+        let a_symbol_id = interpreter.intern_symbol_id("a");
+
+        nia_assert_equal(
+            Some(class_id),
+            interpreter.get_object_prototype(instance_id).unwrap(),
+        );
+        nia_assert_equal(
+            Some(Value::Integer(1)),
+            interpreter
+                .get_object_property(instance_id, a_symbol_id)
+                .unwrap(),
+        );
+        nia_assert_equal(None, interpreter.get_this_object());
+    }
+
+    #[test]
+    fn makes_instance_of_class_without_constructor() {
+        let mut interpreter = Interpreter::new();
+        let environment_id = interpreter.get_main_environment_id();
+        let class_id = interpreter.make_object();
+
+        let instance_id =
+            make_instance(&mut interpreter, environment_id, class_id, vec![])
+                .unwrap();
+
+        nia_assert_equal(
+            Some(class_id),
+            interpreter.get_object_prototype(instance_id).unwrap(),
+        );
+
+        let result = make_instance(
+            &mut interpreter,
+            environment_id,
+            class_id,
+            vec![Value::Integer(1)],
+        );
+
+        utils::assert_invalid_argument_count_error(&result);
+    }
+}
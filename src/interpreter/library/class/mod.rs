@@ -0,0 +1,5 @@
+mod is_instance_of;
+mod make_instance;
+
+pub use is_instance_of::*;
+pub use make_instance::*;
@@ -0,0 +1,104 @@
+use crate::EnvironmentId;
+use crate::Error;
+use crate::FunctionId;
+use crate::Interpreter;
+use crate::ObjectId;
+use crate::Value;
+
+pub fn execute_function_with_this(
+    interpreter: &mut Interpreter,
+    environment_id: EnvironmentId,
+    this_object_id: ObjectId,
+    function_id: FunctionId,
+    arguments: Vec<Value>,
+) -> Result<Value, Error> {
+    let previous_this = interpreter.get_this_object();
+
+    interpreter.set_this_object(this_object_id);
+
+    let result = interpreter.execute_function_with_evaluated_arguments(
+        function_id,
+        environment_id,
+        arguments,
+    );
+
+    match previous_this {
+        Some(previous_this_object_id) => {
+            interpreter.set_this_object(previous_this_object_id);
+        },
+        None => {
+            interpreter.clear_this_object();
+        },
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[allow(unused_imports)]
+    use crate::utils;
+
+    use crate::library;
+
+    #[test]
+    fn executes_function_with_this_bound_to_object() {
+        let mut interpreter = Interpreter::new();
+        let environment_id = interpreter.get_main_environment_id();
+
+        let object_value =
+            interpreter.execute_in_main_environment("{:a 1}").unwrap();
+        let object_id = library::read_as_object_id(object_value).unwrap();
+
+        let function_value = interpreter
+            .execute_in_main_environment("(fn (b) (+ (object:get this :a) b))")
+            .unwrap();
+        let function_id = library::read_as_function_id(function_value).unwrap();
+
+        let result = execute_function_with_this(
+            &mut interpreter,
+            environment_id,
+            object_id,
+            function_id,
+            vec![Value::Integer(2)],
+        );
+
+        nia_assert_equal(Ok(Value::Integer(3)), result);
+        nia_assert_equal(None, interpreter.get_this_object());
+    }
+
+    #[test]
+    fn restores_previous_this() {
+        let mut interpreter = Interpreter::new();
+        let environment_id = interpreter.get_main_environment_id();
+
+        let previous_this_object_id = interpreter.make_object();
+        let object_id = interpreter.make_object();
+
+        let function_value = interpreter
+            .execute_in_main_environment("(fn () this)")
+            .unwrap();
+        let function_id = library::read_as_function_id(function_value).unwrap();
+
+        interpreter.set_this_object(previous_this_object_id);
+
+        let result = execute_function_with_this(
+            &mut interpreter,
+            environment_id,
+            object_id,
+            function_id,
+            vec![],
+        );
+
+        nia_assert_equal(Ok(Value::Object(object_id)), result);
+        nia_assert_equal(
+            Some(previous_this_object_id),
+            interpreter.get_this_object(),
+        );
+    }
+}
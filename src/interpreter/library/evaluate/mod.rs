@@ -1,7 +1,9 @@
 mod evaluate_forms;
 mod evaluate_forms_return_last;
 mod execute_function;
+mod execute_function_with_this;
 
 pub use evaluate_forms::*;
 pub use evaluate_forms_return_last::*;
 pub use execute_function::*;
+pub use execute_function_with_this::*;
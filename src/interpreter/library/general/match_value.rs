@@ -54,28 +54,30 @@ pub fn match_value_recursive(
         },
         Value::Object(binding_object_id) => match value {
             Value::Object(value_object_id) => {
-                let binding_keys =
-                    interpreter.get_object_items(binding_object_id)?;
-                let value_keys =
-                    interpreter.get_object_items(value_object_id)?;
-
-                let mut checkings = Vec::new();
-
-                for (symbol_id, binding_value) in binding_keys {
-                    match value_keys.get(symbol_id) {
-                        Some(value_value) => {
-                            checkings.push((*binding_value, *value_value));
-                        },
-                        _ => return Error::generic_execution_error("").into(),
-                    }
+                let mut bindings = Vec::new();
+
+                for (symbol_id, binding_value) in
+                    interpreter.get_object_items(binding_object_id)?
+                {
+                    bindings.push((*symbol_id, binding_value.get_value()?));
                 }
 
-                for (binding_value, value) in checkings {
+                // properties are read like `object:get' does, so getters
+                // of the destructured object are called
+                for (symbol_id, binding_value) in bindings {
+                    let value = library::get_object_property(
+                        interpreter,
+                        environment_id,
+                        value_object_id,
+                        symbol_id,
+                    )?
+                    .ok_or_else(|| Error::generic_execution_error(""))?;
+
                     match_value_recursive(
                         interpreter,
                         environment_id,
-                        binding_value.get_value()?,
-                        value.get_value()?,
+                        binding_value,
+                        value,
                     )?;
                 }
 
@@ -235,6 +237,12 @@ mod tests {
                 "'(list:new a b c)",
                 "(list:new 1 2 3)",
             ),
+            (
+                "#{:a :b}",
+                "(let ((obj {:a 1})) (object:define-property! obj {:name :b :get (fn () (+ this:a 1))}) obj)",
+                "'(list:new a b)",
+                "(list:new 1 2)",
+            ),
         ];
 
         assert_matches_correctly(&mut interpreter, specs);
@@ -4,6 +4,9 @@ mod is_falsy;
 mod is_truthy;
 mod match_value;
 mod print_value;
+mod printer;
+mod value_to_pretty_string;
+mod value_to_readable_string;
 mod value_to_string;
 
 pub use _format::*;
@@ -12,4 +15,7 @@ pub use is_falsy::*;
 pub use is_truthy::*;
 pub use match_value::*;
 pub use print_value::*;
+pub use printer::*;
+pub use value_to_pretty_string::*;
+pub use value_to_readable_string::*;
 pub use value_to_string::*;
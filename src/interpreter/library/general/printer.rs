@@ -0,0 +1,733 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use crate::interpreter::error::Error;
+use crate::interpreter::interpreter::Interpreter;
+use crate::interpreter::value::ConsId;
+use crate::interpreter::value::Function;
+use crate::interpreter::value::InterpretedFunction;
+use crate::interpreter::value::ObjectId;
+use crate::interpreter::value::Value;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrintMode {
+    Display,
+    Readable,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum NodeId {
+    Cons(ConsId),
+    Object(ObjectId),
+}
+
+fn get_node_id(value: Value) -> Option<NodeId> {
+    match value {
+        Value::Cons(cons_id) => Some(NodeId::Cons(cons_id)),
+        Value::Object(object_id) => Some(NodeId::Object(object_id)),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SequenceKind {
+    List,
+    Call,
+    Object,
+}
+
+#[derive(Debug)]
+enum Document {
+    Text(String),
+    Prefixed(String, Box<Document>),
+    Sequence(SequenceKind, String, Vec<Document>, String),
+}
+
+fn escape_name(name: &str, escape_colons: bool) -> String {
+    let mut result = String::new();
+
+    for character in name.chars() {
+        match character {
+            '\\' | '(' | ')' | '{' | '}' | ',' | '`' | '\'' | ' ' | '"'
+            | ';' => result.push('\\'),
+            ':' if escape_colons => result.push('\\'),
+            _ => {},
+        }
+
+        result.push(character);
+    }
+
+    result
+}
+
+fn escape_string(string: &str) -> String {
+    let mut result = String::from("\"");
+
+    for character in string.chars() {
+        match character {
+            '\\' => result.push_str("\\\\"),
+            '"' => result.push_str("\\\""),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            _ => result.push(character),
+        }
+    }
+
+    result.push('"');
+    result
+}
+
+fn float_to_readable_string(float: f64) -> String {
+    let string = float.to_string();
+
+    if float.is_finite() && !string.contains('.') && !string.contains('e') {
+        format!("{}.0", string)
+    } else {
+        string
+    }
+}
+
+// a function made by `#(...)' takes arguments %1 .. %n and has one form as
+// its body, so it can be printed back in that form
+fn get_short_lambda_code(function: &InterpretedFunction) -> Option<Value> {
+    let arguments = function.get_arguments();
+
+    if !arguments.get_optional_arguments().is_empty()
+        || arguments.get_rest_argument().is_some()
+        || !arguments.get_key_arguments().is_empty()
+    {
+        return None;
+    }
+
+    let is_short_lambda_argument_list = arguments
+        .get_ordinary_arguments()
+        .iter()
+        .enumerate()
+        .all(|(index, name)| *name == format!("%{}", index + 1));
+
+    if !is_short_lambda_argument_list {
+        return None;
+    }
+
+    match function.get_code().as_slice() {
+        [code @ Value::Cons(_)] => Some(*code),
+        _ => None,
+    }
+}
+
+fn get_printed_code(
+    interpreter: &Interpreter,
+    value: Value,
+    mode: PrintMode,
+) -> Result<Option<Value>, Error> {
+    match (value, mode) {
+        (Value::Function(function_id), PrintMode::Readable) => {
+            match interpreter.get_function(function_id)? {
+                Function::Interpreted(interpreted_function) => {
+                    Ok(get_short_lambda_code(interpreted_function))
+                },
+                _ => Ok(None),
+            }
+        },
+        _ => Ok(None),
+    }
+}
+
+// finds conses and objects that are reached more than once, and the ones
+// that are reached from inside themselves
+struct StructureWalker<'a> {
+    interpreter: &'a Interpreter,
+    mode: PrintMode,
+    visited: HashSet<NodeId>,
+    in_progress: HashSet<NodeId>,
+    shared: HashSet<NodeId>,
+    circular: HashSet<NodeId>,
+}
+
+impl<'a> StructureWalker<'a> {
+    fn new(interpreter: &'a Interpreter, mode: PrintMode) -> Self {
+        StructureWalker {
+            interpreter,
+            mode,
+            visited: HashSet::new(),
+            in_progress: HashSet::new(),
+            shared: HashSet::new(),
+            circular: HashSet::new(),
+        }
+    }
+
+    // the cdr chain of a list is walked in a loop, so long lists do not
+    // overflow the stack
+    fn walk(&mut self, value: Value) -> Result<(), Error> {
+        let interpreter = self.interpreter;
+        let mut current = value;
+        let mut walked_nodes = Vec::new();
+
+        loop {
+            let node_id = match get_node_id(current) {
+                Some(node_id) => node_id,
+                None => {
+                    if let Some(code) =
+                        get_printed_code(interpreter, current, self.mode)?
+                    {
+                        self.walk(code)?;
+                    }
+
+                    break;
+                },
+            };
+
+            if self.in_progress.contains(&node_id) {
+                self.circular.insert(node_id);
+                self.shared.insert(node_id);
+                break;
+            }
+
+            if !self.visited.insert(node_id) {
+                self.shared.insert(node_id);
+                break;
+            }
+
+            self.in_progress.insert(node_id);
+            walked_nodes.push(node_id);
+
+            match node_id {
+                NodeId::Cons(cons_id) => {
+                    self.walk(interpreter.get_car(cons_id)?)?;
+                    current = interpreter.get_cdr(cons_id)?;
+                },
+                NodeId::Object(object_id) => {
+                    for value in
+                        interpreter.get_object_items(object_id)?.values()
+                    {
+                        self.walk(value.force_get_value())?;
+                    }
+
+                    break;
+                },
+            }
+        }
+
+        for node_id in walked_nodes {
+            self.in_progress.remove(&node_id);
+        }
+
+        Ok(())
+    }
+}
+
+struct DocumentBuilder<'a> {
+    interpreter: &'a Interpreter,
+    mode: PrintMode,
+    labeled_nodes: HashSet<NodeId>,
+    labels: HashMap<NodeId, usize>,
+}
+
+impl<'a> DocumentBuilder<'a> {
+    fn new(
+        interpreter: &'a Interpreter,
+        mode: PrintMode,
+        labeled_nodes: HashSet<NodeId>,
+    ) -> Self {
+        DocumentBuilder {
+            interpreter,
+            mode,
+            labeled_nodes,
+            labels: HashMap::new(),
+        }
+    }
+
+    fn is_labeled(&self, value: Value) -> bool {
+        match get_node_id(value) {
+            Some(node_id) => self.labeled_nodes.contains(&node_id),
+            None => false,
+        }
+    }
+
+    // the first occurrence of a labeled node is printed as `#n=' followed by
+    // the node, the next ones as `#n#'
+    fn build(&mut self, value: Value) -> Result<Document, Error> {
+        let node_id = match get_node_id(value) {
+            Some(node_id) if self.labeled_nodes.contains(&node_id) => node_id,
+            _ => return self.build_node(value),
+        };
+
+        if let Some(label) = self.labels.get(&node_id) {
+            return Ok(Document::Text(format!("#{}#", label)));
+        }
+
+        let label = self.labels.len() + 1;
+        self.labels.insert(node_id, label);
+
+        let document = self.build_node(value)?;
+
+        Ok(Document::Prefixed(
+            format!("#{}=", label),
+            Box::new(document),
+        ))
+    }
+
+    fn build_node(&mut self, value: Value) -> Result<Document, Error> {
+        let interpreter = self.interpreter;
+
+        let document = match value {
+            Value::Integer(int) => Document::Text(int.to_string()),
+            Value::Float(float) => match self.mode {
+                PrintMode::Display => Document::Text(float.to_string()),
+                PrintMode::Readable => {
+                    Document::Text(float_to_readable_string(float))
+                },
+            },
+            Value::Boolean(true) => Document::Text(String::from("#t")),
+            Value::Boolean(false) => Document::Text(String::from("#f")),
+            Value::String(string_id) => {
+                let string = interpreter.get_string(string_id)?.get_string();
+
+                match self.mode {
+                    PrintMode::Display => Document::Text(string.clone()),
+                    PrintMode::Readable => {
+                        Document::Text(escape_string(string))
+                    },
+                }
+            },
+            Value::Symbol(symbol_id) => {
+                let name = interpreter.get_symbol_name(symbol_id)?;
+
+                match self.mode {
+                    PrintMode::Readable if !name.starts_with('#') => {
+                        Document::Text(escape_name(name, true))
+                    },
+                    _ => Document::Text(name.clone()),
+                }
+            },
+            Value::Keyword(keyword_id) => {
+                let name = interpreter.get_keyword(keyword_id)?.get_name();
+
+                Document::Text(self.make_keyword_text(name))
+            },
+            Value::Cons(cons_id) => self.build_list(cons_id)?,
+            Value::Object(object_id) => self.build_object(object_id)?,
+            Value::Function(function_id) => {
+                if let Some(code) =
+                    get_printed_code(interpreter, value, self.mode)?
+                {
+                    let document = self.build(code)?;
+
+                    return Ok(Document::Prefixed(
+                        String::from("#"),
+                        Box::new(document),
+                    ));
+                }
+
+                let name = match interpreter.get_function(function_id)? {
+                    Function::Interpreted(_) => "<function>",
+                    Function::Builtin(_) => "<builtin-function>",
+                    Function::Macro(_) => "<macro>",
+                    Function::SpecialForm(_) => "<special-form>",
+                };
+
+                match self.mode {
+                    PrintMode::Display => Document::Text(String::from(name)),
+                    PrintMode::Readable => Document::Text(format!("#{}", name)),
+                }
+            },
+        };
+
+        Ok(document)
+    }
+
+    fn make_keyword_text(&self, name: &str) -> String {
+        match self.mode {
+            PrintMode::Display => format!(":{}", name),
+            PrintMode::Readable => format!(":{}", escape_name(name, false)),
+        }
+    }
+
+    fn build_list(&mut self, cons_id: ConsId) -> Result<Document, Error> {
+        let interpreter = self.interpreter;
+
+        let mut items = Vec::new();
+        let mut car_value = interpreter.get_car(cons_id)?;
+        let mut cdr_value = interpreter.get_cdr(cons_id)?;
+
+        loop {
+            items.push(self.build(car_value)?);
+
+            match cdr_value {
+                Value::Cons(cons_id) if !self.is_labeled(cdr_value) => {
+                    car_value = interpreter.get_car(cons_id)?;
+                    cdr_value = interpreter.get_cdr(cons_id)?;
+                },
+                Value::Symbol(symbol_id)
+                    if interpreter.symbol_is_nil(symbol_id)? =>
+                {
+                    break;
+                },
+                _ => {
+                    let document = self.build(cdr_value)?;
+
+                    items.push(Document::Prefixed(
+                        String::from("#. "),
+                        Box::new(document),
+                    ));
+                    break;
+                },
+            }
+        }
+
+        let kind = match interpreter.get_car(cons_id)? {
+            Value::Symbol(_) if items.len() > 2 => SequenceKind::Call,
+            _ => SequenceKind::List,
+        };
+
+        Ok(Document::Sequence(
+            kind,
+            String::from("("),
+            items,
+            String::from(")"),
+        ))
+    }
+
+    fn build_object(&mut self, object_id: ObjectId) -> Result<Document, Error> {
+        let interpreter = self.interpreter;
+        let mut items = Vec::new();
+
+        for (symbol_id, value) in interpreter.get_object_items(object_id)? {
+            let name = interpreter.get_symbol_name(*symbol_id)?;
+
+            items.push(Document::Text(self.make_keyword_text(name)));
+            items.push(self.build(value.force_get_value())?);
+        }
+
+        Ok(Document::Sequence(
+            SequenceKind::Object,
+            String::from("{"),
+            items,
+            String::from("}"),
+        ))
+    }
+}
+
+fn get_text_width(text: &str) -> usize {
+    text.chars().count()
+}
+
+fn get_flat_width(document: &Document) -> usize {
+    match document {
+        Document::Text(text) => get_text_width(text),
+        Document::Prefixed(prefix, document) => {
+            get_text_width(prefix) + get_flat_width(document)
+        },
+        Document::Sequence(_, opening, items, closing) => {
+            let items_width: usize = items.iter().map(get_flat_width).sum();
+            let spaces_width = items.len().saturating_sub(1);
+
+            get_text_width(opening)
+                + items_width
+                + spaces_width
+                + get_text_width(closing)
+        },
+    }
+}
+
+fn render_flat(document: &Document, result: &mut String) {
+    match document {
+        Document::Text(text) => result.push_str(text),
+        Document::Prefixed(prefix, document) => {
+            result.push_str(prefix);
+            render_flat(document, result);
+        },
+        Document::Sequence(_, opening, items, closing) => {
+            result.push_str(opening);
+
+            for (index, item) in items.iter().enumerate() {
+                if index > 0 {
+                    result.push(' ');
+                }
+
+                render_flat(item, result);
+            }
+
+            result.push_str(closing);
+        },
+    }
+}
+
+fn push_line_break(result: &mut String, indentation: usize) {
+    result.push('\n');
+    result.extend(std::iter::repeat(' ').take(indentation));
+}
+
+// documents that do not fit into the rest of the line are broken into one
+// item per line: calls keep their first argument next to the function name,
+// objects keep values next to their keys
+fn render(
+    document: &Document,
+    column: usize,
+    width: usize,
+    result: &mut String,
+) {
+    if column + get_flat_width(document) <= width {
+        render_flat(document, result);
+        return;
+    }
+
+    match document {
+        Document::Text(text) => result.push_str(text),
+        Document::Prefixed(prefix, document) => {
+            result.push_str(prefix);
+            render(document, column + get_text_width(prefix), width, result);
+        },
+        Document::Sequence(kind, opening, items, closing) => {
+            let mut indentation = column + get_text_width(opening);
+            let mut items = items.iter();
+
+            result.push_str(opening);
+
+            match kind {
+                SequenceKind::Call => {
+                    if let Some(head) = items.next() {
+                        let head_width = get_flat_width(head);
+
+                        render_flat(head, result);
+
+                        if indentation + head_width < width / 2 {
+                            indentation += head_width + 1;
+                            result.push(' ');
+                        } else {
+                            push_line_break(result, indentation);
+                        }
+                    }
+
+                    render_items(items, indentation, width, result);
+                },
+                SequenceKind::List => {
+                    render_items(items, indentation, width, result);
+                },
+                SequenceKind::Object => {
+                    let mut first = true;
+
+                    while let Some(key) = items.next() {
+                        if !first {
+                            push_line_break(result, indentation);
+                        }
+                        first = false;
+
+                        render_flat(key, result);
+
+                        if let Some(value) = items.next() {
+                            let value_column =
+                                indentation + get_flat_width(key) + 1;
+
+                            result.push(' ');
+                            render(value, value_column, width, result);
+                        }
+                    }
+                },
+            }
+
+            result.push_str(closing);
+        },
+    }
+}
+
+fn render_items<'b>(
+    items: impl Iterator<Item = &'b Document>,
+    indentation: usize,
+    width: usize,
+    result: &mut String,
+) {
+    for (index, item) in items.enumerate() {
+        if index > 0 {
+            push_line_break(result, indentation);
+        }
+
+        render(item, indentation, width, result);
+    }
+}
+
+fn make_document(
+    interpreter: &Interpreter,
+    value: Value,
+    mode: PrintMode,
+) -> Result<Document, Error> {
+    let mut walker = StructureWalker::new(interpreter, mode);
+    walker.walk(value)?;
+
+    // shared structure is labeled only where reading the text back has to
+    // restore it, and circular structure always, so printing terminates
+    let labeled_nodes = match mode {
+        PrintMode::Display => walker.circular,
+        PrintMode::Readable => walker.shared,
+    };
+
+    DocumentBuilder::new(interpreter, mode, labeled_nodes).build(value)
+}
+
+pub fn value_to_string_with_mode(
+    interpreter: &Interpreter,
+    value: Value,
+    mode: PrintMode,
+) -> Result<String, Error> {
+    let document = make_document(interpreter, value, mode)?;
+    let mut result = String::new();
+
+    render_flat(&document, &mut result);
+
+    Ok(result)
+}
+
+pub fn value_to_string_with_width(
+    interpreter: &Interpreter,
+    value: Value,
+    mode: PrintMode,
+    width: usize,
+) -> Result<String, Error> {
+    let document = make_document(interpreter, value, mode)?;
+    let mut result = String::new();
+
+    render(&document, 0, width, &mut result);
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    fn assert_printed(
+        interpreter: &mut Interpreter,
+        code: &str,
+        mode: PrintMode,
+        expected: &str,
+    ) {
+        let value = interpreter.execute_in_main_environment(code).unwrap();
+        let result =
+            value_to_string_with_mode(interpreter, value, mode).unwrap();
+
+        nia_assert_equal(expected, &result);
+    }
+
+    fn assert_printed_with_width(
+        interpreter: &mut Interpreter,
+        code: &str,
+        width: usize,
+        expected: &str,
+    ) {
+        let value = interpreter.execute_in_main_environment(code).unwrap();
+        let result = value_to_string_with_width(
+            interpreter,
+            value,
+            PrintMode::Readable,
+            width,
+        )
+        .unwrap();
+
+        nia_assert_equal(expected, &result);
+    }
+
+    #[test]
+    fn labels_circular_lists() {
+        let mut interpreter = Interpreter::new();
+
+        let code =
+            "(let ((l (list:new 'a 'b))) (cons:set-cdr! (cons:cdr l) l) l)";
+
+        assert_printed(
+            &mut interpreter,
+            code,
+            PrintMode::Display,
+            "#1=(a b #. #1#)",
+        );
+        assert_printed(
+            &mut interpreter,
+            code,
+            PrintMode::Readable,
+            "#1=(a b #. #1#)",
+        );
+
+        let code = "(let ((l (list:new 'a))) (cons:set-car! l l) l)";
+
+        assert_printed(&mut interpreter, code, PrintMode::Display, "#1=(#1#)");
+    }
+
+    #[test]
+    fn labels_circular_objects() {
+        let mut interpreter = Interpreter::new();
+
+        assert_printed(
+            &mut interpreter,
+            "(let ((o {})) (object:set! o :self o) o)",
+            PrintMode::Readable,
+            "#1={:self #1#}",
+        );
+    }
+
+    #[test]
+    fn labels_shared_structure_only_in_readable_mode() {
+        let mut interpreter = Interpreter::new();
+
+        let code = "(let ((l (list:new 1 2))) (list:new l l))";
+
+        assert_printed(
+            &mut interpreter,
+            code,
+            PrintMode::Display,
+            "((1 2) (1 2))",
+        );
+        assert_printed(
+            &mut interpreter,
+            code,
+            PrintMode::Readable,
+            "(#1=(1 2) #1#)",
+        );
+
+        let code = "(let ((l (list:new 2 3))) (list:new (cons:new 1 l) l))";
+
+        assert_printed(
+            &mut interpreter,
+            code,
+            PrintMode::Readable,
+            "((1 #. #1=(2 3)) #1#)",
+        );
+    }
+
+    #[test]
+    fn keeps_values_that_fit_on_one_line() {
+        let mut interpreter = Interpreter::new();
+
+        assert_printed_with_width(
+            &mut interpreter,
+            "'(defn f (a b) (+ a b))",
+            80,
+            "(defn f (a b) (+ a b))",
+        );
+    }
+
+    #[test]
+    fn breaks_long_lists_and_objects() {
+        let mut interpreter = Interpreter::new();
+
+        assert_printed_with_width(
+            &mut interpreter,
+            "'(defn f (a b) (+ a b))",
+            16,
+            "(defn f\n      (a b)\n      (+ a b))",
+        );
+        assert_printed_with_width(
+            &mut interpreter,
+            "'(1 (2 3) 4)",
+            8,
+            "(1\n (2 3)\n 4)",
+        );
+        assert_printed_with_width(
+            &mut interpreter,
+            "{:key '(first-item second-item)}",
+            20,
+            "{:key (first-item\n       second-item)}",
+        );
+    }
+}
@@ -0,0 +1,48 @@
+use crate::interpreter::error::Error;
+use crate::interpreter::interpreter::Interpreter;
+use crate::interpreter::value::Value;
+
+use crate::library;
+
+pub const DEFAULT_PRETTY_PRINT_WIDTH: usize = 80;
+
+pub fn value_to_pretty_string(
+    interpreter: &Interpreter,
+    value: Value,
+    width: usize,
+) -> Result<String, Error> {
+    library::value_to_string_with_width(
+        interpreter,
+        value,
+        library::PrintMode::Readable,
+        width,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[test]
+    fn breaks_values_that_do_not_fit_into_width() {
+        let mut interpreter = Interpreter::new();
+
+        let specs = vec![
+            ("'(a b c)", 80, "(a b c)"),
+            ("'(a b c)", 6, "(a b\n   c)"),
+            (r#"'("one" "two")"#, 8, "(\"one\"\n \"two\")"),
+            ("{:a '(1 2)}", 6, "{:a (1\n     2)}"),
+        ];
+
+        for (code, width, expected) in specs {
+            let value = interpreter.execute_in_main_environment(code).unwrap();
+            let result =
+                value_to_pretty_string(&interpreter, value, width).unwrap();
+
+            nia_assert_equal(expected, &result);
+        }
+    }
+}
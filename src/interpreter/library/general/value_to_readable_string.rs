@@ -0,0 +1,91 @@
+use crate::interpreter::error::Error;
+use crate::interpreter::interpreter::Interpreter;
+use crate::interpreter::value::Value;
+
+use crate::library;
+
+// unlike `value_to_string', returns the text that reads back into an equal
+// value whenever the value has a textual form
+pub fn value_to_readable_string(
+    interpreter: &Interpreter,
+    value: Value,
+) -> Result<String, Error> {
+    library::value_to_string_with_mode(
+        interpreter,
+        value,
+        library::PrintMode::Readable,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[test]
+    fn returns_readable_representation_of_values() {
+        let mut interpreter = Interpreter::new();
+
+        let pairs = vec![
+            ("1", "1"),
+            ("1.1", "1.1"),
+            ("1.0", "1.0"),
+            ("#t", "#t"),
+            ("#f", "#f"),
+            (r#""string""#, r#""string""#),
+            (r#""a \"quoted\" \\ string""#, r#""a \"quoted\" \\ string""#),
+            ("\"two\nlines\"", r#""two\nlines""#),
+            ("'symbol", "symbol"),
+            (r"'sym\ bol", r"sym\ bol"),
+            (":keyword", ":keyword"),
+            (r#"'(a "b" :c)"#, r#"(a "b" :c)"#),
+            ("{}", "{}"),
+            (r#"{:key "value"}"#, r#"{:key "value"}"#),
+            ("#(+ %1 %2)", "#(+ %1 %2)"),
+            ("#(list)", "#(list)"),
+            ("(function (lambda (a) a))", "#<function>"),
+            ("(flookup 'flookup)", "#<builtin-function>"),
+            ("(function (macro () 1))", "#<macro>"),
+            ("(flookup 'cond)", "#<special-form>"),
+        ];
+
+        for (code, expected) in pairs {
+            let value = interpreter.execute_in_main_environment(code).unwrap();
+            let result = value_to_readable_string(&interpreter, value).unwrap();
+
+            nia_assert_equal(expected, &result);
+        }
+    }
+
+    #[test]
+    fn readable_representation_reads_back_into_equal_value() {
+        let mut interpreter = Interpreter::new();
+
+        let specs = vec![
+            "1",
+            "1.0",
+            r#""a \"quoted\"\nstring""#,
+            r"'sym\ bol",
+            ":keyword",
+            r#"'(1 2.0 "three" :four (five))"#,
+            r#"{:a 1 :b "two"}"#,
+        ];
+
+        for code in specs {
+            let value = interpreter.execute_in_main_environment(code).unwrap();
+            let string = value_to_readable_string(&interpreter, value).unwrap();
+
+            let quoted_string = match value {
+                Value::Symbol(_) | Value::Cons(_) => format!("'{}", string),
+                _ => string,
+            };
+            let read_value = interpreter
+                .execute_in_main_environment(&quoted_string)
+                .unwrap();
+
+            crate::utils::assert_deep_equal(&interpreter, value, read_value);
+        }
+    }
+}
@@ -99,6 +99,33 @@ pub fn action_to_list(
             interpreter.intern_string_value(action_name),
         ],
 
+        Action::Sequence(actions) => {
+            let mut vector = vec![interpreter.intern_symbol_value("sequence")];
+
+            for action in actions {
+                vector.push(action_to_list(interpreter, action)?);
+            }
+
+            vector
+        },
+        Action::Repeat(count, action) => vec![
+            interpreter.intern_symbol_value("repeat"),
+            Value::Integer(*count as i64),
+            action_to_list(interpreter, action)?,
+        ],
+        Action::WithHeld(key_codes, action) => {
+            let key_code_values = key_codes
+                .iter()
+                .map(|key_code| Value::Integer(*key_code as i64))
+                .collect();
+
+            vec![
+                interpreter.intern_symbol_value("with-held"),
+                interpreter.vec_to_list(key_code_values),
+                action_to_list(interpreter, action)?,
+            ]
+        },
+
         &Action::ExecuteFunctionValue(function_value) => vec![
             interpreter.intern_symbol_value("execute-function-value"),
             function_value,
@@ -172,6 +199,29 @@ mod tests {
                 Action::ExecuteFunction(String::from("function name")),
             ),
             (r#"'(wait 1000)"#, Action::Wait(1000)),
+            (r#"'(sequence)"#, Action::Sequence(vec![])),
+            (
+                r#"'(sequence (key-click 1) (wait 100))"#,
+                Action::Sequence(vec![Action::KeyClick(1), Action::Wait(100)]),
+            ),
+            (
+                r#"'(repeat 3 (key-click 1))"#,
+                Action::Repeat(3, Box::new(Action::KeyClick(1))),
+            ),
+            (
+                r#"'(with-held (29 42) (key-click 30))"#,
+                Action::WithHeld(vec![29, 42], Box::new(Action::KeyClick(30))),
+            ),
+            (
+                r#"'(repeat 2 (with-held (29) (sequence (key-click 1))))"#,
+                Action::Repeat(
+                    2,
+                    Box::new(Action::WithHeld(
+                        vec![29],
+                        Box::new(Action::Sequence(vec![Action::KeyClick(1)])),
+                    )),
+                ),
+            ),
         ];
 
         for (expected, action) in specs {
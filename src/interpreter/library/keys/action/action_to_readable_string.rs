@@ -0,0 +1,53 @@
+use crate::Action;
+use crate::Error;
+use crate::Interpreter;
+
+use crate::library;
+
+// actions are printed the way they are written in configuration
+pub fn action_to_readable_string(
+    interpreter: &mut Interpreter,
+    action: &Action,
+) -> Result<String, Error> {
+    let action_value = library::action_to_list(interpreter, action)?;
+
+    library::value_to_readable_string(interpreter, action_value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[test]
+    fn returns_actions_as_code() {
+        let mut interpreter = Interpreter::new();
+        let function_value = interpreter
+            .execute_in_main_environment("(fn () nil)")
+            .unwrap();
+
+        let specs = vec![
+            (Action::KeyPress(10), "(key-press 10)"),
+            (
+                Action::TextType(String::from("a \"b\"")),
+                r#"(text-type "a \"b\"")"#,
+            ),
+            (
+                Action::Sequence(vec![
+                    Action::KeyClick(1),
+                    Action::ExecuteFunctionValue(function_value),
+                ]),
+                "(sequence (key-click 1) (execute-function-value #<function>))",
+            ),
+        ];
+
+        for (action, expected) in specs {
+            nia_assert_equal(
+                Ok(String::from(expected)),
+                action_to_readable_string(&mut interpreter, &action),
+            );
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::convert::TryFrom;
+
 use crate::Action;
 use crate::Error;
 use crate::Interpreter;
@@ -167,6 +169,84 @@ fn parse_execute_function_value_action(
     Ok(Action::ExecuteFunctionValue(function_value))
 }
 
+fn parse_sequence_action(
+    interpreter: &mut Interpreter,
+    action_vector: Vec<Value>,
+) -> Result<Action, Error> {
+    let mut actions = Vec::new();
+
+    for action_value in action_vector {
+        actions.push(list_to_action(interpreter, action_value)?);
+    }
+
+    Ok(Action::Sequence(actions))
+}
+
+fn parse_repeat_action(
+    interpreter: &mut Interpreter,
+    mut action_vector: Vec<Value>,
+) -> Result<Action, Error> {
+    let count_value = try_remove_first_item(
+        &mut action_vector,
+        "Repeat action list must have three items exactly to be considered as action.",
+    )?;
+
+    let action_value = try_remove_first_item(
+        &mut action_vector,
+        "Repeat action list must have three items exactly to be considered as action.",
+    )?;
+
+    check_list_has_no_items_left(
+        &action_vector,
+        "Repeat action list must have three items exactly to be considered as action.",
+    )?;
+
+    let count = i32::try_from(library::read_as_positive_i64(count_value)?)
+        .map_err(|_| {
+            Error::invalid_argument_error("Repeat count is too large.")
+        })?;
+    let action = list_to_action(interpreter, action_value)?;
+
+    Ok(Action::Repeat(count, Box::new(action)))
+}
+
+fn parse_with_held_action(
+    interpreter: &mut Interpreter,
+    mut action_vector: Vec<Value>,
+) -> Result<Action, Error> {
+    let key_codes_value = try_remove_first_item(
+        &mut action_vector,
+        "With held action list must have three items exactly to be considered as action.",
+    )?;
+
+    let action_value = try_remove_first_item(
+        &mut action_vector,
+        "With held action list must have three items exactly to be considered as action.",
+    )?;
+
+    check_list_has_no_items_left(
+        &action_vector,
+        "With held action list must have three items exactly to be considered as action.",
+    )?;
+
+    let key_code_values =
+        library::read_as_vector(interpreter, key_codes_value)?;
+    let mut key_codes = Vec::new();
+
+    for key_code_value in key_code_values {
+        let key_code = i32::try_from(library::read_as_i64(key_code_value)?)
+            .map_err(|_| {
+                Error::invalid_argument_error("Key code is out of range.")
+            })?;
+
+        key_codes.push(key_code);
+    }
+
+    let action = list_to_action(interpreter, action_value)?;
+
+    Ok(Action::WithHeld(key_codes, Box::new(action)))
+}
+
 pub fn list_to_action(
     interpreter: &mut Interpreter,
     action_list: Value,
@@ -213,6 +293,10 @@ pub fn list_to_action(
         
         "execute-function-value" => parse_execute_function_value_action(interpreter, action_vector)?,
 
+        "sequence" => parse_sequence_action(interpreter, action_vector)?,
+        "repeat" => parse_repeat_action(interpreter, action_vector)?,
+        "with-held" => parse_with_held_action(interpreter, action_vector)?,
+
         _ => {
             return Error::invalid_argument_error(format!(
                 "Unknown action type: {}.",
@@ -266,6 +350,12 @@ mod tests {
             (Action::ExecuteFunction(String::from("test")), r#"'(execute-function "test")"#),
             (Action::ExecuteOSCommand(String::from("echo nya")), r#"'(execute-os-command "echo nya")"#),
             (Action::ExecuteNamedAction(String::from("print-nya")), r#"'(execute-named-action "print-nya")"#),
+
+            (Action::Sequence(vec![]), r#"'(sequence)"#),
+            (Action::Sequence(vec![Action::KeyClick(1), Action::Wait(100), Action::KeyClick(2)]), r#"'(sequence (key-click 1) (wait 100) (key-click 2))"#),
+            (Action::Repeat(3, Box::new(Action::KeyClick(1))), r#"'(repeat 3 (key-click 1))"#),
+            (Action::WithHeld(vec![29, 42], Box::new(Action::KeyClick(30))), r#"'(with-held (29 42) (key-click 30))"#),
+            (Action::Repeat(2, Box::new(Action::Sequence(vec![Action::KeyClick(1), Action::Wait(10)]))), r#"'(repeat 2 (sequence (key-click 1) (wait 10)))"#),
         ];
 
         for (expected, code) in specs {
@@ -347,6 +437,21 @@ mod tests {
             r#"'(execute-named-action "print-nya" "print-nya")"#,
             r#"'(wait)"#,
             r#"'(wait 1000 1000)"#,
+
+            r#"'(sequence 1)"#,
+            r#"'(sequence (key-click))"#,
+            r#"'(repeat)"#,
+            r#"'(repeat 3)"#,
+            r#"'(repeat -1 (key-click 1))"#,
+            r#"'(repeat 3 (key-click 1) (key-click 2))"#,
+            r#"'(repeat 3 1)"#,
+            r#"'(repeat 5000000000 (key-click 1))"#,
+            r#"'(with-held)"#,
+            r#"'(with-held (29))"#,
+            r#"'(with-held 29 (key-click 1))"#,
+            r#"'(with-held ("a") (key-click 1))"#,
+            r#"'(with-held (5000000000) (key-click 1))"#,
+            r#"'(with-held (29) (key-click 1) (key-click 2))"#,
         ];
 
         for spec in specs {
@@ -1,4 +1,5 @@
 mod action_to_list;
+mod action_to_readable_string;
 mod define_action;
 mod define_action_with_value;
 mod get_action_by_name;
@@ -8,6 +9,7 @@ mod list_to_action;
 mod remove_action;
 
 pub use action_to_list::*;
+pub use action_to_readable_string::*;
 pub use define_action::*;
 pub use define_action_with_value::*;
 pub use get_action_by_name::*;
@@ -0,0 +1,64 @@
+use crate::Error;
+use crate::Interpreter;
+use crate::KeyChord;
+use crate::EMERGENCY_STOP_KEY_CHORD_ROOT_VARIABLE_NAME;
+
+use crate::library;
+
+pub fn define_emergency_stop_key_chord(
+    interpreter: &mut Interpreter,
+    key_chord: &KeyChord,
+) -> Result<(), Error> {
+    let key_chord_value = library::key_chord_to_list(interpreter, key_chord);
+
+    library::set_root_variable(
+        interpreter,
+        EMERGENCY_STOP_KEY_CHORD_ROOT_VARIABLE_NAME,
+        key_chord_value,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[allow(unused_imports)]
+    use crate::utils;
+
+    #[test]
+    fn sets_emergency_stop_key_chord() {
+        let mut interpreter = Interpreter::new();
+
+        let specs = vec![
+            (
+                KeyChord::new(vec![nia_key!(29)], nia_key!(1)),
+                "'(29 1)",
+            ),
+            (
+                KeyChord::new(vec![nia_key!(1, 29)], nia_key!(1, 1)),
+                "'((1 29) (1 1))",
+            ),
+        ];
+
+        for (key_chord, expected) in specs {
+            nia_assert_is_ok(&define_emergency_stop_key_chord(
+                &mut interpreter,
+                &key_chord,
+            ));
+
+            let expected =
+                interpreter.execute_in_main_environment(expected).unwrap();
+            let result = library::get_root_variable(
+                &mut interpreter,
+                EMERGENCY_STOP_KEY_CHORD_ROOT_VARIABLE_NAME,
+            )
+            .unwrap();
+
+            utils::assert_deep_equal(&mut interpreter, expected, result);
+        }
+    }
+}
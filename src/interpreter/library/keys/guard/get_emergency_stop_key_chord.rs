@@ -0,0 +1,76 @@
+use crate::Error;
+use crate::Interpreter;
+use crate::KeyChord;
+use crate::Value;
+use crate::EMERGENCY_STOP_KEY_CHORD_ROOT_VARIABLE_NAME;
+
+use crate::library;
+
+pub fn get_emergency_stop_key_chord(
+    interpreter: &mut Interpreter,
+) -> Result<Option<KeyChord>, Error> {
+    let key_chord_value = library::get_root_variable(
+        interpreter,
+        EMERGENCY_STOP_KEY_CHORD_ROOT_VARIABLE_NAME,
+    )?;
+
+    if let Value::Symbol(symbol_id) = key_chord_value {
+        if interpreter.symbol_is_nil(symbol_id)? {
+            return Ok(None);
+        }
+    }
+
+    let key_chord = library::list_to_key_chord(interpreter, key_chord_value)?;
+
+    Ok(Some(key_chord))
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[test]
+    fn returns_none_when_emergency_stop_key_chord_is_not_defined() {
+        let mut interpreter = Interpreter::new();
+
+        nia_assert_equal(
+            None,
+            get_emergency_stop_key_chord(&mut interpreter).unwrap(),
+        );
+    }
+
+    #[test]
+    fn returns_defined_emergency_stop_key_chord() {
+        let mut interpreter = Interpreter::new();
+
+        let key_chord =
+            KeyChord::new(vec![nia_key!(29), nia_key!(56)], nia_key!(1));
+
+        library::define_emergency_stop_key_chord(&mut interpreter, &key_chord)
+            .unwrap();
+
+        nia_assert_equal(
+            Some(key_chord),
+            get_emergency_stop_key_chord(&mut interpreter).unwrap(),
+        );
+    }
+
+    #[test]
+    fn returns_invalid_argument_error_when_variable_is_not_a_key_chord() {
+        let mut interpreter = Interpreter::new();
+
+        interpreter
+            .execute_in_main_environment(
+                "(set! nia-emergency-stop-key-chord 1)",
+            )
+            .unwrap();
+
+        crate::utils::assert_invalid_argument_error(
+            &get_emergency_stop_key_chord(&mut interpreter),
+        );
+    }
+}
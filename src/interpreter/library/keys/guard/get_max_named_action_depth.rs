@@ -0,0 +1,73 @@
+use crate::Error;
+use crate::Interpreter;
+use crate::MAX_NAMED_ACTION_DEPTH_ROOT_VARIABLE_NAME;
+
+use crate::library;
+
+pub fn get_max_named_action_depth(
+    interpreter: &mut Interpreter,
+) -> Result<usize, Error> {
+    let value = library::get_root_variable(
+        interpreter,
+        MAX_NAMED_ACTION_DEPTH_ROOT_VARIABLE_NAME,
+    )?;
+
+    let max_named_action_depth = library::read_as_positive_i64(value)?;
+
+    Ok(max_named_action_depth as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    use crate::DEFAULT_MAX_NAMED_ACTION_DEPTH;
+
+    #[test]
+    fn returns_default_value() {
+        let mut interpreter = Interpreter::new();
+
+        nia_assert_equal(
+            DEFAULT_MAX_NAMED_ACTION_DEPTH as usize,
+            get_max_named_action_depth(&mut interpreter).unwrap(),
+        );
+    }
+
+    #[test]
+    fn returns_changed_value() {
+        let mut interpreter = Interpreter::new();
+
+        interpreter
+            .execute_in_main_environment("(set! nia-max-named-action-depth 20)")
+            .unwrap();
+
+        nia_assert_equal(
+            20,
+            get_max_named_action_depth(&mut interpreter).unwrap(),
+        );
+    }
+
+    #[test]
+    fn returns_invalid_argument_error_when_value_is_not_positive_integer() {
+        let specs = vec!["0", "-1", "1.1", "\"string\"", "'symbol"];
+
+        for spec in specs {
+            let mut interpreter = Interpreter::new();
+
+            interpreter
+                .execute_in_main_environment(&format!(
+                    "(set! nia-max-named-action-depth {})",
+                    spec
+                ))
+                .unwrap();
+
+            crate::utils::assert_invalid_argument_error(
+                &get_max_named_action_depth(&mut interpreter),
+            );
+        }
+    }
+}
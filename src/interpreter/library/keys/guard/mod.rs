@@ -0,0 +1,9 @@
+mod define_emergency_stop_key_chord;
+mod get_emergency_stop_key_chord;
+mod get_max_actions_per_second;
+mod get_max_named_action_depth;
+
+pub use define_emergency_stop_key_chord::*;
+pub use get_emergency_stop_key_chord::*;
+pub use get_max_actions_per_second::*;
+pub use get_max_named_action_depth::*;
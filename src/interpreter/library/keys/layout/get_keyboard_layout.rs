@@ -0,0 +1,79 @@
+use crate::Error;
+use crate::Interpreter;
+use crate::KeyboardLayout;
+use crate::KEYBOARD_LAYOUT_ROOT_VARIABLE_NAME;
+
+use crate::library;
+
+pub fn get_keyboard_layout(
+    interpreter: &mut Interpreter,
+) -> Result<KeyboardLayout, Error> {
+    let value = library::get_root_variable(
+        interpreter,
+        KEYBOARD_LAYOUT_ROOT_VARIABLE_NAME,
+    )?;
+
+    let layout_name = library::read_as_string(interpreter, value)?;
+
+    match KeyboardLayout::from_name(layout_name) {
+        Some(keyboard_layout) => Ok(keyboard_layout),
+        None => Error::invalid_argument_error(&format!(
+            "Unknown keyboard layout: {}.",
+            layout_name
+        ))
+        .into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[test]
+    fn returns_default_keyboard_layout() {
+        let mut interpreter = Interpreter::new();
+
+        nia_assert_equal(
+            KeyboardLayout::Us,
+            get_keyboard_layout(&mut interpreter).unwrap(),
+        );
+    }
+
+    #[test]
+    fn returns_changed_keyboard_layout() {
+        let mut interpreter = Interpreter::new();
+
+        interpreter
+            .execute_in_main_environment("(set! nia-keyboard-layout \"de\")")
+            .unwrap();
+
+        nia_assert_equal(
+            KeyboardLayout::De,
+            get_keyboard_layout(&mut interpreter).unwrap(),
+        );
+    }
+
+    #[test]
+    fn returns_invalid_argument_error_when_layout_is_unknown() {
+        let specs = vec!["\"xx\"", "1", "'symbol"];
+
+        for spec in specs {
+            let mut interpreter = Interpreter::new();
+
+            interpreter
+                .execute_in_main_environment(&format!(
+                    "(set! nia-keyboard-layout {})",
+                    spec
+                ))
+                .unwrap();
+
+            crate::utils::assert_invalid_argument_error(&get_keyboard_layout(
+                &mut interpreter,
+            ));
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod get_keyboard_layout;
+
+pub use get_keyboard_layout::*;
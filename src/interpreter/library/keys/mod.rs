@@ -1,9 +1,15 @@
 mod action;
 mod device;
+mod guard;
 mod key_chord;
+mod layout;
 mod modifier;
+mod remap;
 
 pub use action::*;
 pub use device::*;
+pub use guard::*;
 pub use key_chord::*;
+pub use layout::*;
 pub use modifier::*;
+pub use remap::*;
@@ -0,0 +1,85 @@
+use crate::Error;
+use crate::Interpreter;
+use crate::Key;
+use crate::KeyRemap;
+use crate::DEFINED_REMAPS_ROOT_VARIABLE_NAME;
+
+use crate::library;
+
+pub fn define_key_remap(
+    interpreter: &mut Interpreter,
+    key_remap: &KeyRemap,
+) -> Result<(), Error> {
+    let defined_key_remaps = library::get_defined_key_remaps(interpreter)?;
+
+    for defined_key_remap in defined_key_remaps {
+        if Key::keys_are_same(
+            defined_key_remap.get_from(),
+            key_remap.get_from(),
+        ) {
+            return Error::generic_execution_error("Key is already remapped.")
+                .into();
+        }
+    }
+
+    let key_remap_value = library::key_remap_to_list(interpreter, key_remap);
+
+    library::add_value_to_root_list(
+        interpreter,
+        DEFINED_REMAPS_ROOT_VARIABLE_NAME,
+        key_remap_value,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[test]
+    fn defines_new_key_remaps() {
+        let mut interpreter = Interpreter::new();
+
+        let specs = vec![
+            (KeyRemap::new(nia_key!(58), nia_key!(29)), "'((58 29))"),
+            (
+                KeyRemap::new(nia_key!(1, 58), nia_key!(1)),
+                "'(((1 58) 1) (58 29))",
+            ),
+        ];
+
+        for (key_remap, code) in specs {
+            nia_assert_is_ok(&define_key_remap(&mut interpreter, &key_remap));
+
+            let expected =
+                interpreter.execute_in_main_environment(code).unwrap();
+            let result = library::get_root_variable(
+                &mut interpreter,
+                DEFINED_REMAPS_ROOT_VARIABLE_NAME,
+            )
+            .unwrap();
+
+            crate::utils::assert_deep_equal(&mut interpreter, expected, result);
+        }
+    }
+
+    #[test]
+    fn returns_generic_execution_error_when_key_is_already_remapped() {
+        let mut interpreter = Interpreter::new();
+
+        nia_assert_is_ok(&define_key_remap(
+            &mut interpreter,
+            &KeyRemap::new(nia_key!(1, 58), nia_key!(29)),
+        ));
+
+        let result = define_key_remap(
+            &mut interpreter,
+            &KeyRemap::new(nia_key!(1, 58), nia_key!(42)),
+        );
+
+        crate::utils::assert_generic_execution_error(&result);
+    }
+}
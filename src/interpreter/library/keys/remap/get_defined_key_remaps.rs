@@ -0,0 +1,69 @@
+use crate::Error;
+use crate::Interpreter;
+use crate::KeyRemap;
+use crate::DEFINED_REMAPS_ROOT_VARIABLE_NAME;
+
+use crate::library;
+
+pub fn get_defined_key_remaps(
+    interpreter: &mut Interpreter,
+) -> Result<Vec<KeyRemap>, Error> {
+    let key_remaps_value = library::get_root_variable(
+        interpreter,
+        DEFINED_REMAPS_ROOT_VARIABLE_NAME,
+    )?;
+
+    let key_remap_values =
+        library::read_as_vector(interpreter, key_remaps_value)?;
+
+    let mut key_remaps = Vec::new();
+
+    for key_remap_value in key_remap_values {
+        let key_remap =
+            library::list_to_key_remap(interpreter, key_remap_value)?;
+
+        key_remaps.push(key_remap);
+    }
+
+    Ok(key_remaps)
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[test]
+    fn returns_defined_key_remaps() {
+        let mut interpreter = Interpreter::new();
+
+        nia_assert_equal(
+            Ok(Vec::new()),
+            get_defined_key_remaps(&mut interpreter),
+        );
+
+        library::define_key_remap(
+            &mut interpreter,
+            &KeyRemap::new(nia_key!(58), nia_key!(29)),
+        )
+        .unwrap();
+        library::define_key_remap(
+            &mut interpreter,
+            &KeyRemap::new(nia_key!(1, 30), nia_key!(2, 31)),
+        )
+        .unwrap();
+
+        let expected = vec![
+            KeyRemap::new(nia_key!(1, 30), nia_key!(2, 31)),
+            KeyRemap::new(nia_key!(58), nia_key!(29)),
+        ];
+
+        nia_assert_equal(
+            expected,
+            get_defined_key_remaps(&mut interpreter).unwrap(),
+        );
+    }
+}
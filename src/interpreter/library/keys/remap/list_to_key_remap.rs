@@ -0,0 +1,90 @@
+use crate::Error;
+use crate::Interpreter;
+use crate::KeyRemap;
+use crate::Value;
+
+use crate::library;
+
+pub fn list_to_key_remap(
+    interpreter: &mut Interpreter,
+    key_remap_list: Value,
+) -> Result<KeyRemap, Error> {
+    let mut values = library::read_as_vector(interpreter, key_remap_list)?;
+
+    if values.len() != 2 {
+        return Error::invalid_argument_error(
+            "List must have two items exactly to be parsed as a key remap.",
+        )
+        .into();
+    }
+
+    let from = library::list_to_key(interpreter, values.remove(0))?;
+    let to = library::list_to_key(interpreter, values.remove(0))?;
+
+    Ok(KeyRemap::new(from, to))
+}
+
+pub fn key_remap_to_list(
+    interpreter: &mut Interpreter,
+    key_remap: &KeyRemap,
+) -> Value {
+    let from = library::key_to_list(interpreter, key_remap.get_from());
+    let to = library::key_to_list(interpreter, key_remap.get_to());
+
+    interpreter.vec_to_list(vec![from, to])
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    use crate::Key;
+
+    #[test]
+    fn converts_key_remaps_between_lists() {
+        let mut interpreter = Interpreter::new();
+
+        let specs = vec![
+            ("'(58 29)", KeyRemap::new(nia_key!(58), nia_key!(29))),
+            ("'((1 58) 29)", KeyRemap::new(nia_key!(1, 58), nia_key!(29))),
+            (
+                "'((1 58) (2 29))",
+                KeyRemap::new(nia_key!(1, 58), nia_key!(2, 29)),
+            ),
+        ];
+
+        for (code, expected) in specs {
+            let list = interpreter.execute_in_main_environment(code).unwrap();
+            let result = list_to_key_remap(&mut interpreter, list).unwrap();
+
+            nia_assert(Key::keys_are_same(
+                expected.get_from(),
+                result.get_from(),
+            ));
+            nia_assert(Key::keys_are_same(expected.get_to(), result.get_to()));
+
+            let result = key_remap_to_list(&mut interpreter, &expected);
+
+            crate::utils::assert_deep_equal(&mut interpreter, list, result);
+        }
+    }
+
+    #[test]
+    fn returns_invalid_argument_error_when_list_is_invalid() {
+        let mut interpreter = Interpreter::new();
+
+        let specs =
+            vec!["'()", "'(58)", "'(58 29 1)", "'(58 a)", "'((1 2 3) 4)"];
+
+        for code in specs {
+            let list = interpreter.execute_in_main_environment(code).unwrap();
+            let result = list_to_key_remap(&mut interpreter, list);
+
+            crate::utils::assert_invalid_argument_error(&result);
+        }
+    }
+}
@@ -0,0 +1,9 @@
+mod define_key_remap;
+mod get_defined_key_remaps;
+mod list_to_key_remap;
+mod remove_key_remap;
+
+pub use define_key_remap::*;
+pub use get_defined_key_remaps::*;
+pub use list_to_key_remap::*;
+pub use remove_key_remap::*;
@@ -0,0 +1,91 @@
+use crate::Error;
+use crate::Interpreter;
+use crate::Key;
+use crate::DEFINED_REMAPS_ROOT_VARIABLE_NAME;
+
+use crate::library;
+
+pub fn remove_key_remap(
+    interpreter: &mut Interpreter,
+    from: Key,
+) -> Result<(), Error> {
+    let defined_key_remaps = library::get_defined_key_remaps(interpreter)?;
+    let length_before = defined_key_remaps.len();
+
+    let mut result = Vec::new();
+
+    for key_remap in defined_key_remaps {
+        if Key::keys_are_same(key_remap.get_from(), from) {
+            continue;
+        }
+
+        result.push(library::key_remap_to_list(interpreter, &key_remap));
+    }
+
+    if result.len() == length_before {
+        return Error::generic_execution_error("Cannot find key remap.").into();
+    }
+
+    let result = interpreter.vec_to_list(result);
+
+    library::set_root_variable(
+        interpreter,
+        DEFINED_REMAPS_ROOT_VARIABLE_NAME,
+        result,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    use crate::KeyRemap;
+
+    #[test]
+    fn removes_key_remaps() {
+        let mut interpreter = Interpreter::new();
+
+        let key_remaps = vec![
+            KeyRemap::new(nia_key!(58), nia_key!(29)),
+            KeyRemap::new(nia_key!(1, 58), nia_key!(1)),
+            KeyRemap::new(nia_key!(2, 30), nia_key!(31)),
+        ];
+
+        for key_remap in key_remaps.iter() {
+            library::define_key_remap(&mut interpreter, key_remap).unwrap();
+        }
+
+        let specs = vec![
+            (nia_key!(1, 58), "'(((2 30) 31) (58 29))"),
+            (nia_key!(58), "'(((2 30) 31))"),
+            (nia_key!(2, 30), "'()"),
+        ];
+
+        for (key, code) in specs {
+            nia_assert_is_ok(&remove_key_remap(&mut interpreter, key));
+
+            let expected =
+                interpreter.execute_in_main_environment(code).unwrap();
+            let result = library::get_root_variable(
+                &mut interpreter,
+                DEFINED_REMAPS_ROOT_VARIABLE_NAME,
+            )
+            .unwrap();
+
+            crate::utils::assert_deep_equal(&mut interpreter, expected, result);
+        }
+    }
+
+    #[test]
+    fn returns_generic_execution_error_when_there_is_no_such_key_remap() {
+        let mut interpreter = Interpreter::new();
+
+        let result = remove_key_remap(&mut interpreter, nia_key!(2, 2));
+
+        crate::utils::assert_generic_execution_error(&result);
+    }
+}
@@ -3,20 +3,26 @@ mod _lib;
 
 mod alist;
 mod check;
+mod class;
 mod evaluate;
 mod general;
 mod infect;
 mod keys;
 mod list;
+mod module;
+mod object;
 mod read;
 mod root;
 
 pub use alist::*;
 pub use check::*;
+pub use class::*;
 pub use evaluate::*;
 pub use general::*;
 pub use infect::*;
 pub use keys::*;
 pub use list::*;
+pub use module::*;
+pub use object::*;
 pub use read::*;
 pub use root::*;
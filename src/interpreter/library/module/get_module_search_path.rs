@@ -0,0 +1,80 @@
+use crate::Error;
+use crate::Interpreter;
+use crate::MODULE_SEARCH_PATH_ROOT_VARIABLE_NAME;
+
+use crate::library;
+
+pub fn get_module_search_path(
+    interpreter: &mut Interpreter,
+) -> Result<Vec<String>, Error> {
+    let value = library::get_root_variable(
+        interpreter,
+        MODULE_SEARCH_PATH_ROOT_VARIABLE_NAME,
+    )?;
+
+    let values = library::read_as_vector(interpreter, value)?;
+    let mut search_path = Vec::new();
+
+    for value in values {
+        let path = library::read_as_string(interpreter, value)?.clone();
+
+        search_path.push(path);
+    }
+
+    Ok(search_path)
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[test]
+    fn returns_default_value() {
+        let mut interpreter = Interpreter::new();
+
+        nia_assert_equal(
+            crate::utils::get_default_module_search_path(),
+            get_module_search_path(&mut interpreter).unwrap(),
+        );
+    }
+
+    #[test]
+    fn returns_changed_value() {
+        let mut interpreter = Interpreter::new();
+
+        interpreter
+            .execute_in_main_environment(
+                r#"(set! nia-module-search-path '("/a" "/b"))"#,
+            )
+            .unwrap();
+
+        nia_assert_equal(
+            vec![String::from("/a"), String::from("/b")],
+            get_module_search_path(&mut interpreter).unwrap(),
+        );
+    }
+
+    #[test]
+    fn returns_invalid_argument_error_when_value_is_not_list_of_strings() {
+        let specs = vec!["1", "\"/a\"", "'(1 2)", "'(\"/a\" b)"];
+
+        for spec in specs {
+            let mut interpreter = Interpreter::new();
+
+            interpreter
+                .execute_in_main_environment(&format!(
+                    "(set! nia-module-search-path {})",
+                    spec
+                ))
+                .unwrap();
+
+            crate::utils::assert_invalid_argument_error(
+                &get_module_search_path(&mut interpreter),
+            );
+        }
+    }
+}
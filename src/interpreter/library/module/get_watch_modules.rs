@@ -0,0 +1,61 @@
+use crate::Error;
+use crate::Interpreter;
+use crate::WATCH_MODULES_ROOT_VARIABLE_NAME;
+
+use crate::library;
+
+pub fn get_watch_modules(interpreter: &mut Interpreter) -> Result<bool, Error> {
+    let value = library::get_root_variable(
+        interpreter,
+        WATCH_MODULES_ROOT_VARIABLE_NAME,
+    )?;
+
+    library::read_as_bool(value)
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[test]
+    fn returns_default_value() {
+        let mut interpreter = Interpreter::new();
+
+        nia_assert_equal(false, get_watch_modules(&mut interpreter).unwrap());
+    }
+
+    #[test]
+    fn returns_changed_value() {
+        let mut interpreter = Interpreter::new();
+
+        interpreter
+            .execute_in_main_environment("(set! nia-watch-modules #t)")
+            .unwrap();
+
+        nia_assert_equal(true, get_watch_modules(&mut interpreter).unwrap());
+    }
+
+    #[test]
+    fn returns_invalid_argument_error_when_value_is_not_boolean() {
+        let specs = vec!["1", "1.1", "\"string\"", "'symbol", "'(1 2)"];
+
+        for spec in specs {
+            let mut interpreter = Interpreter::new();
+
+            interpreter
+                .execute_in_main_environment(&format!(
+                    "(set! nia-watch-modules {})",
+                    spec
+                ))
+                .unwrap();
+
+            crate::utils::assert_invalid_argument_error(&get_watch_modules(
+                &mut interpreter,
+            ));
+        }
+    }
+}
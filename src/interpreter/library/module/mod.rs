@@ -0,0 +1,5 @@
+mod get_module_search_path;
+mod get_watch_modules;
+
+pub use get_module_search_path::*;
+pub use get_watch_modules::*;
@@ -0,0 +1,112 @@
+use crate::EnvironmentId;
+use crate::Error;
+use crate::Interpreter;
+use crate::ObjectId;
+use crate::SymbolId;
+use crate::Value;
+
+use crate::library;
+
+pub fn get_object_property(
+    interpreter: &mut Interpreter,
+    environment_id: EnvironmentId,
+    object_id: ObjectId,
+    property_symbol_id: SymbolId,
+) -> Result<Option<Value>, Error> {
+    let object_value_wrapper = interpreter
+        .get_object_property_value_wrapper(object_id, property_symbol_id)?;
+
+    let object_value_wrapper = match object_value_wrapper {
+        Some(object_value_wrapper) => object_value_wrapper,
+        None => return Ok(None),
+    };
+
+    if !object_value_wrapper.is_accessor() {
+        return Ok(Some(object_value_wrapper.get_value()?));
+    }
+
+    object_value_wrapper.check_is_internable()?;
+
+    // getter is called with `this' bound to the object it was requested
+    // from, not to the prototype that defines it
+    match object_value_wrapper.get_getter() {
+        Some(getter_function_id) => {
+            let value = library::execute_function_with_this(
+                interpreter,
+                environment_id,
+                object_id,
+                getter_function_id,
+                Vec::new(),
+            )?;
+
+            Ok(Some(value))
+        },
+        None => Ok(Some(interpreter.intern_nil_symbol_value())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[allow(unused_imports)]
+    use crate::utils;
+
+    #[test]
+    fn returns_value_of_data_property() {
+        let mut interpreter = Interpreter::new();
+        let environment_id = interpreter.get_main_environment_id();
+
+        let object_value =
+            interpreter.execute_in_main_environment("{:a 1}").unwrap();
+        let object_id = library::read_as_object_id(object_value).unwrap();
+        let a_symbol_id = interpreter.intern_symbol_id("a");
+        let b_symbol_id = interpreter.intern_symbol_id("b");
+
+        nia_assert_equal(
+            Ok(Some(Value::Integer(1))),
+            get_object_property(
+                &mut interpreter,
+                environment_id,
+                object_id,
+                a_symbol_id,
+            ),
+        );
+        nia_assert_equal(
+            Ok(None),
+            get_object_property(
+                &mut interpreter,
+                environment_id,
+                object_id,
+                b_symbol_id,
+            ),
+        );
+    }
+
+    #[test]
+    fn calls_getter_of_accessor_property() {
+        let mut interpreter = Interpreter::new();
+        let environment_id = interpreter.get_main_environment_id();
+
+        let object_value = interpreter
+            .execute_in_main_environment(
+                "(let ((obj {:a 1})) (object:define-property! obj {:name :b :get (fn () (+ (object:get this :a) 1))}) obj)",
+            )
+            .unwrap();
+        let object_id = library::read_as_object_id(object_value).unwrap();
+        let b_symbol_id = interpreter.intern_symbol_id("b");
+
+        nia_assert_equal(
+            Ok(Some(Value::Integer(2))),
+            get_object_property(
+                &mut interpreter,
+                environment_id,
+                object_id,
+                b_symbol_id,
+            ),
+        );
+    }
+}
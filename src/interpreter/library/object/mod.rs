@@ -0,0 +1,7 @@
+mod get_object_property;
+mod notify_object_observers;
+mod set_object_property;
+
+pub use get_object_property::*;
+pub use notify_object_observers::*;
+pub use set_object_property::*;
@@ -0,0 +1,116 @@
+use crate::EnvironmentId;
+use crate::Error;
+use crate::FunctionId;
+use crate::Interpreter;
+use crate::ObjectId;
+use crate::SymbolId;
+use crate::Value;
+
+use crate::library;
+
+pub fn notify_object_observers(
+    interpreter: &mut Interpreter,
+    environment_id: EnvironmentId,
+    object_id: ObjectId,
+    property_symbol_id: SymbolId,
+    old_value: Value,
+    new_value: Value,
+) -> Result<(), Error> {
+    // observers are collected before calling any of them, so that an
+    // observer may add or remove observers of the same object
+    let observer_function_ids = interpreter
+        .get_object(object_id)?
+        .get_observers()
+        .iter()
+        .filter(|observer| observer.observes(property_symbol_id))
+        .map(|observer| observer.get_function_id())
+        .collect::<Vec<FunctionId>>();
+
+    for function_id in observer_function_ids {
+        library::execute_function_with_this(
+            interpreter,
+            environment_id,
+            object_id,
+            function_id,
+            vec![Value::Symbol(property_symbol_id), old_value, new_value],
+        )?;
+    }
+
+    Ok(())
+}
+
+pub fn get_observed_property_value(
+    interpreter: &mut Interpreter,
+    object_id: ObjectId,
+    property_symbol_id: SymbolId,
+) -> Result<Value, Error> {
+    let value = interpreter
+        .get_object_property_value_wrapper(object_id, property_symbol_id)?
+        .map(|object_value_wrapper| object_value_wrapper.force_get_value())
+        .unwrap_or(interpreter.intern_nil_symbol_value());
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[allow(unused_imports)]
+    use crate::utils;
+
+    use crate::ObjectObserver;
+
+    #[test]
+    fn calls_observers_of_changed_property() {
+        let mut interpreter = Interpreter::new();
+        let environment_id = interpreter.get_main_environment_id();
+
+        interpreter
+            .execute_in_main_environment(
+                "(defv changes '()) \
+                 (defv observer (fn (key old new) (set! changes (cons:new (list:new key old new) changes)))) \
+                 (defv obj {})",
+            )
+            .unwrap();
+
+        let object_value =
+            interpreter.execute_in_main_environment("obj").unwrap();
+        let object_id = library::read_as_object_id(object_value).unwrap();
+        let observer_value =
+            interpreter.execute_in_main_environment("observer").unwrap();
+        let function_id = library::read_as_function_id(observer_value).unwrap();
+        let a_symbol_id = interpreter.intern_symbol_id("a");
+        let b_symbol_id = interpreter.intern_symbol_id("b");
+
+        interpreter
+            .get_object_mut(object_id)
+            .unwrap()
+            .add_observer(ObjectObserver::new(Some(a_symbol_id), function_id));
+
+        nia_assert_is_ok(&notify_object_observers(
+            &mut interpreter,
+            environment_id,
+            object_id,
+            a_symbol_id,
+            Value::Integer(1),
+            Value::Integer(2),
+        ));
+        nia_assert_is_ok(&notify_object_observers(
+            &mut interpreter,
+            environment_id,
+            object_id,
+            b_symbol_id,
+            Value::Integer(3),
+            Value::Integer(4),
+        ));
+
+        utils::assert_results_are_equal(
+            &mut interpreter,
+            vec![("changes", "'((a 1 2))")],
+        );
+    }
+}
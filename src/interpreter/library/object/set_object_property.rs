@@ -0,0 +1,148 @@
+use crate::EnvironmentId;
+use crate::Error;
+use crate::Interpreter;
+use crate::ObjectId;
+use crate::SymbolId;
+use crate::Value;
+
+use crate::library;
+
+pub fn set_object_property(
+    interpreter: &mut Interpreter,
+    environment_id: EnvironmentId,
+    object_id: ObjectId,
+    property_symbol_id: SymbolId,
+    value: Value,
+) -> Result<(), Error> {
+    let object_value_wrapper = interpreter
+        .get_object_property_value_wrapper(object_id, property_symbol_id)?;
+
+    // data properties of prototypes are shadowed by the object, while
+    // accessors of prototypes are invoked for it
+    match object_value_wrapper {
+        Some(object_value_wrapper) if object_value_wrapper.is_accessor() => {
+            match object_value_wrapper.get_setter() {
+                Some(setter_function_id) => {
+                    library::execute_function_with_this(
+                        interpreter,
+                        environment_id,
+                        object_id,
+                        setter_function_id,
+                        vec![value],
+                    )?;
+
+                    Ok(())
+                },
+                None => Error::generic_execution_error(
+                    "Cannot set accessor item of an object without setter.",
+                )
+                .into(),
+            }
+        },
+        _ => {
+            let old_value = library::get_observed_property_value(
+                interpreter,
+                object_id,
+                property_symbol_id,
+            )?;
+
+            interpreter.set_object_property(
+                object_id,
+                property_symbol_id,
+                value,
+            )?;
+
+            library::notify_object_observers(
+                interpreter,
+                environment_id,
+                object_id,
+                property_symbol_id,
+                old_value,
+                value,
+            )
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[allow(unused_imports)]
+    use crate::utils;
+
+    #[test]
+    fn sets_data_property() {
+        let mut interpreter = Interpreter::new();
+        let environment_id = interpreter.get_main_environment_id();
+
+        let object_id = interpreter.make_object();
+        let a_symbol_id = interpreter.intern_symbol_id("a");
+
+        nia_assert_is_ok(&set_object_property(
+            &mut interpreter,
+            environment_id,
+            object_id,
+            a_symbol_id,
+            Value::Integer(1),
+        ));
+        nia_assert_equal(
+            Ok(Some(Value::Integer(1))),
+            interpreter.get_object_property(object_id, a_symbol_id),
+        );
+    }
+
+    #[test]
+    fn calls_setter_of_accessor_property() {
+        let mut interpreter = Interpreter::new();
+        let environment_id = interpreter.get_main_environment_id();
+
+        let object_value = interpreter
+            .execute_in_main_environment(
+                "(let ((obj {:a 1})) (object:define-property! obj {:name :b :set (fn (value) (object:set! this :a (* value 2)))}) obj)",
+            )
+            .unwrap();
+        let object_id = library::read_as_object_id(object_value).unwrap();
+        let a_symbol_id = interpreter.intern_symbol_id("a");
+        let b_symbol_id = interpreter.intern_symbol_id("b");
+
+        nia_assert_is_ok(&set_object_property(
+            &mut interpreter,
+            environment_id,
+            object_id,
+            b_symbol_id,
+            Value::Integer(2),
+        ));
+        nia_assert_equal(
+            Ok(Some(Value::Integer(4))),
+            interpreter.get_object_property(object_id, a_symbol_id),
+        );
+    }
+
+    #[test]
+    fn returns_generic_execution_error_when_accessor_has_no_setter() {
+        let mut interpreter = Interpreter::new();
+        let environment_id = interpreter.get_main_environment_id();
+
+        let object_value = interpreter
+            .execute_in_main_environment(
+                "(let ((obj {})) (object:define-property! obj {:name :b :get (fn () 1)}) obj)",
+            )
+            .unwrap();
+        let object_id = library::read_as_object_id(object_value).unwrap();
+        let b_symbol_id = interpreter.intern_symbol_id("b");
+
+        let result = set_object_property(
+            &mut interpreter,
+            environment_id,
+            object_id,
+            b_symbol_id,
+            Value::Integer(2),
+        );
+
+        utils::assert_generic_execution_error(&result);
+    }
+}
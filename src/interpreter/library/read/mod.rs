@@ -1,6 +1,7 @@
 mod read_as_arguments;
 mod read_as_bool;
 mod read_as_cons_id;
+mod read_as_environment_id;
 mod read_as_f64;
 mod read_as_flet_definitions;
 mod read_as_function;
@@ -22,6 +23,7 @@ mod read_string_keyword_or_symbol_as_symbol_id;
 pub use read_as_arguments::read_as_arguments;
 pub use read_as_bool::read_as_bool;
 pub use read_as_cons_id::read_as_cons_id;
+pub use read_as_environment_id::read_as_environment_id;
 pub use read_as_f64::read_as_f64;
 pub use read_as_flet_definitions::read_as_flet_definitions;
 pub use read_as_function::read_as_function;
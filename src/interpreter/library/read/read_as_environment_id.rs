@@ -0,0 +1,88 @@
+use crate::interpreter::environment::EnvironmentId;
+use crate::interpreter::error::Error;
+use crate::interpreter::interpreter::Interpreter;
+use crate::interpreter::value::Value;
+
+use crate::library;
+
+// reads one of `:current', `:main' or `:root' relative to the environment the
+// value was read in
+pub fn read_as_environment_id(
+    interpreter: &Interpreter,
+    current_environment_id: EnvironmentId,
+    value: Value,
+) -> Result<EnvironmentId, Error> {
+    let keyword = library::read_as_keyword(interpreter, value)?;
+
+    let environment_id = match keyword.get_name().as_str() {
+        "current" => current_environment_id,
+        "main" => interpreter.get_main_environment_id(),
+        "root" => interpreter.get_root_environment_id(),
+        _ => {
+            return Error::invalid_argument_error(
+                "Expected one of `:current', `:main' or `:root'.",
+            )
+            .into()
+        },
+    };
+
+    Ok(environment_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[allow(unused_imports)]
+    use crate::utils;
+
+    #[test]
+    fn returns_correct_environment_id() {
+        let mut interpreter = Interpreter::new();
+        let current_environment_id = interpreter
+            .make_environment(interpreter.get_main_environment_id())
+            .unwrap();
+
+        let specs = vec![
+            ("current", current_environment_id),
+            ("main", interpreter.get_main_environment_id()),
+            ("root", interpreter.get_root_environment_id()),
+        ];
+
+        for (keyword_name, expected) in specs {
+            let value = interpreter.intern_keyword_value(keyword_name);
+            let result = read_as_environment_id(
+                &interpreter,
+                current_environment_id,
+                value,
+            );
+
+            nia_assert_equal(Ok(expected), result);
+        }
+    }
+
+    #[test]
+    fn returns_invalid_argument_error_when_not_an_environment_was_provided() {
+        let mut interpreter = Interpreter::new();
+        let current_environment_id = interpreter.get_main_environment_id();
+
+        let values = vec![
+            Value::Integer(1),
+            interpreter.intern_keyword_value("parent"),
+            interpreter.intern_symbol_value("main"),
+        ];
+
+        for value in values {
+            let result = read_as_environment_id(
+                &interpreter,
+                current_environment_id,
+                value,
+            );
+
+            utils::assert_invalid_argument_error(&result);
+        }
+    }
+}
@@ -0,0 +1,369 @@
+use crate::interpreter::error::Error;
+use crate::interpreter::interpreter::Interpreter;
+use crate::interpreter::value::FunctionArguments;
+use crate::interpreter::value::Value;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArgumentCount {
+    min: usize,
+    max: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ArgumentMode {
+    Ordinary,
+    Optional,
+}
+
+struct ArgumentCounter {
+    mode: ArgumentMode,
+    count: ArgumentCount,
+}
+
+impl ArgumentCounter {
+    fn new() -> ArgumentCounter {
+        ArgumentCounter {
+            mode: ArgumentMode::Ordinary,
+            count: ArgumentCount::new(0, Some(0)),
+        }
+    }
+
+    // returns false after `#rest' or `#keys', since nothing that follows
+    // changes the count
+    fn push(&mut self, name: &str) -> bool {
+        match name {
+            "#opt" => self.mode = ArgumentMode::Optional,
+            "#rest" | "#keys" => {
+                self.count.max = None;
+                return false;
+            },
+            _ => {
+                if self.mode == ArgumentMode::Ordinary {
+                    self.count.min += 1;
+                }
+
+                self.count.max = self.count.max.map(|max| max + 1);
+            },
+        }
+
+        true
+    }
+}
+
+fn pluralize(count: usize) -> &'static str {
+    if count == 1 {
+        "argument"
+    } else {
+        "arguments"
+    }
+}
+
+impl ArgumentCount {
+    pub fn new(min: usize, max: Option<usize>) -> ArgumentCount {
+        ArgumentCount { min, max }
+    }
+
+    pub fn get_min(&self) -> usize {
+        self.min
+    }
+
+    pub fn get_max(&self) -> Option<usize> {
+        self.max
+    }
+
+    // signatures of the documentation tables, like `(f a #opt b)'; returns
+    // None for the ones with nested forms, which describe special syntax
+    pub fn from_signature(signature: &str) -> Option<ArgumentCount> {
+        let inner = signature.strip_prefix('(')?.strip_suffix(')')?;
+
+        if inner.contains(|character| "()|".contains(character)) {
+            return None;
+        }
+
+        let mut counter = ArgumentCounter::new();
+
+        for name in inner.split_whitespace().skip(1) {
+            if !counter.push(name) {
+                break;
+            }
+        }
+
+        Some(counter.count)
+    }
+
+    pub fn from_function_arguments(
+        arguments: &FunctionArguments,
+    ) -> ArgumentCount {
+        let min = arguments.required_len();
+        let max = if arguments.get_rest_argument().is_some()
+            || !arguments.get_key_arguments().is_empty()
+        {
+            None
+        } else {
+            Some(min + arguments.get_optional_arguments().len())
+        };
+
+        ArgumentCount::new(min, max)
+    }
+
+    // argument lists as they are written in `defn' and `fn', returns None
+    // when the list is malformed
+    pub fn from_argument_list(
+        interpreter: &Interpreter,
+        value: Value,
+    ) -> Result<Option<ArgumentCount>, Error> {
+        let arguments = match value {
+            Value::Cons(cons_id) => interpreter.list_to_vec(cons_id)?,
+            Value::Symbol(symbol_id)
+                if interpreter.symbol_is_nil(symbol_id)? =>
+            {
+                Vec::new()
+            },
+            _ => return Ok(None),
+        };
+
+        let mut counter = ArgumentCounter::new();
+
+        for argument in arguments {
+            let name = match argument {
+                Value::Symbol(symbol_id) => {
+                    interpreter.get_symbol_name(symbol_id)?.as_str()
+                },
+                Value::Cons(_) => "",
+                _ => return Ok(None),
+            };
+
+            if !counter.push(name) {
+                break;
+            }
+        }
+
+        Ok(Some(counter.count))
+    }
+
+    pub fn accepts(&self, count: usize) -> bool {
+        self.min <= count && self.max.map(|max| count <= max).unwrap_or(true)
+    }
+
+    pub fn describe(&self) -> String {
+        match self.max {
+            Some(max) if max == self.min => {
+                format!("{} {}", max, pluralize(max))
+            },
+            Some(max) => {
+                format!("from {} to {} {}", self.min, max, pluralize(max))
+            },
+            None => format!("at least {} {}", self.min, pluralize(self.min)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    use crate::interpreter::documentation::get_builtin_documentation;
+    use crate::interpreter::error::ErrorKind;
+    use crate::interpreter::value::BuiltinFunctionType;
+    use crate::interpreter::value::Function;
+    use crate::BUILTIN_FUNCTIONS;
+    use crate::BUILTIN_OBJECTS;
+
+    #[test]
+    fn reads_signatures() {
+        let specs = vec![
+            ("(nia:quit)", Some(ArgumentCount::new(0, Some(0)))),
+            (
+                "(list:nth index list)",
+                Some(ArgumentCount::new(2, Some(2))),
+            ),
+            (
+                "(- number-1 #opt number-2)",
+                Some(ArgumentCount::new(1, Some(2))),
+            ),
+            (
+                "(+ number-1 number-2 #rest numbers)",
+                Some(ArgumentCount::new(2, None)),
+            ),
+            ("(f a #keys b c)", Some(ArgumentCount::new(1, None))),
+            ("(let ((name value)...) #rest body)", None),
+            ("(function (lambda|macro (arguments) #rest forms))", None),
+        ];
+
+        for (signature, expected) in specs {
+            nia_assert_equal(
+                expected,
+                ArgumentCount::from_signature(signature),
+            );
+        }
+    }
+
+    #[test]
+    fn reads_argument_lists() {
+        let mut interpreter = Interpreter::new();
+
+        let specs = vec![
+            ("()", Some(ArgumentCount::new(0, Some(0)))),
+            ("(a b)", Some(ArgumentCount::new(2, Some(2)))),
+            ("(a #opt (b 1) c)", Some(ArgumentCount::new(1, Some(3)))),
+            ("(a #rest b)", Some(ArgumentCount::new(1, None))),
+            ("(#keys a)", Some(ArgumentCount::new(0, None))),
+            ("1", None),
+        ];
+
+        for (code, expected) in specs {
+            let value = interpreter
+                .execute_in_main_environment(&format!("'{}", code))
+                .unwrap();
+
+            nia_assert_equal(
+                expected,
+                ArgumentCount::from_argument_list(&interpreter, value).unwrap(),
+            );
+        }
+    }
+
+    #[test]
+    fn reads_function_arguments() {
+        let mut interpreter = Interpreter::new();
+
+        let specs = vec![
+            ("(fn (a b) a)", ArgumentCount::new(2, Some(2))),
+            ("(fn (a #opt b) a)", ArgumentCount::new(1, Some(2))),
+            ("(fn (#rest b) b)", ArgumentCount::new(0, None)),
+        ];
+
+        for (code, expected) in specs {
+            let function_id =
+                match interpreter.execute_in_main_environment(code).unwrap() {
+                    Value::Function(function_id) => function_id,
+                    _ => unreachable!(),
+                };
+
+            let arguments = match interpreter.get_function(function_id) {
+                Ok(Function::Interpreted(function)) => {
+                    function.get_arguments().clone()
+                },
+                _ => unreachable!(),
+            };
+
+            nia_assert_equal(
+                expected,
+                ArgumentCount::from_function_arguments(&arguments),
+            );
+        }
+    }
+
+    #[test]
+    fn checks_and_describes_counts() {
+        let specs = vec![
+            (
+                ArgumentCount::new(1, Some(1)),
+                "1 argument",
+                vec![1],
+                vec![0, 2],
+            ),
+            (
+                ArgumentCount::new(0, Some(0)),
+                "0 arguments",
+                vec![0],
+                vec![1],
+            ),
+            (
+                ArgumentCount::new(1, Some(3)),
+                "from 1 to 3 arguments",
+                vec![1, 2, 3],
+                vec![0, 4],
+            ),
+            (
+                ArgumentCount::new(2, None),
+                "at least 2 arguments",
+                vec![2, 10],
+                vec![1],
+            ),
+        ];
+
+        for (count, description, accepted, rejected) in specs {
+            nia_assert_equal(String::from(description), count.describe());
+
+            for argument_count in accepted {
+                nia_assert(count.accepts(argument_count));
+            }
+
+            for argument_count in rejected {
+                nia_assert(!count.accepts(argument_count));
+            }
+        }
+    }
+
+    // the linter checks calls against documented signatures, so they must
+    // agree with the checks of the builtins themselves
+    #[test]
+    fn documented_signatures_match_builtin_argument_checks() {
+        // without capabilities, no builtin touches devices, files or
+        // processes after it checked the count of its arguments
+        let mut interpreter = Interpreter::sandboxed();
+        let environment_id = interpreter.get_main_environment_id();
+        let nil = interpreter.intern_nil_symbol_value();
+
+        let builtin_functions = BUILTIN_FUNCTIONS
+            .iter()
+            .map(|(name, function)| (String::from(*name), *function))
+            .chain(BUILTIN_OBJECTS.iter().flat_map(
+                |(object_name, functions)| {
+                    functions.iter().map(move |(name, function)| {
+                        (format!("{}:{}", object_name, name), *function)
+                    })
+                },
+            ))
+            .collect::<Vec<(String, BuiltinFunctionType)>>();
+
+        let mut mismatches = Vec::new();
+
+        for (name, function) in builtin_functions {
+            let count = match get_builtin_documentation(&name).and_then(
+                |documentation| {
+                    ArgumentCount::from_signature(documentation.get_signature())
+                },
+            ) {
+                Some(count) => count,
+                None => continue,
+            };
+
+            let mut argument_counts = vec![count.get_min()];
+
+            if count.get_min() > 0 {
+                argument_counts.push(count.get_min() - 1);
+            }
+
+            if let Some(max) = count.get_max() {
+                argument_counts.push(max);
+                argument_counts.push(max + 1);
+            }
+
+            for argument_count in argument_counts {
+                let result = function(
+                    &mut interpreter,
+                    environment_id,
+                    vec![nil; argument_count],
+                );
+                let is_rejected = match result {
+                    Err(error) => {
+                        error.get_error_kind()
+                            == ErrorKind::InvalidArgumentCount
+                    },
+                    Ok(_) => false,
+                };
+
+                if is_rejected == count.accepts(argument_count) {
+                    mismatches.push((name.clone(), argument_count));
+                }
+            }
+        }
+
+        nia_assert_equal(Vec::<(String, usize)>::new(), mismatches);
+    }
+}
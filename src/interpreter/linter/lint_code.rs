@@ -0,0 +1,1446 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use crate::interpreter::documentation::get_builtin_documentation;
+use crate::interpreter::documentation::is_builtin_object_name;
+use crate::interpreter::environment::EnvironmentId;
+use crate::interpreter::error::Error;
+use crate::interpreter::interpreter::Interpreter;
+use crate::interpreter::parser::parse;
+use crate::interpreter::parser::parse_syntax_tree;
+use crate::interpreter::parser::tokenize;
+use crate::interpreter::parser::Span;
+use crate::interpreter::parser::SyntaxNode;
+use crate::interpreter::parser::Token;
+use crate::interpreter::parser::TokenKind;
+use crate::interpreter::reader::read_elements;
+use crate::interpreter::value::ConsId;
+use crate::interpreter::value::Function;
+use crate::interpreter::value::Value;
+
+use super::ArgumentCount;
+use super::LintDiagnostic;
+use super::LintKind;
+
+// names that are bound wherever code is evaluated
+const ALWAYS_BOUND_NAMES: &[&str] = &["nil", "this", "super"];
+
+// core macros whose arguments are ordinary expressions
+const EXPRESSION_MACRO_NAMES: &[&str] = &["if", "when", "unless"];
+
+const LOOP_FUNCTION_NAMES: &[&str] = &["break", "continue"];
+
+const MAPPING_FUNCTION_NAME: &str = "device:define-global-mapping";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FunctionSignature {
+    Function(Option<ArgumentCount>),
+    Macro(Option<ArgumentCount>),
+}
+
+// read values do not remember where they came from, so findings keep the
+// forms they were found in and are located at the anchor name among the
+// items of the innermost one, see `FormSpans'
+struct Finding {
+    kind: LintKind,
+    message: String,
+    form_index: usize,
+    form_ids: Vec<ConsId>,
+    anchor: String,
+}
+
+// where a list read from code was written, and where each of its items was;
+// items that come from expansions, like the ones of `a:b', are located at
+// the expanded code
+struct FormSpans {
+    span: Span,
+    item_spans: Vec<Span>,
+}
+
+struct VariableBinding {
+    name: String,
+    is_used: bool,
+    is_reported_unused: bool,
+}
+
+struct Scope {
+    variables: Vec<VariableBinding>,
+    functions: HashMap<String, FunctionSignature>,
+}
+
+impl Scope {
+    fn new() -> Scope {
+        Scope {
+            variables: Vec::new(),
+            functions: HashMap::new(),
+        }
+    }
+}
+
+struct Linter<'a> {
+    interpreter: &'a Interpreter,
+    variables: HashSet<String>,
+    constants: HashSet<String>,
+    functions: HashMap<String, FunctionSignature>,
+    knows_all_variables: bool,
+    scopes: Vec<Scope>,
+    mappings: HashSet<String>,
+    findings: Vec<Finding>,
+    form_index: usize,
+    form_ids: Vec<ConsId>,
+}
+
+fn is_always_truthy(value: Value) -> bool {
+    match value {
+        Value::Boolean(boolean) => boolean,
+        Value::Integer(_)
+        | Value::Float(_)
+        | Value::String(_)
+        | Value::Keyword(_) => true,
+        _ => false,
+    }
+}
+
+impl<'a> Linter<'a> {
+    fn new(interpreter: &'a Interpreter) -> Linter<'a> {
+        Linter {
+            interpreter,
+            variables: HashSet::new(),
+            constants: HashSet::new(),
+            functions: HashMap::new(),
+            knows_all_variables: false,
+            scopes: Vec::new(),
+            mappings: HashSet::new(),
+            findings: Vec::new(),
+            form_index: 0,
+            form_ids: Vec::new(),
+        }
+    }
+
+    fn get_symbol_name(&self, value: Value) -> Result<Option<String>, Error> {
+        match value {
+            Value::Symbol(symbol_id) => {
+                Ok(Some(self.interpreter.get_symbol_name(symbol_id)?.clone()))
+            },
+            _ => Ok(None),
+        }
+    }
+
+    fn get_item_name(
+        &self,
+        items: &[Value],
+        index: usize,
+    ) -> Result<Option<String>, Error> {
+        match items.get(index) {
+            Some(item) => self.get_symbol_name(*item),
+            None => Ok(None),
+        }
+    }
+
+    fn get_argument_count(
+        &self,
+        arguments: Option<&Value>,
+    ) -> Result<Option<ArgumentCount>, Error> {
+        match arguments {
+            Some(arguments) => {
+                ArgumentCount::from_argument_list(self.interpreter, *arguments)
+            },
+            None => Ok(None),
+        }
+    }
+
+    // forms that are not proper lists, like `(a . b)', are treated as empty
+    fn read_list(&self, value: Value) -> Result<Vec<Value>, Error> {
+        match value {
+            Value::Cons(cons_id) => {
+                Ok(self.interpreter.list_to_vec(cons_id).unwrap_or_default())
+            },
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    fn report(&mut self, kind: LintKind, message: String, anchor: &str) {
+        self.findings.push(Finding {
+            kind,
+            message,
+            form_index: self.form_index,
+            form_ids: self.form_ids.clone(),
+            anchor: String::from(anchor),
+        });
+    }
+
+    fn get_function_signature(
+        &self,
+        name: &str,
+        function: &Function,
+    ) -> FunctionSignature {
+        match function {
+            Function::Builtin(_) => FunctionSignature::Function(
+                get_builtin_documentation(name).and_then(|documentation| {
+                    ArgumentCount::from_signature(documentation.get_signature())
+                }),
+            ),
+            Function::Interpreted(function) => FunctionSignature::Function(
+                Some(ArgumentCount::from_function_arguments(
+                    function.get_arguments(),
+                )),
+            ),
+            Function::Macro(function) => FunctionSignature::Macro(Some(
+                ArgumentCount::from_function_arguments(
+                    function.get_arguments(),
+                ),
+            )),
+            Function::SpecialForm(_) => FunctionSignature::Function(None),
+        }
+    }
+
+    fn collect_environment_names(
+        &mut self,
+        environment_id: EnvironmentId,
+    ) -> Result<(), Error> {
+        let environment = self
+            .interpreter
+            .get_environment_arena()
+            .get_environment(environment_id)?;
+
+        for (symbol_id, wrapper) in environment.get_variables() {
+            let name = self.interpreter.get_symbol_name(*symbol_id)?.clone();
+
+            if !wrapper.is_writable() {
+                self.constants.insert(name.clone());
+            }
+
+            self.variables.insert(name);
+        }
+
+        for (symbol_id, wrapper) in environment.get_functions() {
+            let name = self.interpreter.get_symbol_name(*symbol_id)?.clone();
+            let signature = match wrapper.force_get_value() {
+                Value::Function(function_id) => self.get_function_signature(
+                    &name,
+                    self.interpreter.get_function(function_id)?,
+                ),
+                _ => FunctionSignature::Function(None),
+            };
+
+            self.functions.insert(name, signature);
+        }
+
+        Ok(())
+    }
+
+    fn get_lambda_signature(
+        &self,
+        value: Value,
+    ) -> Result<FunctionSignature, Error> {
+        let items = self.read_list(value)?;
+        let kind = self.get_item_name(&items, 0)?;
+        let count = self.get_argument_count(items.get(1))?;
+
+        match kind.as_deref() {
+            Some("macro") => Ok(FunctionSignature::Macro(count)),
+            Some("lambda") => Ok(FunctionSignature::Function(count)),
+            _ => Ok(FunctionSignature::Function(None)),
+        }
+    }
+
+    // `(import name from ...)', `(import {:a 'b} as name from ...)' and
+    // `(import * from ...)'; the last one may define anything
+    fn collect_import_names(&mut self, items: &[Value]) -> Result<(), Error> {
+        let mut names = Vec::new();
+
+        for (index, item) in items.iter().enumerate() {
+            if self.get_symbol_name(*item)?.as_deref() == Some("as") {
+                if let Some(name) = self.get_item_name(items, index + 1)? {
+                    self.variables.insert(name);
+                }
+
+                return Ok(());
+            }
+        }
+
+        match items.first() {
+            Some(Value::Cons(_)) => {
+                let specifier = items[0];
+
+                self.collect_quoted_names(specifier, &mut names)?;
+            },
+            Some(value) => match self.get_symbol_name(*value)?.as_deref() {
+                Some("*") => self.knows_all_variables = true,
+                Some("from") | None => {},
+                Some(name) => names.push(String::from(name)),
+            },
+            None => {},
+        }
+
+        self.variables.extend(names);
+
+        Ok(())
+    }
+
+    fn collect_quoted_names(
+        &self,
+        value: Value,
+        names: &mut Vec<String>,
+    ) -> Result<(), Error> {
+        let items = self.read_list(value)?;
+
+        match items.as_slice() {
+            [head, Value::Symbol(symbol_id)]
+                if self.get_symbol_name(*head)?.as_deref() == Some("quote") =>
+            {
+                names.push(
+                    self.interpreter.get_symbol_name(*symbol_id)?.clone(),
+                );
+            },
+            _ => {
+                for item in items.iter() {
+                    self.collect_quoted_names(*item, names)?;
+                }
+            },
+        }
+
+        Ok(())
+    }
+
+    // the first pass collects everything the code defines, so uses that
+    // precede definitions are not reported
+    fn collect_definitions(&mut self, value: Value) -> Result<(), Error> {
+        let items = self.read_list(value)?;
+
+        let head = self.get_item_name(&items, 0)?;
+        let name = self.get_item_name(&items, 1)?;
+
+        match (head.as_deref(), name) {
+            (Some("quote"), _) | (Some("`"), _) => return Ok(()),
+            (Some("import"), _) => {
+                return self.collect_import_names(&items[1..])
+            },
+            (Some("defv"), Some(name)) | (Some("defclass"), Some(name)) => {
+                self.variables.insert(name);
+            },
+            (Some("defc"), Some(name)) => {
+                self.constants.insert(name.clone());
+                self.variables.insert(name);
+            },
+            (Some("define-variable"), Some(name)) => {
+                let mut is_constant = false;
+
+                for item in &items[2..] {
+                    if let Value::Keyword(keyword_id) = item {
+                        is_constant |= self
+                            .interpreter
+                            .get_keyword(*keyword_id)?
+                            .get_name()
+                            == "const";
+                    }
+                }
+
+                if is_constant {
+                    self.constants.insert(name.clone());
+                }
+
+                self.variables.insert(name);
+            },
+            (Some("defn"), Some(name)) => {
+                let count = self.get_argument_count(items.get(2))?;
+
+                self.functions
+                    .insert(name, FunctionSignature::Function(count));
+            },
+            (Some("defm"), Some(name)) => {
+                let count = self.get_argument_count(items.get(2))?;
+
+                self.functions.insert(name, FunctionSignature::Macro(count));
+            },
+            (Some("define-function"), Some(name))
+            | (Some("fset!"), Some(name)) => {
+                let signature = match items.get(2) {
+                    Some(value) => {
+                        let items = self.read_list(*value)?;
+
+                        match items.as_slice() {
+                            [head, lambda]
+                                if self.get_symbol_name(*head)?.as_deref()
+                                    == Some("function") =>
+                            {
+                                self.get_lambda_signature(*lambda)?
+                            },
+                            _ => FunctionSignature::Function(None),
+                        }
+                    },
+                    None => FunctionSignature::Function(None),
+                };
+
+                self.functions.insert(name, signature);
+            },
+            _ => {},
+        }
+
+        for item in items {
+            self.collect_definitions(item)?;
+        }
+
+        Ok(())
+    }
+
+    fn push_scope(&mut self) {
+        self.scopes.push(Scope::new());
+    }
+
+    fn pop_scope(&mut self) {
+        let scope = match self.scopes.pop() {
+            Some(scope) => scope,
+            None => return,
+        };
+
+        for variable in scope.variables {
+            if variable.is_reported_unused
+                && !variable.is_used
+                && !variable.name.starts_with('_')
+            {
+                self.report(
+                    LintKind::UnusedBinding,
+                    format!("Variable `{}' is never used.", variable.name),
+                    &variable.name,
+                );
+            }
+        }
+    }
+
+    fn declare_variable(&mut self, name: String, is_reported_unused: bool) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.variables.push(VariableBinding {
+                name,
+                is_used: false,
+                is_reported_unused,
+            });
+        }
+    }
+
+    fn declare_function(&mut self, name: String, signature: FunctionSignature) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.functions.insert(name, signature);
+        }
+    }
+
+    fn find_local_variable(
+        &mut self,
+        name: &str,
+    ) -> Option<&mut VariableBinding> {
+        self.scopes
+            .iter_mut()
+            .rev()
+            .flat_map(|scope| scope.variables.iter_mut().rev())
+            .find(|variable| variable.name == name)
+    }
+
+    fn use_variable(&mut self, name: &str) {
+        if ALWAYS_BOUND_NAMES.contains(&name) {
+            return;
+        }
+
+        if let Some(variable) = self.find_local_variable(name) {
+            variable.is_used = true;
+            return;
+        }
+
+        if self.variables.contains(name) || self.knows_all_variables {
+            return;
+        }
+
+        self.report(
+            LintKind::UnboundVariable,
+            format!("Variable `{}' is not defined.", name),
+            name,
+        );
+    }
+
+    fn find_function(&self, name: &str) -> Option<FunctionSignature> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.functions.get(name))
+            .or_else(|| self.functions.get(name))
+            .copied()
+    }
+
+    fn check_argument_count(
+        &mut self,
+        name: &str,
+        count: Option<ArgumentCount>,
+        argument_count: usize,
+    ) {
+        let count = match count {
+            Some(count) if !count.accepts(argument_count) => count,
+            _ => return,
+        };
+
+        self.report(
+            LintKind::WrongArgumentCount,
+            format!(
+                "Function `{}' takes {}, but {} {} given.",
+                name,
+                count.describe(),
+                argument_count,
+                if argument_count == 1 { "was" } else { "were" }
+            ),
+            name,
+        );
+    }
+
+    fn lint_values(&mut self, values: &[Value]) -> Result<(), Error> {
+        for value in values {
+            self.lint_value(*value)?;
+        }
+
+        Ok(())
+    }
+
+    fn lint_value(&mut self, value: Value) -> Result<(), Error> {
+        match value {
+            Value::Symbol(_) => {
+                if let Some(name) = self.get_symbol_name(value)? {
+                    self.use_variable(&name);
+                }
+
+                Ok(())
+            },
+            Value::Cons(_) => self.lint_form(value),
+            _ => Ok(()),
+        }
+    }
+
+    // binds ordinary, optional and rest arguments of an argument list and
+    // lints the body with them
+    fn lint_lambda(&mut self, values: &[Value]) -> Result<(), Error> {
+        let (arguments, body) = match values.split_first() {
+            Some(split) => split,
+            None => return Ok(()),
+        };
+
+        self.push_scope();
+
+        for argument in self.read_list(*arguments)? {
+            match argument {
+                Value::Symbol(_) => {
+                    if let Some(name) = self.get_symbol_name(argument)? {
+                        if !name.starts_with('#') {
+                            self.declare_variable(name, false);
+                        }
+                    }
+                },
+                Value::Cons(_) => {
+                    let items = self.read_list(argument)?;
+
+                    if let Some(default) = items.get(1) {
+                        self.lint_value(*default)?;
+                    }
+
+                    for item in items.iter().step_by(2) {
+                        if let Some(name) = self.get_symbol_name(*item)? {
+                            self.declare_variable(name, false);
+                        }
+                    }
+                },
+                _ => {},
+            }
+        }
+
+        self.lint_values(body)?;
+        self.pop_scope();
+
+        Ok(())
+    }
+
+    fn lint_function(&mut self, arguments: &[Value]) -> Result<(), Error> {
+        for argument in arguments {
+            let items = self.read_list(*argument)?;
+
+            match items.first() {
+                Some(head) if self.get_symbol_name(*head)?.is_some() => {
+                    self.lint_lambda(&items[1..])?
+                },
+                _ => self.lint_value(*argument)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    fn lint_let(
+        &mut self,
+        arguments: &[Value],
+        is_sequential: bool,
+    ) -> Result<(), Error> {
+        let (definitions, body) = match arguments.split_first() {
+            Some(split) => split,
+            None => return Ok(()),
+        };
+
+        let mut bindings = Vec::new();
+
+        for definition in self.read_list(*definitions)? {
+            let items = match definition {
+                Value::Cons(_) => self.read_list(definition)?,
+                _ => vec![definition],
+            };
+
+            if let Some(name) = self.get_item_name(&items, 0)? {
+                bindings.push((name, items.get(1).copied()));
+            }
+        }
+
+        if !is_sequential {
+            for value in bindings.iter().filter_map(|(_, value)| *value) {
+                self.lint_value(value)?;
+            }
+        }
+
+        self.push_scope();
+
+        for (name, value) in bindings {
+            if is_sequential {
+                if let Some(value) = value {
+                    self.lint_value(value)?;
+                }
+            }
+
+            self.declare_variable(name, true);
+        }
+
+        self.lint_values(body)?;
+        self.pop_scope();
+
+        Ok(())
+    }
+
+    fn lint_flet(
+        &mut self,
+        arguments: &[Value],
+        is_sequential: bool,
+        is_macro: bool,
+    ) -> Result<(), Error> {
+        let (definitions, body) = match arguments.split_first() {
+            Some(split) => split,
+            None => return Ok(()),
+        };
+
+        let mut functions = Vec::new();
+
+        if is_sequential {
+            self.push_scope();
+        }
+
+        for definition in self.read_list(*definitions)? {
+            let items = self.read_list(definition)?;
+
+            let name = self.get_item_name(&items, 0)?;
+            let count = self.get_argument_count(items.get(1))?;
+            let signature = if is_macro {
+                FunctionSignature::Macro(count)
+            } else {
+                FunctionSignature::Function(count)
+            };
+
+            if items.len() > 1 {
+                self.lint_lambda(&items[1..])?;
+            }
+
+            if let Some(name) = name {
+                if is_sequential {
+                    self.declare_function(name, signature);
+                } else {
+                    functions.push((name, signature));
+                }
+            }
+        }
+
+        if !is_sequential {
+            self.push_scope();
+        }
+
+        for (name, signature) in functions {
+            self.declare_function(name, signature);
+        }
+
+        self.lint_values(body)?;
+        self.pop_scope();
+
+        Ok(())
+    }
+
+    fn lint_cond(&mut self, clauses: &[Value]) -> Result<(), Error> {
+        let mut has_matching_clause = false;
+        let mut is_reported = false;
+
+        for clause in clauses {
+            let items = self.read_list(*clause)?;
+
+            if has_matching_clause && !is_reported {
+                self.report(
+                    LintKind::UnreachableClause,
+                    String::from(
+                        "Clause is never reached, since a previous clause always matches.",
+                    ),
+                    "cond",
+                );
+
+                is_reported = true;
+            }
+
+            if let Some(condition) = items.first() {
+                has_matching_clause |= is_always_truthy(*condition);
+            }
+
+            self.lint_values(&items)?;
+        }
+
+        Ok(())
+    }
+
+    fn collect_pattern_names(
+        &self,
+        pattern: Value,
+        names: &mut Vec<String>,
+    ) -> Result<(), Error> {
+        match pattern {
+            Value::Symbol(_) => {
+                if let Some(name) = self.get_symbol_name(pattern)? {
+                    names.push(name);
+                }
+            },
+            Value::Cons(_) => {
+                for item in self.read_list(pattern)? {
+                    self.collect_pattern_names(item, names)?;
+                }
+            },
+            _ => {},
+        }
+
+        Ok(())
+    }
+
+    // every symbol of a pattern may be bound by it, so all of them are
+    // declared and none is reported as unused
+    fn lint_match(&mut self, arguments: &[Value]) -> Result<(), Error> {
+        let (value, clauses) = match arguments.split_first() {
+            Some(split) => split,
+            None => return Ok(()),
+        };
+
+        self.lint_value(*value)?;
+
+        for clause in clauses {
+            let items = self.read_list(*clause)?;
+            let mut names = Vec::new();
+
+            if let Some(pattern) = items.first() {
+                self.collect_pattern_names(*pattern, &mut names)?;
+            }
+
+            self.push_scope();
+
+            for name in names {
+                self.declare_variable(name, false);
+            }
+
+            self.lint_values(items.get(1..).unwrap_or_default())?;
+            self.pop_scope();
+        }
+
+        Ok(())
+    }
+
+    fn lint_try(&mut self, arguments: &[Value]) -> Result<(), Error> {
+        for (index, argument) in arguments.iter().enumerate() {
+            let items = self.read_list(*argument)?;
+            let is_catch_clause = match items.first() {
+                Some(head) if index > 0 => {
+                    self.get_symbol_name(*head)?.as_deref() == Some("catch")
+                },
+                _ => false,
+            };
+
+            if is_catch_clause {
+                self.lint_values(&items[1..])?;
+            } else {
+                self.lint_value(*argument)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn lint_defclass(&mut self, arguments: &[Value]) -> Result<(), Error> {
+        if let Some(parents) = arguments.get(1) {
+            let parents = self.read_list(*parents)?;
+
+            self.lint_values(&parents)?;
+        }
+
+        for definition in arguments.get(2..).unwrap_or_default() {
+            let items = self.read_list(*definition)?;
+            let head = self.get_item_name(&items, 0)?;
+
+            match head.as_deref() {
+                Some("constructor") => self.lint_lambda(&items[1..])?,
+                Some("method") => {
+                    self.lint_lambda(items.get(2..).unwrap_or_default())?
+                },
+                Some("static") => {
+                    self.lint_values(items.get(2..).unwrap_or_default())?
+                },
+                _ => self.lint_value(*definition)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    fn declare_loop_functions(&mut self) {
+        for name in LOOP_FUNCTION_NAMES {
+            self.declare_function(
+                String::from(*name),
+                FunctionSignature::Function(None),
+            );
+        }
+    }
+
+    // `(dolist (item list) ...)' and the like: the last item of the head is
+    // evaluated, the others are bound
+    fn lint_loop(&mut self, arguments: &[Value]) -> Result<(), Error> {
+        let (head, body) = match arguments.split_first() {
+            Some(split) => split,
+            None => return Ok(()),
+        };
+        let head = self.read_list(*head)?;
+
+        if let Some(value) = head.last() {
+            self.lint_value(*value)?;
+        }
+
+        self.push_scope();
+        self.declare_loop_functions();
+
+        for name in head.iter().take(head.len().saturating_sub(1)) {
+            if let Some(name) = self.get_symbol_name(*name)? {
+                self.declare_variable(name, false);
+            }
+        }
+
+        self.lint_values(body)?;
+        self.pop_scope();
+
+        Ok(())
+    }
+
+    fn lint_while(&mut self, arguments: &[Value]) -> Result<(), Error> {
+        self.push_scope();
+        self.declare_loop_functions();
+        self.lint_values(arguments)?;
+        self.pop_scope();
+
+        Ok(())
+    }
+
+    fn lint_assignment(&mut self, arguments: &[Value]) -> Result<(), Error> {
+        let name = self.get_item_name(arguments, 0)?;
+
+        if let Some(name) = name {
+            if self.find_local_variable(&name).is_none()
+                && self.constants.contains(&name)
+            {
+                self.report(
+                    LintKind::SetConstant,
+                    format!("Constant `{}' cannot be set.", name),
+                    &name,
+                );
+            }
+        }
+
+        self.lint_values(arguments)
+    }
+
+    fn check_mapping(&mut self, arguments: &[Value]) -> Result<(), Error> {
+        let chords = match arguments.first() {
+            Some(Value::String(string_id)) => self
+                .interpreter
+                .get_string(*string_id)?
+                .get_string()
+                .split_whitespace()
+                .collect::<Vec<&str>>()
+                .join(" "),
+            _ => return Ok(()),
+        };
+
+        if !self.mappings.insert(chords.clone()) {
+            self.report(
+                LintKind::DuplicateMapping,
+                format!("Key chords `{}' are already mapped.", chords),
+                MAPPING_FUNCTION_NAME,
+            );
+        }
+
+        Ok(())
+    }
+
+    // `(object:member ...)' reads as `(call-with-this object (:member object)
+    // ...)', members of builtin objects are checked against documentation
+    fn lint_call_with_this(
+        &mut self,
+        arguments: &[Value],
+    ) -> Result<(), Error> {
+        let object_name = self.get_item_name(arguments, 0)?;
+        let member_items = match arguments.get(1) {
+            Some(value) => self.read_list(*value)?,
+            None => Vec::new(),
+        };
+        let member = match member_items.as_slice() {
+            [Value::Keyword(keyword_id), object]
+                if Some(object) == arguments.first() =>
+            {
+                Some(
+                    self.interpreter
+                        .get_keyword(*keyword_id)?
+                        .get_name()
+                        .clone(),
+                )
+            },
+            _ => None,
+        };
+
+        if let (Some(object_name), Some(member)) = (object_name, member) {
+            let is_builtin_object = is_builtin_object_name(&object_name)
+                && self.find_local_variable(&object_name).is_none();
+            let name = format!("{}:{}", object_name, member);
+            let call_arguments = arguments.get(2..).unwrap_or_default();
+
+            if is_builtin_object {
+                if let Some(documentation) = get_builtin_documentation(&name) {
+                    self.check_argument_count(
+                        &name,
+                        ArgumentCount::from_signature(
+                            documentation.get_signature(),
+                        ),
+                        call_arguments.len(),
+                    );
+                }
+
+                if name == MAPPING_FUNCTION_NAME {
+                    self.check_mapping(call_arguments)?;
+                }
+            }
+        }
+
+        self.lint_values(arguments)
+    }
+
+    fn report_unbound_function(&mut self, name: &str) {
+        self.report(
+            LintKind::UnboundFunction,
+            format!("Function `{}' is not defined.", name),
+            name,
+        );
+    }
+
+    fn lint_call(
+        &mut self,
+        name: &str,
+        arguments: &[Value],
+    ) -> Result<(), Error> {
+        match self.find_function(name) {
+            Some(FunctionSignature::Function(count)) => {
+                self.check_argument_count(name, count, arguments.len());
+                self.lint_values(arguments)
+            },
+            Some(FunctionSignature::Macro(count)) => {
+                self.check_argument_count(name, count, arguments.len());
+
+                // arguments of other macros may be any syntax
+                if EXPRESSION_MACRO_NAMES.contains(&name) {
+                    self.lint_values(arguments)
+                } else {
+                    Ok(())
+                }
+            },
+            None => {
+                self.report_unbound_function(name);
+                self.lint_values(arguments)
+            },
+        }
+    }
+
+    fn lint_form(&mut self, form: Value) -> Result<(), Error> {
+        let form_id = match form {
+            Value::Cons(cons_id) => cons_id,
+            _ => return Ok(()),
+        };
+
+        self.form_ids.push(form_id);
+        let result = self.lint_form_items(form);
+        self.form_ids.pop();
+
+        result
+    }
+
+    fn lint_form_items(&mut self, form: Value) -> Result<(), Error> {
+        let items = self.read_list(form)?;
+
+        let (head, arguments) = match items.split_first() {
+            Some(split) => split,
+            None => return Ok(()),
+        };
+
+        let name = match self.get_symbol_name(*head)? {
+            Some(name) => name,
+            None => return self.lint_values(&items),
+        };
+
+        match name.as_str() {
+            "quote" | "`" | "import" | "export" => Ok(()),
+            "flookup" => {
+                for argument in arguments {
+                    if let Some(name) = self.get_symbol_name(*argument)? {
+                        if self.find_function(&name).is_none() {
+                            self.report_unbound_function(&name);
+                        }
+                    }
+                }
+
+                Ok(())
+            },
+            "let" => self.lint_let(arguments, false),
+            "let*" => self.lint_let(arguments, true),
+            "flet" => self.lint_flet(arguments, false, false),
+            "flet*" => self.lint_flet(arguments, true, false),
+            "mlet" => self.lint_flet(arguments, false, true),
+            "mlet*" => self.lint_flet(arguments, true, true),
+            "function" => self.lint_function(arguments),
+            "fn" => self.lint_lambda(arguments),
+            "defn" | "defm" => {
+                self.lint_lambda(arguments.get(1..).unwrap_or_default())
+            },
+            "defon" => {
+                self.lint_values(arguments.get(..1).unwrap_or_default())?;
+                self.lint_lambda(arguments.get(2..).unwrap_or_default())
+            },
+            "defv" | "defc" | "define-variable" | "define-function"
+            | "fset!" => {
+                self.lint_values(arguments.get(1..).unwrap_or_default())
+            },
+            "set!" | "inc!" | "dec!" => self.lint_assignment(arguments),
+            "cond" => self.lint_cond(arguments),
+            "match" => self.lint_match(arguments),
+            "try" => self.lint_try(arguments),
+            "defclass" => self.lint_defclass(arguments),
+            "dolist" | "dotimes" | "dokeys" | "dovalues" | "doitems" => {
+                self.lint_loop(arguments)
+            },
+            "while" => self.lint_while(arguments),
+            "call-with-this" => self.lint_call_with_this(arguments),
+            _ => self.lint_call(&name, arguments),
+        }
+    }
+}
+
+fn is_method_call(nodes: &[&SyntaxNode]) -> bool {
+    match nodes.first() {
+        Some(SyntaxNode::Atom(TokenKind::Symbol, text, _)) => {
+            !text.starts_with(':') && text.contains(':')
+        },
+        _ => false,
+    }
+}
+
+fn collect_form_spans(
+    interpreter: &Interpreter,
+    node: &SyntaxNode,
+    value: Value,
+    form_spans: &mut HashMap<ConsId, FormSpans>,
+) {
+    let cons_id = match value {
+        Value::Cons(cons_id) => cons_id,
+        _ => return,
+    };
+    let items = interpreter.list_to_vec(cons_id).unwrap_or_default();
+
+    let item_nodes = match node {
+        SyntaxNode::Sequence(opening, children, _, _) if opening == "(" => {
+            let nodes = children
+                .iter()
+                .filter(|child| child.get_span().is_some())
+                .collect::<Vec<&SyntaxNode>>();
+
+            if nodes.len() == items.len() {
+                nodes
+            } else if is_method_call(&nodes) && nodes.len() + 2 == items.len() {
+                // `(a:b ...)' reads as `(call-with-this a (:b a) ...)'
+                vec![nodes[0], nodes[0]]
+                    .into_iter()
+                    .chain(nodes.into_iter())
+                    .collect()
+            } else {
+                Vec::new()
+            }
+        },
+        // `'a' reads as `(quote a)'
+        SyntaxNode::Prefixed(_, prefixed_node, _) if items.len() == 2 => {
+            vec![node, prefixed_node.as_ref()]
+        },
+        // `a:b' reads as `(:b a)'
+        SyntaxNode::Atom(TokenKind::Symbol, _, _) => vec![node; items.len()],
+        _ => Vec::new(),
+    };
+
+    let span = match node.get_span() {
+        Some(span) => span,
+        None => return,
+    };
+    let item_spans = item_nodes
+        .iter()
+        .filter_map(|item_node| item_node.get_span())
+        .collect();
+
+    form_spans.insert(cons_id, FormSpans { span, item_spans });
+
+    for (item_node, item) in item_nodes.into_iter().zip(items.into_iter()) {
+        collect_form_spans(interpreter, item_node, item, form_spans);
+    }
+}
+
+fn matches_anchor(text: &str, anchor: &str) -> bool {
+    text == anchor
+        || (text.starts_with(anchor) && text[anchor.len()..].starts_with(':'))
+}
+
+// the first symbol of the span with the anchor name
+fn find_anchor_token(
+    code: &str,
+    tokens: &[Token],
+    span: Span,
+    anchor: &str,
+) -> Span {
+    tokens
+        .iter()
+        .filter(|token| {
+            token.get_kind() == TokenKind::Symbol
+                && span.0 <= token.get_start()
+                && token.get_end() <= span.1
+        })
+        .find(|token| matches_anchor(token.get_text(code), anchor))
+        .map(|token| (token.get_start(), token.get_end()))
+        .unwrap_or(span)
+}
+
+struct FindingLocator<'a> {
+    code: &'a str,
+    tokens: Vec<Token>,
+    form_spans: HashMap<ConsId, FormSpans>,
+    top_level_spans: Vec<Span>,
+    located_counts: HashMap<(ConsId, String), usize>,
+}
+
+impl<'a> FindingLocator<'a> {
+    // a finding repeated in one form, like an unbound variable that is used
+    // twice, is located at the next matching item each time
+    fn locate(&mut self, finding: &Finding) -> Span {
+        let innermost_form =
+            finding.form_ids.iter().rev().find_map(|form_id| {
+                self.form_spans
+                    .get(form_id)
+                    .map(|form_spans| (*form_id, form_spans))
+            });
+
+        let (form_id, form_spans) = match innermost_form {
+            Some(innermost_form) => innermost_form,
+            None => {
+                let span = self
+                    .top_level_spans
+                    .get(finding.form_index)
+                    .copied()
+                    .unwrap_or((0, self.code.len()));
+
+                return find_anchor_token(
+                    self.code,
+                    &self.tokens,
+                    span,
+                    &finding.anchor,
+                );
+            },
+        };
+
+        let mut item_spans = form_spans
+            .item_spans
+            .iter()
+            .copied()
+            .filter(|span| {
+                matches_anchor(&self.code[span.0..span.1], &finding.anchor)
+            })
+            .collect::<Vec<Span>>();
+
+        item_spans.dedup();
+
+        if item_spans.is_empty() {
+            return find_anchor_token(
+                self.code,
+                &self.tokens,
+                form_spans.span,
+                &finding.anchor,
+            );
+        }
+
+        let located_count = self
+            .located_counts
+            .entry((form_id, finding.anchor.clone()))
+            .or_insert(0);
+        let index = (*located_count).min(item_spans.len() - 1);
+
+        *located_count += 1;
+
+        item_spans[index]
+    }
+}
+
+// the code is read into the interpreter and never evaluated, so one that
+// is kept between calls, like the one of the language server, spares the
+// loading of the standard library; the read values are left to the garbage
+// collector
+pub fn lint_code_with_interpreter(
+    interpreter: &mut Interpreter,
+    code: &str,
+) -> Result<Vec<LintDiagnostic>, Error> {
+    let parsed_code = parse(code).map_err(|error| {
+        Error::parse_error(format!("Error while parsing code: {:?}", error))
+    })?;
+    let nodes = parse_syntax_tree(code).map_err(|error| {
+        Error::parse_error(format!("Error while parsing code: {:?}", error))
+    })?;
+    let nodes = nodes
+        .iter()
+        .filter(|node| node.get_span().is_some())
+        .collect::<Vec<&SyntaxNode>>();
+
+    let values = read_elements(interpreter, parsed_code.get_elements())?;
+
+    let mut form_spans = HashMap::new();
+
+    for (node, value) in nodes.iter().zip(values.iter()) {
+        collect_form_spans(interpreter, node, *value, &mut form_spans);
+    }
+
+    let mut linter = Linter::new(interpreter);
+
+    linter.collect_environment_names(interpreter.get_root_environment_id())?;
+    linter.collect_environment_names(interpreter.get_main_environment_id())?;
+
+    for value in &values {
+        linter.collect_definitions(*value)?;
+    }
+
+    for (form_index, value) in values.iter().enumerate() {
+        linter.form_index = form_index;
+        linter.lint_value(*value)?;
+    }
+
+    let mut locator = FindingLocator {
+        code,
+        tokens: tokenize(code),
+        form_spans,
+        top_level_spans: nodes
+            .iter()
+            .filter_map(|node| node.get_span())
+            .collect(),
+        located_counts: HashMap::new(),
+    };
+
+    let mut diagnostics = linter
+        .findings
+        .into_iter()
+        .map(|finding| {
+            let (start, end) = locator.locate(&finding);
+
+            LintDiagnostic::new(finding.kind, finding.message, start, end)
+        })
+        .collect::<Vec<LintDiagnostic>>();
+
+    diagnostics.sort_by_key(|diagnostic| diagnostic.get_start());
+
+    Ok(diagnostics)
+}
+
+pub fn lint_code(code: &str) -> Result<Vec<LintDiagnostic>, Error> {
+    let mut interpreter = Interpreter::new();
+
+    lint_code_with_interpreter(&mut interpreter, code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    fn lint(code: &str) -> Vec<(&'static str, String)> {
+        lint_code(code)
+            .unwrap()
+            .into_iter()
+            .map(|diagnostic| {
+                (
+                    diagnostic.get_kind().get_code(),
+                    String::from(
+                        &code[diagnostic.get_start()..diagnostic.get_end()],
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    fn assert_lints(code: &str, expected: Vec<(&'static str, &str)>) {
+        let expected = expected
+            .into_iter()
+            .map(|(kind, text)| (kind, String::from(text)))
+            .collect::<Vec<(&'static str, String)>>();
+
+        nia_assert_equal(expected, lint(code));
+    }
+
+    #[test]
+    fn accepts_valid_code() {
+        let specs = vec![
+            "(defv a 1) (defn f (b #opt (c 2) #rest d) (+ a b c (list:length d))) (f 1 2 3 4)",
+            "(defn g () (h 1)) (defn h (x) x)",
+            "(let* ((a 1) (b (+ a 1))) (list:new a b))",
+            "(flet ((f (a) a)) (f 1))",
+            "(mlet ((m (a) a)) (m anything))",
+            "(dolist (item '(1 2)) (when (= item 2) (break)) (print item))",
+            "(doitems (key value {:a 1}) (list:new key value))",
+            "(match '(1 2) ((a b) (+ a b)) (#{:c} c))",
+            "(try (throw 'oops) (catch 'oops 1))",
+            "(defclass point () (constructor (x) (object:set! this :x x)) (method get () this:x))",
+            "(import a from \"./a.nia\") (import {:b 'c} from \"./b.nia\") (list:new a c)",
+            "(import * as m from \"./m.nia\") (print m:value)",
+            "(with-this {:a 1} this:a)",
+            "(list:map #(+ %1 1) '(1 2))",
+            "(let ((_unused 1)) 2)",
+            "'(unknown-function unknown-variable)",
+            "(defn f () (while #t (break)))",
+        ];
+
+        for code in specs {
+            nia_assert_equal(Vec::<(&'static str, String)>::new(), lint(code));
+        }
+    }
+
+    #[test]
+    fn reports_unbound_names() {
+        assert_lints(
+            "(defn f (a)\n  (+ a b))\n(g 1)",
+            vec![("unbound-variable", "b"), ("unbound-function", "g")],
+        );
+        assert_lints(
+            "(let ((a 1)) a) (print a)",
+            vec![("unbound-variable", "a")],
+        );
+        assert_lints("(print m:value)", vec![("unbound-variable", "m:value")]);
+        assert_lints("(import * from \"./m.nia\") (print anything)", vec![]);
+    }
+
+    #[test]
+    fn reports_wrong_argument_counts() {
+        assert_lints(
+            "(defn f (a #opt b) a) (f) (f 1 2 3) (f 1)",
+            vec![("wrong-argument-count", "f"), ("wrong-argument-count", "f")],
+        );
+        assert_lints("(+ 1)", vec![("wrong-argument-count", "+")]);
+        assert_lints(
+            "(list:nth 1)",
+            vec![("wrong-argument-count", "list:nth")],
+        );
+        assert_lints(
+            "(flet ((f () 1)) (f 1))",
+            vec![("wrong-argument-count", "f")],
+        );
+
+        let diagnostics = lint_code("(list:nth 1)").unwrap();
+
+        nia_assert_equal(
+            &String::from(
+                "Function `list:nth' takes 2 arguments, but 1 was given.",
+            ),
+            diagnostics[0].get_message(),
+        );
+    }
+
+    #[test]
+    fn reports_unused_bindings() {
+        assert_lints(
+            "(let ((a 1)\n      (b 2))\n  b)",
+            vec![("unused-binding", "a")],
+        );
+        assert_lints("(let* (a (b 2)) (set! a b))", vec![]);
+        assert_lints(
+            "(let ((a 1)) (let ((a 2)) a))",
+            vec![("unused-binding", "a")],
+        );
+    }
+
+    #[test]
+    fn reports_setting_constants() {
+        assert_lints(
+            "(defc a 1) (defv b 2) (set! a 2) (set! b 3) (inc! a)",
+            vec![("set-constant", "a"), ("set-constant", "a")],
+        );
+        assert_lints("(defc a 1) (let ((a 2)) (set! a 3) a)", vec![]);
+    }
+
+    #[test]
+    fn reports_unreachable_cond_clauses() {
+        assert_lints(
+            "(defv a 1)\n(cond ((= a 1) 'one)\n      (#t 'other)\n      (:else 'never))",
+            vec![("unreachable-clause", "cond")],
+        );
+        assert_lints("(cond ((= 1 1) 'one) (#t 'other))", vec![]);
+    }
+
+    #[test]
+    fn reports_duplicate_mappings() {
+        assert_lints(
+            "(device:define-global-mapping \"CtrlL+a\" #(print 1))\n\
+             (device:define-global-mapping \"CtrlL+b\" #(print 2))\n\
+             (device:define-global-mapping \"CtrlL+a\" #(print 3))",
+            vec![("duplicate-mapping", "device:define-global-mapping")],
+        );
+
+        let code = "(device:define-global-mapping \"CtrlL+a  b\" #())\n\
+                    (device:define-global-mapping \"CtrlL+a b\" #())";
+        let diagnostics = lint_code(code).unwrap();
+
+        nia_assert_equal(1, diagnostics.len());
+        nia_assert_equal(
+            String::from(
+                "config.nia:2:2: duplicate-mapping: Key chords `CtrlL+a b' are already mapped.",
+            ),
+            diagnostics[0].format("config.nia", code),
+        );
+    }
+
+    #[test]
+    fn locates_findings_at_their_occurrences() {
+        let code = "(defv a 1)\n(print b (+ a b) 'b b)";
+
+        let starts = lint_code(code)
+            .unwrap()
+            .into_iter()
+            .map(|diagnostic| diagnostic.get_start())
+            .collect::<Vec<usize>>();
+
+        nia_assert_equal(vec![18, 25, 31], starts);
+    }
+
+    #[test]
+    fn returns_error_for_invalid_code() {
+        nia_assert(lint_code("(defn f (a)").is_err());
+    }
+}
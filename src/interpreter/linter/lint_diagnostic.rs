@@ -0,0 +1,124 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintKind {
+    UnboundVariable,
+    UnboundFunction,
+    WrongArgumentCount,
+    UnusedBinding,
+    SetConstant,
+    UnreachableClause,
+    DuplicateMapping,
+}
+
+impl LintKind {
+    pub fn get_code(&self) -> &'static str {
+        match self {
+            LintKind::UnboundVariable => "unbound-variable",
+            LintKind::UnboundFunction => "unbound-function",
+            LintKind::WrongArgumentCount => "wrong-argument-count",
+            LintKind::UnusedBinding => "unused-binding",
+            LintKind::SetConstant => "set-constant",
+            LintKind::UnreachableClause => "unreachable-clause",
+            LintKind::DuplicateMapping => "duplicate-mapping",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintDiagnostic {
+    kind: LintKind,
+    message: String,
+    start: usize,
+    end: usize,
+}
+
+// returns one-based line and column of byte offset in code, columns are
+// counted in characters
+pub fn get_line_and_column(code: &str, offset: usize) -> (usize, usize) {
+    let before = &code[..offset.min(code.len())];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map(|index| index + 1).unwrap_or(0);
+
+    (line, before[line_start..].chars().count() + 1)
+}
+
+impl LintDiagnostic {
+    pub fn new(
+        kind: LintKind,
+        message: String,
+        start: usize,
+        end: usize,
+    ) -> LintDiagnostic {
+        LintDiagnostic {
+            kind,
+            message,
+            start,
+            end,
+        }
+    }
+
+    pub fn get_kind(&self) -> LintKind {
+        self.kind
+    }
+
+    pub fn get_message(&self) -> &String {
+        &self.message
+    }
+
+    pub fn get_start(&self) -> usize {
+        self.start
+    }
+
+    pub fn get_end(&self) -> usize {
+        self.end
+    }
+
+    // one diagnostic per line in the `path:line:column: code: message'
+    // format that editors and grep understand
+    pub fn format(&self, path: &str, code: &str) -> String {
+        let (line, column) = get_line_and_column(code, self.start);
+
+        format!(
+            "{}:{}:{}: {}: {}",
+            path,
+            line,
+            column,
+            self.kind.get_code(),
+            self.message
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[test]
+    fn computes_lines_and_columns() {
+        let code = "(a)\n(bé c)";
+
+        nia_assert_equal((1, 1), get_line_and_column(code, 0));
+        nia_assert_equal((1, 4), get_line_and_column(code, 3));
+        nia_assert_equal((2, 1), get_line_and_column(code, 4));
+        nia_assert_equal((2, 5), get_line_and_column(code, 9));
+    }
+
+    #[test]
+    fn formats_diagnostics() {
+        let diagnostic = LintDiagnostic::new(
+            LintKind::UnboundVariable,
+            String::from("Variable `b' is not defined."),
+            6,
+            7,
+        );
+
+        nia_assert_equal(
+            String::from(
+                "a.nia:2:2: unbound-variable: Variable `b' is not defined.",
+            ),
+            diagnostic.format("a.nia", "(f a)\n b"),
+        );
+    }
+}
@@ -0,0 +1,7 @@
+mod argument_count;
+mod lint_code;
+mod lint_diagnostic;
+
+pub use argument_count::*;
+pub use lint_code::*;
+pub use lint_diagnostic::*;
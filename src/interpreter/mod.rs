@@ -6,14 +6,20 @@ mod parser;
 mod reader;
 
 mod call_stack;
+mod capabilities;
+mod completion;
 mod context;
+mod documentation;
 mod environment;
 mod error;
 mod event_loop;
+mod formatter;
 mod garbage_collector;
+mod image;
 mod internal_functions;
 mod interpreter;
 pub mod library;
+mod linter;
 mod module;
 mod special_variables;
 mod stdlib;
@@ -25,13 +31,19 @@ pub use parser::*;
 pub use reader::*;
 
 pub use call_stack::*;
+pub use capabilities::*;
+pub use completion::*;
 pub use context::*;
+pub use documentation::*;
 pub use environment::*;
 pub use error::*;
 pub use event_loop::*;
+pub use formatter::*;
 pub use garbage_collector::*;
+pub use image::*;
 pub use internal_functions::*;
 pub use interpreter::*;
+pub use linter::*;
 pub use module::*;
 pub use special_variables::*;
 pub use stdlib::*;
@@ -1,7 +1,11 @@
 mod module;
 mod module_arena;
 mod module_id;
+mod module_state;
+mod std_modules;
 
 pub use module::*;
 pub use module_arena::*;
 pub use module_id::*;
+pub use module_state::*;
+pub use std_modules::*;
@@ -1,6 +1,12 @@
 use std::collections::HashMap;
+use std::time::SystemTime;
 
 use crate::EnvironmentId;
+use crate::ImageItem;
+use crate::ImageReader;
+use crate::ImageWriter;
+use crate::ModuleId;
+use crate::ModuleState;
 use crate::Value;
 use crate::{Error, SymbolId};
 
@@ -10,6 +16,10 @@ pub struct Module {
     environment_id: EnvironmentId,
     exports: HashMap<SymbolId, Value>,
     default_export: Option<Value>,
+    dependencies: Vec<ModuleId>,
+    modified_time: Option<SystemTime>,
+    defines_key_configuration: bool,
+    state: ModuleState,
 }
 
 impl Module {
@@ -19,11 +29,19 @@ impl Module {
             environment_id,
             exports: HashMap::new(),
             default_export: None,
+            dependencies: Vec::new(),
+            modified_time: None,
+            defines_key_configuration: false,
+            state: ModuleState::Loading,
         }
     }
 
     pub fn new_root_module(environment_id: EnvironmentId) -> Module {
-        Module::new(String::from(""), environment_id)
+        let mut module = Module::new(String::from(""), environment_id);
+
+        module.set_state(ModuleState::Loaded);
+
+        module
     }
 
     pub fn get_path(&self) -> &String {
@@ -79,6 +97,88 @@ impl Module {
 
         Ok(())
     }
+
+    pub fn get_dependencies(&self) -> &Vec<ModuleId> {
+        &self.dependencies
+    }
+
+    pub fn add_dependency(&mut self, module_id: ModuleId) {
+        if !self.dependencies.contains(&module_id) {
+            self.dependencies.push(module_id);
+        }
+    }
+
+    pub fn get_modified_time(&self) -> Option<SystemTime> {
+        self.modified_time
+    }
+
+    pub fn set_modified_time(&mut self, modified_time: Option<SystemTime>) {
+        self.modified_time = modified_time;
+    }
+
+    pub fn defines_key_configuration(&self) -> bool {
+        self.defines_key_configuration
+    }
+
+    pub fn set_defines_key_configuration(
+        &mut self,
+        defines_key_configuration: bool,
+    ) {
+        self.defines_key_configuration = defines_key_configuration;
+    }
+
+    pub fn get_state(&self) -> ModuleState {
+        self.state
+    }
+
+    pub fn set_state(&mut self, state: ModuleState) {
+        self.state = state;
+    }
+
+    // Drops everything module evaluation produced, so that the module can be
+    // evaluated again in a fresh environment.
+    pub fn reset(&mut self, environment_id: EnvironmentId) {
+        self.environment_id = environment_id;
+        self.exports.clear();
+        self.default_export = None;
+        self.dependencies.clear();
+        self.defines_key_configuration = false;
+    }
+}
+
+impl ImageItem for Module {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write(&self.path)?;
+        writer.write(&self.environment_id)?;
+        writer.write(&self.exports)?;
+        writer.write(&self.default_export)?;
+        writer.write(&self.dependencies)?;
+        writer.write(&self.modified_time)?;
+        writer.write(&self.defines_key_configuration)?;
+        writer.write(&self.state)
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        let path = reader.read()?;
+        let environment_id = reader.read()?;
+        let exports = reader.read()?;
+        let default_export = reader.read()?;
+        let dependencies = reader.read()?;
+        let modified_time = reader.read()?;
+        let defines_key_configuration = reader.read()?;
+        let state = reader.read()?;
+
+        Ok(Module {
+            path,
+            environment_id,
+            exports,
+            default_export,
+            dependencies,
+            modified_time,
+            defines_key_configuration,
+            state,
+        })
+    }
 }
 
 // todo: tests
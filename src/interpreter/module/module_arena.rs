@@ -1,5 +1,8 @@
 use std::collections::HashMap;
 
+use crate::ImageItem;
+use crate::ImageReader;
+use crate::ImageWriter;
 use crate::Module;
 use crate::{EnvironmentId, Error, ModuleId};
 
@@ -67,6 +70,41 @@ impl ModuleArena {
             })
     }
 
+    pub fn get_module_file_path(&self, module_id: ModuleId) -> Option<String> {
+        self.mapping
+            .iter()
+            .find(|(path, id)| **id == module_id && path.len() > 0)
+            .map(|(path, _)| path.clone())
+    }
+
+    pub fn get_file_module_ids(&self) -> Vec<ModuleId> {
+        let mut module_ids = self
+            .mapping
+            .iter()
+            .filter(|(path, _)| path.len() > 0)
+            .map(|(_, module_id)| *module_id)
+            .collect::<Vec<ModuleId>>();
+
+        module_ids.sort_by_key(|module_id| module_id.get_id());
+
+        module_ids
+    }
+
+    pub fn get_dependents(&self, module_id: ModuleId) -> Vec<ModuleId> {
+        let mut dependents = self
+            .modules
+            .iter()
+            .filter(|(_, module)| {
+                module.get_dependencies().contains(&module_id)
+            })
+            .map(|(dependent_id, _)| *dependent_id)
+            .collect::<Vec<ModuleId>>();
+
+        dependents.sort_by_key(|module_id| module_id.get_id());
+
+        dependents
+    }
+
     pub fn get_module(&self, module_id: ModuleId) -> Result<&Module, Error> {
         self.modules.get(&module_id).ok_or_else(|| {
             Error::generic_execution_error(&format!(
@@ -95,3 +133,23 @@ impl ModuleArena {
             .collect()
     }
 }
+
+impl ImageItem for ModuleArena {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write(&self.modules)?;
+        writer.write(&self.mapping)?;
+        writer.write(&self.next_id)
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        let modules = reader.read()?;
+        let mapping = reader.read()?;
+        let next_id = reader.read()?;
+
+        Ok(ModuleArena {
+            modules,
+            mapping,
+            next_id,
+        })
+    }
+}
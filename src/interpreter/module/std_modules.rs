@@ -0,0 +1,83 @@
+// Standard modules are written in Nia and bundled into the binary. They are
+// interned under paths with this prefix, so that they never clash with files.
+pub const STD_MODULE_PATH_PREFIX: &'static str = "nia:";
+
+const STD_MODULES: [(&'static str, &'static str); 2] = [
+    ("std/keys", include_str!("std/keys.nia")),
+    ("std/strings", include_str!("std/strings.nia")),
+];
+
+fn strip_extension(module_name: &str) -> &str {
+    if module_name.ends_with(".nia") {
+        &module_name[..module_name.len() - 4]
+    } else {
+        module_name
+    }
+}
+
+pub fn is_std_module_path(module_path: &str) -> bool {
+    module_path.starts_with(STD_MODULE_PATH_PREFIX)
+}
+
+pub fn get_std_module_names() -> Vec<&'static str> {
+    STD_MODULES.iter().map(|(name, _)| *name).collect()
+}
+
+pub fn get_std_module_path(module_name: &str) -> Option<String> {
+    let module_name = strip_extension(module_name);
+
+    STD_MODULES
+        .iter()
+        .find(|(name, _)| *name == module_name)
+        .map(|(name, _)| format!("{}{}", STD_MODULE_PATH_PREFIX, name))
+}
+
+pub fn get_std_module_source(module_path: &str) -> Option<&'static str> {
+    if !is_std_module_path(module_path) {
+        return None;
+    }
+
+    let module_name =
+        strip_extension(&module_path[STD_MODULE_PATH_PREFIX.len()..]);
+
+    STD_MODULES
+        .iter()
+        .find(|(name, _)| *name == module_name)
+        .map(|(_, source)| *source)
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[test]
+    fn resolves_std_module_paths() {
+        let specs = vec![
+            ("std/keys", Some(String::from("nia:std/keys"))),
+            ("std/keys.nia", Some(String::from("nia:std/keys"))),
+            ("std/strings", Some(String::from("nia:std/strings"))),
+            ("std/unknown", None),
+            ("keys", None),
+        ];
+
+        for (module_name, expected) in specs {
+            nia_assert_equal(expected, get_std_module_path(module_name));
+        }
+    }
+
+    #[test]
+    fn returns_std_module_sources() {
+        for module_name in get_std_module_names() {
+            let module_path = get_std_module_path(module_name).unwrap();
+
+            nia_assert(get_std_module_source(&module_path).is_some());
+        }
+
+        nia_assert(get_std_module_source("std/keys").is_none());
+        nia_assert(get_std_module_source("nia:std/unknown").is_none());
+    }
+}
@@ -0,0 +1,130 @@
+use crate::interpreter::parser::parse;
+use crate::interpreter::parser::ParseError;
+
+#[derive(Debug, Clone)]
+pub enum CodeStatus {
+    Complete,
+    Incomplete,
+    Invalid(ParseError),
+}
+
+// returns the text that closes every string, comment and bracket left open
+// at the end of the code
+fn make_closing_suffix(code: &str) -> String {
+    let mut closing_characters = Vec::new();
+    let mut inside_string = false;
+    let mut inside_comment = false;
+    let mut escaped = false;
+
+    for character in code.chars() {
+        if escaped {
+            escaped = false;
+        } else if inside_comment {
+            inside_comment = character != '\n';
+        } else if character == '\\' {
+            escaped = true;
+        } else if inside_string {
+            inside_string = character != '"';
+        } else {
+            match character {
+                '"' => inside_string = true,
+                ';' => inside_comment = true,
+                '(' => closing_characters.push(')'),
+                '{' => closing_characters.push('}'),
+                ')' | '}' => {
+                    closing_characters.pop();
+                },
+                _ => {},
+            }
+        }
+    }
+
+    let mut suffix = String::new();
+
+    if inside_comment {
+        suffix.push('\n');
+    }
+
+    if inside_string {
+        suffix.push('"');
+    }
+
+    suffix.extend(closing_characters.into_iter().rev());
+
+    suffix
+}
+
+// code is incomplete when the parser accepts it once the open strings and
+// brackets are closed, so more lines can still make it valid
+pub fn get_code_status(code: &str) -> CodeStatus {
+    let error = match parse(code) {
+        Ok(_) => return CodeStatus::Complete,
+        Err(error) => error,
+    };
+
+    let suffix = make_closing_suffix(code);
+
+    if suffix.trim().is_empty() {
+        return CodeStatus::Invalid(error);
+    }
+
+    match parse(&format!("{}{}", code, suffix)) {
+        Ok(_) => CodeStatus::Incomplete,
+        Err(_) => CodeStatus::Invalid(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    fn assert_complete(code: &str) {
+        nia_assert(match get_code_status(code) {
+            CodeStatus::Complete => true,
+            _ => false,
+        });
+    }
+
+    fn assert_incomplete(code: &str) {
+        nia_assert(match get_code_status(code) {
+            CodeStatus::Incomplete => true,
+            _ => false,
+        });
+    }
+
+    fn assert_invalid(code: &str) {
+        nia_assert(match get_code_status(code) {
+            CodeStatus::Invalid(_) => true,
+            _ => false,
+        });
+    }
+
+    #[test]
+    fn returns_complete_for_parsed_code() {
+        assert_complete("");
+        assert_complete("(+ 1 2)");
+        assert_complete("(defn f (a)\n  (+ a 1))");
+        assert_complete("\"multi\nline\"");
+        assert_complete("(f 1) ; comment (");
+        assert_complete("(f \\( 1)");
+    }
+
+    #[test]
+    fn returns_incomplete_for_unclosed_brackets_and_strings() {
+        assert_incomplete("(defn f (a)");
+        assert_incomplete("(defn f (a)\n  (+ a");
+        assert_incomplete("#{:a 1");
+        assert_incomplete("(f \"open");
+        assert_incomplete("(f \"escaped \\\" quote");
+    }
+
+    #[test]
+    fn returns_invalid_for_code_that_cannot_be_completed() {
+        assert_invalid("(f))");
+        assert_invalid("}");
+        assert_invalid("#{1 2");
+    }
+}
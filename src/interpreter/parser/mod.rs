@@ -4,11 +4,17 @@ mod element;
 mod elements;
 
 mod code;
+mod code_status;
 mod parse_error;
+mod syntax_tree;
+mod token;
 
 pub use {
-    code::parse, code::Code, element::Element, elements::*,
-    parse_error::ParseError,
+    code::parse, code::Code, code_status::get_code_status,
+    code_status::CodeStatus, element::Element, elements::*,
+    parse_error::ParseError, syntax_tree::parse_partial_syntax_tree,
+    syntax_tree::parse_syntax_tree, syntax_tree::Span, syntax_tree::SyntaxNode,
+    token::tokenize, token::Token, token::TokenKind,
 };
 
 #[cfg(test)]
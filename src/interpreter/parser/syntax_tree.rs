@@ -0,0 +1,435 @@
+use crate::interpreter::parser::parse;
+use crate::interpreter::parser::tokenize;
+use crate::interpreter::parser::ParseError;
+use crate::interpreter::parser::Token;
+use crate::interpreter::parser::TokenKind;
+
+// byte offsets of the start and of the end of a node in the code
+pub type Span = (usize, usize);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SyntaxNode {
+    Atom(TokenKind, String, Span),
+    Comment(String),
+    LineBreaks(usize),
+    Prefixed(String, Box<SyntaxNode>, Span),
+    Sequence(String, Vec<SyntaxNode>, String, Span),
+}
+
+impl SyntaxNode {
+    // comments and line breaks are not located, nothing refers to them
+    pub fn get_span(&self) -> Option<Span> {
+        match self {
+            SyntaxNode::Atom(_, _, span)
+            | SyntaxNode::Prefixed(_, _, span)
+            | SyntaxNode::Sequence(_, _, _, span) => Some(*span),
+            SyntaxNode::Comment(_) | SyntaxNode::LineBreaks(_) => None,
+        }
+    }
+
+    // the forms of a sequence, without comments and line breaks
+    pub fn get_children(&self) -> Vec<&SyntaxNode> {
+        match self {
+            SyntaxNode::Sequence(_, children, _, _) => children
+                .iter()
+                .filter(|child| child.get_span().is_some())
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    pub fn is_list(&self) -> bool {
+        match self {
+            SyntaxNode::Sequence(opening, _, _, _) => opening == "(",
+            _ => false,
+        }
+    }
+
+    pub fn get_symbol(&self) -> Option<&str> {
+        match self {
+            SyntaxNode::Atom(TokenKind::Symbol, text, _) => Some(text),
+            _ => None,
+        }
+    }
+
+    // the contents of a string literal, escapes are kept as they are
+    pub fn get_string(&self) -> Option<&str> {
+        match self {
+            SyntaxNode::Atom(TokenKind::String, text, _) => {
+                let text = text.strip_prefix('"')?;
+
+                Some(text.strip_suffix('"').unwrap_or(text))
+            },
+            _ => None,
+        }
+    }
+
+    // the head symbol of `(head ...)'
+    pub fn get_head(&self) -> Option<&str> {
+        if !self.is_list() {
+            return None;
+        }
+
+        self.get_children().first()?.get_symbol()
+    }
+}
+
+struct SyntaxTreeReader<'a> {
+    code: &'a str,
+    tokens: Vec<Token>,
+    index: usize,
+    partial: bool,
+}
+
+impl<'a> SyntaxTreeReader<'a> {
+    fn new(code: &'a str, partial: bool) -> Self {
+        SyntaxTreeReader {
+            code,
+            tokens: tokenize(code),
+            index: 0,
+            partial,
+        }
+    }
+
+    fn next_token(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.index).copied();
+
+        self.index += 1;
+        token
+    }
+
+    fn make_error(&self, token: Token) -> ParseError {
+        ParseError::TrailingInput(String::from(&self.code[token.get_start()..]))
+    }
+
+    fn read_sequence(
+        &mut self,
+        opening: Token,
+    ) -> Result<SyntaxNode, ParseError> {
+        let mut children = Vec::new();
+
+        loop {
+            let token = match self.next_token() {
+                Some(token) => token,
+                // unfinished sequences of partial trees last until the end
+                // of the code
+                None if self.partial => {
+                    return Ok(SyntaxNode::Sequence(
+                        String::from(opening.get_text(self.code)),
+                        children,
+                        String::new(),
+                        (opening.get_start(), self.code.len()),
+                    ));
+                },
+                None => return Err(ParseError::NomIncomplete()),
+            };
+
+            if token.get_kind() == TokenKind::ClosingBracket {
+                return Ok(SyntaxNode::Sequence(
+                    String::from(opening.get_text(self.code)),
+                    children,
+                    String::from(token.get_text(self.code)),
+                    (opening.get_start(), token.get_end()),
+                ));
+            }
+
+            if let Some(node) = self.read_node(token)? {
+                children.push(node);
+            }
+        }
+    }
+
+    // whitespace is reduced to the number of line breaks it contains, since
+    // everything else about it is normalized by printers
+    fn read_node(
+        &mut self,
+        token: Token,
+    ) -> Result<Option<SyntaxNode>, ParseError> {
+        let text = token.get_text(self.code);
+
+        let node = match token.get_kind() {
+            TokenKind::Whitespace => {
+                let line_breaks = text.matches('\n').count();
+
+                if line_breaks == 0 {
+                    return Ok(None);
+                }
+
+                SyntaxNode::LineBreaks(line_breaks)
+            },
+            TokenKind::Comment => {
+                SyntaxNode::Comment(String::from(text.trim_end()))
+            },
+            TokenKind::OpeningBracket => self.read_sequence(token)?,
+            TokenKind::Prefix => {
+                let node = loop {
+                    let next_token = match self.next_token() {
+                        Some(next_token) => next_token,
+                        None if self.partial => return Ok(None),
+                        None => return Err(ParseError::NomIncomplete()),
+                    };
+
+                    if next_token.get_kind() == TokenKind::Whitespace {
+                        continue;
+                    }
+
+                    // the bracket is left to the sequence it closes
+                    if self.partial
+                        && next_token.get_kind() == TokenKind::ClosingBracket
+                    {
+                        self.index -= 1;
+                        return Ok(None);
+                    }
+
+                    match self.read_node(next_token)? {
+                        Some(SyntaxNode::Comment(_)) | None if self.partial => {
+                            continue;
+                        },
+                        Some(SyntaxNode::Comment(_)) | None => {
+                            return Err(self.make_error(next_token));
+                        },
+                        Some(node) => break node,
+                    }
+                };
+
+                let end = node
+                    .get_span()
+                    .map(|(_, end)| end)
+                    .unwrap_or(token.get_end());
+
+                SyntaxNode::Prefixed(
+                    String::from(text),
+                    Box::new(node),
+                    (token.get_start(), end),
+                )
+            },
+            TokenKind::ClosingBracket | TokenKind::Unknown if self.partial => {
+                return Ok(None);
+            },
+            TokenKind::ClosingBracket | TokenKind::Unknown => {
+                return Err(self.make_error(token));
+            },
+            kind => SyntaxNode::Atom(
+                kind,
+                String::from(text),
+                (token.get_start(), token.get_end()),
+            ),
+        };
+
+        Ok(Some(node))
+    }
+}
+
+// unlike `parse', keeps comments and line breaks, so the code can be printed
+// back without losing them
+pub fn parse_syntax_tree(code: &str) -> Result<Vec<SyntaxNode>, ParseError> {
+    parse(code)?;
+
+    let mut reader = SyntaxTreeReader::new(code, false);
+    let mut nodes = Vec::new();
+
+    while let Some(token) = reader.next_token() {
+        if let Some(node) = reader.read_node(token)? {
+            nodes.push(node);
+        }
+    }
+
+    Ok(nodes)
+}
+
+// reads code that is being typed, so that editors can point at its parts:
+// unfinished sequences end with the code and stray brackets are skipped
+pub fn parse_partial_syntax_tree(code: &str) -> Vec<SyntaxNode> {
+    let mut reader = SyntaxTreeReader::new(code, true);
+    let mut nodes = Vec::new();
+
+    while let Some(token) = reader.next_token() {
+        if let Ok(Some(node)) = reader.read_node(token) {
+            nodes.push(node);
+        }
+    }
+
+    nodes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    fn atom(kind: TokenKind, text: &str, start: usize) -> SyntaxNode {
+        SyntaxNode::Atom(kind, String::from(text), (start, start + text.len()))
+    }
+
+    fn sequence(
+        opening: &str,
+        children: Vec<SyntaxNode>,
+        closing: &str,
+        span: Span,
+    ) -> SyntaxNode {
+        SyntaxNode::Sequence(
+            String::from(opening),
+            children,
+            String::from(closing),
+            span,
+        )
+    }
+
+    #[test]
+    fn keeps_comments_and_line_breaks() {
+        let expected = vec![
+            SyntaxNode::Comment(String::from(";; adds one")),
+            SyntaxNode::LineBreaks(1),
+            sequence(
+                "(",
+                vec![
+                    atom(TokenKind::Symbol, "defn", 13),
+                    atom(TokenKind::Symbol, "f", 18),
+                    sequence(
+                        "(",
+                        vec![atom(TokenKind::Symbol, "a", 21)],
+                        ")",
+                        (20, 23),
+                    ),
+                    SyntaxNode::LineBreaks(1),
+                    sequence(
+                        "(",
+                        vec![
+                            atom(TokenKind::Symbol, "+", 27),
+                            atom(TokenKind::Symbol, "a", 29),
+                            atom(TokenKind::Number, "1", 31),
+                        ],
+                        ")",
+                        (26, 33),
+                    ),
+                ],
+                ")",
+                (12, 34),
+            ),
+            SyntaxNode::LineBreaks(2),
+            SyntaxNode::Comment(String::from("; end")),
+        ];
+
+        let result =
+            parse_syntax_tree(";; adds one\n(defn f (a)\n  (+ a 1))\n\n; end");
+
+        nia_assert_equal(
+            Ok(expected),
+            result.map_err(|error| error.to_string()),
+        );
+    }
+
+    #[test]
+    fn reads_prefixes_and_object_literals() {
+        let expected = vec![
+            SyntaxNode::Prefixed(
+                String::from("'"),
+                Box::new(sequence(
+                    "(",
+                    vec![atom(TokenKind::String, "\"a\"", 2)],
+                    ")",
+                    (1, 6),
+                )),
+                (0, 6),
+            ),
+            sequence(
+                "{",
+                vec![
+                    atom(TokenKind::Keyword, ":a", 8),
+                    atom(TokenKind::Boolean, "#t", 11),
+                ],
+                "}",
+                (7, 14),
+            ),
+            sequence(
+                "#(",
+                vec![atom(TokenKind::Symbol, "f", 17)],
+                ")",
+                (15, 19),
+            ),
+        ];
+
+        let result = parse_syntax_tree("'(\"a\") {:a #t} #(f)");
+
+        nia_assert_equal(
+            Ok(expected),
+            result.map_err(|error| error.to_string()),
+        );
+    }
+
+    #[test]
+    fn returns_error_for_invalid_code() {
+        let specs = vec!["(f", "(f))", "}"];
+
+        for code in specs {
+            nia_assert(parse_syntax_tree(code).is_err());
+        }
+    }
+
+    #[test]
+    fn reads_partial_trees() {
+        let specs = vec![
+            (
+                "(defn f (a) 'b) ) (g '",
+                vec![
+                    sequence(
+                        "(",
+                        vec![
+                            atom(TokenKind::Symbol, "defn", 1),
+                            atom(TokenKind::Symbol, "f", 6),
+                            sequence(
+                                "(",
+                                vec![atom(TokenKind::Symbol, "a", 9)],
+                                ")",
+                                (8, 11),
+                            ),
+                            SyntaxNode::Prefixed(
+                                String::from("'"),
+                                Box::new(atom(TokenKind::Symbol, "b", 13)),
+                                (12, 14),
+                            ),
+                        ],
+                        ")",
+                        (0, 15),
+                    ),
+                    sequence(
+                        "(",
+                        vec![atom(TokenKind::Symbol, "g", 19)],
+                        "",
+                        (18, 22),
+                    ),
+                ],
+            ),
+            (
+                "(f ')",
+                vec![sequence(
+                    "(",
+                    vec![atom(TokenKind::Symbol, "f", 1)],
+                    ")",
+                    (0, 5),
+                )],
+            ),
+        ];
+
+        for (code, expected) in specs {
+            nia_assert_equal(expected, parse_partial_syntax_tree(code));
+        }
+    }
+
+    #[test]
+    fn returns_parts_of_forms() {
+        let code = "(defn f \"doc\" ; comment\n  a) {:a 1}";
+        let nodes = parse_syntax_tree(code).unwrap();
+        let children = nodes[0].get_children();
+
+        nia_assert_equal(Some("defn"), nodes[0].get_head());
+        nia_assert_equal(4, children.len());
+        nia_assert_equal(Some("f"), children[1].get_symbol());
+        nia_assert_equal(Some("doc"), children[2].get_string());
+        nia_assert_equal(None, children[2].get_symbol());
+        nia_assert(!nodes[1].is_list());
+        nia_assert_equal(None, nodes[1].get_head());
+    }
+}
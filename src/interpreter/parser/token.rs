@@ -0,0 +1,232 @@
+use nom::error::ErrorKind;
+use nom::IResult;
+
+use crate::interpreter::parser::boolean_element;
+use crate::interpreter::parser::delimited_symbols_element;
+use crate::interpreter::parser::float_element;
+use crate::interpreter::parser::integer_element;
+use crate::interpreter::parser::keyword_element;
+use crate::interpreter::parser::string_element;
+use crate::interpreter::parser::symbol_element;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    OpeningBracket,
+    ClosingBracket,
+    Prefix,
+    Boolean,
+    Number,
+    String,
+    Keyword,
+    Symbol,
+    Comment,
+    Whitespace,
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    kind: TokenKind,
+    start: usize,
+    end: usize,
+}
+
+impl Token {
+    pub fn new(kind: TokenKind, start: usize, end: usize) -> Token {
+        Token { kind, start, end }
+    }
+
+    pub fn get_kind(&self) -> TokenKind {
+        self.kind
+    }
+
+    pub fn get_start(&self) -> usize {
+        self.start
+    }
+
+    pub fn get_end(&self) -> usize {
+        self.end
+    }
+
+    pub fn get_text<'a>(&self, code: &'a str) -> &'a str {
+        &code[self.start..self.end]
+    }
+}
+
+fn get_parsed_length<T>(
+    parser: fn(&str) -> IResult<&str, T, (&str, ErrorKind)>,
+    input: &str,
+) -> Option<usize> {
+    match parser(input) {
+        Ok((rest, _)) if rest.len() < input.len() => {
+            Some(input.len() - rest.len())
+        },
+        _ => None,
+    }
+}
+
+fn get_length_while(input: &str, predicate: impl Fn(char) -> bool) -> usize {
+    input
+        .char_indices()
+        .find(|(_, character)| !predicate(*character))
+        .map(|(index, _)| index)
+        .unwrap_or(input.len())
+}
+
+// atoms are recognized by the element parsers, so tokens agree with `parse'
+fn read_atom(input: &str) -> Option<(TokenKind, usize)> {
+    let parsers: Vec<(TokenKind, fn(&str) -> Option<usize>)> = vec![
+        (TokenKind::Boolean, |input| {
+            get_parsed_length(boolean_element::parse, input)
+        }),
+        (TokenKind::Number, |input| {
+            get_parsed_length(float_element::parse, input)
+        }),
+        (TokenKind::Number, |input| {
+            get_parsed_length(integer_element::parse, input)
+        }),
+        (TokenKind::Keyword, |input| {
+            get_parsed_length(keyword_element::parse, input)
+        }),
+        (TokenKind::Symbol, |input| {
+            get_parsed_length(delimited_symbols_element::parse, input)
+        }),
+        (TokenKind::Symbol, |input| {
+            get_parsed_length(symbol_element::parse, input)
+        }),
+    ];
+
+    parsers
+        .into_iter()
+        .filter_map(|(kind, parser)| parser(input).map(|length| (kind, length)))
+        .next()
+}
+
+fn read_token(input: &str) -> (TokenKind, usize) {
+    let character = match input.chars().next() {
+        Some(character) => character,
+        None => return (TokenKind::Unknown, 0),
+    };
+
+    if character.is_whitespace() {
+        return (
+            TokenKind::Whitespace,
+            get_length_while(input, |character| character.is_whitespace()),
+        );
+    }
+
+    if input.starts_with("#{") || input.starts_with("#(") {
+        return (TokenKind::OpeningBracket, 2);
+    }
+
+    if input.starts_with("#'") || input.starts_with(",@") {
+        return (TokenKind::Prefix, 2);
+    }
+
+    match character {
+        ';' => (
+            TokenKind::Comment,
+            get_length_while(input, |character| character != '\n'),
+        ),
+        '(' | '{' => (TokenKind::OpeningBracket, 1),
+        ')' | '}' => (TokenKind::ClosingBracket, 1),
+        '\'' | '`' | ',' => (TokenKind::Prefix, 1),
+        '"' => (
+            TokenKind::String,
+            get_parsed_length(string_element::parse, input)
+                .unwrap_or(input.len()),
+        ),
+        _ => read_atom(input)
+            .unwrap_or((TokenKind::Unknown, character.len_utf8())),
+    }
+}
+
+// unlike `parse', never fails, so it works on code that is being typed
+pub fn tokenize(code: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut start = 0;
+
+    while start < code.len() {
+        let (kind, length) = read_token(&code[start..]);
+
+        tokens.push(Token::new(kind, start, start + length));
+        start += length;
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    fn assert_tokens(code: &str, expected: Vec<(TokenKind, &str)>) {
+        let tokens = tokenize(code);
+
+        nia_assert_equal(expected.len(), tokens.len());
+
+        for (token, (expected_kind, expected_text)) in
+            tokens.into_iter().zip(expected.into_iter())
+        {
+            nia_assert_equal(expected_kind, token.get_kind());
+            nia_assert_equal(expected_text, token.get_text(code));
+        }
+    }
+
+    #[test]
+    fn tokenizes_atoms() {
+        assert_tokens(
+            "#t 1 1.5 \"s\" :k sym obj:member",
+            vec![
+                (TokenKind::Boolean, "#t"),
+                (TokenKind::Whitespace, " "),
+                (TokenKind::Number, "1"),
+                (TokenKind::Whitespace, " "),
+                (TokenKind::Number, "1.5"),
+                (TokenKind::Whitespace, " "),
+                (TokenKind::String, "\"s\""),
+                (TokenKind::Whitespace, " "),
+                (TokenKind::Keyword, ":k"),
+                (TokenKind::Whitespace, " "),
+                (TokenKind::Symbol, "sym"),
+                (TokenKind::Whitespace, " "),
+                (TokenKind::Symbol, "obj:member"),
+            ],
+        );
+    }
+
+    #[test]
+    fn tokenizes_brackets_prefixes_and_comments() {
+        assert_tokens(
+            "#{:a '(b)} ; comment",
+            vec![
+                (TokenKind::OpeningBracket, "#{"),
+                (TokenKind::Keyword, ":a"),
+                (TokenKind::Whitespace, " "),
+                (TokenKind::Prefix, "'"),
+                (TokenKind::OpeningBracket, "("),
+                (TokenKind::Symbol, "b"),
+                (TokenKind::ClosingBracket, ")"),
+                (TokenKind::ClosingBracket, "}"),
+                (TokenKind::Whitespace, " "),
+                (TokenKind::Comment, "; comment"),
+            ],
+        );
+    }
+
+    #[test]
+    fn tokenizes_incomplete_code() {
+        assert_tokens(
+            "(f \"unclosed",
+            vec![
+                (TokenKind::OpeningBracket, "("),
+                (TokenKind::Symbol, "f"),
+                (TokenKind::Whitespace, " "),
+                (TokenKind::String, "\"unclosed"),
+            ],
+        );
+    }
+}
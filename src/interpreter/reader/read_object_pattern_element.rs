@@ -49,9 +49,12 @@ mod tests {
 
     use crate::interpreter::parser::KeywordElement;
 
+    use crate::library;
+
     #[test]
     fn reads_elements_correctly() {
         let mut interpreter = Interpreter::new();
+        let environment_id = interpreter.get_main_environment_id();
 
         let a_symbol_id = interpreter.intern_symbol_id("a");
         let b_symbol_id = interpreter.intern_symbol_id("b");
@@ -103,16 +106,20 @@ mod tests {
             .unwrap();
 
             let object_id = interpreter
-                .execute_value(interpreter.get_main_environment_id(), object_id)
+                .execute_value(environment_id, object_id)
                 .unwrap()
                 .try_into()
                 .unwrap();
 
             for (key_symbol_id, expected_value) in pairs {
-                let result_value = interpreter
-                    .get_object_property(object_id, key_symbol_id)
-                    .unwrap()
-                    .unwrap();
+                let result_value = library::get_object_property(
+                    &mut interpreter,
+                    environment_id,
+                    object_id,
+                    key_symbol_id,
+                )
+                .unwrap()
+                .unwrap();
 
                 crate::utils::assert_deep_equal(
                     &mut interpreter,
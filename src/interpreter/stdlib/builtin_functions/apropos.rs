@@ -0,0 +1,100 @@
+use crate::collect_documented_names;
+use crate::EnvironmentId;
+use crate::Error;
+use crate::Interpreter;
+use crate::Value;
+
+use crate::library;
+
+pub fn apropos(
+    interpreter: &mut Interpreter,
+    environment_id: EnvironmentId,
+    values: Vec<Value>,
+) -> Result<Value, Error> {
+    if values.len() != 1 {
+        return Error::invalid_argument_count_error(
+            "Built-in function `apropos' takes one argument exactly.",
+        )
+        .into();
+    }
+
+    let mut values = values;
+
+    let substring =
+        library::read_as_string(interpreter, values.remove(0))?.clone();
+
+    let names = collect_documented_names(interpreter, environment_id)?
+        .into_iter()
+        .filter(|name| name.contains(&substring))
+        .collect::<Vec<String>>();
+
+    let name_values = names
+        .into_iter()
+        .map(|name| interpreter.intern_string_value(name))
+        .collect::<Vec<Value>>();
+
+    Ok(interpreter.vec_to_list(name_values))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[allow(unused_imports)]
+    use crate::utils;
+
+    #[test]
+    fn returns_sorted_matching_names() {
+        let mut interpreter = Interpreter::new();
+
+        let pairs = vec![
+            (
+                "(apropos \"string:trim\")",
+                "'(\"string:trim\" \"string:trim-left\" \"string:trim-right\")",
+            ),
+            ("(apropos \"take\")", "'(\"list:take\" \"list:take-while\")"),
+            (
+                "(defv my-apropos-variable 1) (apropos \"my-apropos\")",
+                "'(\"my-apropos-variable\")",
+            ),
+            ("(apropos \"not-a-name-of-anything\")", "nil"),
+        ];
+
+        utils::assert_results_are_equal(&mut interpreter, pairs);
+    }
+
+    #[test]
+    fn returns_invalid_argument_count_error_when_argument_count_is_not_correct()
+    {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec!["(apropos)", "(apropos \"a\" \"b\")"];
+
+        utils::assert_results_are_invalid_argument_count_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+
+    #[test]
+    fn returns_invalid_argument_error_when_invalid_arguments_were_provided() {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec![
+            "(apropos 1)",
+            "(apropos 1.1)",
+            "(apropos #t)",
+            "(apropos 'symbol)",
+            "(apropos :keyword)",
+            "(apropos {})",
+        ];
+
+        utils::assert_results_are_invalid_argument_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use crate::describe_name;
+use crate::EnvironmentId;
+use crate::Error;
+use crate::Interpreter;
+use crate::Value;
+
+use crate::library;
+
+pub fn describe(
+    interpreter: &mut Interpreter,
+    environment_id: EnvironmentId,
+    values: Vec<Value>,
+) -> Result<Value, Error> {
+    if values.len() != 1 {
+        return Error::invalid_argument_count_error(
+            "Built-in function `describe' takes one argument exactly.",
+        )
+        .into();
+    }
+
+    let mut values = values;
+
+    let symbol_id = library::read_string_keyword_or_symbol_as_symbol_id(
+        interpreter,
+        values.remove(0),
+    )?;
+    let name = interpreter.get_symbol_name(symbol_id)?.clone();
+
+    let description = match describe_name(interpreter, environment_id, &name)? {
+        Some(description) => description,
+        None => return Ok(interpreter.intern_nil_symbol_value()),
+    };
+
+    let name_value = interpreter.intern_string_value(description.get_name());
+    let kind_value = interpreter.intern_keyword_value(description.get_kind());
+    let signature_value = match description.get_signature() {
+        Some(signature) => interpreter.intern_string_value(signature),
+        None => interpreter.intern_nil_symbol_value(),
+    };
+    let documentation_value = match description.get_documentation() {
+        Some(documentation) => interpreter.intern_string_value(documentation),
+        None => interpreter.intern_nil_symbol_value(),
+    };
+
+    let object_id = interpreter.make_object();
+    let items = vec![
+        ("name", name_value),
+        ("kind", kind_value),
+        ("signature", signature_value),
+        ("doc", documentation_value),
+    ];
+
+    for (key, value) in items {
+        let key_symbol_id = interpreter.intern_symbol_id(key);
+
+        interpreter.set_object_property(object_id, key_symbol_id, value)?;
+    }
+
+    Ok(Value::Object(object_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[allow(unused_imports)]
+    use crate::utils;
+
+    #[test]
+    fn describes_builtins() {
+        let mut interpreter = Interpreter::new();
+
+        let pairs = vec![
+            ("(:name (describe \"list:nth\"))", "\"list:nth\""),
+            ("(:kind (describe \"list:nth\"))", ":builtin-function"),
+            (
+                "(:signature (describe \"list:nth\"))",
+                "\"(list:nth index list)\"",
+            ),
+            (
+                "(:doc (describe \"list:nth\"))",
+                "\"Returns the item of list at index.\"",
+            ),
+            ("(:kind (describe \"cond\"))", ":special-form"),
+            ("(:kind (describe :defn))", ":macro"),
+            (
+                "(:signature (describe :defn))",
+                "\"(defn name (arguments) #rest forms)\"",
+            ),
+            ("(:kind (describe \"func:bind\"))", ":function"),
+        ];
+
+        utils::assert_results_are_equal(&mut interpreter, pairs);
+    }
+
+    #[test]
+    fn describes_user_definitions() {
+        let mut interpreter = Interpreter::new();
+
+        let pairs = vec![
+            ("(defn f (a #opt b) \"Function.\" a) (:signature (describe 'f))", "\"(f a #opt b)\""),
+            ("(:doc (describe 'f))", "\"Function.\""),
+            ("(defm m (#keys a) a) (:kind (describe 'm))", ":macro"),
+            ("(defv v 1 \"Variable.\") (:kind (describe 'v))", ":variable"),
+            ("(:doc (describe 'v))", "\"Variable.\""),
+            ("(:signature (describe 'v))", "nil"),
+            ("(describe 'not-defined)", "nil"),
+        ];
+
+        utils::assert_results_are_equal(&mut interpreter, pairs);
+    }
+
+    #[test]
+    fn returns_invalid_argument_count_error_when_argument_count_is_not_correct()
+    {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec!["(describe)", "(describe 'a 'b)"];
+
+        utils::assert_results_are_invalid_argument_count_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+
+    #[test]
+    fn returns_invalid_argument_error_when_invalid_arguments_were_provided() {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec![
+            "(describe 1)",
+            "(describe 1.1)",
+            "(describe #t)",
+            "(describe {})",
+            "(describe #(+ %1 %2))",
+        ];
+
+        utils::assert_results_are_invalid_argument_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+}
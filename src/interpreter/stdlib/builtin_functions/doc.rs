@@ -0,0 +1,113 @@
+use crate::describe_name;
+use crate::get_function_documentation;
+use crate::EnvironmentId;
+use crate::Error;
+use crate::Interpreter;
+use crate::Value;
+
+use crate::library;
+
+pub fn doc(
+    interpreter: &mut Interpreter,
+    environment_id: EnvironmentId,
+    values: Vec<Value>,
+) -> Result<Value, Error> {
+    if values.len() != 1 {
+        return Error::invalid_argument_count_error(
+            "Built-in function `doc' takes one argument exactly.",
+        )
+        .into();
+    }
+
+    let mut values = values;
+
+    let documentation = match values.remove(0) {
+        Value::Function(function_id) => {
+            get_function_documentation(interpreter, function_id)?
+        },
+        value => {
+            let symbol_id =
+                library::read_string_keyword_or_symbol_as_symbol_id(
+                    interpreter,
+                    value,
+                )?;
+            let name = interpreter.get_symbol_name(symbol_id)?.clone();
+
+            describe_name(interpreter, environment_id, &name)?.and_then(
+                |description| description.get_documentation().cloned(),
+            )
+        },
+    };
+
+    let result = match documentation {
+        Some(documentation) => interpreter.intern_string_value(documentation),
+        None => interpreter.intern_nil_symbol_value(),
+    };
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[allow(unused_imports)]
+    use crate::utils;
+
+    #[test]
+    fn returns_documentation_of_builtins() {
+        let mut interpreter = Interpreter::new();
+
+        let pairs = vec![
+            ("(doc \"list:nth\")", "\"Returns the item of list at index.\""),
+            ("(doc 'quote)", "\"Returns value without evaluating it. `'value' is a shorthand.\""),
+            ("(doc :not)", "\"Returns the negation of boolean.\""),
+        ];
+
+        utils::assert_results_are_equal(&mut interpreter, pairs);
+    }
+
+    #[test]
+    fn returns_documentation_of_user_definitions() {
+        let mut interpreter = Interpreter::new();
+
+        let pairs = vec![
+            ("(defn f () \"Function.\" 1) (doc \"f\")", "\"Function.\""),
+            ("(doc (fn () \"Lambda.\" 1))", "\"Lambda.\""),
+            ("(defv v 1 \"Variable.\") (doc 'v)", "\"Variable.\""),
+            ("(let ((v 2)) (doc 'v))", "nil"),
+            ("(defn g () 1) (doc 'g)", "nil"),
+            ("(doc 'not-defined)", "nil"),
+        ];
+
+        utils::assert_results_are_equal(&mut interpreter, pairs);
+    }
+
+    #[test]
+    fn returns_invalid_argument_count_error_when_argument_count_is_not_correct()
+    {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec!["(doc)", "(doc 'a 'b)"];
+
+        utils::assert_results_are_invalid_argument_count_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+
+    #[test]
+    fn returns_invalid_argument_error_when_invalid_arguments_were_provided() {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec!["(doc 1)", "(doc 1.1)", "(doc #t)", "(doc {})"];
+
+        utils::assert_results_are_invalid_argument_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use crate::interpreter::environment::EnvironmentId;
+use crate::interpreter::error::Error;
+use crate::interpreter::interpreter::Interpreter;
+use crate::interpreter::value::Value;
+
+use crate::interpreter::library;
+
+pub fn instance_of_question(
+    interpreter: &mut Interpreter,
+    _environment_id: EnvironmentId,
+    values: Vec<Value>,
+) -> Result<Value, Error> {
+    if values.len() != 2 {
+        return Error::invalid_argument_count_error(
+            "Built-in function `instance-of?' takes two arguments exactly.",
+        )
+        .into();
+    }
+
+    let mut values = values;
+
+    let value = values.remove(0);
+    let class_id = library::read_as_object_id(values.remove(0))?;
+
+    let result = match value {
+        Value::Object(object_id) => {
+            library::is_instance_of(interpreter, object_id, class_id)?
+        },
+        _ => false,
+    };
+
+    Ok(Value::Boolean(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[allow(unused_imports)]
+    use crate::utils;
+
+    #[test]
+    fn checks_if_value_is_instance_of_class() {
+        let mut interpreter = Interpreter::new();
+
+        let pairs = vec![
+            ("(defv c1 {}) (instance-of? (new c1) c1)", "#t"),
+            ("(defv c2 {}) (object:set-proto! c2 c1) (instance-of? (new c2) c1)", "#t"),
+            ("(instance-of? (new c1) c2)", "#f"),
+            ("(instance-of? c1 c1)", "#f"),
+            ("(instance-of? {} c1)", "#f"),
+            ("(instance-of? 1 c1)", "#f"),
+            ("(instance-of? \"string\" c1)", "#f"),
+            ("(instance-of? 'symbol c1)", "#f"),
+        ];
+
+        utils::assert_results_are_equal(&mut interpreter, pairs);
+    }
+
+    #[test]
+    fn returns_invalid_argument_count_error_when_incorrect_count_of_arguments_were_provided(
+    ) {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec![
+            "(instance-of?)",
+            "(instance-of? {})",
+            "(instance-of? {} {} {})",
+        ];
+
+        utils::assert_results_are_invalid_argument_count_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+
+    #[test]
+    fn returns_invalid_argument_error_when_class_is_not_an_object() {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec![
+            "(instance-of? {} 1)",
+            "(instance-of? {} 1.1)",
+            "(instance-of? {} #t)",
+            "(instance-of? {} \"string\")",
+            "(instance-of? {} :keyword)",
+            "(instance-of? {} 'symbol)",
+            "(instance-of? {} '(1 2))",
+            "(instance-of? {} #(+ %1 %2))",
+        ];
+
+        utils::assert_results_are_invalid_argument_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+}
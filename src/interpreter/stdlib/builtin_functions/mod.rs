@@ -6,9 +6,13 @@ use crate::library;
 
 mod _println;
 mod _type;
+mod apropos;
 mod assert;
 mod dec;
+mod describe;
+mod display;
 mod div;
+mod doc;
 mod eq_question;
 mod equal_question;
 mod eval;
@@ -17,6 +21,7 @@ mod ge;
 mod gensym;
 mod gt;
 mod inc;
+mod instance_of_question;
 mod intern;
 mod le;
 mod lookup;
@@ -24,47 +29,59 @@ mod lt;
 mod mul;
 mod neq_question;
 mod nequal_question;
+mod new;
 mod not;
+mod pprint;
 mod rem;
 mod string;
 mod sub;
 mod sum;
+mod write;
 
-pub fn infect(interpreter: &mut Interpreter) -> Result<(), Error> {
-    let pairs: Vec<(&str, BuiltinFunctionType)> = vec![
-        ("assert", assert::assert),
-        ("string", string::string),
-        ("flookup", flookup::flookup),
-        ("gensym", gensym::gensym),
-        ("intern", intern::intern),
-        ("lookup", lookup::lookup),
-        ("/", div::div),
-        ("*", mul::mul),
-        ("%", rem::rem),
-        ("-", sub::sub),
-        ("+", sum::sum),
-        ("not", not::not),
-        ("eq?", eq_question::eq_question),
-        ("equal?", equal_question::equal_question),
-        ("neq?", neq_question::neq_question),
-        ("nequal?", nequal_question::nequal_question),
-        ("=", eq_question::eq_question),
-        ("==", equal_question::equal_question),
-        ("!=", neq_question::neq_question),
-        ("!==", nequal_question::nequal_question),
-        ("<", lt::lt),
-        ("<=", le::le),
-        (">", gt::gt),
-        (">=", ge::ge),
-        ("dec", dec::dec),
-        ("inc", inc::inc),
-        ("eval", eval::eval),
-        ("type", _type::_type),
-        ("println", _println::_println),
-    ];
+pub const BUILTIN_FUNCTIONS: &[(&str, BuiltinFunctionType)] = &[
+    ("assert", assert::assert),
+    ("string", string::string),
+    ("flookup", flookup::flookup),
+    ("gensym", gensym::gensym),
+    ("intern", intern::intern),
+    ("lookup", lookup::lookup),
+    ("/", div::div),
+    ("*", mul::mul),
+    ("%", rem::rem),
+    ("-", sub::sub),
+    ("+", sum::sum),
+    ("not", not::not),
+    ("eq?", eq_question::eq_question),
+    ("equal?", equal_question::equal_question),
+    ("neq?", neq_question::neq_question),
+    ("nequal?", nequal_question::nequal_question),
+    ("=", eq_question::eq_question),
+    ("==", equal_question::equal_question),
+    ("!=", neq_question::neq_question),
+    ("!==", nequal_question::nequal_question),
+    ("<", lt::lt),
+    ("<=", le::le),
+    (">", gt::gt),
+    (">=", ge::ge),
+    ("dec", dec::dec),
+    ("inc", inc::inc),
+    ("eval", eval::eval),
+    ("type", _type::_type),
+    ("new", new::new),
+    ("instance-of?", instance_of_question::instance_of_question),
+    ("println", _println::_println),
+    ("print", write::write),
+    ("pprint", pprint::pprint),
+    ("write", write::write),
+    ("display", display::display),
+    ("doc", doc::doc),
+    ("describe", describe::describe),
+    ("apropos", apropos::apropos),
+];
 
-    for (name, func) in pairs {
-        library::infect_builtin_function(interpreter, name, func)?;
+pub fn infect(interpreter: &mut Interpreter) -> Result<(), Error> {
+    for (name, func) in BUILTIN_FUNCTIONS {
+        library::infect_builtin_function(interpreter, name, *func)?;
     }
 
     Ok(())
@@ -0,0 +1,103 @@
+use crate::interpreter::environment::EnvironmentId;
+use crate::interpreter::error::Error;
+use crate::interpreter::interpreter::Interpreter;
+use crate::interpreter::value::Value;
+
+use crate::interpreter::library;
+
+pub fn new(
+    interpreter: &mut Interpreter,
+    environment_id: EnvironmentId,
+    values: Vec<Value>,
+) -> Result<Value, Error> {
+    if values.len() < 1 {
+        return Error::invalid_argument_count_error(
+            "Built-in function `new' takes one argument at least.",
+        )
+        .into();
+    }
+
+    let mut values = values;
+
+    let class_id = library::read_as_object_id(values.remove(0))?;
+    let arguments = values;
+
+    let instance_id = library::make_instance(
+        interpreter,
+        environment_id,
+        class_id,
+        arguments,
+    )?;
+
+    Ok(instance_id.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[allow(unused_imports)]
+    use crate::utils;
+
+    #[test]
+    fn makes_instances_of_classes() {
+        let mut interpreter = Interpreter::new();
+
+        let pairs = vec![
+            ("(defv c1 {}) (object:get-proto (new c1))", "c1"),
+            (
+                "(defv c2 {:constructor (fn (a b) (object:set! this :a (+ a b)))}) (object:get (new c2 1 2) :a)",
+                "3",
+            ),
+            (
+                "(defv c3 {}) (object:set-proto! c3 c2) (object:get (new c3 2 3) :a)",
+                "5",
+            ),
+        ];
+
+        utils::assert_results_are_equal(&mut interpreter, pairs);
+    }
+
+    #[test]
+    fn returns_invalid_argument_count_error_when_incorrect_count_of_arguments_were_provided(
+    ) {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec![
+            "(new)",
+            "(new {} 1)",
+            "(new {:constructor (fn (a) a)})",
+            "(new {:constructor (fn (a) a)} 1 2)",
+        ];
+
+        utils::assert_results_are_invalid_argument_count_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+
+    #[test]
+    fn returns_invalid_argument_error_when_incorrect_arguments_were_provided() {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec![
+            "(new 1)",
+            "(new 1.1)",
+            "(new #t)",
+            "(new \"string\")",
+            "(new :keyword)",
+            "(new 'symbol)",
+            "(new '(1 2))",
+            "(new #(+ %1 %2))",
+            "(new {:constructor 1})",
+        ];
+
+        utils::assert_results_are_invalid_argument_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+}
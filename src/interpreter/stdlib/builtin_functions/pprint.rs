@@ -0,0 +1,90 @@
+use std::io::Write;
+
+use crate::interpreter::environment::EnvironmentId;
+use crate::interpreter::error::Error;
+use crate::interpreter::interpreter::Interpreter;
+use crate::interpreter::value::Value;
+
+use crate::interpreter::library;
+
+pub fn pprint(
+    interpreter: &mut Interpreter,
+    _environment: EnvironmentId,
+    values: Vec<Value>,
+) -> Result<Value, Error> {
+    if values.len() < 1 || values.len() > 2 {
+        return Error::invalid_argument_count_error(
+            "Built-in function `pprint' takes one or two arguments.",
+        )
+        .into();
+    }
+
+    let mut values = values;
+
+    let value = values.remove(0);
+    let width = if values.len() > 0 {
+        library::read_as_positive_i64(values.remove(0))? as usize
+    } else {
+        library::DEFAULT_PRETTY_PRINT_WIDTH
+    };
+
+    let string = library::value_to_pretty_string(interpreter, value, width)?;
+
+    println!("{}", string);
+    std::io::stdout()
+        .flush()
+        .map_err(|_| Error::generic_execution_error("Cannot flush stdout."))?;
+
+    Ok(interpreter.intern_nil_symbol_value())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[allow(unused_imports)]
+    use crate::utils;
+
+    #[test]
+    fn returns_nil() {
+        let mut interpreter = Interpreter::new();
+
+        let pairs = vec![
+            ("(pprint 1)", "nil"),
+            ("(pprint '(defn f (a b) (+ a b)))", "nil"),
+            ("(pprint '(defn f (a b) (+ a b)) 10)", "nil"),
+            ("(pprint {:a '(1 2 3)} 4)", "nil"),
+        ];
+
+        utils::assert_results_are_equal(&mut interpreter, pairs);
+    }
+
+    #[test]
+    fn returns_invalid_argument_error_when_width_is_not_positive_integer() {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector =
+            vec!["(pprint 1 0)", "(pprint 1 -1)", "(pprint 1 1.5)"];
+
+        utils::assert_results_are_invalid_argument_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+
+    #[test]
+    fn returns_invalid_argument_count_error_when_incorrect_count_of_arguments_were_provided(
+    ) {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec!["(pprint)", "(pprint 1 2 3)"];
+
+        utils::assert_results_are_invalid_argument_count_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+}
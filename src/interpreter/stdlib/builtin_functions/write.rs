@@ -0,0 +1,71 @@
+use std::io::Write;
+
+use crate::interpreter::environment::EnvironmentId;
+use crate::interpreter::error::Error;
+use crate::interpreter::interpreter::Interpreter;
+use crate::interpreter::value::Value;
+
+use crate::interpreter::library;
+
+pub fn write(
+    interpreter: &mut Interpreter,
+    _environment: EnvironmentId,
+    values: Vec<Value>,
+) -> Result<Value, Error> {
+    if values.len() != 1 {
+        return Error::invalid_argument_count_error(
+            "Built-in function `write' takes exactly one argument.",
+        )
+        .into();
+    }
+
+    let mut values = values;
+
+    let string =
+        library::value_to_readable_string(interpreter, values.remove(0))?;
+
+    print!("{}", string);
+    std::io::stdout()
+        .flush()
+        .map_err(|_| Error::generic_execution_error("Cannot flush stdout."))?;
+
+    Ok(interpreter.intern_nil_symbol_value())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[allow(unused_imports)]
+    use crate::utils;
+
+    #[test]
+    fn returns_nil() {
+        let mut interpreter = Interpreter::new();
+
+        let pairs = vec![
+            ("(write 1)", "nil"),
+            ("(write \"string\")", "nil"),
+            ("(write '(a \"b\" :c))", "nil"),
+            ("(write {:a 1})", "nil"),
+        ];
+
+        utils::assert_results_are_equal(&mut interpreter, pairs);
+    }
+
+    #[test]
+    fn returns_invalid_argument_count_error_when_incorrect_count_of_arguments_were_provided(
+    ) {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec!["(write)", "(write 1 2)"];
+
+        utils::assert_results_are_invalid_argument_count_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+}
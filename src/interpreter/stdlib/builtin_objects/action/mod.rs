@@ -21,48 +21,56 @@ mod send_text_type;
 
 mod send_wait;
 
+mod pressed_keys;
+mod pressed_mouse_buttons;
+
+pub const BUILTIN_FUNCTIONS: &[(&str, BuiltinFunctionType)] = &[
+    ("send-key-press", send_key_press::send_key_press),
+    ("send-key-click", send_key_click::send_key_click),
+    ("send-key-release", send_key_release::send_key_release),
+    (
+        "send-mouse-button-press",
+        send_mouse_button_press::send_mouse_button_press,
+    ),
+    (
+        "send-mouse-button-click",
+        send_mouse_button_click::send_mouse_button_click,
+    ),
+    (
+        "send-mouse-button-release",
+        send_mouse_button_release::send_mouse_button_release,
+    ),
+    (
+        "send-mouse-relative-move",
+        send_mouse_relative_move::send_mouse_relative_move,
+    ),
+    (
+        "send-mouse-absolute-move",
+        send_mouse_absolute_move::send_mouse_absolute_move,
+    ),
+    ("send-text-type", send_text_type::send_text_type),
+    (
+        "send-execute-os-command",
+        send_execute_os_command::send_execute_os_command,
+    ),
+    ("send-wait", send_wait::send_wait),
+    ("pressed-keys", pressed_keys::pressed_keys),
+    (
+        "pressed-mouse-buttons",
+        pressed_mouse_buttons::pressed_mouse_buttons,
+    ),
+];
+
 pub fn infect(interpreter: &mut Interpreter) -> Result<(), Error> {
     let action_object_id = interpreter.make_object();
     let action_symbol_id = interpreter.intern_symbol_id("action");
 
-    let bindings: Vec<(&str, BuiltinFunctionType)> = vec![
-        ("send-key-press", send_key_press::send_key_press),
-        ("send-key-click", send_key_click::send_key_click),
-        ("send-key-release", send_key_release::send_key_release),
-        (
-            "send-mouse-button-press",
-            send_mouse_button_press::send_mouse_button_press,
-        ),
-        (
-            "send-mouse-button-click",
-            send_mouse_button_click::send_mouse_button_click,
-        ),
-        (
-            "send-mouse-button-release",
-            send_mouse_button_release::send_mouse_button_release,
-        ),
-        (
-            "send-mouse-relative-move",
-            send_mouse_relative_move::send_mouse_relative_move,
-        ),
-        (
-            "send-mouse-absolute-move",
-            send_mouse_absolute_move::send_mouse_absolute_move,
-        ),
-        ("send-text-type", send_text_type::send_text_type),
-        (
-            "send-execute-os-command",
-            send_execute_os_command::send_execute_os_command,
-        ),
-        ("send-wait", send_wait::send_wait),
-    ];
-
-    for (name, func) in bindings {
+    for (name, func) in BUILTIN_FUNCTIONS {
         library::infect_object_builtin_function(
             interpreter,
             action_object_id,
             name,
-            func,
+            *func,
         )?;
     }
 
@@ -0,0 +1,83 @@
+use crate::interpreter::environment::EnvironmentId;
+use crate::interpreter::error::Error;
+use crate::interpreter::interpreter::Interpreter;
+use crate::interpreter::value::Value;
+
+pub fn pressed_keys(
+    interpreter: &mut Interpreter,
+    _environment_id: EnvironmentId,
+    values: Vec<Value>,
+) -> Result<Value, Error> {
+    if values.len() != 0 {
+        return Error::invalid_argument_count_error(
+            "Built-in function `action:pressed-keys' takes no arguments.",
+        )
+        .into();
+    }
+
+    let key_codes = interpreter
+        .get_pressed_inputs()
+        .get_key_codes()
+        .into_iter()
+        .map(|key_code| Value::Integer(key_code as i64))
+        .collect();
+
+    Ok(interpreter.vec_to_list(key_codes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[allow(unused_imports)]
+    use crate::utils;
+
+    use crate::Action;
+
+    #[test]
+    fn returns_pressed_key_codes() {
+        let mut interpreter = Interpreter::new();
+
+        let pairs = vec![("(action:pressed-keys)", "'()")];
+
+        utils::assert_results_are_equal(&mut interpreter, pairs);
+
+        interpreter
+            .get_pressed_inputs_mut()
+            .register_action(&Action::KeyPress(42));
+        interpreter
+            .get_pressed_inputs_mut()
+            .register_action(&Action::KeyPress(29));
+        interpreter
+            .get_pressed_inputs_mut()
+            .register_action(&Action::MouseButtonPress(272));
+
+        let pairs = vec![("(action:pressed-keys)", "'(29 42)")];
+
+        utils::assert_results_are_equal(&mut interpreter, pairs);
+
+        interpreter
+            .get_pressed_inputs_mut()
+            .register_action(&Action::KeyRelease(29));
+
+        let pairs = vec![("(action:pressed-keys)", "'(42)")];
+
+        utils::assert_results_are_equal(&mut interpreter, pairs);
+    }
+
+    #[test]
+    fn returns_invalid_argument_count_error_when_incorrect_count_of_arguments_were_passed(
+    ) {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec!["(action:pressed-keys 1)"];
+
+        utils::assert_results_are_invalid_argument_count_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+}
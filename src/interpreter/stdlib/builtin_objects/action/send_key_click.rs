@@ -2,6 +2,7 @@ use crate::interpreter::environment::EnvironmentId;
 use crate::interpreter::error::Error;
 use crate::interpreter::interpreter::Interpreter;
 use crate::interpreter::value::Value;
+use crate::Capability;
 use crate::PRIMITIVE_ACTIONS_VARIABLE_NAME;
 
 use crate::interpreter::library;
@@ -18,6 +19,8 @@ pub fn send_key_click(
         .into();
     }
 
+    interpreter.check_capability(Capability::UInputActions)?;
+
     let mut values = values;
 
     let key_code = match values.remove(0) {
@@ -104,4 +107,16 @@ mod tests {
             code_vector,
         );
     }
+
+    #[test]
+    fn returns_permission_denied_error_when_capability_is_not_granted() {
+        let mut interpreter = Interpreter::sandboxed();
+
+        let code_vector = vec!["(action:send-key-click 2)"];
+
+        utils::assert_results_are_permission_denied_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
 }
@@ -4,6 +4,7 @@ use crate::interpreter::interpreter::Interpreter;
 use crate::interpreter::value::Value;
 
 use crate::interpreter::library;
+use crate::Capability;
 use crate::PRIMITIVE_ACTIONS_VARIABLE_NAME;
 
 pub fn send_mouse_button_click(
@@ -18,6 +19,8 @@ pub fn send_mouse_button_click(
         .into();
     }
 
+    interpreter.check_capability(Capability::UInputActions)?;
+
     let mut values = values;
 
     let key_code = match values.remove(0) {
@@ -112,4 +115,16 @@ mod tests {
             code_vector,
         );
     }
+
+    #[test]
+    fn returns_permission_denied_error_when_capability_is_not_granted() {
+        let mut interpreter = Interpreter::sandboxed();
+
+        let code_vector = vec!["(action:send-mouse-button-click 2)"];
+
+        utils::assert_results_are_permission_denied_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
 }
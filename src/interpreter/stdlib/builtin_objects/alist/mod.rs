@@ -12,25 +12,25 @@ mod has_value_question;
 mod lookup;
 mod new;
 
+pub const BUILTIN_FUNCTIONS: &[(&str, BuiltinFunctionType)] = &[
+    ("acons", acons::acons),
+    ("acons!", acons_mark::acons_mark),
+    ("has-key?", has_key_question::has_key_question),
+    ("has-value?", has_value_question::has_value_question),
+    ("lookup", lookup::lookup),
+    ("new", new::new),
+];
+
 pub fn infect(interpreter: &mut Interpreter) -> Result<(), Error> {
     let alist_object_id = interpreter.make_object();
     let alist_symbol_id = interpreter.intern_symbol_id("alist");
 
-    let bindings: Vec<(&str, BuiltinFunctionType)> = vec![
-        ("acons", acons::acons),
-        ("acons!", acons_mark::acons_mark),
-        ("has-key?", has_key_question::has_key_question),
-        ("has-value?", has_value_question::has_value_question),
-        ("lookup", lookup::lookup),
-        ("new", new::new),
-    ];
-
-    for (name, func) in bindings {
+    for (name, func) in BUILTIN_FUNCTIONS {
         library::infect_object_builtin_function(
             interpreter,
             alist_object_id,
             name,
-            func,
+            *func,
         )?;
     }
 
@@ -16,29 +16,29 @@ mod shift_right;
 mod test;
 mod xor;
 
+pub const BUILTIN_FUNCTIONS: &[(&str, BuiltinFunctionType)] = &[
+    ("and", and::and),
+    ("clear", clear::clear),
+    ("flip", flip::flip),
+    ("not", not::not),
+    ("or", or::or),
+    ("set", set::set),
+    ("shift-left", shift_left::shift_left),
+    ("shift-right", shift_right::shift_right),
+    ("test", test::test),
+    ("xor", xor::xor),
+];
+
 pub fn infect(interpreter: &mut Interpreter) -> Result<(), Error> {
     let bit_object_id = interpreter.make_object();
     let bit_symbol_id = interpreter.intern_symbol_id("bit");
 
-    let bindings: Vec<(&str, BuiltinFunctionType)> = vec![
-        ("and", and::and),
-        ("clear", clear::clear),
-        ("flip", flip::flip),
-        ("not", not::not),
-        ("or", or::or),
-        ("set", set::set),
-        ("shift-left", shift_left::shift_left),
-        ("shift-right", shift_right::shift_right),
-        ("test", test::test),
-        ("xor", xor::xor),
-    ];
-
-    for (name, func) in bindings {
+    for (name, func) in BUILTIN_FUNCTIONS {
         library::infect_object_builtin_function(
             interpreter,
             bit_object_id,
             name,
-            func,
+            *func,
         )?;
     }
 
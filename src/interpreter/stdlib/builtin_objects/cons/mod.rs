@@ -11,24 +11,24 @@ mod new;
 mod set_car_mark;
 mod set_cdr_mark;
 
+pub const BUILTIN_FUNCTIONS: &[(&str, BuiltinFunctionType)] = &[
+    ("new", new::new),
+    ("car", car::car),
+    ("cdr", cdr::cdr),
+    ("set-car!", set_car_mark::set_car_mark),
+    ("set-cdr!", set_cdr_mark::set_cdr_mark),
+];
+
 pub fn infect(interpreter: &mut Interpreter) -> Result<(), Error> {
     let cons_object_id = interpreter.make_object();
     let cons_symbol_id = interpreter.intern_symbol_id("cons");
 
-    let bindings: Vec<(&str, BuiltinFunctionType)> = vec![
-        ("new", new::new),
-        ("car", car::car),
-        ("cdr", cdr::cdr),
-        ("set-car!", set_car_mark::set_car_mark),
-        ("set-cdr!", set_cdr_mark::set_cdr_mark),
-    ];
-
-    for (name, func) in bindings {
+    for (name, func) in BUILTIN_FUNCTIONS {
         library::infect_object_builtin_function(
             interpreter,
             cons_object_id,
             name,
-            func,
+            *func,
         )?;
     }
 
@@ -0,0 +1,102 @@
+use crate::EnvironmentId;
+use crate::Error;
+use crate::Interpreter;
+use crate::Value;
+
+use crate::library;
+
+use super::define_global_mapping::string_to_key_chord;
+
+pub fn define_emergency_stop_chord(
+    interpreter: &mut Interpreter,
+    _environment_id: EnvironmentId,
+    values: Vec<Value>,
+) -> Result<Value, Error> {
+    if values.len() != 1 {
+        return Error::invalid_argument_count_error(
+            "Built-in function `device:define-emergency-stop-chord' takes one argument exactly.",
+        )
+        .into();
+    }
+
+    let key_chord_part = library::read_as_string(interpreter, values[0])?;
+    let key_chord = string_to_key_chord(key_chord_part.trim())?;
+
+    library::define_emergency_stop_key_chord(interpreter, &key_chord)?;
+
+    Ok(interpreter.intern_nil_symbol_value())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[allow(unused_imports)]
+    use crate::utils;
+    use crate::EMERGENCY_STOP_KEY_CHORD_ROOT_VARIABLE_NAME;
+
+    #[test]
+    fn defines_emergency_stop_chord() {
+        let mut interpreter = Interpreter::new();
+
+        let pairs = vec![
+            (EMERGENCY_STOP_KEY_CHORD_ROOT_VARIABLE_NAME, "nil"),
+            ("(device:define-emergency-stop-chord \"CtrlL+c\")", "nil"),
+            (EMERGENCY_STOP_KEY_CHORD_ROOT_VARIABLE_NAME, "'(29 46)"),
+            (
+                "(device:define-emergency-stop-chord \"0:CtrlL+0:b\")",
+                "nil",
+            ),
+            (
+                EMERGENCY_STOP_KEY_CHORD_ROOT_VARIABLE_NAME,
+                "'((0 29) (0 48))",
+            ),
+        ];
+
+        utils::assert_results_are_equal(&mut interpreter, pairs)
+    }
+
+    #[test]
+    fn returns_invalid_argument_error_when_invalid_arguments_were_passed() {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec![
+            "(device:define-emergency-stop-chord 1)",
+            "(device:define-emergency-stop-chord 1.1)",
+            "(device:define-emergency-stop-chord #t)",
+            "(device:define-emergency-stop-chord #f)",
+            "(device:define-emergency-stop-chord :keyword)",
+            "(device:define-emergency-stop-chord 'symbol)",
+            "(device:define-emergency-stop-chord '(1 2))",
+            "(device:define-emergency-stop-chord {})",
+            "(device:define-emergency-stop-chord #())",
+            r#"(device:define-emergency-stop-chord "")"#,
+            r#"(device:define-emergency-stop-chord "unexistingkey")"#,
+            r#"(device:define-emergency-stop-chord "a++")"#,
+        ];
+
+        utils::assert_results_are_invalid_argument_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+
+    #[test]
+    fn returns_invalid_argument_count_error_when_incorrect_count_of_arguments_were_passed(
+    ) {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec![
+            "(device:define-emergency-stop-chord)",
+            "(device:define-emergency-stop-chord \"q\" \"q\")",
+        ];
+
+        utils::assert_results_are_invalid_argument_count_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+}
@@ -9,7 +9,7 @@ use crate::Value;
 
 use crate::library;
 
-fn string_to_key(s: &str) -> Result<Key, Error> {
+pub fn string_to_key(s: &str) -> Result<Key, Error> {
     let parts = s.split(":").collect::<Vec<&str>>();
 
     match parts.len() {
@@ -38,7 +38,7 @@ fn string_to_key(s: &str) -> Result<Key, Error> {
     }
 }
 
-fn string_to_key_chord(s: &str) -> Result<KeyChord, Error> {
+pub fn string_to_key_chord(s: &str) -> Result<KeyChord, Error> {
     let mut keys = s
         .split("+")
         .map(string_to_key)
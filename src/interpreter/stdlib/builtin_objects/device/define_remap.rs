@@ -0,0 +1,117 @@
+use crate::EnvironmentId;
+use crate::Error;
+use crate::Interpreter;
+use crate::KeyRemap;
+use crate::Value;
+
+use crate::library;
+
+use super::define_global_mapping::string_to_key;
+
+pub fn define_remap(
+    interpreter: &mut Interpreter,
+    _environment_id: EnvironmentId,
+    values: Vec<Value>,
+) -> Result<Value, Error> {
+    if values.len() != 2 {
+        return Error::invalid_argument_count_error(
+            "Built-in function `device:define-remap' takes two arguments exactly.",
+        )
+        .into();
+    }
+
+    let from =
+        string_to_key(library::read_as_string(interpreter, values[0])?.trim())?;
+    let to =
+        string_to_key(library::read_as_string(interpreter, values[1])?.trim())?;
+
+    library::define_key_remap(interpreter, &KeyRemap::new(from, to))?;
+
+    Ok(interpreter.intern_nil_symbol_value())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[allow(unused_imports)]
+    use crate::utils;
+    use crate::DEFINED_REMAPS_ROOT_VARIABLE_NAME;
+
+    #[test]
+    fn defines_new_remaps() {
+        let mut interpreter = Interpreter::new();
+
+        let pairs = vec![
+            (DEFINED_REMAPS_ROOT_VARIABLE_NAME, "'()"),
+            ("(device:define-remap \"0:b\" \"CtrlL\")", "nil"),
+            (DEFINED_REMAPS_ROOT_VARIABLE_NAME, "'(((0 48) 29))"),
+            ("(device:define-remap \"c\" \"1:q\")", "nil"),
+            (
+                DEFINED_REMAPS_ROOT_VARIABLE_NAME,
+                "'((46 (1 16)) ((0 48) 29))",
+            ),
+        ];
+
+        utils::assert_results_are_equal(&mut interpreter, pairs)
+    }
+
+    #[test]
+    fn returns_generic_execution_error_when_key_is_already_remapped() {
+        let mut interpreter = Interpreter::new();
+
+        interpreter
+            .execute_in_main_environment("(device:define-remap \"0:b\" \"c\")")
+            .unwrap();
+
+        let code_vector = vec!["(device:define-remap \"0:b\" \"q\")"];
+
+        utils::assert_results_are_generic_execution_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+
+    #[test]
+    fn returns_invalid_argument_error_when_invalid_arguments_were_passed() {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec![
+            "(device:define-remap 1 \"b\")",
+            "(device:define-remap 1.1 \"b\")",
+            "(device:define-remap #t \"b\")",
+            "(device:define-remap :keyword \"b\")",
+            "(device:define-remap 'symbol \"b\")",
+            "(device:define-remap \"b\" 1)",
+            "(device:define-remap \"b\" '(list:new))",
+            "(device:define-remap \"b\" {})",
+            "(device:define-remap \"unknown-key\" \"b\")",
+            "(device:define-remap \"b\" \"a:b\")",
+        ];
+
+        utils::assert_results_are_invalid_argument_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+
+    #[test]
+    fn returns_invalid_argument_count_error_when_incorrect_count_of_arguments_were_passed(
+    ) {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec![
+            "(device:define-remap)",
+            "(device:define-remap \"b\")",
+            "(device:define-remap \"b\" \"c\" \"q\")",
+        ];
+
+        utils::assert_results_are_invalid_argument_count_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+}
@@ -0,0 +1,65 @@
+use crate::EnvironmentId;
+use crate::Error;
+use crate::Interpreter;
+use crate::Value;
+
+use crate::library;
+
+pub fn get_defined_remaps(
+    interpreter: &mut Interpreter,
+    _environment_id: EnvironmentId,
+    values: Vec<Value>,
+) -> Result<Value, Error> {
+    if values.len() != 0 {
+        return Error::invalid_argument_count_error(
+            "Built-in function `device:get-defined-remaps' takes no arguments.",
+        )
+        .into();
+    }
+
+    let key_remap_values = library::get_defined_key_remaps(interpreter)?
+        .iter()
+        .map(|key_remap| library::key_remap_to_list(interpreter, key_remap))
+        .collect::<Vec<Value>>();
+
+    Ok(interpreter.vec_to_list(key_remap_values))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[allow(unused_imports)]
+    use crate::utils;
+
+    #[test]
+    fn returns_defined_remaps() {
+        let mut interpreter = Interpreter::new();
+
+        let pairs = vec![
+            ("(device:get-defined-remaps)", "'()"),
+            ("(device:define-remap \"0:b\" \"CtrlL\")", "nil"),
+            ("(device:get-defined-remaps)", "'(((0 48) 29))"),
+            ("(device:define-remap \"c\" \"q\")", "nil"),
+            ("(device:get-defined-remaps)", "'((46 16) ((0 48) 29))"),
+        ];
+
+        utils::assert_results_are_equal(&mut interpreter, pairs)
+    }
+
+    #[test]
+    fn returns_invalid_argument_count_error_when_incorrect_count_of_arguments_were_passed(
+    ) {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec!["(device:get-defined-remaps 1)"];
+
+        utils::assert_results_are_invalid_argument_count_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+}
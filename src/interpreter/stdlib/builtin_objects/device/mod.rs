@@ -6,36 +6,47 @@ use crate::interpreter::value::Value;
 use crate::library;
 
 mod define;
+mod define_emergency_stop_chord;
 mod define_global_mapping;
 mod define_modifier;
+mod define_remap;
+mod get_defined_remaps;
 mod is_listening_question;
+mod remove_remap;
 mod start_listening;
 mod stop_listening;
 
+pub const BUILTIN_FUNCTIONS: &[(&str, BuiltinFunctionType)] = &[
+    (
+        "define-global-mapping",
+        define_global_mapping::define_global_mapping,
+    ),
+    ("define-modifier", define_modifier::define_modifier),
+    (
+        "define-emergency-stop-chord",
+        define_emergency_stop_chord::define_emergency_stop_chord,
+    ),
+    ("define-remap", define_remap::define_remap),
+    ("remove-remap", remove_remap::remove_remap),
+    ("get-defined-remaps", get_defined_remaps::get_defined_remaps),
+    ("define", define::define),
+    ("start-listening", start_listening::start_listening),
+    ("stop-listening", stop_listening::stop_listening),
+    (
+        "is-listening?",
+        is_listening_question::is_listening_question,
+    ),
+];
+
 pub fn infect(interpreter: &mut Interpreter) -> Result<(), Error> {
     let device_object_id = interpreter.make_object();
 
-    let bindings: Vec<(&str, BuiltinFunctionType)> = vec![
-        (
-            "define-global-mapping",
-            define_global_mapping::define_global_mapping,
-        ),
-        ("define-modifier", define_modifier::define_modifier),
-        ("define", define::define),
-        ("start-listening", start_listening::start_listening),
-        ("stop-listening", stop_listening::stop_listening),
-        (
-            "is-listening?",
-            is_listening_question::is_listening_question,
-        ),
-    ];
-
-    for (name, func) in bindings {
+    for (name, func) in BUILTIN_FUNCTIONS {
         library::infect_object_builtin_function(
             interpreter,
             device_object_id,
             name,
-            func,
+            *func,
         )?;
     }
 
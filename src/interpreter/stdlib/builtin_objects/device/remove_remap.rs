@@ -0,0 +1,102 @@
+use crate::EnvironmentId;
+use crate::Error;
+use crate::Interpreter;
+use crate::Value;
+
+use crate::library;
+
+use super::define_global_mapping::string_to_key;
+
+pub fn remove_remap(
+    interpreter: &mut Interpreter,
+    _environment_id: EnvironmentId,
+    values: Vec<Value>,
+) -> Result<Value, Error> {
+    if values.len() != 1 {
+        return Error::invalid_argument_count_error(
+            "Built-in function `device:remove-remap' takes one argument exactly.",
+        )
+        .into();
+    }
+
+    let from =
+        string_to_key(library::read_as_string(interpreter, values[0])?.trim())?;
+
+    library::remove_key_remap(interpreter, from)?;
+
+    Ok(interpreter.intern_nil_symbol_value())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[allow(unused_imports)]
+    use crate::utils;
+    use crate::DEFINED_REMAPS_ROOT_VARIABLE_NAME;
+
+    #[test]
+    fn removes_remaps() {
+        let mut interpreter = Interpreter::new();
+
+        let pairs = vec![
+            ("(device:define-remap \"0:b\" \"CtrlL\")", "nil"),
+            ("(device:define-remap \"c\" \"q\")", "nil"),
+            ("(device:remove-remap \"0:b\")", "nil"),
+            (DEFINED_REMAPS_ROOT_VARIABLE_NAME, "'((46 16))"),
+            ("(device:remove-remap \"c\")", "nil"),
+            (DEFINED_REMAPS_ROOT_VARIABLE_NAME, "'()"),
+        ];
+
+        utils::assert_results_are_equal(&mut interpreter, pairs)
+    }
+
+    #[test]
+    fn returns_generic_execution_error_when_there_is_no_such_remap() {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec!["(device:remove-remap \"b\")"];
+
+        utils::assert_results_are_generic_execution_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+
+    #[test]
+    fn returns_invalid_argument_error_when_invalid_arguments_were_passed() {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec![
+            "(device:remove-remap 1)",
+            "(device:remove-remap 1.1)",
+            "(device:remove-remap #t)",
+            "(device:remove-remap :keyword)",
+            "(device:remove-remap 'symbol)",
+            "(device:remove-remap {})",
+            "(device:remove-remap \"unknown-key\")",
+        ];
+
+        utils::assert_results_are_invalid_argument_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+
+    #[test]
+    fn returns_invalid_argument_count_error_when_incorrect_count_of_arguments_were_passed(
+    ) {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector =
+            vec!["(device:remove-remap)", "(device:remove-remap \"b\" \"c\")"];
+
+        utils::assert_results_are_invalid_argument_count_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+}
@@ -66,4 +66,16 @@ mod tests {
 
         crate::utils::assert_generic_execution_error(&result);
     }
+
+    #[test]
+    fn returns_permission_denied_error_when_listening_control_is_not_granted() {
+        let mut interpreter = Interpreter::sandboxed();
+
+        let result = interpreter.execute_in_main_environment(
+            r#"(device:define 0 "/dev/input/event6" "first") (device:start-listening)"#,
+        );
+
+        crate::utils::assert_permission_denied_error(&result);
+        nia_assert(!interpreter.is_listening());
+    }
 }
@@ -0,0 +1,94 @@
+use crate::interpreter::environment::EnvironmentId;
+use crate::interpreter::error::Error;
+use crate::interpreter::interpreter::Interpreter;
+use crate::interpreter::value::Value;
+
+pub fn functions(
+    interpreter: &mut Interpreter,
+    environment_id: EnvironmentId,
+    values: Vec<Value>,
+) -> Result<Value, Error> {
+    if values.len() > 1 {
+        return Error::invalid_argument_count_error(
+            "Built-in function `env:functions' takes one optional argument.",
+        )
+        .into();
+    }
+
+    let environment_id = super::variables::read_environment_id(
+        interpreter,
+        environment_id,
+        values,
+    )?;
+
+    let symbol_ids = interpreter
+        .get_environment_arena()
+        .get_environment(environment_id)?
+        .get_functions()
+        .keys()
+        .cloned()
+        .collect();
+
+    super::variables::make_sorted_symbol_list(interpreter, symbol_ids)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[allow(unused_imports)]
+    use crate::utils;
+
+    #[test]
+    fn lists_functions_of_environments() {
+        let mut interpreter = Interpreter::new();
+
+        let pairs = vec![
+            ("(flet ((b () 1) (a () 2)) (env:functions))", "'(a b)"),
+            (
+                "(flet ((b () 1) (a () 2)) (env:functions :current))",
+                "'(a b)",
+            ),
+            (
+                "(defn main-b () 1) (defn main-a () 2) (env:functions :main)",
+                "'(main-a main-b)",
+            ),
+            ("(list:contains? 'defn (env:functions :root))", "#t"),
+            ("(list:contains? 'main-a (env:functions :root))", "#f"),
+        ];
+
+        utils::assert_results_are_equal(&mut interpreter, pairs);
+    }
+
+    #[test]
+    fn returns_invalid_argument_count_error_when_argument_count_is_not_correct()
+    {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec!["(env:functions :main :root)"];
+
+        utils::assert_results_are_invalid_argument_count_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+
+    #[test]
+    fn returns_invalid_argument_error_when_invalid_arguments_were_provided() {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec![
+            "(env:functions 1)",
+            "(env:functions \"main\")",
+            "(env:functions :parent)",
+        ];
+
+        utils::assert_results_are_invalid_argument_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use crate::interpreter::environment::EnvironmentId;
+use crate::interpreter::error::Error;
+use crate::interpreter::interpreter::Interpreter;
+use crate::interpreter::value::Value;
+
+use crate::library;
+
+pub fn is_const_variable_question(
+    interpreter: &mut Interpreter,
+    environment_id: EnvironmentId,
+    values: Vec<Value>,
+) -> Result<Value, Error> {
+    if values.len() != 1 {
+        return Error::invalid_argument_count_error(
+            "Built-in function `env:is-const-variable?' takes one argument exactly.",
+        )
+        .into();
+    }
+
+    let mut values = values;
+
+    let symbol_id = library::read_as_symbol_id(values.remove(0))?;
+
+    let defining_environment_id = match interpreter
+        .lookup_environment_by_variable(environment_id, symbol_id)?
+    {
+        Some(defining_environment_id) => defining_environment_id,
+        None => {
+            return Error::generic_execution_error("Cannot find variable.")
+                .into()
+        },
+    };
+
+    let is_const = interpreter
+        .get_environment_arena()
+        .get_environment(defining_environment_id)?
+        .get_variables()
+        .get(&symbol_id)
+        .map(|wrapper| !wrapper.is_writable())
+        .unwrap_or(false);
+
+    Ok(Value::Boolean(is_const))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[allow(unused_imports)]
+    use crate::utils;
+
+    #[test]
+    fn returns_true_for_const_variables() {
+        let mut interpreter = Interpreter::new();
+
+        let pairs = vec![
+            ("(defv a 1) (env:is-const-variable? 'a)", "#f"),
+            ("(defc b 1) (env:is-const-variable? 'b)", "#t"),
+            ("(let ((b 1)) (env:is-const-variable? 'b))", "#f"),
+        ];
+
+        utils::assert_results_are_equal(&mut interpreter, pairs);
+    }
+
+    #[test]
+    fn returns_generic_execution_error_when_variable_is_not_defined() {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec!["(env:is-const-variable? 'not-defined)"];
+
+        utils::assert_results_are_generic_execution_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+
+    #[test]
+    fn returns_invalid_argument_count_error_when_argument_count_is_not_correct()
+    {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector =
+            vec!["(env:is-const-variable?)", "(env:is-const-variable? 'a 'b)"];
+
+        utils::assert_results_are_invalid_argument_count_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+
+    #[test]
+    fn returns_invalid_argument_error_when_invalid_arguments_were_provided() {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec![
+            "(env:is-const-variable? 1)",
+            "(env:is-const-variable? \"a\")",
+            "(env:is-const-variable? :a)",
+        ];
+
+        utils::assert_results_are_invalid_argument_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use crate::interpreter::environment::EnvironmentId;
+use crate::interpreter::error::Error;
+use crate::interpreter::interpreter::Interpreter;
+use crate::interpreter::value::Value;
+
+use crate::library;
+
+pub fn lookup_function_environment(
+    interpreter: &mut Interpreter,
+    environment_id: EnvironmentId,
+    values: Vec<Value>,
+) -> Result<Value, Error> {
+    if values.len() != 1 {
+        return Error::invalid_argument_count_error(
+            "Built-in function `env:lookup-function-environment' takes one argument exactly.",
+        )
+        .into();
+    }
+
+    let mut values = values;
+
+    let symbol_id = library::read_as_symbol_id(values.remove(0))?;
+
+    let defining_environment_id = interpreter
+        .lookup_environment_by_function(environment_id, symbol_id)?;
+
+    let environment_keyword =
+        super::lookup_variable_environment::make_environment_keyword(
+            interpreter,
+            environment_id,
+            defining_environment_id,
+        );
+
+    Ok(environment_keyword)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[allow(unused_imports)]
+    use crate::utils;
+
+    #[test]
+    fn returns_environment_that_defines_function() {
+        let mut interpreter = Interpreter::new();
+
+        let pairs = vec![
+            ("(env:lookup-function-environment 'defn)", ":root"),
+            ("(defn a () 1) (env:lookup-function-environment 'a)", ":main"),
+            ("(flet ((a () 1)) (env:lookup-function-environment 'a))", ":current"),
+            (
+                "(flet ((a () 1)) (let ((b 2)) (env:lookup-function-environment 'a)))",
+                ":enclosing",
+            ),
+            ("(env:lookup-function-environment 'not-defined)", "nil"),
+        ];
+
+        utils::assert_results_are_equal(&mut interpreter, pairs);
+    }
+
+    #[test]
+    fn returns_invalid_argument_count_error_when_argument_count_is_not_correct()
+    {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec![
+            "(env:lookup-function-environment)",
+            "(env:lookup-function-environment 'a 'b)",
+        ];
+
+        utils::assert_results_are_invalid_argument_count_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+
+    #[test]
+    fn returns_invalid_argument_error_when_invalid_arguments_were_provided() {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec![
+            "(env:lookup-function-environment 1)",
+            "(env:lookup-function-environment \"a\")",
+            "(env:lookup-function-environment :a)",
+        ];
+
+        utils::assert_results_are_invalid_argument_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use crate::interpreter::environment::EnvironmentId;
+use crate::interpreter::error::Error;
+use crate::interpreter::interpreter::Interpreter;
+use crate::interpreter::value::Value;
+
+use crate::library;
+
+// environments are not values, so they are described relative to the
+// environment of invocation
+pub fn make_environment_keyword(
+    interpreter: &mut Interpreter,
+    current_environment_id: EnvironmentId,
+    defining_environment_id: Option<EnvironmentId>,
+) -> Value {
+    let defining_environment_id = match defining_environment_id {
+        Some(defining_environment_id) => defining_environment_id,
+        None => return interpreter.intern_nil_symbol_value(),
+    };
+
+    let keyword_name = if defining_environment_id
+        == interpreter.get_root_environment_id()
+    {
+        "root"
+    } else if defining_environment_id == interpreter.get_main_environment_id() {
+        "main"
+    } else if defining_environment_id
+        == interpreter.get_current_module().get_environment_id()
+    {
+        "module"
+    } else if defining_environment_id == current_environment_id {
+        "current"
+    } else {
+        "enclosing"
+    };
+
+    interpreter.intern_keyword_value(keyword_name)
+}
+
+pub fn lookup_variable_environment(
+    interpreter: &mut Interpreter,
+    environment_id: EnvironmentId,
+    values: Vec<Value>,
+) -> Result<Value, Error> {
+    if values.len() != 1 {
+        return Error::invalid_argument_count_error(
+            "Built-in function `env:lookup-variable-environment' takes one argument exactly.",
+        )
+        .into();
+    }
+
+    let mut values = values;
+
+    let symbol_id = library::read_as_symbol_id(values.remove(0))?;
+
+    let defining_environment_id = interpreter
+        .lookup_environment_by_variable(environment_id, symbol_id)?;
+
+    Ok(make_environment_keyword(
+        interpreter,
+        environment_id,
+        defining_environment_id,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[allow(unused_imports)]
+    use crate::utils;
+
+    #[test]
+    fn returns_environment_that_defines_variable() {
+        let mut interpreter = Interpreter::new();
+
+        let pairs = vec![
+            ("(env:lookup-variable-environment 'list)", ":root"),
+            ("(defv a 1) (env:lookup-variable-environment 'a)", ":main"),
+            ("(let ((a 1)) (env:lookup-variable-environment 'a))", ":current"),
+            (
+                "(let ((a 1)) (let ((b 2)) (env:lookup-variable-environment 'a)))",
+                ":enclosing",
+            ),
+            ("(env:lookup-variable-environment 'not-defined)", "nil"),
+        ];
+
+        utils::assert_results_are_equal(&mut interpreter, pairs);
+    }
+
+    #[test]
+    fn returns_module_when_variable_is_defined_by_current_module() {
+        utils::with_tempdir(|directory| {
+            let module_path = format!("{}/module.nia", directory);
+            let mut interpreter = Interpreter::new();
+
+            std::fs::write(
+                &module_path,
+                "(defv module-variable 1) \
+                 (defv environment (env:lookup-variable-environment 'module-variable)) \
+                 (export environment)",
+            )
+            .unwrap();
+
+            let code =
+                format!("(import #{{:environment}} from \"{}\")", module_path);
+            nia_assert_is_ok(&interpreter.execute_in_main_environment(&code));
+
+            let pairs = vec![("environment", ":module")];
+
+            utils::assert_results_are_equal(&mut interpreter, pairs);
+        });
+    }
+
+    #[test]
+    fn returns_invalid_argument_count_error_when_argument_count_is_not_correct()
+    {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec![
+            "(env:lookup-variable-environment)",
+            "(env:lookup-variable-environment 'a 'b)",
+        ];
+
+        utils::assert_results_are_invalid_argument_count_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+
+    #[test]
+    fn returns_invalid_argument_error_when_invalid_arguments_were_provided() {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec![
+            "(env:lookup-variable-environment 1)",
+            "(env:lookup-variable-environment \"a\")",
+            "(env:lookup-variable-environment :a)",
+        ];
+
+        utils::assert_results_are_invalid_argument_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use crate::interpreter::error::Error;
+use crate::interpreter::interpreter::Interpreter;
+use crate::interpreter::value::BuiltinFunctionType;
+use crate::interpreter::value::Value;
+
+use crate::library;
+
+mod functions;
+mod is_const_function_question;
+mod is_const_variable_question;
+mod lookup_function_environment;
+mod lookup_variable_environment;
+mod variables;
+
+pub const BUILTIN_FUNCTIONS: &[(&str, BuiltinFunctionType)] = &[
+    ("functions", functions::functions),
+    (
+        "is-const-function?",
+        is_const_function_question::is_const_function_question,
+    ),
+    (
+        "is-const-variable?",
+        is_const_variable_question::is_const_variable_question,
+    ),
+    (
+        "lookup-function-environment",
+        lookup_function_environment::lookup_function_environment,
+    ),
+    (
+        "lookup-variable-environment",
+        lookup_variable_environment::lookup_variable_environment,
+    ),
+    ("variables", variables::variables),
+];
+
+pub fn infect(interpreter: &mut Interpreter) -> Result<(), Error> {
+    let env_object_id = interpreter.make_object();
+    let env_symbol_id = interpreter.intern_symbol_id("env");
+
+    for (name, func) in BUILTIN_FUNCTIONS {
+        library::infect_object_builtin_function(
+            interpreter,
+            env_object_id,
+            name,
+            *func,
+        )?;
+    }
+
+    interpreter.define_variable(
+        interpreter.get_root_environment_id(),
+        env_symbol_id,
+        Value::Object(env_object_id),
+    )?;
+
+    Ok(())
+}
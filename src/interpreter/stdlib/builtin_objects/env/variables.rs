@@ -0,0 +1,133 @@
+use crate::interpreter::environment::EnvironmentId;
+use crate::interpreter::error::Error;
+use crate::interpreter::interpreter::Interpreter;
+use crate::interpreter::value::SymbolId;
+use crate::interpreter::value::Value;
+
+use crate::library;
+
+pub fn read_environment_id(
+    interpreter: &Interpreter,
+    environment_id: EnvironmentId,
+    values: Vec<Value>,
+) -> Result<EnvironmentId, Error> {
+    let mut values = values;
+
+    if values.len() == 1 {
+        library::read_as_environment_id(
+            interpreter,
+            environment_id,
+            values.remove(0),
+        )
+    } else {
+        Ok(environment_id)
+    }
+}
+
+pub fn make_sorted_symbol_list(
+    interpreter: &mut Interpreter,
+    symbol_ids: Vec<SymbolId>,
+) -> Result<Value, Error> {
+    let mut named_symbol_ids = Vec::new();
+
+    for symbol_id in symbol_ids {
+        let name = interpreter.get_symbol_name(symbol_id)?.clone();
+
+        named_symbol_ids.push((name, symbol_id));
+    }
+
+    named_symbol_ids.sort();
+
+    let values = named_symbol_ids
+        .into_iter()
+        .map(|(_, symbol_id)| Value::Symbol(symbol_id))
+        .collect();
+
+    Ok(interpreter.vec_to_list(values))
+}
+
+pub fn variables(
+    interpreter: &mut Interpreter,
+    environment_id: EnvironmentId,
+    values: Vec<Value>,
+) -> Result<Value, Error> {
+    if values.len() > 1 {
+        return Error::invalid_argument_count_error(
+            "Built-in function `env:variables' takes one optional argument.",
+        )
+        .into();
+    }
+
+    let environment_id =
+        read_environment_id(interpreter, environment_id, values)?;
+
+    let symbol_ids = interpreter
+        .get_environment_arena()
+        .get_environment(environment_id)?
+        .get_variables()
+        .keys()
+        .cloned()
+        .collect();
+
+    make_sorted_symbol_list(interpreter, symbol_ids)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[allow(unused_imports)]
+    use crate::utils;
+
+    #[test]
+    fn lists_variables_of_environments() {
+        let mut interpreter = Interpreter::new();
+
+        let pairs = vec![
+            ("(let ((b 1) (a 2)) (env:variables))", "'(a b)"),
+            ("(let ((b 1) (a 2)) (env:variables :current))", "'(a b)"),
+            (
+                "(defv main-b 1) (defv main-a 2) (env:variables :main)",
+                "'(main-a main-b)",
+            ),
+            ("(let ((b 1)) (env:variables :main))", "'(main-a main-b)"),
+            ("(list:contains? 'list (env:variables :root))", "#t"),
+            ("(list:contains? 'main-a (env:variables :root))", "#f"),
+        ];
+
+        utils::assert_results_are_equal(&mut interpreter, pairs);
+    }
+
+    #[test]
+    fn returns_invalid_argument_count_error_when_argument_count_is_not_correct()
+    {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec!["(env:variables :main :root)"];
+
+        utils::assert_results_are_invalid_argument_count_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+
+    #[test]
+    fn returns_invalid_argument_error_when_invalid_arguments_were_provided() {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec![
+            "(env:variables 1)",
+            "(env:variables \"main\")",
+            "(env:variables 'main)",
+            "(env:variables :parent)",
+        ];
+
+        utils::assert_results_are_invalid_argument_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+}
@@ -13,25 +13,25 @@ mod f;
 mod id;
 mod t;
 
+pub const BUILTIN_FUNCTIONS: &[(&str, BuiltinFunctionType)] = &[
+    ("always", always::always),
+    ("apply", apply::apply),
+    ("call", call::call),
+    ("combine", combine::combine),
+    ("id", id::id),
+    ("f", f::f),
+    ("t", t::t),
+];
+
 pub fn infect(interpreter: &mut Interpreter) -> Result<(), Error> {
     let func_object_id = interpreter.make_object();
 
-    let bindings: Vec<(&str, BuiltinFunctionType)> = vec![
-        ("always", always::always),
-        ("apply", apply::apply),
-        ("call", call::call),
-        ("combine", combine::combine),
-        ("id", id::id),
-        ("f", f::f),
-        ("t", t::t),
-    ];
-
-    for (name, func) in bindings {
+    for (name, func) in BUILTIN_FUNCTIONS {
         library::infect_object_builtin_function(
             interpreter,
             func_object_id,
             name,
-            func,
+            *func,
         )?;
     }
 
@@ -11,23 +11,23 @@ mod nor;
 mod or;
 mod xor;
 
+pub const BUILTIN_FUNCTIONS: &[(&str, BuiltinFunctionType)] = &[
+    ("and", and::and),
+    ("nand", nand::nand),
+    ("nor", nor::nor),
+    ("or", or::or),
+    ("xor", xor::xor),
+];
+
 pub fn infect(interpreter: &mut Interpreter) -> Result<(), Error> {
     let logic_object_id = interpreter.make_object();
 
-    let bindings: Vec<(&str, BuiltinFunctionType)> = vec![
-        ("and", and::and),
-        ("nand", nand::nand),
-        ("nor", nor::nor),
-        ("or", or::or),
-        ("xor", xor::xor),
-    ];
-
-    for (name, func) in bindings {
+    for (name, func) in BUILTIN_FUNCTIONS {
         library::infect_object_builtin_function(
             interpreter,
             logic_object_id,
             name,
-            func,
+            *func,
         )?;
     }
 
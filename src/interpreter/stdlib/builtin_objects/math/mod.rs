@@ -13,26 +13,26 @@ mod min;
 mod pow;
 mod round;
 
+pub const BUILTIN_FUNCTIONS: &[(&str, BuiltinFunctionType)] = &[
+    ("abs", abs::abs),
+    ("ceil", ceil::ceil),
+    ("floor", floor::floor),
+    ("max", max::max),
+    ("min", min::min),
+    ("pow", pow::pow),
+    ("round", round::round),
+];
+
 pub fn infect(interpreter: &mut Interpreter) -> Result<(), Error> {
     let math_symbol_id = interpreter.intern_symbol_id("math");
     let math_object_id = interpreter.make_object();
 
-    let bindings: Vec<(&str, BuiltinFunctionType)> = vec![
-        ("abs", abs::abs),
-        ("ceil", ceil::ceil),
-        ("floor", floor::floor),
-        ("max", max::max),
-        ("min", min::min),
-        ("pow", pow::pow),
-        ("round", round::round),
-    ];
-
-    for (name, func) in bindings {
+    for (name, func) in BUILTIN_FUNCTIONS {
         library::infect_object_builtin_function(
             interpreter,
             math_object_id,
             name,
-            func,
+            *func,
         )?;
     }
 
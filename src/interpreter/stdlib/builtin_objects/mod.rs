@@ -1,16 +1,19 @@
 use crate::interpreter::error::Error;
 use crate::interpreter::interpreter::Interpreter;
+use crate::interpreter::value::BuiltinFunctionType;
 
 mod action;
 mod alist;
 mod bit;
 mod cons;
 mod device;
+mod env;
 mod func;
 mod is;
 mod list;
 mod logic;
 mod math;
+mod module;
 mod object;
 mod rand;
 mod string;
@@ -18,6 +21,27 @@ mod to;
 
 mod nia; // :3
 
+// built-in functions of every built-in object, by the name of the object
+pub const BUILTIN_OBJECTS: &[(&str, &[(&str, BuiltinFunctionType)])] = &[
+    ("alist", alist::BUILTIN_FUNCTIONS),
+    ("action", action::BUILTIN_FUNCTIONS),
+    ("bit", bit::BUILTIN_FUNCTIONS),
+    ("cons", cons::BUILTIN_FUNCTIONS),
+    ("func", func::BUILTIN_FUNCTIONS),
+    ("is", is::BUILTIN_FUNCTIONS),
+    ("device", device::BUILTIN_FUNCTIONS),
+    ("env", env::BUILTIN_FUNCTIONS),
+    ("list", list::BUILTIN_FUNCTIONS),
+    ("logic", logic::BUILTIN_FUNCTIONS),
+    ("math", math::BUILTIN_FUNCTIONS),
+    ("module", module::BUILTIN_FUNCTIONS),
+    ("object", object::BUILTIN_FUNCTIONS),
+    ("rand", rand::BUILTIN_FUNCTIONS),
+    ("string", string::BUILTIN_FUNCTIONS),
+    ("to", to::BUILTIN_FUNCTIONS),
+    ("nia", nia::BUILTIN_FUNCTIONS),
+];
+
 pub fn infect(interpreter: &mut Interpreter) -> Result<(), Error> {
     alist::infect(interpreter)?;
     action::infect(interpreter)?;
@@ -26,9 +50,11 @@ pub fn infect(interpreter: &mut Interpreter) -> Result<(), Error> {
     func::infect(interpreter)?;
     is::infect(interpreter)?;
     device::infect(interpreter)?;
+    env::infect(interpreter)?;
     list::infect(interpreter)?;
     logic::infect(interpreter)?;
     math::infect(interpreter)?;
+    module::infect(interpreter)?;
     object::infect(interpreter)?;
     rand::infect(interpreter)?;
     string::infect(interpreter)?;
@@ -0,0 +1,151 @@
+use crate::interpreter::environment::EnvironmentId;
+use crate::interpreter::error::Error;
+use crate::interpreter::interpreter::Interpreter;
+use crate::interpreter::value::Value;
+
+use crate::library;
+
+pub fn exports(
+    interpreter: &mut Interpreter,
+    _environment_id: EnvironmentId,
+    values: Vec<Value>,
+) -> Result<Value, Error> {
+    if values.len() > 1 {
+        return Error::invalid_argument_count_error(
+            "Built-in function `module:exports' takes one optional argument.",
+        )
+        .into();
+    }
+
+    let mut values = values;
+
+    let module = if values.len() == 1 {
+        let module_path =
+            library::read_as_string(interpreter, values.remove(0))?.clone();
+        let module_path =
+            interpreter.resolve_with_current_module_path(module_path)?;
+
+        let module_id =
+            match interpreter.get_module_arena().get_module_id(&module_path) {
+                Some(module_id) if module_path.len() > 0 => module_id,
+                _ => {
+                    return Error::generic_execution_error(&format!(
+                        "Module \"{}\" was not loaded.",
+                        module_path
+                    ))
+                    .into();
+                },
+            };
+
+        interpreter.get_module(module_id)?
+    } else {
+        interpreter.get_current_module()
+    };
+
+    let mut export_symbol_ids =
+        module.get_exports().keys().cloned().collect::<Vec<_>>();
+    let has_default_export = module.get_default_export().is_some();
+
+    if has_default_export {
+        export_symbol_ids.push(interpreter.intern_symbol_id("default"));
+    }
+
+    let mut named_exports = Vec::new();
+
+    for symbol_id in export_symbol_ids {
+        let name = interpreter.get_symbol_name(symbol_id)?.clone();
+
+        named_exports.push((name, symbol_id));
+    }
+
+    named_exports.sort();
+
+    let export_values = named_exports
+        .into_iter()
+        .map(|(_, symbol_id)| Value::Symbol(symbol_id))
+        .collect();
+
+    Ok(interpreter.vec_to_list(export_values))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[allow(unused_imports)]
+    use crate::utils;
+
+    #[test]
+    fn lists_exports_of_module() {
+        utils::with_tempdir(|directory| {
+            let module_path = format!("{}/module.nia", directory);
+            let mut interpreter = Interpreter::new();
+
+            std::fs::write(
+                &module_path,
+                "(defv b 1) (defv a 2) (defv c 3) \
+                 (export b) (export a) (export c as default)",
+            )
+            .unwrap();
+            interpreter
+                .execute_in_main_environment(&format!(
+                    "(import \"{}\")",
+                    module_path
+                ))
+                .unwrap();
+
+            let code = format!("(module:exports \"{}\")", module_path);
+            let pairs = vec![
+                (code.as_str(), "'(a b default)"),
+                ("(module:exports)", "nil"),
+            ];
+
+            utils::assert_results_are_equal(&mut interpreter, pairs);
+        });
+    }
+
+    #[test]
+    fn returns_generic_execution_error_when_module_was_not_loaded() {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec!["(module:exports \"/not/loaded/module.nia\")"];
+
+        utils::assert_results_are_generic_execution_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+
+    #[test]
+    fn returns_invalid_argument_error_when_argument_is_not_a_string() {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec![
+            "(module:exports 1)",
+            "(module:exports 'symbol)",
+            "(module:exports :keyword)",
+            "(module:exports {})",
+        ];
+
+        utils::assert_results_are_invalid_argument_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+
+    #[test]
+    fn returns_invalid_argument_count_error_when_incorrect_count_of_arguments_were_passed(
+    ) {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec!["(module:exports \"a.nia\" \"b.nia\")"];
+
+        utils::assert_results_are_invalid_argument_count_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+}
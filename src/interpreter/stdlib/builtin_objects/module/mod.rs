@@ -0,0 +1,34 @@
+use crate::interpreter::error::Error;
+use crate::interpreter::interpreter::Interpreter;
+use crate::interpreter::value::BuiltinFunctionType;
+use crate::interpreter::value::Value;
+
+use crate::library;
+
+mod exports;
+mod reload;
+
+pub const BUILTIN_FUNCTIONS: &[(&str, BuiltinFunctionType)] =
+    &[("exports", exports::exports), ("reload", reload::reload)];
+
+pub fn infect(interpreter: &mut Interpreter) -> Result<(), Error> {
+    let module_object_id = interpreter.make_object();
+    let module_symbol_id = interpreter.intern_symbol_id("module");
+
+    for (name, func) in BUILTIN_FUNCTIONS {
+        library::infect_object_builtin_function(
+            interpreter,
+            module_object_id,
+            name,
+            *func,
+        )?;
+    }
+
+    interpreter.define_variable(
+        interpreter.get_root_environment_id(),
+        module_symbol_id,
+        Value::Object(module_object_id),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use crate::interpreter::environment::EnvironmentId;
+use crate::interpreter::error::Error;
+use crate::interpreter::interpreter::Interpreter;
+use crate::interpreter::value::Value;
+
+use crate::library;
+
+pub fn reload(
+    interpreter: &mut Interpreter,
+    _environment_id: EnvironmentId,
+    values: Vec<Value>,
+) -> Result<Value, Error> {
+    if values.len() > 1 {
+        return Error::invalid_argument_count_error(
+            "Built-in function `module:reload' takes one optional argument.",
+        )
+        .into();
+    }
+
+    let mut values = values;
+
+    let reloaded_module_paths = if values.len() == 1 {
+        let module_path =
+            library::read_as_string(interpreter, values.remove(0))?.clone();
+        let module_path =
+            interpreter.resolve_with_current_module_path(module_path)?;
+
+        interpreter.reload_module(&module_path)?
+    } else {
+        interpreter.reload_changed_modules()?
+    };
+
+    let reloaded_module_paths = reloaded_module_paths
+        .into_iter()
+        .map(|module_path| interpreter.intern_string_value(module_path))
+        .collect();
+
+    Ok(interpreter.vec_to_list(reloaded_module_paths))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[allow(unused_imports)]
+    use crate::utils;
+
+    #[test]
+    fn reloads_module() {
+        utils::with_tempdir(|directory| {
+            let module_path = format!("{}/module.nia", directory);
+            let mut interpreter = Interpreter::new();
+
+            std::fs::write(&module_path, "(defv nia-test 1)").unwrap();
+            interpreter
+                .execute_in_main_environment(&format!(
+                    "(import \"{}\")",
+                    module_path
+                ))
+                .unwrap();
+            std::fs::write(&module_path, "(defv nia-test 2)").unwrap();
+
+            let code = format!("(module:reload \"{}\")", module_path);
+            let expected = format!("'(\"{}\")", module_path);
+            let pairs = vec![
+                (code.as_str(), expected.as_str()),
+                ("(module:reload)", "'()"),
+            ];
+
+            utils::assert_results_are_equal(&mut interpreter, pairs);
+        });
+    }
+
+    #[test]
+    fn returns_generic_execution_error_when_module_was_not_loaded() {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec!["(module:reload \"/not/loaded/module.nia\")"];
+
+        utils::assert_results_are_generic_execution_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+
+    #[test]
+    fn returns_invalid_argument_error_when_argument_is_not_a_string() {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec![
+            "(module:reload 1)",
+            "(module:reload 1.1)",
+            "(module:reload #t)",
+            "(module:reload 'symbol)",
+            "(module:reload :keyword)",
+            "(module:reload '(1 2))",
+            "(module:reload {})",
+            "(module:reload #())",
+        ];
+
+        utils::assert_results_are_invalid_argument_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+
+    #[test]
+    fn returns_invalid_argument_count_error_when_incorrect_count_of_arguments_were_passed(
+    ) {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec!["(module:reload \"a.nia\" \"b.nia\")"];
+
+        utils::assert_results_are_invalid_argument_count_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+}
@@ -7,18 +7,19 @@ use crate::library;
 
 mod quit;
 
+pub const BUILTIN_FUNCTIONS: &[(&str, BuiltinFunctionType)] =
+    &[("quit", quit::quit)];
+
 pub fn infect(interpreter: &mut Interpreter) -> Result<(), Error> {
     let nia_symbol_id = interpreter.intern_symbol_id("nia");
     let nia_object_id = interpreter.make_object();
 
-    let bindings: Vec<(&str, BuiltinFunctionType)> = vec![("quit", quit::quit)];
-
-    for (name, func) in bindings {
+    for (name, func) in BUILTIN_FUNCTIONS {
         library::infect_object_builtin_function(
             interpreter,
             nia_object_id,
             name,
-            func,
+            *func,
         )?;
     }
 
@@ -1,16 +1,29 @@
-use std::process::exit;
-
 use crate::interpreter::environment::EnvironmentId;
 use crate::interpreter::error::Error;
 use crate::interpreter::interpreter::Interpreter;
 use crate::interpreter::value::Value;
+use crate::Capability;
 
 pub fn quit(
-    _interpreter: &mut Interpreter,
+    interpreter: &mut Interpreter,
     _environment: EnvironmentId,
-    _values: Vec<Value>,
+    values: Vec<Value>,
 ) -> Result<Value, Error> {
-    exit(0);
+    if values.len() != 0 {
+        return Error::invalid_argument_count_error(
+            "Built-in function `nia:quit' takes no arguments.",
+        )
+        .into();
+    }
+
+    interpreter.check_capability(Capability::ListeningControl)?;
+
+    // evaluation stops after the current top-level form, and the event loop
+    // after the current command, so that it can release pressed inputs and
+    // stop the listener first
+    interpreter.request_quit();
+
+    Ok(interpreter.intern_nil_symbol_value())
 }
 
 #[cfg(test)]
@@ -20,4 +33,74 @@ mod tests {
 
     #[allow(unused_imports)]
     use nia_basic_assertions::*;
+
+    #[allow(unused_imports)]
+    use crate::utils;
+
+    #[test]
+    fn returns_permission_denied_error_when_listening_control_is_not_granted() {
+        let mut interpreter = Interpreter::sandboxed();
+
+        let code_vector = vec!["(nia:quit)"];
+
+        utils::assert_results_are_permission_denied_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+
+    #[test]
+    fn requests_quit() {
+        let mut interpreter = Interpreter::new();
+
+        nia_assert(!interpreter.is_quit_requested());
+
+        utils::assert_results_are_equal(
+            &mut interpreter,
+            vec![("(nia:quit)", "nil")],
+        );
+
+        nia_assert(interpreter.is_quit_requested());
+    }
+
+    #[test]
+    fn stops_executing_code_after_quit() {
+        let mut interpreter = Interpreter::new();
+
+        interpreter
+            .execute_in_main_environment("(defv a 1) (nia:quit) (set! a 2)")
+            .unwrap();
+
+        utils::assert_results_are_equal(&mut interpreter, vec![("a", "1")]);
+    }
+
+    #[test]
+    fn stops_executing_file_after_quit() {
+        utils::with_tempdir(|directory| {
+            let script_path = format!("{}/script.nia", directory);
+            let mut interpreter = Interpreter::new();
+
+            std::fs::write(&script_path, "(defv a 1) (nia:quit) (set! a 2)")
+                .unwrap();
+            interpreter
+                .execute_file_in_main_environment(&script_path)
+                .unwrap();
+
+            utils::assert_results_are_equal(&mut interpreter, vec![("a", "1")]);
+        })
+    }
+
+    #[test]
+    fn returns_invalid_argument_count_error_when_called_with_arguments() {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec!["(nia:quit 1)"];
+
+        utils::assert_results_are_invalid_argument_count_errors(
+            &mut interpreter,
+            code_vector,
+        );
+
+        nia_assert(!interpreter.is_quit_requested());
+    }
 }
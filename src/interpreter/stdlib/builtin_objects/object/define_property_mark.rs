@@ -1,5 +1,6 @@
 use crate::EnvironmentId;
 use crate::Error;
+use crate::FunctionId;
 use crate::Interpreter;
 use crate::ObjectId;
 use crate::SymbolId;
@@ -62,6 +63,49 @@ pub fn read_property_value_from_property_descriptor(
     Ok(value)
 }
 
+pub fn read_accessor_from_property_descriptor(
+    interpreter: &mut Interpreter,
+    property_descriptor_object_id: ObjectId,
+    accessor_name: &str,
+) -> Result<Option<FunctionId>, Error> {
+    let accessor_symbol_id = interpreter.intern_symbol_id(accessor_name);
+
+    let accessor = if let Some(value) = interpreter.get_object_property(
+        property_descriptor_object_id,
+        accessor_symbol_id,
+    )? {
+        Some(library::read_as_function_id(value)?)
+    } else {
+        None
+    };
+
+    Ok(accessor)
+}
+
+pub fn check_property_descriptor_is_not_mixed(
+    interpreter: &mut Interpreter,
+    property_descriptor_object_id: ObjectId,
+) -> Result<(), Error> {
+    let value_symbol_id = interpreter.intern_symbol_id("value");
+    let writable_symbol_id = interpreter.intern_symbol_id("writable");
+
+    let has_value = interpreter
+        .object_has_property(property_descriptor_object_id, value_symbol_id)?;
+    let has_writable = interpreter.object_has_property(
+        property_descriptor_object_id,
+        writable_symbol_id,
+    )?;
+
+    if has_value || has_writable {
+        return Error::invalid_argument_error(
+            "Built-in function `object:define-property!' takes property descriptor with either a value or accessors.",
+        )
+        .into();
+    }
+
+    Ok(())
+}
+
 pub fn read_internable_flag_from_property_descriptor(
     interpreter: &mut Interpreter,
     property_descriptor_object_id: ObjectId,
@@ -181,7 +225,7 @@ pub fn read_flags_from_property_descriptor(
 
 pub fn define_property_mark(
     interpreter: &mut Interpreter,
-    _environment: EnvironmentId,
+    environment_id: EnvironmentId,
     values: Vec<Value>,
 ) -> Result<Value, Error> {
     if values.len() != 2 {
@@ -210,16 +254,62 @@ pub fn define_property_mark(
         property_descriptor_object_id,
     )?;
 
-    let flags = read_flags_from_property_descriptor(
+    let getter = read_accessor_from_property_descriptor(
         interpreter,
         property_descriptor_object_id,
+        "get",
+    )?;
+
+    let setter = read_accessor_from_property_descriptor(
+        interpreter,
+        property_descriptor_object_id,
+        "set",
+    )?;
+
+    let is_accessor = getter.is_some() || setter.is_some();
+
+    if is_accessor {
+        check_property_descriptor_is_not_mixed(
+            interpreter,
+            property_descriptor_object_id,
+        )?;
+    }
+
+    let mut flags = read_flags_from_property_descriptor(
+        interpreter,
+        property_descriptor_object_id,
+    )?;
+
+    // accessor properties are changed only through their setter
+    if is_accessor {
+        flags &= !OBJECT_VALUE_WRAPPER_FLAG_WRITABLE;
+    }
+
+    let old_value = library::get_observed_property_value(
+        interpreter,
+        object_id,
+        property_symbol_id,
     )?;
 
     let object = interpreter.get_object_mut(object_id)?;
 
     object.set_property(property_symbol_id, property_value)?;
+
+    if is_accessor {
+        object.set_property_accessors(property_symbol_id, getter, setter)?;
+    }
+
     object.set_property_flags(property_symbol_id, flags)?;
 
+    library::notify_object_observers(
+        interpreter,
+        environment_id,
+        object_id,
+        property_symbol_id,
+        old_value,
+        property_value,
+    )?;
+
     Ok(Value::Boolean(true))
 }
 
@@ -325,6 +415,95 @@ mod tests {
         utils::assert_results_are_equal(&mut interpreter, specs);
     }
 
+    #[test]
+    fn defines_accessor_property() {
+        let mut interpreter = Interpreter::new();
+
+        let specs = vec![
+            (
+                "(let ((obj {:a 1})) (object:define-property! obj {:name :b :get (fn () (+ this:a 1))}) (object:get obj :b))",
+                "2",
+            ),
+            (
+                "(let ((obj {:a 1})) (object:define-property! obj {:name :b :get (fn () (+ this:a 1))}) obj:b)",
+                "2",
+            ),
+            (
+                "(let ((obj {:a 1})) (object:define-property! obj {:name :b :get (fn () (+ this:a 1))}) (:b obj))",
+                "2",
+            ),
+            (
+                "(let ((obj {:a 1})) (object:define-property! obj {:name :b :set (fn (value) (object:set! this :a value))}) (object:set! obj :b 5) obj:a)",
+                "5",
+            ),
+            (
+                "(let ((obj {:a 1})) (object:define-property! obj {:name :b :set (fn (value) (object:set! this :a value))}) (:b obj 6) obj:a)",
+                "6",
+            ),
+            (
+                "(let ((obj {:a 1})) (object:define-property! obj {:name :b :set (fn (value) (object:set! this :a value))}) obj:b)",
+                "nil",
+            ),
+            (
+                "(let ((obj {:a 1})) (object:define-property! obj {:name :b :get (fn () 1)}) (object:is-writable? obj :b))",
+                "#f",
+            ),
+        ];
+
+        utils::assert_results_are_equal(&mut interpreter, specs);
+    }
+
+    #[test]
+    fn invokes_accessors_of_prototype_with_this_bound_to_object() {
+        let mut interpreter = Interpreter::new();
+
+        let specs = vec![
+            (
+                "(let ((proto {}) (obj {:a 1})) (object:define-property! proto {:name :b :get (fn () (* this:a 10))}) (object:set-proto! obj proto) obj:b)",
+                "10",
+            ),
+            (
+                "(let ((proto {}) (obj {:a 1})) (object:define-property! proto {:name :b :set (fn (value) (object:set! this :a value))}) (object:set-proto! obj proto) (object:set! obj :b 3) (list:new obj:a (:a proto)))",
+                "(list:new 3 nil)",
+            ),
+        ];
+
+        utils::assert_results_are_equal(&mut interpreter, specs);
+    }
+
+    #[test]
+    fn returns_generic_execution_error_when_setting_accessor_without_setter() {
+        let mut interpreter = Interpreter::new();
+
+        let specs = vec![
+            "(let ((obj {})) (object:define-property! obj {:name :b :get (fn () 1)}) (object:set! obj :b 2))",
+            "(let ((obj {})) (object:define-property! obj {:name :b :get (fn () 1)}) (:b obj 2))",
+        ];
+
+        utils::assert_results_are_generic_execution_errors(
+            &mut interpreter,
+            specs,
+        );
+    }
+
+    #[test]
+    fn returns_invalid_argument_error_when_descriptor_mixes_value_and_accessors(
+    ) {
+        let mut interpreter = Interpreter::new();
+
+        let specs = vec![
+            "(let ((obj {})) (object:define-property! obj {:name :b :value 1 :get (fn () 1)}) obj)",
+            "(let ((obj {})) (object:define-property! obj {:name :b :writable #t :set (fn (value) value)}) obj)",
+            "(let ((obj {})) (object:define-property! obj {:name :b :get 1}) obj)",
+            "(let ((obj {})) (object:define-property! obj {:name :b :set 'sym}) obj)",
+        ];
+
+        utils::assert_results_are_invalid_argument_errors(
+            &mut interpreter,
+            specs,
+        );
+    }
+
     #[test]
     fn returns_generic_execution_error_when_property_already_defined() {
         let mut interpreter = Interpreter::new();
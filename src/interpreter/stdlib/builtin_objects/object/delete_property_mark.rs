@@ -7,7 +7,7 @@ use crate::library;
 
 pub fn delete_property_mark(
     interpreter: &mut Interpreter,
-    _environment: EnvironmentId,
+    environment_id: EnvironmentId,
     values: Vec<Value>,
 ) -> Result<Value, Error> {
     if values.len() != 2 {
@@ -27,14 +27,36 @@ pub fn delete_property_mark(
             values.remove(0),
         )?;
 
-    let object = interpreter.get_object_mut(object_id)?;
-
-    if !object.has_property(property_symbol_id) {
+    if !interpreter.object_has_property(object_id, property_symbol_id)? {
         return Error::generic_execution_error("Object has not property.")
             .into();
     }
 
-    object.delete_property(property_symbol_id)?;
+    let old_value = library::get_observed_property_value(
+        interpreter,
+        object_id,
+        property_symbol_id,
+    )?;
+
+    interpreter
+        .get_object_mut(object_id)?
+        .delete_property(property_symbol_id)?;
+
+    // after deletion the property may still be inherited from prototype
+    let new_value = library::get_observed_property_value(
+        interpreter,
+        object_id,
+        property_symbol_id,
+    )?;
+
+    library::notify_object_observers(
+        interpreter,
+        environment_id,
+        object_id,
+        property_symbol_id,
+        old_value,
+        new_value,
+    )?;
 
     Ok(Value::Boolean(true))
 }
@@ -7,7 +7,7 @@ use crate::interpreter::library;
 
 pub fn get(
     interpreter: &mut Interpreter,
-    _environment: EnvironmentId,
+    environment_id: EnvironmentId,
     values: Vec<Value>,
 ) -> Result<Value, Error> {
     if values.len() != 2 {
@@ -27,9 +27,13 @@ pub fn get(
 
     library::check_symbol_is_assignable(interpreter, symbol_id)?;
 
-    let value = interpreter
-        .get_object_property(object_id, symbol_id)
-        .map_err(|err| Error::generic_execution_error_caused("", err))?;
+    let value = library::get_object_property(
+        interpreter,
+        environment_id,
+        object_id,
+        symbol_id,
+    )
+    .map_err(|err| Error::generic_execution_error_caused("", err))?;
 
     match value {
         Some(value) => Ok(value),
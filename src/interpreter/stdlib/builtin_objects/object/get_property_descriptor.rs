@@ -43,7 +43,58 @@ fn extract_property_descriptor_items(
     Ok((name, value, internable, writable, enumerable, configurable))
 }
 
-fn construct_property_descriptor(
+fn construct_accessor_property_descriptor(
+    interpreter: &mut Interpreter,
+    object_id: ObjectId,
+    property_symbol_id: SymbolId,
+) -> Result<Value, Error> {
+    let name_symbol_id = interpreter.intern_symbol_id("name");
+    let get_symbol_id = interpreter.intern_symbol_id("get");
+    let set_symbol_id = interpreter.intern_symbol_id("set");
+    let internable_symbol_id = interpreter.intern_symbol_id("internable");
+    let enumerable_symbol_id = interpreter.intern_symbol_id("enumerable");
+    let configurable_symbol_id = interpreter.intern_symbol_id("configurable");
+    let nil = interpreter.intern_nil_symbol_value();
+
+    let object = interpreter.get_object(object_id)?;
+    let object_value_wrapper = *object
+        .get_property_value_wrapper(property_symbol_id)
+        .ok_or_else(|| {
+            Error::generic_execution_error("Object has not property to yield.")
+        })?;
+
+    let getter = object_value_wrapper
+        .get_getter()
+        .map(Value::Function)
+        .unwrap_or(nil);
+    let setter = object_value_wrapper
+        .get_setter()
+        .map(Value::Function)
+        .unwrap_or(nil);
+
+    let property_descriptor_object_id = interpreter.make_object();
+    let object = interpreter.get_object_mut(property_descriptor_object_id)?;
+
+    object.set_property(name_symbol_id, property_symbol_id.into())?;
+    object.set_property(get_symbol_id, getter)?;
+    object.set_property(set_symbol_id, setter)?;
+    object.set_property(
+        internable_symbol_id,
+        object_value_wrapper.is_internable().into(),
+    )?;
+    object.set_property(
+        enumerable_symbol_id,
+        object_value_wrapper.is_enumerable().into(),
+    )?;
+    object.set_property(
+        configurable_symbol_id,
+        object_value_wrapper.is_configurable().into(),
+    )?;
+
+    Ok(Value::Object(property_descriptor_object_id))
+}
+
+fn construct_data_property_descriptor(
     interpreter: &mut Interpreter,
     object_id: ObjectId,
     property_symbol_id: SymbolId,
@@ -75,6 +126,32 @@ fn construct_property_descriptor(
     Ok(Value::Object(property_descriptor_object_id))
 }
 
+fn construct_property_descriptor(
+    interpreter: &mut Interpreter,
+    object_id: ObjectId,
+    property_symbol_id: SymbolId,
+) -> Result<Value, Error> {
+    let is_accessor = interpreter
+        .get_object(object_id)?
+        .get_property_value_wrapper(property_symbol_id)
+        .map(|object_value_wrapper| object_value_wrapper.is_accessor())
+        .unwrap_or(false);
+
+    if is_accessor {
+        construct_accessor_property_descriptor(
+            interpreter,
+            object_id,
+            property_symbol_id,
+        )
+    } else {
+        construct_data_property_descriptor(
+            interpreter,
+            object_id,
+            property_symbol_id,
+        )
+    }
+}
+
 pub fn get_property_descriptor(
     interpreter: &mut Interpreter,
     _environment: EnvironmentId,
@@ -146,6 +223,24 @@ mod tests {
         utils::assert_results_are_equal(&mut interpreter, code_vector);
     }
 
+    #[test]
+    fn returns_accessor_property_descriptor() {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec![
+            (
+                "(let ((obj {})) (object:define-property! obj {:name :a :get (fn () 1)}) (let ((descriptor (object:get-property-descriptor obj :a))) (list:new descriptor:name (descriptor:get) descriptor:set descriptor:internable descriptor:enumerable descriptor:configurable)))",
+                "(list:new 'a 1 nil #t #t #t)",
+            ),
+            (
+                "(let ((obj {:b 1})) (object:define-property! obj {:name :a :set (fn (value) (object:set! this :b value)) :enumerable #f}) (let ((descriptor (object:get-property-descriptor obj :a))) (list:new descriptor:get (is:function? descriptor:set) descriptor:enumerable descriptor:value descriptor:writable)))",
+                "(list:new nil #t #f nil nil)",
+            ),
+        ];
+
+        utils::assert_results_are_equal(&mut interpreter, code_vector);
+    }
+
     #[test]
     fn returns_invalid_argument_count_error_when_argument_count_is_not_correct()
     {
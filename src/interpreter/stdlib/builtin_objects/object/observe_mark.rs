@@ -0,0 +1,167 @@
+use crate::EnvironmentId;
+use crate::Error;
+use crate::Interpreter;
+use crate::ObjectId;
+use crate::ObjectObserver;
+use crate::Value;
+
+use crate::library;
+
+pub fn read_object_observer(
+    interpreter: &mut Interpreter,
+    values: Vec<Value>,
+) -> Result<(ObjectId, ObjectObserver), Error> {
+    let mut values = values;
+
+    let object_id = library::read_as_object_id(values.remove(0))?;
+
+    let property_symbol_id = if values.len() == 2 {
+        let property_symbol_id =
+            library::read_string_keyword_or_symbol_as_symbol_id(
+                interpreter,
+                values.remove(0),
+            )?;
+
+        Some(property_symbol_id)
+    } else {
+        None
+    };
+
+    let function_id = library::read_as_function_id(values.remove(0))?;
+
+    Ok((
+        object_id,
+        ObjectObserver::new(property_symbol_id, function_id),
+    ))
+}
+
+pub fn observe_mark(
+    interpreter: &mut Interpreter,
+    _environment: EnvironmentId,
+    values: Vec<Value>,
+) -> Result<Value, Error> {
+    if values.len() < 2 || values.len() > 3 {
+        return Error::invalid_argument_count_error(
+            "Built-in function `object:observe!' takes two or three arguments.",
+        )
+        .into();
+    }
+
+    let (object_id, observer) = read_object_observer(interpreter, values)?;
+
+    interpreter
+        .get_object_mut(object_id)?
+        .add_observer(observer);
+
+    Ok(Value::Function(observer.get_function_id()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[allow(unused_imports)]
+    use crate::utils;
+
+    fn define_observed_object(interpreter: &mut Interpreter, code: &str) {
+        interpreter
+            .execute_in_main_environment(
+                "(defv changes '()) \
+                 (defv observer (fn (key old new) (set! changes (cons:new (list:new key old new) changes))))",
+            )
+            .unwrap();
+
+        nia_assert_is_ok(&interpreter.execute_in_main_environment(code));
+    }
+
+    #[test]
+    fn calls_observer_after_object_changes() {
+        let mut interpreter = Interpreter::new();
+
+        define_observed_object(
+            &mut interpreter,
+            "(defv obj {:a 1}) \
+             (object:observe! obj observer) \
+             (object:set! obj :a 2) \
+             (:b obj 3) \
+             (object:define-property! obj {:name :c :value 4}) \
+             (object:update-property! obj {:name :c :value 5}) \
+             (object:delete-property! obj :a)",
+        );
+
+        utils::assert_results_are_equal(
+            &mut interpreter,
+            vec![(
+                "changes",
+                "'((a 2 nil) (c 4 5) (c nil 4) (b nil 3) (a 1 2))",
+            )],
+        );
+    }
+
+    #[test]
+    fn calls_observer_of_single_property() {
+        let mut interpreter = Interpreter::new();
+
+        define_observed_object(
+            &mut interpreter,
+            "(defv obj {:a 1 :b 1}) \
+             (object:observe! obj :a observer) \
+             (object:set! obj :a 2) \
+             (object:set! obj :b 2)",
+        );
+
+        utils::assert_results_are_equal(
+            &mut interpreter,
+            vec![("changes", "'((a 1 2))")],
+        );
+    }
+
+    #[test]
+    fn binds_this_to_observed_object() {
+        let mut interpreter = Interpreter::new();
+
+        let pairs = vec![(
+            "(let ((obj {:a 1 :b 0})) (object:observe! obj :a (fn (key old new) (object:set! this :b new))) (object:set! obj :a 2) obj:b)",
+            "2",
+        )];
+
+        utils::assert_results_are_equal(&mut interpreter, pairs);
+    }
+
+    #[test]
+    fn returns_invalid_argument_count_error_when_argument_count_is_not_correct()
+    {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec![
+            "(object:observe!)",
+            "(object:observe! {})",
+            "(object:observe! {} :a (fn () 1) 1)",
+        ];
+
+        utils::assert_results_are_invalid_argument_count_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+
+    #[test]
+    fn returns_invalid_argument_error_when_invalid_arguments_were_provided() {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec![
+            "(object:observe! 1 (fn () 1))",
+            "(object:observe! {} 1)",
+            "(object:observe! {} :a 1)",
+            "(object:observe! {} 1 (fn () 1))",
+        ];
+
+        utils::assert_results_are_invalid_argument_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+}
@@ -6,7 +6,7 @@ use crate::interpreter::value::Value;
 
 pub fn set_mark(
     interpreter: &mut Interpreter,
-    _environment: EnvironmentId,
+    environment_id: EnvironmentId,
     values: Vec<Value>,
 ) -> Result<Value, Error> {
     if values.len() != 3 {
@@ -28,7 +28,13 @@ pub fn set_mark(
 
     let value = values.remove(0);
 
-    interpreter.set_object_property(object_id, symbol_id, value)?;
+    library::set_object_property(
+        interpreter,
+        environment_id,
+        object_id,
+        symbol_id,
+        value,
+    )?;
 
     Ok(value)
 }
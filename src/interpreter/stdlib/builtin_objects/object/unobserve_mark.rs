@@ -0,0 +1,95 @@
+use crate::EnvironmentId;
+use crate::Error;
+use crate::Interpreter;
+use crate::Value;
+
+pub fn unobserve_mark(
+    interpreter: &mut Interpreter,
+    _environment: EnvironmentId,
+    values: Vec<Value>,
+) -> Result<Value, Error> {
+    if values.len() < 2 || values.len() > 3 {
+        return Error::invalid_argument_count_error(
+            "Built-in function `object:unobserve!' takes two or three arguments.",
+        )
+        .into();
+    }
+
+    let (object_id, observer) =
+        super::observe_mark::read_object_observer(interpreter, values)?;
+
+    let removed = interpreter
+        .get_object_mut(object_id)?
+        .remove_observer(observer);
+
+    Ok(Value::Boolean(removed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[allow(unused_imports)]
+    use crate::utils;
+
+    #[test]
+    fn stops_calling_removed_observer() {
+        let mut interpreter = Interpreter::new();
+
+        interpreter
+            .execute_in_main_environment(
+                "(defv counter 0) \
+                 (defv observer (fn (key old new) (set! counter (inc counter)))) \
+                 (defv obj {:a 1})",
+            )
+            .unwrap();
+
+        let pairs = vec![
+            ("(object:observe! obj :a observer)", "observer"),
+            ("(progn (object:set! obj :a 2) counter)", "1"),
+            ("(object:unobserve! obj observer)", "#f"),
+            ("(object:unobserve! obj :a observer)", "#t"),
+            ("(object:unobserve! obj :a observer)", "#f"),
+            ("(progn (object:set! obj :a 3) counter)", "1"),
+        ];
+
+        utils::assert_results_are_equal(&mut interpreter, pairs);
+    }
+
+    #[test]
+    fn returns_invalid_argument_count_error_when_argument_count_is_not_correct()
+    {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec![
+            "(object:unobserve!)",
+            "(object:unobserve! {})",
+            "(object:unobserve! {} :a (fn () 1) 1)",
+        ];
+
+        utils::assert_results_are_invalid_argument_count_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+
+    #[test]
+    fn returns_invalid_argument_error_when_invalid_arguments_were_provided() {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec![
+            "(object:unobserve! 1 (fn () 1))",
+            "(object:unobserve! {} 1)",
+            "(object:unobserve! {} :a 1)",
+            "(object:unobserve! {} 1 (fn () 1))",
+        ];
+
+        utils::assert_results_are_invalid_argument_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+}
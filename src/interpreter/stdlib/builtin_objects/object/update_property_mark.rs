@@ -25,7 +25,7 @@ fn read_create_flag_from_property_descriptor(
 
 pub fn update_property_mark(
     interpreter: &mut Interpreter,
-    _environment: EnvironmentId,
+    environment_id: EnvironmentId,
     values: Vec<Value>,
 ) -> Result<Value, Error> {
     if values.len() != 2 {
@@ -73,11 +73,26 @@ pub fn update_property_mark(
             property_descriptor_object_id,
         )?;
 
+    let old_value = library::get_observed_property_value(
+        interpreter,
+        object_id,
+        property_symbol_id,
+    )?;
+
     let object = interpreter.get_object_mut(object_id)?;
 
     object.set_property(property_symbol_id, property_value)?;
     object.set_property_flags(property_symbol_id, flags)?;
 
+    library::notify_object_observers(
+        interpreter,
+        environment_id,
+        object_id,
+        property_symbol_id,
+        old_value,
+        property_value,
+    )?;
+
     Ok(Value::Boolean(true))
 }
 
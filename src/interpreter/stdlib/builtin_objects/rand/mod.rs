@@ -8,18 +8,18 @@ use crate::library;
 mod float;
 mod int;
 
+pub const BUILTIN_FUNCTIONS: &[(&str, BuiltinFunctionType)] =
+    &[("int", int::int), ("float", float::float)];
+
 pub fn infect(interpreter: &mut Interpreter) -> Result<(), Error> {
     let rand_object_id = interpreter.make_object();
 
-    let bindings: Vec<(&str, BuiltinFunctionType)> =
-        vec![("int", int::int), ("float", float::float)];
-
-    for (name, func) in bindings {
+    for (name, func) in BUILTIN_FUNCTIONS {
         library::infect_object_builtin_function(
             interpreter,
             rand_object_id,
             name,
-            func,
+            *func,
         )?;
     }
 
@@ -26,39 +26,39 @@ mod trim_left;
 mod trim_right;
 mod upper;
 
+pub const BUILTIN_FUNCTIONS: &[(&str, BuiltinFunctionType)] = &[
+    ("compare", compare::compare),
+    ("concat", concat::concat),
+    ("contains?", contains::contains),
+    ("equal?", equal_question::equal_question),
+    ("find", find::find),
+    ("format", format::format),
+    ("greater?", greater_question::greater_question),
+    ("join", join::join),
+    ("left", left::left),
+    ("length", length::length),
+    ("less?", less_question::less_question),
+    ("lower", lower::lower),
+    ("repeat", repeat::repeat),
+    ("right", right::right),
+    ("split", split::split),
+    ("substr", substr::substr),
+    ("trim", trim::trim),
+    ("trim-left", trim_left::trim_left),
+    ("trim-right", trim_right::trim_right),
+    ("upper", upper::upper),
+];
+
 pub fn infect(interpreter: &mut Interpreter) -> Result<(), Error> {
     let string_object_id = interpreter.make_object();
     let string_symbol_id = interpreter.intern_symbol_id("string");
 
-    let bindings: Vec<(&str, BuiltinFunctionType)> = vec![
-        ("compare", compare::compare),
-        ("concat", concat::concat),
-        ("contains?", contains::contains),
-        ("equal?", equal_question::equal_question),
-        ("find", find::find),
-        ("format", format::format),
-        ("greater?", greater_question::greater_question),
-        ("join", join::join),
-        ("left", left::left),
-        ("length", length::length),
-        ("less?", less_question::less_question),
-        ("lower", lower::lower),
-        ("repeat", repeat::repeat),
-        ("right", right::right),
-        ("split", split::split),
-        ("substr", substr::substr),
-        ("trim", trim::trim),
-        ("trim-left", trim_left::trim_left),
-        ("trim-right", trim_right::trim_right),
-        ("upper", upper::upper),
-    ];
-
-    for (name, func) in bindings {
+    for (name, func) in BUILTIN_FUNCTIONS {
         library::infect_object_builtin_function(
             interpreter,
             string_object_id,
             name,
-            func,
+            *func,
         )?;
     }
 
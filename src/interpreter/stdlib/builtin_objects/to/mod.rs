@@ -12,25 +12,25 @@ mod keyword;
 mod string;
 mod symbol;
 
+pub const BUILTIN_FUNCTIONS: &[(&str, BuiltinFunctionType)] = &[
+    ("boolean", boolean::boolean),
+    ("float", float::float),
+    ("int", int::int),
+    ("keyword", keyword::keyword),
+    ("string", string::string),
+    ("symbol", symbol::symbol),
+];
+
 pub fn infect(interpreter: &mut Interpreter) -> Result<(), Error> {
     let to_object_id = interpreter.make_object();
     let to_symbol_id = interpreter.intern_symbol_id("to");
 
-    let bindings: Vec<(&str, BuiltinFunctionType)> = vec![
-        ("boolean", boolean::boolean),
-        ("float", float::float),
-        ("int", int::int),
-        ("keyword", keyword::keyword),
-        ("string", string::string),
-        ("symbol", symbol::symbol),
-    ];
-
-    for (name, func) in bindings {
+    for (name, func) in BUILTIN_FUNCTIONS {
         library::infect_object_builtin_function(
             interpreter,
             to_object_id,
             name,
-            func,
+            *func,
         )?;
     }
 
@@ -1,5 +1,6 @@
 use crate::interpreter::error::Error;
 use crate::interpreter::interpreter::Interpreter;
+use crate::interpreter::value::Value;
 
 pub const DEFINED_DEVICES_ROOT_VARIABLE_NAME: &'static str =
     "nia-defined-devices";
@@ -7,9 +8,47 @@ pub const DEFINED_MODIFIERS_ROOT_VARIABLE_NAME: &'static str =
     "nia-defined-modifiers";
 pub const DEFINED_ACTIONS_ROOT_VARIABLE_NAME: &'static str =
     "nia-defined-actions";
+pub const DEFINED_REMAPS_ROOT_VARIABLE_NAME: &'static str =
+    "nia-defined-remaps";
 pub const GLOBAL_MAP_ROOT_VARIABLE_NAME: &'static str = "nia-global-map";
 pub const PRIMITIVE_ACTIONS_VARIABLE_NAME: &'static str =
     "nia-primitive-actions";
+pub const EMERGENCY_STOP_KEY_CHORD_ROOT_VARIABLE_NAME: &'static str =
+    "nia-emergency-stop-key-chord";
+pub const MAX_ACTIONS_PER_SECOND_ROOT_VARIABLE_NAME: &'static str =
+    "nia-max-actions-per-second";
+pub const MAX_NAMED_ACTION_DEPTH_ROOT_VARIABLE_NAME: &'static str =
+    "nia-max-named-action-depth";
+
+pub const WATCH_MODULES_ROOT_VARIABLE_NAME: &'static str =
+    "nia-watch-modules";
+pub const KEYBOARD_LAYOUT_ROOT_VARIABLE_NAME: &'static str =
+    "nia-keyboard-layout";
+pub const MODULE_SEARCH_PATH_ROOT_VARIABLE_NAME: &'static str =
+    "nia-module-search-path";
+
+// root variables that are rebuilt from scratch when modules are reloaded
+pub const KEY_CONFIGURATION_ROOT_VARIABLE_NAMES: [&'static str; 6] = [
+    DEFINED_DEVICES_ROOT_VARIABLE_NAME,
+    DEFINED_MODIFIERS_ROOT_VARIABLE_NAME,
+    DEFINED_ACTIONS_ROOT_VARIABLE_NAME,
+    DEFINED_REMAPS_ROOT_VARIABLE_NAME,
+    GLOBAL_MAP_ROOT_VARIABLE_NAME,
+    EMERGENCY_STOP_KEY_CHORD_ROOT_VARIABLE_NAME,
+];
+
+// root variables which the action listener is constructed from
+pub const LISTENER_CONFIGURATION_ROOT_VARIABLE_NAMES: [&'static str; 5] = [
+    DEFINED_DEVICES_ROOT_VARIABLE_NAME,
+    DEFINED_MODIFIERS_ROOT_VARIABLE_NAME,
+    DEFINED_REMAPS_ROOT_VARIABLE_NAME,
+    GLOBAL_MAP_ROOT_VARIABLE_NAME,
+    EMERGENCY_STOP_KEY_CHORD_ROOT_VARIABLE_NAME,
+];
+
+pub const DEFAULT_MAX_ACTIONS_PER_SECOND: i64 = 1000;
+pub const DEFAULT_MAX_NAMED_ACTION_DEPTH: i64 = 32;
+pub const DEFAULT_KEYBOARD_LAYOUT: &'static str = "us";
 
 fn define_variable_with_nil(
     interpreter: &mut Interpreter,
@@ -24,6 +63,72 @@ fn define_variable_with_nil(
     Ok(())
 }
 
+fn define_variable_with_integer(
+    interpreter: &mut Interpreter,
+    name: &str,
+    value: i64,
+) -> Result<(), Error> {
+    let root_environment_id = interpreter.get_root_environment_id();
+    let symbol_id = interpreter.intern_symbol_id(name);
+
+    interpreter.define_variable(
+        root_environment_id,
+        symbol_id,
+        Value::Integer(value),
+    )?;
+
+    Ok(())
+}
+
+fn define_variable_with_boolean(
+    interpreter: &mut Interpreter,
+    name: &str,
+    value: bool,
+) -> Result<(), Error> {
+    let root_environment_id = interpreter.get_root_environment_id();
+    let symbol_id = interpreter.intern_symbol_id(name);
+
+    interpreter.define_variable(
+        root_environment_id,
+        symbol_id,
+        Value::Boolean(value),
+    )?;
+
+    Ok(())
+}
+
+fn define_variable_with_string(
+    interpreter: &mut Interpreter,
+    name: &str,
+    value: &str,
+) -> Result<(), Error> {
+    let root_environment_id = interpreter.get_root_environment_id();
+    let symbol_id = interpreter.intern_symbol_id(name);
+    let value = interpreter.intern_string_value(value);
+
+    interpreter.define_variable(root_environment_id, symbol_id, value)?;
+
+    Ok(())
+}
+
+fn define_variable_with_string_list(
+    interpreter: &mut Interpreter,
+    name: &str,
+    strings: Vec<String>,
+) -> Result<(), Error> {
+    let root_environment_id = interpreter.get_root_environment_id();
+    let symbol_id = interpreter.intern_symbol_id(name);
+    let values = strings
+        .into_iter()
+        .map(|string| interpreter.intern_string_value(string))
+        .collect();
+    let value = interpreter.vec_to_list(values);
+
+    interpreter.define_variable(root_environment_id, symbol_id, value)?;
+
+    Ok(())
+}
+
 fn define_empty_list(
     interpreter: &mut Interpreter,
     name: &str,
@@ -35,9 +140,40 @@ pub fn infect(interpreter: &mut Interpreter) -> Result<(), Error> {
     define_empty_list(interpreter, DEFINED_DEVICES_ROOT_VARIABLE_NAME)?;
     define_empty_list(interpreter, DEFINED_MODIFIERS_ROOT_VARIABLE_NAME)?;
     define_empty_list(interpreter, DEFINED_ACTIONS_ROOT_VARIABLE_NAME)?;
+    define_empty_list(interpreter, DEFINED_REMAPS_ROOT_VARIABLE_NAME)?;
     define_empty_list(interpreter, GLOBAL_MAP_ROOT_VARIABLE_NAME)?;
 
     define_empty_list(interpreter, PRIMITIVE_ACTIONS_VARIABLE_NAME)?;
 
+    define_variable_with_nil(
+        interpreter,
+        EMERGENCY_STOP_KEY_CHORD_ROOT_VARIABLE_NAME,
+    )?;
+    define_variable_with_integer(
+        interpreter,
+        MAX_ACTIONS_PER_SECOND_ROOT_VARIABLE_NAME,
+        DEFAULT_MAX_ACTIONS_PER_SECOND,
+    )?;
+    define_variable_with_integer(
+        interpreter,
+        MAX_NAMED_ACTION_DEPTH_ROOT_VARIABLE_NAME,
+        DEFAULT_MAX_NAMED_ACTION_DEPTH,
+    )?;
+    define_variable_with_string(
+        interpreter,
+        KEYBOARD_LAYOUT_ROOT_VARIABLE_NAME,
+        DEFAULT_KEYBOARD_LAYOUT,
+    )?;
+    define_variable_with_boolean(
+        interpreter,
+        WATCH_MODULES_ROOT_VARIABLE_NAME,
+        false,
+    )?;
+    define_variable_with_string_list(
+        interpreter,
+        MODULE_SEARCH_PATH_ROOT_VARIABLE_NAME,
+        crate::utils::get_default_module_search_path(),
+    )?;
+
     Ok(())
 }
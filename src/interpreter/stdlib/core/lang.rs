@@ -31,7 +31,7 @@ mod defv {
 
     pub fn infect(interpreter: &mut Interpreter) -> Result<(), Error> {
         interpreter.execute_in_root_environment(
-            "(define-function defv (function (macro (name #opt (value nil)) (list:new 'define-variable name value))))"
+            "(define-function defv (function (macro (name #opt (value nil) documentation) (cond (documentation (list:new 'define-variable name value documentation)) (#t (list:new 'define-variable name value))))))"
         )?;
 
         Ok(())
@@ -48,7 +48,12 @@ mod defv {
         fn defines_variable() {
             let mut interpreter = Interpreter::new();
 
-            let pairs = vec![("(defv a 1) a", "1"), ("(defv b) b", "nil")];
+            let pairs = vec![
+                ("(defv a 1) a", "1"),
+                ("(defv b) b", "nil"),
+                ("(defv c 2 \"Doc.\") c", "2"),
+                ("(doc \"c\")", "\"Doc.\""),
+            ];
 
             utils::assert_results_are_equal(&mut interpreter, pairs);
         }
@@ -61,7 +66,7 @@ mod defc {
 
     pub fn infect(interpreter: &mut Interpreter) -> Result<(), Error> {
         interpreter.execute_in_root_environment(
-            "(define-function defc (function (macro (name #opt (value nil)) (list:new 'define-variable name value :const))))"
+            "(define-function defc (function (macro (name #opt (value nil) documentation) (cond (documentation (list:new 'define-variable name value :const documentation)) (#t (list:new 'define-variable name value :const))))))"
         )?;
 
         Ok(())
@@ -80,6 +85,8 @@ mod defc {
             let pairs = vec![
                 ("(defc a 1) a", "1"),
                 ("(defc b) b", "nil"),
+                ("(defc d 2 \"Doc.\") d", "2"),
+                ("(doc \"d\")", "\"Doc.\""),
                 // todo: probably change error symbol to smth like "setting-constant-error"
                 (
                     "(try (progn (defc c 2) (set! c 3) c) (catch 'generic-execution-error #t))",
@@ -124,6 +131,8 @@ mod defn {
                 ("(defn e (#opt b c) (list:new b c)) (e 2 3)", "'(2 3)"),
                 ("(defn f (#rest b) b) (f 2 3 4)", "'(2 3 4)"),
                 ("(defn g (#keys b) b) (g :b 1)", "1"),
+                ("(defn h (a) \"Returns a.\" a) (h 1)", "1"),
+                ("(doc \"h\")", "\"Returns a.\""),
             ];
 
             utils::assert_results_are_equal(&mut interpreter, pairs);
@@ -19,8 +19,20 @@ pub fn infect_stdlib(interpreter: &mut Interpreter) -> Result<(), Error> {
     Ok(())
 }
 
+pub use builtin_functions::BUILTIN_FUNCTIONS;
+pub use builtin_objects::BUILTIN_OBJECTS;
+pub use special_forms::SPECIAL_FORMS;
+
 pub use builtin_variables::{
-    DEFINED_ACTIONS_ROOT_VARIABLE_NAME, DEFINED_DEVICES_ROOT_VARIABLE_NAME,
-    DEFINED_MODIFIERS_ROOT_VARIABLE_NAME, GLOBAL_MAP_ROOT_VARIABLE_NAME,
-    PRIMITIVE_ACTIONS_VARIABLE_NAME,
+    DEFAULT_KEYBOARD_LAYOUT, DEFAULT_MAX_ACTIONS_PER_SECOND,
+    DEFAULT_MAX_NAMED_ACTION_DEPTH, DEFINED_ACTIONS_ROOT_VARIABLE_NAME,
+    DEFINED_DEVICES_ROOT_VARIABLE_NAME, DEFINED_MODIFIERS_ROOT_VARIABLE_NAME,
+    DEFINED_REMAPS_ROOT_VARIABLE_NAME,
+    EMERGENCY_STOP_KEY_CHORD_ROOT_VARIABLE_NAME, GLOBAL_MAP_ROOT_VARIABLE_NAME,
+    KEYBOARD_LAYOUT_ROOT_VARIABLE_NAME, KEY_CONFIGURATION_ROOT_VARIABLE_NAMES,
+    LISTENER_CONFIGURATION_ROOT_VARIABLE_NAMES,
+    MAX_ACTIONS_PER_SECOND_ROOT_VARIABLE_NAME,
+    MAX_NAMED_ACTION_DEPTH_ROOT_VARIABLE_NAME,
+    MODULE_SEARCH_PATH_ROOT_VARIABLE_NAME, PRIMITIVE_ACTIONS_VARIABLE_NAME,
+    WATCH_MODULES_ROOT_VARIABLE_NAME,
 };
@@ -0,0 +1,427 @@
+use crate::interpreter::environment::EnvironmentId;
+use crate::interpreter::error::Error;
+use crate::interpreter::interpreter::Interpreter;
+use crate::interpreter::value::Function;
+use crate::interpreter::value::InterpretedFunction;
+use crate::interpreter::value::ObjectId;
+use crate::interpreter::value::Value;
+
+use crate::interpreter::library;
+
+const ERROR_MESSAGE_INCORRECT_CLAUSE: &'static str = "Clauses of special form `defclass' must be lists of signature \
+ (constructor ([arguments]) form1 ...), (method name ([arguments]) form1 ...) or (static name value).";
+
+// `(super:method ...)' reads as `(call-with-this super (:method super) ...)',
+// which would call the parent's method with the parent class as `this'. In
+// methods it is rewritten to `(call-with-this this (:method super) ...)', so
+// that the method of the parent class runs on the same instance.
+fn rewrite_super_calls(
+    interpreter: &mut Interpreter,
+    value: Value,
+) -> Result<Value, Error> {
+    let cons_id = match value {
+        Value::Cons(cons_id) => cons_id,
+        _ => return Ok(value),
+    };
+
+    let car = interpreter.get_car(cons_id)?;
+    let cdr = interpreter.get_cdr(cons_id)?;
+
+    if car == interpreter.intern_symbol_value("quote") {
+        return Ok(value);
+    }
+
+    if car == interpreter.intern_symbol_value("call-with-this") {
+        if let Value::Cons(arguments_cons_id) = cdr {
+            let context = interpreter.get_car(arguments_cons_id)?;
+
+            if context == interpreter.intern_symbol_value("super") {
+                let rest = interpreter.get_cdr(arguments_cons_id)?;
+                let rest = rewrite_super_calls(interpreter, rest)?;
+                let this = interpreter.intern_symbol_value("this");
+                let arguments = interpreter.make_cons_value(this, rest);
+
+                return Ok(interpreter.make_cons_value(car, arguments));
+            }
+        }
+    }
+
+    let car = rewrite_super_calls(interpreter, car)?;
+    let cdr = rewrite_super_calls(interpreter, cdr)?;
+
+    Ok(interpreter.make_cons_value(car, cdr))
+}
+
+fn make_method(
+    interpreter: &mut Interpreter,
+    class_environment_id: EnvironmentId,
+    arguments: Value,
+    code: Vec<Value>,
+) -> Result<Value, Error> {
+    let arguments = library::read_as_arguments(interpreter, arguments)?;
+    let code = code
+        .into_iter()
+        .map(|value| rewrite_super_calls(interpreter, value))
+        .collect::<Result<Vec<Value>, Error>>()?;
+
+    let function = Function::Interpreted(InterpretedFunction::new(
+        class_environment_id,
+        arguments,
+        code,
+    ));
+
+    let function_id = interpreter.register_function(function);
+
+    Ok(Value::Function(function_id))
+}
+
+fn define_class_member(
+    interpreter: &mut Interpreter,
+    class_environment_id: EnvironmentId,
+    class_id: ObjectId,
+    clause: Value,
+) -> Result<(), Error> {
+    let mut values =
+        library::read_as_vector(interpreter, clause).map_err(|_| {
+            Error::invalid_argument_error(ERROR_MESSAGE_INCORRECT_CLAUSE)
+        })?;
+
+    if values.len() < 2 {
+        return Error::invalid_argument_error(ERROR_MESSAGE_INCORRECT_CLAUSE)
+            .into();
+    }
+
+    let clause_name =
+        library::read_as_symbol_name(interpreter, values.remove(0))?.clone();
+
+    let (property_name, value) = match clause_name.as_str() {
+        "constructor" => {
+            let arguments = values.remove(0);
+            let code = values;
+            let constructor = make_method(
+                interpreter,
+                class_environment_id,
+                arguments,
+                code,
+            )?;
+
+            (
+                String::from(library::CONSTRUCTOR_PROPERTY_NAME),
+                constructor,
+            )
+        },
+        "method" => {
+            if values.len() < 2 {
+                return Error::invalid_argument_error(
+                    ERROR_MESSAGE_INCORRECT_CLAUSE,
+                )
+                .into();
+            }
+
+            let name =
+                library::read_as_symbol_name(interpreter, values.remove(0))?
+                    .clone();
+            let arguments = values.remove(0);
+            let code = values;
+            let method = make_method(
+                interpreter,
+                class_environment_id,
+                arguments,
+                code,
+            )?;
+
+            (name, method)
+        },
+        "static" => {
+            if values.len() != 2 {
+                return Error::invalid_argument_error(
+                    ERROR_MESSAGE_INCORRECT_CLAUSE,
+                )
+                .into();
+            }
+
+            let name =
+                library::read_as_symbol_name(interpreter, values.remove(0))?
+                    .clone();
+            let value = interpreter
+                .execute_value(class_environment_id, values.remove(0))?;
+
+            (name, value)
+        },
+        _ => {
+            return Error::invalid_argument_error(
+                ERROR_MESSAGE_INCORRECT_CLAUSE,
+            )
+            .into();
+        },
+    };
+
+    let property_symbol_id = interpreter.intern_symbol_id(&property_name);
+
+    interpreter.set_object_property(class_id, property_symbol_id, value)
+}
+
+pub fn defclass(
+    interpreter: &mut Interpreter,
+    environment_id: EnvironmentId,
+    values: Vec<Value>,
+) -> Result<Value, Error> {
+    if values.len() < 2 {
+        return Error::invalid_argument_count_error(
+            "Special form `defclass' takes two arguments at least.",
+        )
+        .into();
+    }
+
+    let mut values = values;
+
+    let class_symbol_id = library::read_as_symbol_id(values.remove(0))?;
+
+    library::check_symbol_is_assignable(interpreter, class_symbol_id)?;
+
+    let parent_forms = library::read_as_vector(interpreter, values.remove(0))?;
+
+    let parent_class_id = match parent_forms.len() {
+        0 => None,
+        1 => {
+            let parent_class =
+                interpreter.execute_value(environment_id, parent_forms[0])?;
+
+            Some(library::read_as_object_id(parent_class)?)
+        },
+        _ => {
+            return Error::invalid_argument_error(
+                "Class can extend only one class.",
+            )
+            .into();
+        },
+    };
+
+    // instances use class as prototype, so methods and static members are
+    // inherited through the existing prototype chain
+    let class_id = match parent_class_id {
+        Some(parent_class_id) => interpreter.make_child_object(parent_class_id),
+        None => interpreter.make_object(),
+    };
+
+    // methods are closed over an environment in which `super' refers to the
+    // parent class instead of the prototype of `this'
+    let class_environment_id = interpreter.make_environment(environment_id)?;
+
+    if let Some(parent_class_id) = parent_class_id {
+        let super_symbol_id = interpreter.intern_symbol_id("super");
+
+        interpreter.define_variable(
+            class_environment_id,
+            super_symbol_id,
+            parent_class_id.into(),
+        )?;
+    }
+
+    for clause in values {
+        define_class_member(
+            interpreter,
+            class_environment_id,
+            class_id,
+            clause,
+        )?;
+    }
+
+    let class_value = Value::Object(class_id);
+
+    interpreter.define_variable(
+        environment_id,
+        class_symbol_id,
+        class_value,
+    )?;
+
+    Ok(class_value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[allow(unused_imports)]
+    use crate::utils;
+
+    fn define_classes(interpreter: &mut Interpreter, code: &str) {
+        nia_assert_is_ok(&interpreter.execute_in_main_environment(code));
+    }
+
+    #[test]
+    fn defines_classes_with_constructors_and_methods() {
+        let mut interpreter = Interpreter::new();
+
+        define_classes(
+            &mut interpreter,
+            "(defclass point () \
+               (constructor (x y) (object:set! this :x x) (object:set! this :y y)) \
+               (method sum () (+ this:x this:y)) \
+               (method add (p) (new point (+ this:x p:x) (+ this:y p:y))))",
+        );
+
+        let pairs = vec![
+            ("(object:get (new point 1 2) :x)", "1"),
+            ("(let ((p (new point 1 2))) (p:sum))", "3"),
+            (
+                "(let* ((p (new point 1 2)) (q (p:add (new point 3 4)))) (q:sum))",
+                "10",
+            ),
+            ("(instance-of? (new point 1 2) point)", "#t"),
+        ];
+
+        utils::assert_results_are_equal(&mut interpreter, pairs);
+    }
+
+    #[test]
+    fn returns_defined_class() {
+        let mut interpreter = Interpreter::new();
+
+        let result = interpreter
+            .execute_in_main_environment("(defclass a ())")
+            .unwrap();
+        let expected = interpreter.execute_in_main_environment("a").unwrap();
+
+        nia_assert_equal(expected, result);
+    }
+
+    #[test]
+    fn supports_inheritance() {
+        let mut interpreter = Interpreter::new();
+
+        define_classes(
+            &mut interpreter,
+            "(defclass animal () \
+               (constructor (name) (object:set! this :name name)) \
+               (method speak () \"...\") \
+               (method describe () (list:new this:name (this:speak)))) \
+             (defclass dog (animal) \
+               (method speak () \"woof\") \
+               (method describe () (cons:new :dog (super:describe))))",
+        );
+
+        let pairs = vec![
+            (
+                "(let ((a (new animal \"a\"))) (a:describe))",
+                "'(\"a\" \"...\")",
+            ),
+            (
+                "(let ((d (new dog \"d\"))) (d:describe))",
+                "'(:dog \"d\" \"woof\")",
+            ),
+            ("(instance-of? (new dog \"d\") dog)", "#t"),
+            ("(instance-of? (new dog \"d\") animal)", "#t"),
+            ("(instance-of? (new animal \"a\") dog)", "#f"),
+        ];
+
+        utils::assert_results_are_equal(&mut interpreter, pairs);
+    }
+
+    #[test]
+    fn calls_parent_constructor_with_same_this() {
+        let mut interpreter = Interpreter::new();
+
+        define_classes(
+            &mut interpreter,
+            "(defclass base () (constructor (a) (object:set! this :a a))) \
+             (defclass derived (base) \
+               (constructor (a b) (super:constructor a) (object:set! this :b b)))",
+        );
+
+        let pairs = vec![
+            ("(let ((d (new derived 1 2))) (list:new d:a d:b))", "'(1 2)"),
+            ("(let ((d (new base 1))) d:a)", "1"),
+            ("(object:get base :a)", "nil"),
+        ];
+
+        utils::assert_results_are_equal(&mut interpreter, pairs);
+    }
+
+    #[test]
+    fn calls_parent_methods_through_several_levels() {
+        let mut interpreter = Interpreter::new();
+
+        define_classes(
+            &mut interpreter,
+            "(defclass a () (method name () (list:new :a this:tag))) \
+             (defclass b (a) (method name () (cons:new :b (super:name)))) \
+             (defclass c (b) \
+               (constructor () (object:set! this :tag 1)) \
+               (method name () (cons:new :c (super:name))) \
+               (method quoted () '(super:name)))",
+        );
+
+        let pairs = vec![
+            ("(let ((o (new c))) (o:name))", "'(:c :b :a 1)"),
+            (
+                "(let ((o (new c))) (o:quoted))",
+                "'(call-with-this super (:name super))",
+            ),
+        ];
+
+        utils::assert_results_are_equal(&mut interpreter, pairs);
+    }
+
+    #[test]
+    fn supports_static_members() {
+        let mut interpreter = Interpreter::new();
+
+        define_classes(
+            &mut interpreter,
+            "(defclass counter () \
+               (static count 0) \
+               (static make (fn () (object:set! this :count (inc this:count)) (new this))))",
+        );
+
+        let pairs = vec![
+            ("counter:count", "0"),
+            ("(instance-of? (counter:make) counter)", "#t"),
+            ("counter:count", "1"),
+        ];
+
+        utils::assert_results_are_equal(&mut interpreter, pairs);
+    }
+
+    #[test]
+    fn returns_invalid_argument_count_error_when_incorrect_count_of_arguments_were_provided(
+    ) {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec!["(defclass)", "(defclass a)"];
+
+        utils::assert_results_are_invalid_argument_count_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+
+    #[test]
+    fn returns_invalid_argument_error_when_incorrect_arguments_were_provided() {
+        let mut interpreter = Interpreter::new();
+
+        let code_vector = vec![
+            "(defclass 1 ())",
+            "(defclass this ())",
+            "(defclass a 1)",
+            "(defclass a (1))",
+            "(defclass a ({} {}))",
+            "(defclass a () 1)",
+            "(defclass a () (constructor))",
+            "(defclass a () (constructor 1))",
+            "(defclass a () (method f))",
+            "(defclass a () (method 1 ()))",
+            "(defclass a () (static b))",
+            "(defclass a () (static b 1 2))",
+            "(defclass a () (unknown b 1))",
+        ];
+
+        utils::assert_results_are_invalid_argument_errors(
+            &mut interpreter,
+            code_vector,
+        );
+    }
+}
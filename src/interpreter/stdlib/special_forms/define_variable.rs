@@ -11,9 +11,9 @@ pub fn define_variable(
 ) -> Result<Value, Error> {
     let mut values = values;
 
-    if values.len() < 1 || values.len() > 3 {
+    if values.len() < 1 || values.len() > 4 {
         return Error::invalid_argument_count_error(
-            "Special form `define-variable' must be used with one or two forms.",
+            "Special form `define-variable' must be used with one or two forms, optional keyword `:const' and optional docstring.",
         )
         .into();
     }
@@ -25,21 +25,37 @@ pub fn define_variable(
         None
     };
 
-    let need_to_be_const = if values.len() > 0 {
-        let result =
-            library::read_as_keyword(interpreter, values.remove(0))?.is_const();
-
-        if !result {
-            return Error::invalid_argument_error(
-                "Third argument of special form `define-variable' must be a keyword `:const'.",
-            )
-            .into();
+    let mut need_to_be_const = false;
+    let mut documentation = None;
+
+    for value in values {
+        match value {
+            Value::Keyword(keyword_id)
+                if !need_to_be_const && documentation.is_none() =>
+            {
+                if !interpreter.get_keyword(keyword_id)?.is_const() {
+                    return Error::invalid_argument_error(
+                        "Third argument of special form `define-variable' must be a keyword `:const' or a docstring.",
+                    )
+                    .into();
+                }
+
+                need_to_be_const = true;
+            },
+            Value::String(string_id) if documentation.is_none() => {
+                let string =
+                    interpreter.get_string(string_id)?.get_string().clone();
+
+                documentation = Some(string);
+            },
+            _ => {
+                return Error::invalid_argument_error(
+                    "Special form `define-variable' takes optional keyword `:const' followed by optional docstring.",
+                )
+                .into();
+            },
         }
-
-        result
-    } else {
-        false
-    };
+    }
 
     let variable_symbol_id = match first_argument {
         Value::Symbol(symbol) => symbol,
@@ -93,6 +109,14 @@ pub fn define_variable(
         Error::generic_execution_error_caused(variable_name, err)
     })?;
 
+    if let Some(documentation) = documentation {
+        interpreter.set_variable_documentation(
+            environment,
+            variable_symbol_id,
+            documentation,
+        )?;
+    }
+
     Ok(Value::Boolean(true))
 }
 
@@ -167,6 +191,39 @@ mod tests {
         nia_assert_is_err(&result)
     }
 
+    #[test]
+    fn stores_docstring_of_variable() {
+        let mut interpreter = Interpreter::new();
+
+        interpreter
+            .execute_in_main_environment(
+                "(define-variable a 1 \"First.\") (define-variable b 2 :const \"Second.\") (define-variable c 3)",
+            )
+            .unwrap();
+
+        let main_environment_id = interpreter.get_main_environment_id();
+        let a = interpreter.intern_symbol_id("a");
+        let b = interpreter.intern_symbol_id("b");
+        let c = interpreter.intern_symbol_id("c");
+
+        nia_assert_equal(
+            Ok(Some(String::from("First."))),
+            interpreter.get_variable_documentation(main_environment_id, a),
+        );
+        nia_assert_equal(
+            Ok(Some(String::from("Second."))),
+            interpreter.get_variable_documentation(main_environment_id, b),
+        );
+        nia_assert_equal(
+            Ok(None),
+            interpreter.get_variable_documentation(main_environment_id, c),
+        );
+        nia_assert_equal(
+            Ok(Value::Integer(2)),
+            interpreter.execute_in_main_environment("b"),
+        );
+    }
+
     #[test]
     fn returns_error_when_attempts_to_define_constant_or_special_symbol() {
         let mut interpreter = Interpreter::new();
@@ -193,8 +250,10 @@ mod tests {
     fn returns_err_when_incorrect_count_of_forms_were_provided() {
         let mut interpreter = Interpreter::new();
 
-        let specs =
-            vec!["(define-variable)", "(define-variable test 2 :const kek)"];
+        let specs = vec![
+            "(define-variable)",
+            "(define-variable test 2 :const \"Test.\" kek)",
+        ];
 
         utils::assert_results_are_invalid_argument_count_errors(
             &mut interpreter,
@@ -206,7 +265,13 @@ mod tests {
     fn returns_err_when_an_incorrect_form_were_provided() {
         let mut interpreter = Interpreter::new();
 
-        let specs = vec!["(define-variable 3 2)"];
+        let specs = vec![
+            "(define-variable 3 2)",
+            "(define-variable test 2 :kek)",
+            "(define-variable test 2 \"Test.\" :const)",
+            "(define-variable test 2 :const :const)",
+            "(define-variable test 2 \"Test.\" \"Test.\")",
+        ];
 
         utils::assert_results_are_invalid_argument_errors(
             &mut interpreter,
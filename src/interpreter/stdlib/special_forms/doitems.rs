@@ -87,7 +87,10 @@ pub fn doitems(
     let code = values;
 
     for enumerable_key in object_enumerable_keys {
-        let value = interpreter.get_object_property(
+        // getters are called with `this' bound to the iterated object
+        let value = library::get_object_property(
+            interpreter,
+            environment_id,
             object_id,
             enumerable_key
         )?.ok_or_else(|| Error::generic_execution_error(
@@ -176,6 +179,25 @@ mod tests {
         utils::assert_results_are_equal(&mut interpreter, pairs)
     }
 
+    #[test]
+    fn calls_getters_of_accessor_properties() {
+        let mut interpreter = Interpreter::new();
+
+        let pairs = vec![(
+            r#"
+            (let ((obj {:a 1})
+                  (acc 0))
+              (object:define-property! obj {:name :b :get (fn () (+ this:a 1))})
+              (doitems (key value obj)
+                (set! acc (+ acc value (object:get obj key))))
+              acc)
+            "#,
+            "6",
+        )];
+
+        utils::assert_results_are_equal(&mut interpreter, pairs)
+    }
+
     #[test]
     fn ignores_not_enumerable_and_not_internable_properties() {
         let mut interpreter = Interpreter::new();
@@ -11,21 +11,47 @@ use crate::interpreter::value::Value;
 const ERROR_MESSAGE_INCORRECT_ARGUMENT: &'static str = "The first argument of special form `function', must be a list of signature \
  (lambda|macro ([arguments]) form1 form2 ...).";
 
+// a string that is followed by other forms is a docstring, a single string
+// is the value function returns
+fn extract_documentation(
+    interpreter: &mut Interpreter,
+    code: Vec<Value>,
+) -> Result<(Option<String>, Vec<Value>), Error> {
+    let mut code = code;
+
+    let documentation = match code.get(0) {
+        Some(Value::String(string_id)) if code.len() > 1 => {
+            Some(interpreter.get_string(*string_id)?.get_string().clone())
+        },
+        _ => None,
+    };
+
+    if documentation.is_some() {
+        code.remove(0);
+    }
+
+    Ok((documentation, code))
+}
+
 fn construct_interpreted_function(
     interpreter: &mut Interpreter,
     environment: EnvironmentId,
     arguments: FunctionArguments,
     code: Vec<Value>,
-) -> Value {
-    let function = Function::Interpreted(InterpretedFunction::new(
-        environment,
-        arguments,
-        code,
-    ));
+) -> Result<Value, Error> {
+    let (documentation, code) = extract_documentation(interpreter, code)?;
+
+    let function =
+        Function::Interpreted(InterpretedFunction::with_documentation(
+            environment,
+            arguments,
+            code,
+            documentation,
+        ));
 
     let function_id = interpreter.register_function(function);
 
-    Value::Function(function_id)
+    Ok(Value::Function(function_id))
 }
 
 fn construct_macro_function(
@@ -88,12 +114,12 @@ pub fn function(
             let name = interpreter.get_symbol_name(symbol_id)?;
 
             if name == "lambda" {
-                Ok(construct_interpreted_function(
+                construct_interpreted_function(
                     interpreter,
                     environment,
                     arguments,
                     code,
-                ))
+                )
             } else if name == "macro" {
                 Ok(construct_macro_function(
                     interpreter,
@@ -413,6 +439,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn extracts_docstring_of_interpreted_function() {
+        let mut interpreter = Interpreter::new();
+
+        let result = interpreter
+            .execute_in_main_environment(
+                "(function (lambda (a) \"Returns a.\" a))",
+            )
+            .unwrap();
+        let function_id = library::read_as_function_id(result).unwrap();
+
+        match interpreter.get_function(function_id).unwrap() {
+            Function::Interpreted(interpreted_function) => {
+                nia_assert_equal(
+                    Some(&String::from("Returns a.")),
+                    interpreted_function.get_documentation(),
+                );
+                nia_assert_equal(1, interpreted_function.get_code().len());
+            },
+            _ => nia_assert(false),
+        }
+
+        let pairs = vec![
+            ("((function (lambda (a) \"Returns a.\" a)) 1)", "1"),
+            (
+                "((function (lambda () \"Returns itself.\")))",
+                "\"Returns itself.\"",
+            ),
+        ];
+
+        utils::assert_results_are_equal(&mut interpreter, pairs);
+    }
+
     #[test]
     fn returns_error_when_incorrect_amount_of_elements_of_first_argument_were_provided(
     ) {
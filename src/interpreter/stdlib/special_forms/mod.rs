@@ -12,6 +12,7 @@ mod and;
 mod block;
 mod call_with_this;
 mod cond;
+mod defclass;
 mod define_function;
 mod define_variable;
 mod doitems;
@@ -35,42 +36,43 @@ mod set;
 mod throw;
 mod with_this;
 
-pub fn infect(interpreter: &mut Interpreter) -> Result<(), Error> {
-    let pairs: Vec<(&str, SpecialFormFunctionType)> = vec![
-        ("and", and::and),
-        ("call-with-this", call_with_this::call_with_this),
-        ("cond", cond::cond),
-        ("quote", quote::quote),
-        ("define-variable", define_variable::define_variable),
-        ("define-function", define_function::define_function),
-        ("doitems", doitems::doitems),
-        ("dokeys", dokeys::dokeys),
-        ("dolist", dolist::dolist),
-        ("dotimes", dotimes::dotimes),
-        ("dovalues", dovalues::dovalues),
-        ("export", export::export),
-        ("function", function::function),
-        ("fset!", fset::fset),
-        ("import", import::import),
-        ("let", _let::_let),
-        ("let*", let_star::let_star),
-        ("flet", flet::flet),
-        ("flet*", flet_star::flet_star),
-        ("match", _match::_match),
-        ("mlet", mlet::mlet),
-        ("mlet*", mlet_star::mlet_star),
-        ("or", or::or),
-        ("progn", progn::progn),
-        ("set!", set::set),
-        ("block", block::block),
-        ("throw", throw::throw),
-        ("try", _try::_try),
-        ("while", _while::_while),
-        ("with-this", with_this::with_this),
-    ];
+pub const SPECIAL_FORMS: &[(&str, SpecialFormFunctionType)] = &[
+    ("and", and::and),
+    ("call-with-this", call_with_this::call_with_this),
+    ("cond", cond::cond),
+    ("defclass", defclass::defclass),
+    ("quote", quote::quote),
+    ("define-variable", define_variable::define_variable),
+    ("define-function", define_function::define_function),
+    ("doitems", doitems::doitems),
+    ("dokeys", dokeys::dokeys),
+    ("dolist", dolist::dolist),
+    ("dotimes", dotimes::dotimes),
+    ("dovalues", dovalues::dovalues),
+    ("export", export::export),
+    ("function", function::function),
+    ("fset!", fset::fset),
+    ("import", import::import),
+    ("let", _let::_let),
+    ("let*", let_star::let_star),
+    ("flet", flet::flet),
+    ("flet*", flet_star::flet_star),
+    ("match", _match::_match),
+    ("mlet", mlet::mlet),
+    ("mlet*", mlet_star::mlet_star),
+    ("or", or::or),
+    ("progn", progn::progn),
+    ("set!", set::set),
+    ("block", block::block),
+    ("throw", throw::throw),
+    ("try", _try::_try),
+    ("while", _while::_while),
+    ("with-this", with_this::with_this),
+];
 
-    for (name, func) in pairs {
-        library::infect_special_form(interpreter, name, func)?;
+pub fn infect(interpreter: &mut Interpreter) -> Result<(), Error> {
+    for (name, func) in SPECIAL_FORMS {
+        library::infect_special_form(interpreter, name, *func)?;
     }
 
     Ok(())
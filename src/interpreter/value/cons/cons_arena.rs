@@ -1,6 +1,9 @@
 use std::collections::HashMap;
 
 use crate::interpreter::error::Error;
+use crate::interpreter::image::ImageItem;
+use crate::interpreter::image::ImageReader;
+use crate::interpreter::image::ImageWriter;
 use crate::interpreter::value::{Cons, ConsId, Value};
 
 #[derive(Clone)]
@@ -226,6 +229,20 @@ impl ConsArena {
     }
 }
 
+impl ImageItem for ConsArena {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write(&self.arena)?;
+        writer.write(&self.next_id)
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        let arena = reader.read()?;
+        let next_id = reader.read()?;
+
+        Ok(ConsArena { arena, next_id })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
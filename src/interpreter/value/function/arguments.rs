@@ -1,3 +1,6 @@
+use crate::interpreter::image::ImageItem;
+use crate::interpreter::image::ImageReader;
+use crate::interpreter::image::ImageWriter;
 use crate::interpreter::value::Value;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -224,6 +227,29 @@ impl FunctionArguments {
     }
 }
 
+impl ImageItem for FunctionArguments {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write(&self.ordinary)?;
+        writer.write(&self.optional)?;
+        writer.write(&self.rest)?;
+        writer.write(&self.keys)
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        let ordinary = reader.read()?;
+        let optional = reader.read()?;
+        let rest = reader.read()?;
+        let keys = reader.read()?;
+
+        Ok(FunctionArguments {
+            ordinary,
+            optional,
+            rest,
+            keys,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2,6 +2,9 @@ use std::collections::HashMap;
 
 use crate::interpreter::environment::EnvironmentId;
 use crate::interpreter::error::Error;
+use crate::interpreter::image::ImageItem;
+use crate::interpreter::image::ImageReader;
+use crate::interpreter::image::ImageWriter;
 use crate::interpreter::value::Function;
 use crate::interpreter::value::FunctionId;
 use crate::interpreter::value::Value;
@@ -92,6 +95,20 @@ impl FunctionArena {
     }
 }
 
+impl ImageItem for FunctionArena {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write(&self.arena)?;
+        writer.write(&self.next_id)
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        let arena = reader.read()?;
+        let next_id = reader.read()?;
+
+        Ok(FunctionArena { arena, next_id })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
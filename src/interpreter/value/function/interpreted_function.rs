@@ -7,6 +7,7 @@ pub struct InterpretedFunction {
     environment: EnvironmentId,
     arguments: FunctionArguments,
     code: Vec<Value>,
+    documentation: Option<String>,
 }
 
 impl InterpretedFunction {
@@ -19,6 +20,21 @@ impl InterpretedFunction {
             environment,
             arguments,
             code,
+            documentation: None,
+        }
+    }
+
+    pub fn with_documentation(
+        environment: EnvironmentId,
+        arguments: FunctionArguments,
+        code: Vec<Value>,
+        documentation: Option<String>,
+    ) -> InterpretedFunction {
+        InterpretedFunction {
+            environment,
+            arguments,
+            code,
+            documentation,
         }
     }
 
@@ -34,6 +50,10 @@ impl InterpretedFunction {
         &self.code
     }
 
+    pub fn get_documentation(&self) -> Option<&String> {
+        self.documentation.as_ref()
+    }
+
     pub fn get_gc_items(&self) -> Option<Vec<Value>> {
         let mut result = self.code.clone();
 
@@ -1,9 +1,11 @@
 mod object;
 mod object_arena;
 mod object_id;
+mod object_observer;
 mod object_value_wrapper;
 
 pub use object::*;
 pub use object_arena::*;
 pub use object_id::*;
+pub use object_observer::*;
 pub use object_value_wrapper::*;
@@ -1,6 +1,11 @@
 use std::collections::HashMap;
 
+use crate::interpreter::image::ImageItem;
+use crate::interpreter::image::ImageReader;
+use crate::interpreter::image::ImageWriter;
+use crate::interpreter::value::FunctionId;
 use crate::interpreter::value::ObjectId;
+use crate::interpreter::value::ObjectObserver;
 use crate::interpreter::value::ObjectValueWrapper;
 use crate::interpreter::value::SymbolId;
 use crate::interpreter::value::Value;
@@ -12,6 +17,7 @@ pub struct Object {
     properties: HashMap<SymbolId, ObjectValueWrapper>,
     prototype: Option<ObjectId>,
     frozen: bool,
+    observers: Vec<ObjectObserver>,
 }
 
 impl Object {
@@ -20,6 +26,7 @@ impl Object {
             properties: HashMap::new(),
             prototype: None,
             frozen: false,
+            observers: Vec::new(),
         }
     }
 
@@ -28,6 +35,7 @@ impl Object {
             properties: HashMap::new(),
             prototype: Some(object_id),
             frozen: false,
+            observers: Vec::new(),
         }
     }
 
@@ -75,7 +83,7 @@ impl Object {
         }
     }
 
-    fn get_property_value_wrapper(
+    pub fn get_property_value_wrapper(
         &self,
         property_symbol_id: SymbolId,
     ) -> Option<&ObjectValueWrapper> {
@@ -201,6 +209,18 @@ impl Object {
         object_property_wrapper.set_flags(flags)
     }
 
+    pub fn set_property_accessors(
+        &mut self,
+        property_symbol_id: SymbolId,
+        getter: Option<FunctionId>,
+        setter: Option<FunctionId>,
+    ) -> Result<(), Error> {
+        self.check_is_not_frozen()?;
+
+        self.get_property_value_wrapper_mut_required(property_symbol_id)?
+            .set_accessors(getter, setter)
+    }
+
     pub fn get_property_flag(
         &self,
         property_symbol_id: SymbolId,
@@ -321,6 +341,23 @@ impl Object {
         &self.properties
     }
 
+    pub fn add_observer(&mut self, observer: ObjectObserver) {
+        self.observers.push(observer);
+    }
+
+    pub fn remove_observer(&mut self, observer: ObjectObserver) -> bool {
+        let observers_count = self.observers.len();
+
+        self.observers
+            .retain(|existing_observer| *existing_observer != observer);
+
+        self.observers.len() != observers_count
+    }
+
+    pub fn get_observers(&self) -> &Vec<ObjectObserver> {
+        &self.observers
+    }
+
     pub fn get_gc_items(&self) -> Vec<Value> {
         let mut result = self
             .properties
@@ -336,6 +373,24 @@ impl Object {
                 .map(|value_wrapper| value_wrapper.force_get_value()),
         );
 
+        for value_wrapper in self.properties.values() {
+            if let Some(getter_function_id) = value_wrapper.get_getter() {
+                result.push(Value::Function(getter_function_id));
+            }
+
+            if let Some(setter_function_id) = value_wrapper.get_setter() {
+                result.push(Value::Function(setter_function_id));
+            }
+        }
+
+        for observer in &self.observers {
+            if let Some(property_symbol_id) = observer.get_property_symbol_id() {
+                result.push(Value::Symbol(property_symbol_id));
+            }
+
+            result.push(Value::Function(observer.get_function_id()));
+        }
+
         match self.prototype {
             Some(prototype_id) => result.push(Value::Object(prototype_id)),
             _ => {}
@@ -345,6 +400,29 @@ impl Object {
     }
 }
 
+impl ImageItem for Object {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write(&self.properties)?;
+        writer.write(&self.prototype)?;
+        writer.write(&self.frozen)?;
+        writer.write(&self.observers)
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        let properties = reader.read()?;
+        let prototype = reader.read()?;
+        let frozen = reader.read()?;
+        let observers = reader.read()?;
+
+        Ok(Object {
+            properties,
+            prototype,
+            frozen,
+            observers,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
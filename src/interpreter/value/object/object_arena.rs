@@ -1,8 +1,13 @@
 use std::collections::HashMap;
 
-use crate::interpreter::value::{Object, ObjectId, SymbolId, Value};
+use crate::interpreter::value::{
+    Object, ObjectId, ObjectValueWrapper, SymbolId, Value,
+};
 
 use crate::interpreter::error::Error;
+use crate::interpreter::image::ImageItem;
+use crate::interpreter::image::ImageReader;
+use crate::interpreter::image::ImageWriter;
 
 #[derive(Clone)]
 pub struct ObjectArena {
@@ -143,6 +148,23 @@ impl ObjectArena {
         }
     }
 
+    pub fn get_property_value_wrapper(
+        &self,
+        object_id: ObjectId,
+        property_symbol_id: SymbolId,
+    ) -> Result<Option<ObjectValueWrapper>, Error> {
+        let object = self.get_object(object_id)?;
+
+        match object.get_property_value_wrapper(property_symbol_id) {
+            Some(value_wrapper) => Ok(Some(*value_wrapper)),
+            None => match object.get_prototype() {
+                Some(prototype_id) => self
+                    .get_property_value_wrapper(prototype_id, property_symbol_id),
+                None => Ok(None),
+            },
+        }
+    }
+
     pub fn set_property(
         &mut self,
         object_id: ObjectId,
@@ -351,6 +373,20 @@ impl ObjectArena {
     }
 }
 
+impl ImageItem for ObjectArena {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write(&self.arena)?;
+        writer.write(&self.next_id)
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        let arena = reader.read()?;
+        let next_id = reader.read()?;
+
+        Ok(ObjectArena { arena, next_id })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
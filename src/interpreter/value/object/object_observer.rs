@@ -0,0 +1,62 @@
+use crate::interpreter::value::FunctionId;
+use crate::interpreter::value::SymbolId;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ObjectObserver {
+    property_symbol_id: Option<SymbolId>,
+    function_id: FunctionId,
+}
+
+impl ObjectObserver {
+    pub fn new(
+        property_symbol_id: Option<SymbolId>,
+        function_id: FunctionId,
+    ) -> ObjectObserver {
+        ObjectObserver {
+            property_symbol_id,
+            function_id,
+        }
+    }
+
+    pub fn get_property_symbol_id(&self) -> Option<SymbolId> {
+        self.property_symbol_id
+    }
+
+    pub fn get_function_id(&self) -> FunctionId {
+        self.function_id
+    }
+
+    pub fn observes(&self, property_symbol_id: SymbolId) -> bool {
+        match self.property_symbol_id {
+            Some(observed_symbol_id) => {
+                observed_symbol_id == property_symbol_id
+            },
+            None => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[test]
+    fn observes_every_property_when_property_is_not_specified() {
+        let observer = ObjectObserver::new(None, FunctionId::new(0));
+
+        nia_assert(observer.observes(SymbolId::new(1)));
+        nia_assert(observer.observes(SymbolId::new(2)));
+    }
+
+    #[test]
+    fn observes_only_specified_property() {
+        let observer =
+            ObjectObserver::new(Some(SymbolId::new(1)), FunctionId::new(0));
+
+        nia_assert(observer.observes(SymbolId::new(1)));
+        nia_assert(!observer.observes(SymbolId::new(2)));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Error, Value};
+use crate::{Error, FunctionId, Value};
 
 pub const OBJECT_VALUE_WRAPPER_FLAG_INTERNABLE: u8 = 0x1;
 pub const OBJECT_VALUE_WRAPPER_FLAG_WRITABLE: u8 = 0x2;
@@ -16,12 +16,19 @@ pub const OBJECT_VALUE_WRAPPER_FLAGS_NONE: u8 = 0x0;
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct ObjectValueWrapper {
     value: Value,
+    getter: Option<FunctionId>,
+    setter: Option<FunctionId>,
     flags: u8,
 }
 
 impl ObjectValueWrapper {
     pub fn with_flags(value: Value, flags: u8) -> ObjectValueWrapper {
-        ObjectValueWrapper { value, flags }
+        ObjectValueWrapper {
+            value,
+            getter: None,
+            setter: None,
+            flags,
+        }
     }
 
     pub fn new(value: Value) -> ObjectValueWrapper {
@@ -47,7 +54,11 @@ impl ObjectValueWrapper {
         self.flags & OBJECT_VALUE_WRAPPER_FLAG_CONFIGURABLE != 0
     }
 
-    fn check_is_internable(&self) -> Result<(), Error> {
+    pub fn is_accessor(&self) -> bool {
+        self.getter.is_some() || self.setter.is_some()
+    }
+
+    pub fn check_is_internable(&self) -> Result<(), Error> {
         if self.is_internable() {
             Ok(())
         } else {
@@ -142,6 +153,26 @@ impl ObjectValueWrapper {
         self.set_flag(OBJECT_VALUE_WRAPPER_FLAG_CONFIGURABLE, configurable)
     }
 
+    pub fn get_getter(&self) -> Option<FunctionId> {
+        self.getter
+    }
+
+    pub fn get_setter(&self) -> Option<FunctionId> {
+        self.setter
+    }
+
+    pub fn set_accessors(
+        &mut self,
+        getter: Option<FunctionId>,
+        setter: Option<FunctionId>,
+    ) -> Result<(), Error> {
+        self.check_is_configurable()?;
+        self.getter = getter;
+        self.setter = setter;
+
+        Ok(())
+    }
+
     pub fn set_value(&mut self, value: Value) -> Result<(), Error> {
         self.check_is_settable()?;
         self.value = value;
@@ -167,6 +198,15 @@ impl ObjectValueWrapper {
     pub fn force_set_flags(&mut self, flags: u8) {
         self.flags = flags;
     }
+
+    pub fn force_set_accessors(
+        &mut self,
+        getter: Option<FunctionId>,
+        setter: Option<FunctionId>,
+    ) {
+        self.getter = getter;
+        self.setter = setter;
+    }
 }
 
 #[cfg(test)]
@@ -461,6 +501,61 @@ mod tests {
         }
     }
 
+    #[allow(non_snake_case)]
+    #[cfg(test)]
+    mod set_accessors__is_accessor {
+        use super::*;
+
+        #[test]
+        fn sets_accessors() {
+            let mut object_value_wrapper =
+                ObjectValueWrapper::new(Value::Integer(0));
+
+            nia_assert_equal(false, object_value_wrapper.is_accessor());
+
+            nia_assert_equal(
+                Ok(()),
+                object_value_wrapper
+                    .set_accessors(Some(FunctionId::new(1)), None),
+            );
+            nia_assert_equal(true, object_value_wrapper.is_accessor());
+            nia_assert_equal(
+                Some(FunctionId::new(1)),
+                object_value_wrapper.get_getter(),
+            );
+            nia_assert_equal(None, object_value_wrapper.get_setter());
+
+            nia_assert_equal(
+                Ok(()),
+                object_value_wrapper
+                    .set_accessors(None, Some(FunctionId::new(2))),
+            );
+            nia_assert_equal(true, object_value_wrapper.is_accessor());
+            nia_assert_equal(None, object_value_wrapper.get_getter());
+            nia_assert_equal(
+                Some(FunctionId::new(2)),
+                object_value_wrapper.get_setter(),
+            );
+        }
+
+        #[test]
+        fn returns_error_when_attempts_to_change_not_configurable_value_wrapper(
+        ) {
+            let mut object_value_wrapper = ObjectValueWrapper::with_flags(
+                Value::Integer(0),
+                OBJECT_VALUE_WRAPPER_FLAGS_DEFAULT
+                    ^ OBJECT_VALUE_WRAPPER_FLAG_CONFIGURABLE,
+            );
+
+            nia_assert(
+                object_value_wrapper
+                    .set_accessors(Some(FunctionId::new(1)), None)
+                    .is_err(),
+            );
+            nia_assert_equal(false, object_value_wrapper.is_accessor());
+        }
+    }
+
     #[allow(non_snake_case)]
     #[cfg(test)]
     mod set_configurable__is_configurable {
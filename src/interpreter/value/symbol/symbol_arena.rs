@@ -1,6 +1,9 @@
 use std::collections::HashMap;
 
 use crate::interpreter::error::Error;
+use crate::interpreter::image::ImageItem;
+use crate::interpreter::image::ImageReader;
+use crate::interpreter::image::ImageWriter;
 use crate::interpreter::value::Symbol;
 use crate::interpreter::value::SymbolId;
 
@@ -69,6 +72,13 @@ impl SymbolArena {
         }
     }
 
+    pub fn find_interned(&self, symbol_name: &str) -> Option<SymbolId> {
+        self.mapping
+            .get(symbol_name)
+            .and_then(|symbols| symbols.get(0))
+            .copied()
+    }
+
     pub fn gensym(&mut self, symbol_name: &str) -> SymbolId {
         self.ensure_symbol_defined(symbol_name);
         self.ensure_symbol_internable(symbol_name);
@@ -118,6 +128,26 @@ impl SymbolArena {
     }
 }
 
+impl ImageItem for SymbolArena {
+    fn write_to_image(&self, writer: &mut ImageWriter) -> Result<(), Error> {
+        writer.write(&self.arena)?;
+        writer.write(&self.mapping)?;
+        writer.write(&self.next_id)
+    }
+
+    fn read_from_image(reader: &mut ImageReader) -> Result<Self, Error> {
+        let arena = reader.read()?;
+        let mapping = reader.read()?;
+        let next_id = reader.read()?;
+
+        Ok(SymbolArena {
+            arena,
+            mapping,
+            next_id,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -141,6 +171,28 @@ mod tests {
         }
     }
 
+    #[cfg(test)]
+    mod find_interned {
+        use super::*;
+
+        #[test]
+        pub fn finds_interned_symbols() {
+            let mut arena = SymbolArena::new();
+
+            let sym = arena.intern("test");
+
+            nia_assert_equal(Some(sym), arena.find_interned("test"));
+        }
+
+        #[test]
+        pub fn does_not_intern_unknown_symbols() {
+            let arena = SymbolArena::new();
+
+            nia_assert_equal(None, arena.find_interned("test"));
+            nia_assert(arena.get_all_symbol_identifiers().is_empty());
+        }
+    }
+
     #[cfg(test)]
     mod gensym {
         use super::*;
@@ -1,6 +1,8 @@
 pub mod utils;
 
+pub mod cli;
 pub mod interpreter;
+pub mod lsp;
 pub mod repl;
 
 pub use interpreter::*;
@@ -0,0 +1,170 @@
+use crate::complete_code;
+use crate::get_builtin_documentation;
+use crate::parse_partial_syntax_tree;
+use crate::Interpreter;
+
+use super::find_document_definitions;
+use super::make_range;
+use super::JsonValue;
+
+const COMPLETION_ITEM_KIND_FUNCTION: usize = 3;
+const COMPLETION_ITEM_KIND_VARIABLE: usize = 6;
+
+fn make_completion_item(
+    text: &str,
+    (start, end): (usize, usize),
+    label: &str,
+    signature: Option<String>,
+    documentation: Option<&str>,
+) -> JsonValue {
+    let kind = if signature.is_some() {
+        COMPLETION_ITEM_KIND_FUNCTION
+    } else {
+        COMPLETION_ITEM_KIND_VARIABLE
+    };
+
+    let mut members = vec![
+        ("label", JsonValue::string(label)),
+        ("kind", JsonValue::number(kind)),
+    ];
+
+    if let Some(signature) = signature {
+        members.push(("detail", JsonValue::string(signature)));
+    }
+
+    if let Some(documentation) = documentation {
+        members.push(("documentation", JsonValue::string(documentation)));
+    }
+
+    members.push((
+        "textEdit",
+        JsonValue::object(vec![
+            ("range", make_range(text, start, end)),
+            ("newText", JsonValue::string(label)),
+        ]),
+    ));
+
+    JsonValue::object(members)
+}
+
+// candidates come from the interpreter, so names of the builtin objects
+// complete to their members, like `list:' to `list:append'; definitions of
+// the document are added since it is not evaluated
+pub fn get_completion_items(
+    interpreter: &mut Interpreter,
+    text: &str,
+    offset: usize,
+) -> Vec<JsonValue> {
+    let completion = match complete_code(interpreter, text, offset) {
+        Ok(completion) => completion,
+        Err(_) => return Vec::new(),
+    };
+
+    let start = completion.get_start();
+    let prefix = &text[start..offset];
+    let mut items = Vec::new();
+    let mut labels = Vec::new();
+
+    if !prefix.is_empty() && !prefix.contains(':') {
+        let nodes = parse_partial_syntax_tree(text);
+
+        for definition in find_document_definitions(text, &nodes) {
+            let name = definition.get_name();
+
+            if name.starts_with(prefix) && !labels.contains(name) {
+                items.push(make_completion_item(
+                    text,
+                    (start, offset),
+                    name,
+                    definition
+                        .get_arguments()
+                        .map(|_| definition.get_signature()),
+                    definition.get_documentation().map(String::as_str),
+                ));
+                labels.push(name.clone());
+            }
+        }
+    }
+
+    for candidate in completion.take_candidates() {
+        if labels.contains(&candidate) {
+            continue;
+        }
+
+        let documentation = get_builtin_documentation(&candidate);
+
+        items.push(make_completion_item(
+            text,
+            (start, offset),
+            &candidate,
+            documentation
+                .map(|documentation| documentation.get_signature().to_string()),
+            documentation
+                .map(|documentation| documentation.get_documentation()),
+        ));
+        labels.push(candidate);
+    }
+
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    fn get_labels(items: &[JsonValue]) -> Vec<&str> {
+        items
+            .iter()
+            .filter_map(|item| item.get("label").and_then(JsonValue::as_str))
+            .collect()
+    }
+
+    #[test]
+    fn completes_members_of_builtin_objects() {
+        let mut interpreter = Interpreter::new();
+        let text = "(list:app";
+        let items = get_completion_items(&mut interpreter, text, text.len());
+
+        nia_assert(get_labels(&items).contains(&"list:append"));
+
+        let item = items
+            .iter()
+            .find(|item| {
+                item.get("label").and_then(JsonValue::as_str)
+                    == Some("list:append")
+            })
+            .unwrap();
+        let documentation = get_builtin_documentation("list:append").unwrap();
+
+        nia_assert_equal(
+            Some(documentation.get_signature()),
+            item.get("detail").and_then(JsonValue::as_str),
+        );
+        nia_assert_equal(
+            Some(1),
+            item.get_path(&["textEdit", "range", "start", "character"])
+                .and_then(JsonValue::as_usize),
+        );
+        nia_assert_equal(
+            Some(9),
+            item.get_path(&["textEdit", "range", "end", "character"])
+                .and_then(JsonValue::as_usize),
+        );
+    }
+
+    #[test]
+    fn completes_definitions_of_the_document() {
+        let mut interpreter = Interpreter::new();
+        let text = "(defn my-function (a) a)\n(my-f";
+        let items = get_completion_items(&mut interpreter, text, text.len());
+
+        nia_assert_equal(vec!["my-function"], get_labels(&items));
+        nia_assert_equal(
+            Some("(defn my-function (a))"),
+            items[0].get("detail").and_then(JsonValue::as_str),
+        );
+    }
+}
@@ -0,0 +1,449 @@
+use std::collections::HashMap;
+use std::path::Component;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::is_std_module_path;
+use crate::parse_partial_syntax_tree;
+use crate::Interpreter;
+use crate::SyntaxNode;
+use crate::TokenKind;
+
+use super::find_document_definitions;
+use super::find_symbol_at;
+use super::make_range;
+use super::path_to_uri;
+use super::uri_to_path;
+use super::JsonValue;
+
+// what an imported name refers to in the module it comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ImportTarget {
+    Module,
+    Name(String),
+    DefaultExport,
+    // names of `(import * from "path")' may be defined elsewhere, so they
+    // do not lead to the module
+    Unqualified(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ImportBinding {
+    // `(import name from "path")'
+    Default(String),
+    // `(import {:name 'binding} from "path")'
+    Named(String, String),
+    // `(import * as binding from "path")'
+    Namespace(String),
+    // `(import * from "path")'
+    All,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ImportForm {
+    path: String,
+    path_start: usize,
+    path_end: usize,
+    bindings: Vec<ImportBinding>,
+}
+
+fn read_named_bindings(node: &SyntaxNode) -> Vec<ImportBinding> {
+    node.get_children()
+        .chunks(2)
+        .filter_map(|pair| {
+            let key = match *pair.first()? {
+                SyntaxNode::Atom(TokenKind::Keyword, key, _) => key,
+                _ => return None,
+            };
+            let binding = match *pair.get(1)? {
+                SyntaxNode::Prefixed(prefix, binding, _) if prefix == "'" => {
+                    binding.get_symbol()?
+                },
+                _ => return None,
+            };
+
+            Some(ImportBinding::Named(
+                key.trim_start_matches(':').to_string(),
+                binding.to_string(),
+            ))
+        })
+        .collect()
+}
+
+impl ImportForm {
+    fn from_node(node: &SyntaxNode) -> Option<ImportForm> {
+        if node.get_head()? != "import" {
+            return None;
+        }
+
+        let children = node.get_children();
+        let path_node = children.last()?;
+        let path = path_node.get_string()?.to_string();
+        let (path_start, path_end) = path_node.get_span()?;
+        let mut bindings = Vec::new();
+
+        // `(import "path")' binds nothing
+        if children.len() > 2 {
+            let specification = children[1];
+            let alias = match (children.get(2), children.get(3)) {
+                (Some(as_node), Some(alias))
+                    if as_node.get_symbol() == Some("as") =>
+                {
+                    alias.get_symbol()
+                },
+                _ => None,
+            };
+
+            if let Some(alias) = alias {
+                bindings.push(ImportBinding::Namespace(alias.to_string()));
+            }
+
+            match specification.get_symbol() {
+                Some("*") if alias.is_none() => {
+                    bindings.push(ImportBinding::All)
+                },
+                Some("*") => {},
+                Some(name) => {
+                    bindings.push(ImportBinding::Default(name.to_string()))
+                },
+                None => bindings.extend(read_named_bindings(specification)),
+            }
+        }
+
+        Some(ImportForm {
+            path,
+            path_start,
+            path_end,
+            bindings,
+        })
+    }
+
+    fn find_target(&self, name: &str) -> Option<ImportTarget> {
+        let (object_name, member_name) = match name.find(':') {
+            Some(index) if index > 0 => {
+                (&name[..index], Some(&name[index + 1..]))
+            },
+            _ => (name, None),
+        };
+
+        self.bindings.iter().find_map(|binding| match binding {
+            ImportBinding::Default(binding) if binding == name => {
+                Some(ImportTarget::DefaultExport)
+            },
+            ImportBinding::Named(key, binding) if binding == name => {
+                Some(ImportTarget::Name(key.clone()))
+            },
+            ImportBinding::Default(binding)
+            | ImportBinding::Named(_, binding)
+            | ImportBinding::Namespace(binding)
+                if binding == object_name =>
+            {
+                member_name.map(|member_name| {
+                    ImportTarget::Name(member_name.to_string())
+                })
+            },
+            ImportBinding::All => {
+                Some(ImportTarget::Unqualified(name.to_string()))
+            },
+            _ => None,
+        })
+    }
+}
+
+fn make_location(uri: &str, code: &str, start: usize, end: usize) -> JsonValue {
+    JsonValue::object(vec![
+        ("uri", JsonValue::string(uri)),
+        ("range", make_range(code, start, end)),
+    ])
+}
+
+// drops `.' and `..' from resolved paths, so that they match the uris of
+// the documents
+fn normalize_path(path: &str) -> String {
+    let mut normalized_path = PathBuf::new();
+
+    for component in Path::new(path).components() {
+        match component {
+            Component::CurDir => {},
+            Component::ParentDir => {
+                normalized_path.pop();
+            },
+            _ => normalized_path.push(component.as_os_str()),
+        }
+    }
+
+    normalized_path.to_string_lossy().to_string()
+}
+
+// modules are resolved as `import' does when it is evaluated in the
+// document, standard modules have no file to go to
+fn resolve_module_path(
+    interpreter: &mut Interpreter,
+    document_path: &str,
+    path: &str,
+) -> Option<String> {
+    let module = interpreter.get_current_module_mut();
+    let previous_path = module.get_path().clone();
+
+    module.set_path(document_path.to_string());
+    let resolved_path =
+        interpreter.resolve_with_current_module_path(path.to_string());
+    interpreter.get_current_module_mut().set_path(previous_path);
+
+    resolved_path
+        .ok()
+        .filter(|resolved_path| !is_std_module_path(resolved_path))
+        .map(|resolved_path| normalize_path(&resolved_path))
+}
+
+fn find_target_in_module(
+    uri: &str,
+    code: &str,
+    target: &ImportTarget,
+) -> Option<JsonValue> {
+    let nodes = parse_partial_syntax_tree(code);
+
+    let name = match target {
+        ImportTarget::Module => None,
+        ImportTarget::Name(name) | ImportTarget::Unqualified(name) => {
+            Some(name.clone())
+        },
+        // `(export name as default)'
+        ImportTarget::DefaultExport => nodes.iter().find_map(|node| {
+            let children = node.get_children();
+
+            if node.get_head() == Some("export")
+                && children.len() == 4
+                && children[2].get_symbol() == Some("as")
+                && children[3].get_symbol() == Some("default")
+            {
+                children[1].get_symbol().map(String::from)
+            } else {
+                None
+            }
+        }),
+    };
+
+    let definition = name.and_then(|name| {
+        find_document_definitions(code, &nodes)
+            .into_iter()
+            .find(|definition| definition.get_name() == &name)
+    });
+
+    match (definition, target) {
+        (Some(definition), _) => Some(make_location(
+            uri,
+            code,
+            definition.get_name_start(),
+            definition.get_name_end(),
+        )),
+        (None, ImportTarget::Unqualified(_)) => None,
+        (None, _) => Some(make_location(uri, code, 0, 0)),
+    }
+}
+
+fn find_target_in_import(
+    interpreter: &mut Interpreter,
+    documents: &HashMap<String, String>,
+    document_path: &str,
+    import: &ImportForm,
+    target: &ImportTarget,
+) -> Option<JsonValue> {
+    let module_path =
+        resolve_module_path(interpreter, document_path, &import.path)?;
+    let module_uri = path_to_uri(&module_path);
+
+    let module_code = match documents.get(&module_uri) {
+        Some(module_code) => module_code.clone(),
+        None => std::fs::read_to_string(&module_path).ok()?,
+    };
+
+    find_target_in_module(&module_uri, &module_code, target)
+}
+
+// names defined in the document are found first, then the ones brought by
+// `import' forms, which lead to the module when the definition is not found
+pub fn find_definition(
+    interpreter: &mut Interpreter,
+    documents: &HashMap<String, String>,
+    uri: &str,
+    offset: usize,
+) -> Option<JsonValue> {
+    let code = documents.get(uri)?;
+    let nodes = parse_partial_syntax_tree(code);
+    let imports = nodes
+        .iter()
+        .filter_map(ImportForm::from_node)
+        .collect::<Vec<ImportForm>>();
+
+    let document_path = uri_to_path(uri)?;
+
+    let name = match find_symbol_at(&nodes, offset) {
+        Some((name, _)) => name,
+        None => {
+            let import = imports.iter().find(|import| {
+                import.path_start <= offset && offset < import.path_end
+            })?;
+
+            return find_target_in_import(
+                interpreter,
+                documents,
+                &document_path,
+                import,
+                &ImportTarget::Module,
+            );
+        },
+    };
+
+    if let Some(definition) = find_document_definitions(code, &nodes)
+        .into_iter()
+        .find(|definition| definition.get_name() == name)
+    {
+        return Some(make_location(
+            uri,
+            code,
+            definition.get_name_start(),
+            definition.get_name_end(),
+        ));
+    }
+
+    imports.iter().find_map(|import| {
+        let target = import.find_target(name)?;
+
+        find_target_in_import(
+            interpreter,
+            documents,
+            &document_path,
+            import,
+            &target,
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    use crate::parse_syntax_tree;
+    use crate::utils;
+
+    fn describe(location: Option<JsonValue>) -> Option<(String, usize, usize)> {
+        let location = location?;
+
+        Some((
+            location.get("uri")?.as_str()?.to_string(),
+            location.get_path(&["range", "start", "line"])?.as_usize()?,
+            location
+                .get_path(&["range", "start", "character"])?
+                .as_usize()?,
+        ))
+    }
+
+    #[test]
+    fn reads_import_forms() {
+        let specs = vec![
+            (
+                r#"(import nya from "./a.nia")"#,
+                vec![ImportBinding::Default(String::from("nya"))],
+            ),
+            (
+                r#"(import {:a 'b :c 'd} as m from "./a.nia")"#,
+                vec![
+                    ImportBinding::Namespace(String::from("m")),
+                    ImportBinding::Named(String::from("a"), String::from("b")),
+                    ImportBinding::Named(String::from("c"), String::from("d")),
+                ],
+            ),
+            (
+                r#"(import * as m from "./a.nia")"#,
+                vec![ImportBinding::Namespace(String::from("m"))],
+            ),
+            (r#"(import * from "./a.nia")"#, vec![ImportBinding::All]),
+            (r#"(import "./a.nia")"#, vec![]),
+        ];
+
+        for (code, expected) in specs {
+            let nodes = parse_syntax_tree(code).unwrap();
+            let import = ImportForm::from_node(&nodes[0]).unwrap();
+
+            nia_assert_equal(String::from("./a.nia"), import.path.clone());
+            nia_assert_equal(expected, import.bindings);
+        }
+    }
+
+    #[test]
+    fn finds_definitions_in_the_document() {
+        let mut interpreter = Interpreter::new();
+        let mut documents = HashMap::new();
+        let uri = "file:///a.nia";
+
+        documents.insert(
+            String::from(uri),
+            String::from("(defv a 1)\n(defn f () a)"),
+        );
+
+        nia_assert_equal(
+            Some((String::from(uri), 0, 6)),
+            describe(find_definition(&mut interpreter, &documents, uri, 22)),
+        );
+        nia_assert_equal(
+            None,
+            describe(find_definition(&mut interpreter, &documents, uri, 3)),
+        );
+    }
+
+    #[test]
+    fn finds_definitions_in_imported_modules() {
+        utils::with_tempdir(|directory| {
+            let mut interpreter = Interpreter::new();
+            let mut documents = HashMap::new();
+            let module_path = format!("{}/module.nia", directory);
+            let module_uri = path_to_uri(&module_path);
+            let uri = path_to_uri(&format!("{}/main.nia", directory));
+
+            std::fs::write(
+                &module_path,
+                "(defn f () 1)\n(defn g () 2)\n(export g as default)",
+            )
+            .unwrap();
+
+            let code = concat!(
+                "(import {:f 'h} from \"./module.nia\")\n",
+                "(import * as m from \"./module.nia\")\n",
+                "(import d from \"./module.nia\")\n",
+                "(h) (m:g) (d) (m:unknown)"
+            );
+            let line_start = code.rfind('\n').unwrap() + 1;
+
+            documents.insert(uri.clone(), String::from(code));
+
+            let specs = vec![
+                (line_start + 1, Some((module_uri.clone(), 0, 6))),
+                (line_start + 6, Some((module_uri.clone(), 1, 6))),
+                (line_start + 11, Some((module_uri.clone(), 1, 6))),
+                (line_start + 16, Some((module_uri.clone(), 0, 0))),
+                (25, Some((module_uri.clone(), 0, 0))),
+            ];
+
+            for (offset, expected) in specs {
+                nia_assert_equal(
+                    expected,
+                    describe(find_definition(
+                        &mut interpreter,
+                        &documents,
+                        &uri,
+                        offset,
+                    )),
+                );
+            }
+
+            nia_assert_equal(
+                &String::new(),
+                interpreter.get_current_module().get_path(),
+            );
+        })
+    }
+}
@@ -0,0 +1,178 @@
+use crate::collect_garbage;
+use crate::lint_code_with_interpreter;
+use crate::parse;
+use crate::Interpreter;
+use crate::ParseError;
+
+use super::make_range;
+use super::JsonValue;
+
+const SEVERITY_ERROR: usize = 1;
+const SEVERITY_WARNING: usize = 2;
+const SOURCE: &'static str = "nia";
+
+fn make_diagnostic(
+    text: &str,
+    (start, end): (usize, usize),
+    severity: usize,
+    code: Option<&str>,
+    message: String,
+) -> JsonValue {
+    let mut members = vec![
+        ("range", make_range(text, start, end)),
+        ("severity", JsonValue::number(severity)),
+    ];
+
+    if let Some(code) = code {
+        members.push(("code", JsonValue::string(code)));
+    }
+
+    members.push(("source", JsonValue::string(SOURCE)));
+    members.push(("message", JsonValue::string(message)));
+
+    JsonValue::object(members)
+}
+
+// the parser reports the rest of the input it stopped at
+fn get_parse_error_offset(text: &str, error: &ParseError) -> usize {
+    let rest = match error {
+        ParseError::TrailingInput(rest) => rest,
+        ParseError::NomError((rest, _)) => rest,
+        ParseError::NomFailure((rest, _)) => rest,
+        ParseError::NomIncomplete() => return text.len(),
+    };
+
+    if text.ends_with(rest.as_str()) {
+        text.len() - rest.len()
+    } else {
+        0
+    }
+}
+
+// a document that does not parse gets the single parse error, the linter
+// reports the rest; the forms it reads into the interpreter are collected
+// right after
+pub fn get_diagnostics(
+    interpreter: &mut Interpreter,
+    text: &str,
+) -> Vec<JsonValue> {
+    if let Err(error) = parse(text) {
+        let offset = get_parse_error_offset(text, &error);
+        let end = text[offset..]
+            .find('\n')
+            .map(|index| offset + index)
+            .unwrap_or(text.len());
+
+        return vec![make_diagnostic(
+            text,
+            (offset, end),
+            SEVERITY_ERROR,
+            None,
+            format!("{}", error),
+        )];
+    }
+
+    let result =
+        lint_code_with_interpreter(interpreter, text).and_then(|diagnostics| {
+            collect_garbage(interpreter).map(|_| diagnostics)
+        });
+
+    match result {
+        Ok(diagnostics) => diagnostics
+            .into_iter()
+            .map(|diagnostic| {
+                make_diagnostic(
+                    text,
+                    (diagnostic.get_start(), diagnostic.get_end()),
+                    SEVERITY_WARNING,
+                    Some(diagnostic.get_kind().get_code()),
+                    diagnostic.get_message().clone(),
+                )
+            })
+            .collect(),
+        Err(error) => vec![make_diagnostic(
+            text,
+            (0, 0),
+            SEVERITY_ERROR,
+            None,
+            format!("{}", error),
+        )],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    fn get_diagnostics(text: &str) -> Vec<JsonValue> {
+        super::get_diagnostics(&mut Interpreter::new(), text)
+    }
+
+    fn describe(diagnostic: &JsonValue) -> (usize, usize, usize, Option<&str>) {
+        (
+            diagnostic
+                .get_path(&["range", "start", "line"])
+                .and_then(JsonValue::as_usize)
+                .unwrap(),
+            diagnostic
+                .get_path(&["range", "start", "character"])
+                .and_then(JsonValue::as_usize)
+                .unwrap(),
+            diagnostic
+                .get("severity")
+                .and_then(JsonValue::as_usize)
+                .unwrap(),
+            diagnostic.get("code").and_then(JsonValue::as_str),
+        )
+    }
+
+    #[test]
+    fn reports_lint_warnings() {
+        let diagnostics = get_diagnostics("(defv a 1)\n(list:new a b)");
+
+        nia_assert_equal(
+            vec![(1, 12, SEVERITY_WARNING, Some("unbound-variable"))],
+            diagnostics.iter().map(describe).collect::<Vec<_>>(),
+        );
+        nia_assert_equal(
+            Some("nia"),
+            diagnostics[0].get("source").and_then(JsonValue::as_str),
+        );
+    }
+
+    #[test]
+    fn reports_parse_errors() {
+        let diagnostics = get_diagnostics("(defv a 1)\n(list:new a");
+
+        nia_assert_equal(1, diagnostics.len());
+        nia_assert_equal(SEVERITY_ERROR, describe(&diagnostics[0]).2);
+        nia_assert_equal(None, describe(&diagnostics[0]).3);
+    }
+
+    #[test]
+    fn reuses_the_interpreter() {
+        let mut interpreter = Interpreter::new();
+        let text = "(defv a 1)\n(list:new a b)";
+
+        for _ in 0..3 {
+            nia_assert_equal(
+                vec![(1, 12, SEVERITY_WARNING, Some("unbound-variable"))],
+                super::get_diagnostics(&mut interpreter, text)
+                    .iter()
+                    .map(describe)
+                    .collect::<Vec<_>>(),
+            );
+        }
+    }
+
+    #[test]
+    fn reports_nothing_for_correct_code() {
+        nia_assert_equal(
+            Vec::<JsonValue>::new(),
+            get_diagnostics("(defn f (a) (+ a 1))\n(f 2)"),
+        );
+    }
+}
@@ -0,0 +1,221 @@
+use crate::SyntaxNode;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DefinitionKind {
+    Function,
+    Macro,
+    Variable,
+    Constant,
+    Class,
+}
+
+impl DefinitionKind {
+    fn from_head(head: &str) -> Option<DefinitionKind> {
+        match head {
+            "defn" => Some(DefinitionKind::Function),
+            "defm" => Some(DefinitionKind::Macro),
+            "defv" => Some(DefinitionKind::Variable),
+            "defc" => Some(DefinitionKind::Constant),
+            "defclass" => Some(DefinitionKind::Class),
+            _ => None,
+        }
+    }
+
+    pub fn get_head(&self) -> &'static str {
+        match self {
+            DefinitionKind::Function => "defn",
+            DefinitionKind::Macro => "defm",
+            DefinitionKind::Variable => "defv",
+            DefinitionKind::Constant => "defc",
+            DefinitionKind::Class => "defclass",
+        }
+    }
+}
+
+// a top-level definition, positions are byte offsets
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocumentDefinition {
+    kind: DefinitionKind,
+    name: String,
+    arguments: Option<String>,
+    documentation: Option<String>,
+    start: usize,
+    end: usize,
+    name_start: usize,
+    name_end: usize,
+}
+
+impl DocumentDefinition {
+    fn from_node(code: &str, node: &SyntaxNode) -> Option<DocumentDefinition> {
+        let kind = DefinitionKind::from_head(node.get_head()?)?;
+        let children = node.get_children();
+        let name_node = children.get(1)?;
+        let name = name_node.get_symbol()?;
+        let (start, end) = node.get_span()?;
+        let (name_start, name_end) = name_node.get_span()?;
+
+        let (arguments, documentation) = match kind {
+            DefinitionKind::Function | DefinitionKind::Macro => {
+                let arguments = children
+                    .get(2)
+                    .filter(|arguments| arguments.is_list())
+                    .and_then(|arguments| arguments.get_span())
+                    .map(|(start, end)| code[start..end].to_string());
+
+                // a string is the documentation only when the body follows
+                let documentation = if children.len() > 4 {
+                    children
+                        .get(3)
+                        .and_then(|node| node.get_string())
+                        .map(String::from)
+                } else {
+                    None
+                };
+
+                (arguments, documentation)
+            },
+            DefinitionKind::Variable | DefinitionKind::Constant => (
+                None,
+                children
+                    .get(3)
+                    .and_then(|node| node.get_string())
+                    .map(String::from),
+            ),
+            DefinitionKind::Class => (None, None),
+        };
+
+        Some(DocumentDefinition {
+            kind,
+            name: name.to_string(),
+            arguments,
+            documentation,
+            start,
+            end,
+            name_start,
+            name_end,
+        })
+    }
+
+    pub fn get_kind(&self) -> DefinitionKind {
+        self.kind
+    }
+
+    pub fn get_name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn get_arguments(&self) -> Option<&String> {
+        self.arguments.as_ref()
+    }
+
+    pub fn get_documentation(&self) -> Option<&String> {
+        self.documentation.as_ref()
+    }
+
+    pub fn get_start(&self) -> usize {
+        self.start
+    }
+
+    pub fn get_end(&self) -> usize {
+        self.end
+    }
+
+    pub fn get_name_start(&self) -> usize {
+        self.name_start
+    }
+
+    pub fn get_name_end(&self) -> usize {
+        self.name_end
+    }
+
+    // the head of the definition, like `(defn f (a b))'
+    pub fn get_signature(&self) -> String {
+        match &self.arguments {
+            Some(arguments) => {
+                format!(
+                    "({} {} {})",
+                    self.kind.get_head(),
+                    self.name,
+                    arguments
+                )
+            },
+            None => format!("({} {})", self.kind.get_head(), self.name),
+        }
+    }
+}
+
+pub fn find_document_definitions(
+    code: &str,
+    nodes: &[SyntaxNode],
+) -> Vec<DocumentDefinition> {
+    nodes
+        .iter()
+        .filter_map(|node| DocumentDefinition::from_node(code, node))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    use crate::parse_syntax_tree;
+
+    #[test]
+    fn finds_top_level_definitions() {
+        let code = r#"
+(defn add (a b) "Adds numbers." (+ a b))
+(defn id (a) "a")
+(defv counter 0 "Counts.")
+(defc limit 10)
+(defm twice (form) (list:new 'do form form))
+(defclass point)
+(let () (defn inner () 1))
+(defn)
+"#;
+        let nodes = parse_syntax_tree(code).unwrap();
+        let definitions = find_document_definitions(code, &nodes);
+
+        let descriptions = definitions
+            .iter()
+            .map(|definition| {
+                (
+                    definition.get_signature(),
+                    definition.get_documentation().cloned(),
+                )
+            })
+            .collect::<Vec<(String, Option<String>)>>();
+
+        nia_assert_equal(
+            vec![
+                (
+                    String::from("(defn add (a b))"),
+                    Some(String::from("Adds numbers.")),
+                ),
+                (String::from("(defn id (a))"), None),
+                (
+                    String::from("(defv counter)"),
+                    Some(String::from("Counts.")),
+                ),
+                (String::from("(defc limit)"), None),
+                (String::from("(defm twice (form))"), None),
+                (String::from("(defclass point)"), None),
+            ],
+            descriptions,
+        );
+
+        let definition = &definitions[0];
+
+        nia_assert_equal(DefinitionKind::Function, definition.get_kind());
+        nia_assert_equal(
+            "add",
+            &code[definition.get_name_start()..definition.get_name_end()],
+        );
+        nia_assert_equal(
+            "(defn add (a b) \"Adds numbers.\" (+ a b))",
+            &code[definition.get_start()..definition.get_end()],
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use crate::parse_partial_syntax_tree;
+
+use super::find_document_definitions;
+use super::make_range;
+use super::DefinitionKind;
+use super::JsonValue;
+
+const SYMBOL_KIND_CLASS: usize = 5;
+const SYMBOL_KIND_FUNCTION: usize = 12;
+const SYMBOL_KIND_VARIABLE: usize = 13;
+const SYMBOL_KIND_CONSTANT: usize = 14;
+
+fn get_symbol_kind(kind: DefinitionKind) -> usize {
+    match kind {
+        DefinitionKind::Function | DefinitionKind::Macro => {
+            SYMBOL_KIND_FUNCTION
+        },
+        DefinitionKind::Variable => SYMBOL_KIND_VARIABLE,
+        DefinitionKind::Constant => SYMBOL_KIND_CONSTANT,
+        DefinitionKind::Class => SYMBOL_KIND_CLASS,
+    }
+}
+
+pub fn get_document_symbols(text: &str) -> Vec<JsonValue> {
+    let nodes = parse_partial_syntax_tree(text);
+
+    find_document_definitions(text, &nodes)
+        .into_iter()
+        .map(|definition| {
+            JsonValue::object(vec![
+                ("name", JsonValue::string(definition.get_name().clone())),
+                ("detail", JsonValue::string(definition.get_signature())),
+                (
+                    "kind",
+                    JsonValue::number(get_symbol_kind(definition.get_kind())),
+                ),
+                (
+                    "range",
+                    make_range(
+                        text,
+                        definition.get_start(),
+                        definition.get_end(),
+                    ),
+                ),
+                (
+                    "selectionRange",
+                    make_range(
+                        text,
+                        definition.get_name_start(),
+                        definition.get_name_end(),
+                    ),
+                ),
+            ])
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[test]
+    fn lists_definitions() {
+        let text = "(defn f (a)\n  a)\n(defv v 1)\n(defc c 2)\n(f v)";
+        let symbols = get_document_symbols(text);
+
+        let descriptions = symbols
+            .iter()
+            .map(|symbol| {
+                (
+                    symbol.get("name").and_then(JsonValue::as_str).unwrap(),
+                    symbol.get("kind").and_then(JsonValue::as_usize).unwrap(),
+                    symbol
+                        .get_path(&["range", "end", "line"])
+                        .and_then(JsonValue::as_usize)
+                        .unwrap(),
+                    symbol
+                        .get_path(&["selectionRange", "start", "character"])
+                        .and_then(JsonValue::as_usize)
+                        .unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        nia_assert_equal(
+            vec![
+                ("f", SYMBOL_KIND_FUNCTION, 1, 6),
+                ("v", SYMBOL_KIND_VARIABLE, 2, 6),
+                ("c", SYMBOL_KIND_CONSTANT, 3, 6),
+            ],
+            descriptions,
+        );
+    }
+
+    #[test]
+    fn lists_definitions_of_unfinished_documents() {
+        let symbols =
+            get_document_symbols("(defv v 1))\n(defn f (a) (list:new");
+
+        nia_assert_equal(
+            vec!["v", "f"],
+            symbols
+                .iter()
+                .map(|symbol| {
+                    symbol.get("name").and_then(JsonValue::as_str).unwrap()
+                })
+                .collect::<Vec<_>>(),
+        );
+    }
+}
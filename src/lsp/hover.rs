@@ -0,0 +1,100 @@
+use crate::get_builtin_documentation;
+use crate::parse_partial_syntax_tree;
+
+use super::find_document_definitions;
+use super::find_symbol_at;
+use super::make_range;
+use super::JsonValue;
+
+fn make_markdown(signature: &str, documentation: Option<&str>) -> String {
+    match documentation {
+        Some(documentation) if !documentation.is_empty() => {
+            format!("```nia\n{}\n```\n\n{}", signature, documentation)
+        },
+        _ => format!("```nia\n{}\n```", signature),
+    }
+}
+
+// builtins are described by their documentation, other names by their
+// definitions in the document
+pub fn get_hover(text: &str, offset: usize) -> Option<JsonValue> {
+    let nodes = parse_partial_syntax_tree(text);
+    let (name, (start, end)) = find_symbol_at(&nodes, offset)?;
+
+    let markdown = match get_builtin_documentation(name) {
+        Some(documentation) => make_markdown(
+            documentation.get_signature(),
+            Some(documentation.get_documentation()),
+        ),
+        None => {
+            let definitions = find_document_definitions(text, &nodes);
+            let definition = definitions
+                .iter()
+                .find(|definition| definition.get_name() == name)?;
+
+            make_markdown(
+                &definition.get_signature(),
+                definition.get_documentation().map(String::as_str),
+            )
+        },
+    };
+
+    Some(JsonValue::object(vec![
+        (
+            "contents",
+            JsonValue::object(vec![
+                ("kind", JsonValue::string("markdown")),
+                ("value", JsonValue::string(markdown)),
+            ]),
+        ),
+        ("range", make_range(text, start, end)),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    fn get_hover_markdown(text: &str, offset: usize) -> Option<String> {
+        get_hover(text, offset)?
+            .get_path(&["contents", "value"])
+            .and_then(JsonValue::as_str)
+            .map(String::from)
+    }
+
+    #[test]
+    fn describes_builtins() {
+        let text = "(list:append f '(1))";
+        let documentation = get_builtin_documentation("list:append").unwrap();
+
+        nia_assert_equal(
+            Some(format!(
+                "```nia\n{}\n```\n\n{}",
+                documentation.get_signature(),
+                documentation.get_documentation()
+            )),
+            get_hover_markdown(text, 3),
+        );
+    }
+
+    #[test]
+    fn describes_definitions_of_the_document() {
+        let text = "(defn add (a b) \"Adds numbers.\" (+ a b))\n(add 1 2)";
+
+        nia_assert_equal(
+            Some(String::from(
+                "```nia\n(defn add (a b))\n```\n\nAdds numbers.",
+            )),
+            get_hover_markdown(text, 43),
+        );
+    }
+
+    #[test]
+    fn returns_nothing_for_unknown_names() {
+        nia_assert_equal(None, get_hover("(unknown 1)", 2));
+        nia_assert_equal(None, get_hover("(+ 1 2)", 4));
+    }
+}
@@ -0,0 +1,509 @@
+use std::fmt;
+
+// just enough of JSON for the language server protocol, members of objects
+// keep their order so that responses are stable
+
+// deeper values are rejected, so that nested brackets cannot exhaust the
+// stack of the server
+const MAX_DEPTH: usize = 128;
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonValue {
+    Null,
+    Boolean(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+struct JsonParser<'a> {
+    text: &'a str,
+    position: usize,
+    depth: usize,
+}
+
+impl<'a> JsonParser<'a> {
+    fn new(text: &'a str) -> JsonParser<'a> {
+        JsonParser {
+            text,
+            position: 0,
+            depth: 0,
+        }
+    }
+
+    fn make_error(&self, message: &str) -> String {
+        format!("{} at offset {}.", message, self.position)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.text[self.position..].chars().next()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let character = self.peek()?;
+
+        self.position += character.len_utf8();
+
+        Some(character)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(' ') | Some('\t') | Some('\n') | Some('\r') = self.peek()
+        {
+            self.position += 1;
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        match self.next() {
+            Some(character) if character == expected => Ok(()),
+            _ => Err(self.make_error(&format!("Expected `{}'", expected))),
+        }
+    }
+
+    fn parse_literal(
+        &mut self,
+        literal: &str,
+        value: JsonValue,
+    ) -> Result<JsonValue, String> {
+        if self.text[self.position..].starts_with(literal) {
+            self.position += literal.len();
+            Ok(value)
+        } else {
+            Err(self.make_error("Unknown literal"))
+        }
+    }
+
+    fn parse_number(&mut self) -> Result<JsonValue, String> {
+        let start = self.position;
+
+        while let Some(character) = self.peek() {
+            if character.is_ascii_digit() || "+-.eE".contains(character) {
+                self.position += 1;
+            } else {
+                break;
+            }
+        }
+
+        self.text[start..self.position]
+            .parse::<f64>()
+            .map(JsonValue::Number)
+            .map_err(|_| self.make_error("Invalid number"))
+    }
+
+    fn parse_hex_escape(&mut self) -> Result<u32, String> {
+        let digits = self
+            .text
+            .get(self.position..self.position + 4)
+            .ok_or_else(|| self.make_error("Invalid unicode escape"))?;
+        let code = u32::from_str_radix(digits, 16)
+            .map_err(|_| self.make_error("Invalid unicode escape"))?;
+
+        self.position += 4;
+
+        Ok(code)
+    }
+
+    fn parse_unicode_escape(&mut self) -> Result<char, String> {
+        let high = self.parse_hex_escape()?;
+
+        // characters outside of the basic plane come as surrogate pairs,
+        // unpaired surrogates are replaced
+        if !(0xD800..0xDC00).contains(&high)
+            || !self.text[self.position..].starts_with("\\u")
+        {
+            return Ok(std::char::from_u32(high).unwrap_or('\u{FFFD}'));
+        }
+
+        let position = self.position;
+
+        self.position += 2;
+        let low = self.parse_hex_escape()?;
+
+        if !(0xDC00..0xE000).contains(&low) {
+            // the escape that follows is read on its own
+            self.position = position;
+
+            return Ok('\u{FFFD}');
+        }
+
+        let code = 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00);
+
+        Ok(std::char::from_u32(code).unwrap_or('\u{FFFD}'))
+    }
+
+    fn parse_string(&mut self) -> Result<String, String> {
+        self.expect('"')?;
+
+        let mut string = String::new();
+
+        loop {
+            let character = self
+                .next()
+                .ok_or_else(|| self.make_error("Unterminated string"))?;
+
+            match character {
+                '"' => return Ok(string),
+                '\\' => {
+                    let escaped = match self.next() {
+                        Some('"') => '"',
+                        Some('\\') => '\\',
+                        Some('/') => '/',
+                        Some('b') => '\u{8}',
+                        Some('f') => '\u{c}',
+                        Some('n') => '\n',
+                        Some('r') => '\r',
+                        Some('t') => '\t',
+                        Some('u') => self.parse_unicode_escape()?,
+                        _ => return Err(self.make_error("Invalid escape")),
+                    };
+
+                    string.push(escaped);
+                },
+                _ => string.push(character),
+            }
+        }
+    }
+
+    fn parse_array(&mut self) -> Result<JsonValue, String> {
+        self.expect('[')?;
+        self.skip_whitespace();
+
+        let mut items = Vec::new();
+
+        if self.peek() == Some(']') {
+            self.position += 1;
+            return Ok(JsonValue::Array(items));
+        }
+
+        loop {
+            items.push(self.parse_value()?);
+            self.skip_whitespace();
+
+            match self.next() {
+                Some(',') => {},
+                Some(']') => return Ok(JsonValue::Array(items)),
+                _ => return Err(self.make_error("Expected `,' or `]'")),
+            }
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<JsonValue, String> {
+        self.expect('{')?;
+        self.skip_whitespace();
+
+        let mut members = Vec::new();
+
+        if self.peek() == Some('}') {
+            self.position += 1;
+            return Ok(JsonValue::Object(members));
+        }
+
+        loop {
+            self.skip_whitespace();
+            let key = self.parse_string()?;
+            self.skip_whitespace();
+            self.expect(':')?;
+
+            members.push((key, self.parse_value()?));
+            self.skip_whitespace();
+
+            match self.next() {
+                Some(',') => {},
+                Some('}') => return Ok(JsonValue::Object(members)),
+                _ => return Err(self.make_error("Expected `,' or `}'")),
+            }
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<JsonValue, String> {
+        if self.depth >= MAX_DEPTH {
+            return Err(self.make_error("Too deeply nested value"));
+        }
+
+        self.depth += 1;
+        let result = self.parse_value_at_depth();
+        self.depth -= 1;
+
+        result
+    }
+
+    fn parse_value_at_depth(&mut self) -> Result<JsonValue, String> {
+        self.skip_whitespace();
+
+        match self.peek() {
+            Some('n') => self.parse_literal("null", JsonValue::Null),
+            Some('t') => self.parse_literal("true", JsonValue::Boolean(true)),
+            Some('f') => self.parse_literal("false", JsonValue::Boolean(false)),
+            Some('"') => self.parse_string().map(JsonValue::String),
+            Some('[') => self.parse_array(),
+            Some('{') => self.parse_object(),
+            Some(character)
+                if character == '-' || character.is_ascii_digit() =>
+            {
+                self.parse_number()
+            },
+            Some(_) => Err(self.make_error("Unexpected character")),
+            None => Err(self.make_error("Unexpected end of input")),
+        }
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, string: &str) -> fmt::Result {
+    write!(f, "\"")?;
+
+    for character in string.chars() {
+        match character {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            _ if (character as u32) < 0x20 => {
+                write!(f, "\\u{:04x}", character as u32)?
+            },
+            _ => write!(f, "{}", character)?,
+        }
+    }
+
+    write!(f, "\"")
+}
+
+impl fmt::Display for JsonValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JsonValue::Null => write!(f, "null"),
+            JsonValue::Boolean(boolean) => write!(f, "{}", boolean),
+            JsonValue::Number(number) => {
+                if number.fract() == 0.0 && number.abs() < 1e15 {
+                    write!(f, "{}", *number as i64)
+                } else if number.is_finite() {
+                    write!(f, "{}", number)
+                } else {
+                    write!(f, "null")
+                }
+            },
+            JsonValue::String(string) => write_string(f, string),
+            JsonValue::Array(items) => {
+                write!(f, "[")?;
+
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+
+                    write!(f, "{}", item)?;
+                }
+
+                write!(f, "]")
+            },
+            JsonValue::Object(members) => {
+                write!(f, "{{")?;
+
+                for (index, (key, value)) in members.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+
+                    write_string(f, key)?;
+                    write!(f, ":{}", value)?;
+                }
+
+                write!(f, "}}")
+            },
+        }
+    }
+}
+
+impl JsonValue {
+    pub fn parse(text: &str) -> Result<JsonValue, String> {
+        let mut parser = JsonParser::new(text);
+        let value = parser.parse_value()?;
+
+        parser.skip_whitespace();
+
+        if parser.position != text.len() {
+            return Err(parser.make_error("Trailing input"));
+        }
+
+        Ok(value)
+    }
+
+    pub fn object(members: Vec<(&str, JsonValue)>) -> JsonValue {
+        JsonValue::Object(
+            members
+                .into_iter()
+                .map(|(key, value)| (String::from(key), value))
+                .collect(),
+        )
+    }
+
+    pub fn string<S: Into<String>>(string: S) -> JsonValue {
+        JsonValue::String(string.into())
+    }
+
+    pub fn number(number: usize) -> JsonValue {
+        JsonValue::Number(number as f64)
+    }
+
+    pub fn get(&self, key: &str) -> Option<&JsonValue> {
+        match self {
+            JsonValue::Object(members) => members
+                .iter()
+                .find(|(member_key, _)| member_key == key)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    pub fn get_path(&self, keys: &[&str]) -> Option<&JsonValue> {
+        keys.iter().try_fold(self, |value, key| value.get(key))
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            JsonValue::String(string) => Some(string),
+            _ => None,
+        }
+    }
+
+    pub fn as_usize(&self) -> Option<usize> {
+        match self {
+            JsonValue::Number(number)
+                if *number >= 0.0 && number.fract() == 0.0 =>
+            {
+                Some(*number as usize)
+            },
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&Vec<JsonValue>> {
+        match self {
+            JsonValue::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[test]
+    fn parses_values() {
+        let specs = vec![
+            ("null", JsonValue::Null),
+            (" true ", JsonValue::Boolean(true)),
+            ("-1.5e1", JsonValue::Number(-15.0)),
+            (r#""a\"\né😀""#, JsonValue::string("a\"\né\u{1F600}")),
+            (
+                r#"[1, [], {}]"#,
+                JsonValue::Array(vec![
+                    JsonValue::Number(1.0),
+                    JsonValue::Array(vec![]),
+                    JsonValue::Object(vec![]),
+                ]),
+            ),
+            (
+                r#"{"a": {"b": "c"}, "d": false}"#,
+                JsonValue::object(vec![
+                    (
+                        "a",
+                        JsonValue::object(vec![("b", JsonValue::string("c"))]),
+                    ),
+                    ("d", JsonValue::Boolean(false)),
+                ]),
+            ),
+        ];
+
+        for (text, expected) in specs {
+            nia_assert_equal(Ok(expected), JsonValue::parse(text));
+        }
+    }
+
+    #[test]
+    fn replaces_unpaired_surrogates() {
+        let specs = vec![
+            (r#""\ud83d\ude00""#, "\u{1F600}"),
+            (r#""\ud83d""#, "\u{FFFD}"),
+            (r#""\ud83da""#, "\u{FFFD}a"),
+            (r#""\ude00""#, "\u{FFFD}"),
+            (r#""\ud83d\u0041""#, "\u{FFFD}A"),
+            (r#""\ud83d\ud83d\ude00""#, "\u{FFFD}\u{1F600}"),
+        ];
+
+        for (text, expected) in specs {
+            nia_assert_equal(
+                Ok(JsonValue::string(expected)),
+                JsonValue::parse(text),
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_too_deeply_nested_values() {
+        let nested = |depth: usize| {
+            format!("{}{}", "[".repeat(depth), "]".repeat(depth))
+        };
+
+        nia_assert(JsonValue::parse(&nested(MAX_DEPTH)).is_ok());
+        nia_assert(JsonValue::parse(&nested(MAX_DEPTH + 1)).is_err());
+        nia_assert(JsonValue::parse(&"[".repeat(100_000)).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_json() {
+        let specs = vec!["", "nul", "[1,", r#"{"a" 1}"#, r#""a"#, "1 2"];
+
+        for text in specs {
+            nia_assert(JsonValue::parse(text).is_err());
+        }
+    }
+
+    #[test]
+    fn serializes_values() {
+        let value = JsonValue::object(vec![
+            ("id", JsonValue::number(1)),
+            ("float", JsonValue::Number(0.5)),
+            ("text", JsonValue::string("a\"b\\\n\u{1}")),
+            (
+                "items",
+                JsonValue::Array(vec![
+                    JsonValue::Null,
+                    JsonValue::Boolean(true),
+                ]),
+            ),
+        ]);
+
+        let expected = r#"{"id":1,"float":0.5,"text":"a\"b\\\n\u0001","items":[null,true]}"#;
+
+        nia_assert_equal(String::from(expected), value.to_string());
+        nia_assert_equal(Ok(value.clone()), JsonValue::parse(expected));
+    }
+
+    #[test]
+    fn accesses_members() {
+        let value = JsonValue::parse(
+            r#"{"params": {"position": {"line": 2}, "uri": "file:///a"}}"#,
+        )
+        .unwrap();
+
+        nia_assert_equal(
+            Some(2),
+            value
+                .get_path(&["params", "position", "line"])
+                .and_then(JsonValue::as_usize),
+        );
+        nia_assert_equal(
+            Some("file:///a"),
+            value
+                .get_path(&["params", "uri"])
+                .and_then(JsonValue::as_str),
+        );
+        nia_assert_equal(None, value.get_path(&["params", "missing"]));
+    }
+}
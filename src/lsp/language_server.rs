@@ -0,0 +1,461 @@
+use std::collections::HashMap;
+
+use crate::Interpreter;
+
+use super::find_definition;
+use super::get_completion_items;
+use super::get_diagnostics;
+use super::get_document_symbols;
+use super::get_hover;
+use super::position_to_offset;
+use super::JsonValue;
+
+const SERVER_NAME: &'static str = "nia-lsp";
+
+const ERROR_INVALID_REQUEST: f64 = -32600.0;
+const ERROR_METHOD_NOT_FOUND: f64 = -32601.0;
+const ERROR_INVALID_PARAMS: f64 = -32602.0;
+
+const TEXT_DOCUMENT_SYNC_FULL: usize = 1;
+
+fn make_response(id: JsonValue, result: JsonValue) -> JsonValue {
+    JsonValue::object(vec![
+        ("jsonrpc", JsonValue::string("2.0")),
+        ("id", id),
+        ("result", result),
+    ])
+}
+
+pub fn make_error_response(
+    id: JsonValue,
+    code: f64,
+    message: &str,
+) -> JsonValue {
+    JsonValue::object(vec![
+        ("jsonrpc", JsonValue::string("2.0")),
+        ("id", id),
+        (
+            "error",
+            JsonValue::object(vec![
+                ("code", JsonValue::Number(code)),
+                ("message", JsonValue::string(message)),
+            ]),
+        ),
+    ])
+}
+
+fn make_notification(method: &str, params: JsonValue) -> JsonValue {
+    JsonValue::object(vec![
+        ("jsonrpc", JsonValue::string("2.0")),
+        ("method", JsonValue::string(method)),
+        ("params", params),
+    ])
+}
+
+fn get_capabilities() -> JsonValue {
+    JsonValue::object(vec![
+        (
+            "capabilities",
+            JsonValue::object(vec![
+                (
+                    "textDocumentSync",
+                    JsonValue::number(TEXT_DOCUMENT_SYNC_FULL),
+                ),
+                ("hoverProvider", JsonValue::Boolean(true)),
+                ("definitionProvider", JsonValue::Boolean(true)),
+                (
+                    "completionProvider",
+                    JsonValue::object(vec![(
+                        "triggerCharacters",
+                        JsonValue::Array(vec![JsonValue::string(":")]),
+                    )]),
+                ),
+                ("documentSymbolProvider", JsonValue::Boolean(true)),
+            ]),
+        ),
+        (
+            "serverInfo",
+            JsonValue::object(vec![
+                ("name", JsonValue::string(SERVER_NAME)),
+                ("version", JsonValue::string(env!("CARGO_PKG_VERSION"))),
+            ]),
+        ),
+    ])
+}
+
+fn get_uri(params: &JsonValue) -> Option<&str> {
+    params.get_path(&["textDocument", "uri"])?.as_str()
+}
+
+// documents are kept in memory as the editor sends them, the interpreter
+// serves linting, completion and module resolution and never evaluates them
+pub struct LanguageServer {
+    interpreter: Interpreter,
+    documents: HashMap<String, String>,
+    shutdown_requested: bool,
+    exited: bool,
+}
+
+impl LanguageServer {
+    pub fn new() -> LanguageServer {
+        LanguageServer {
+            interpreter: Interpreter::new(),
+            documents: HashMap::new(),
+            shutdown_requested: false,
+            exited: false,
+        }
+    }
+
+    pub fn is_shutdown_requested(&self) -> bool {
+        self.shutdown_requested
+    }
+
+    pub fn is_exited(&self) -> bool {
+        self.exited
+    }
+
+    fn publish_diagnostics(&mut self, uri: &str) -> JsonValue {
+        let diagnostics = match self.documents.get(uri) {
+            Some(text) => get_diagnostics(&mut self.interpreter, text),
+            None => Vec::new(),
+        };
+
+        make_notification(
+            "textDocument/publishDiagnostics",
+            JsonValue::object(vec![
+                ("uri", JsonValue::string(uri)),
+                ("diagnostics", JsonValue::Array(diagnostics)),
+            ]),
+        )
+    }
+
+    // returns the document and the byte offset of the position in it
+    fn get_document_position(
+        &self,
+        params: &JsonValue,
+    ) -> Option<(String, usize)> {
+        let uri = get_uri(params)?;
+        let text = self.documents.get(uri)?;
+        let offset = position_to_offset(text, params.get("position")?)?;
+
+        Some((uri.to_string(), offset))
+    }
+
+    fn handle_notification(
+        &mut self,
+        method: &str,
+        params: &JsonValue,
+    ) -> Vec<JsonValue> {
+        match method {
+            "exit" => {
+                self.exited = true;
+                Vec::new()
+            },
+            "textDocument/didOpen" => {
+                let document = params.get("textDocument");
+                let uri = document
+                    .and_then(|document| document.get("uri"))
+                    .and_then(JsonValue::as_str);
+                let text = document
+                    .and_then(|document| document.get("text"))
+                    .and_then(JsonValue::as_str);
+
+                match (uri, text) {
+                    (Some(uri), Some(text)) => {
+                        self.documents
+                            .insert(uri.to_string(), text.to_string());
+                        vec![self.publish_diagnostics(uri)]
+                    },
+                    _ => Vec::new(),
+                }
+            },
+            // with full synchronization the last change holds the document
+            "textDocument/didChange" => {
+                let uri = get_uri(params);
+                let text = params
+                    .get("contentChanges")
+                    .and_then(JsonValue::as_array)
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change.get("text"))
+                    .and_then(JsonValue::as_str);
+
+                match (uri, text) {
+                    (Some(uri), Some(text)) => {
+                        self.documents
+                            .insert(uri.to_string(), text.to_string());
+                        vec![self.publish_diagnostics(uri)]
+                    },
+                    _ => Vec::new(),
+                }
+            },
+            "textDocument/didClose" => match get_uri(params) {
+                Some(uri) => {
+                    self.documents.remove(uri);
+                    vec![self.publish_diagnostics(uri)]
+                },
+                None => Vec::new(),
+            },
+            // `initialized', `didSave' and the rest need no answer
+            _ => Vec::new(),
+        }
+    }
+
+    fn handle_request(
+        &mut self,
+        method: &str,
+        params: &JsonValue,
+    ) -> Result<JsonValue, (f64, String)> {
+        if self.shutdown_requested {
+            return Err((
+                ERROR_INVALID_REQUEST,
+                String::from("Server is shutting down."),
+            ));
+        }
+
+        let invalid_params =
+            || (ERROR_INVALID_PARAMS, String::from("Invalid parameters."));
+
+        match method {
+            "initialize" => Ok(get_capabilities()),
+            "shutdown" => {
+                self.shutdown_requested = true;
+                Ok(JsonValue::Null)
+            },
+            "textDocument/hover" => {
+                let (uri, offset) = self
+                    .get_document_position(params)
+                    .ok_or_else(invalid_params)?;
+
+                Ok(get_hover(&self.documents[&uri], offset)
+                    .unwrap_or(JsonValue::Null))
+            },
+            "textDocument/definition" => {
+                let (uri, offset) = self
+                    .get_document_position(params)
+                    .ok_or_else(invalid_params)?;
+
+                Ok(find_definition(
+                    &mut self.interpreter,
+                    &self.documents,
+                    &uri,
+                    offset,
+                )
+                .unwrap_or(JsonValue::Null))
+            },
+            "textDocument/completion" => {
+                let (uri, offset) = self
+                    .get_document_position(params)
+                    .ok_or_else(invalid_params)?;
+
+                Ok(JsonValue::Array(get_completion_items(
+                    &mut self.interpreter,
+                    &self.documents[&uri],
+                    offset,
+                )))
+            },
+            "textDocument/documentSymbol" => {
+                let text = get_uri(params)
+                    .and_then(|uri| self.documents.get(uri))
+                    .ok_or_else(invalid_params)?;
+
+                Ok(JsonValue::Array(get_document_symbols(text)))
+            },
+            _ => Err((
+                ERROR_METHOD_NOT_FOUND,
+                format!("Method `{}' is not supported.", method),
+            )),
+        }
+    }
+
+    // returns the messages to send back, that is the response to a request
+    // and the notifications the message caused
+    pub fn handle_message(&mut self, message: &JsonValue) -> Vec<JsonValue> {
+        let method = match message.get("method").and_then(JsonValue::as_str) {
+            Some(method) => method,
+            // responses of the client are not expected
+            None => return Vec::new(),
+        };
+        let params = message.get("params").cloned().unwrap_or(JsonValue::Null);
+
+        match message.get("id") {
+            Some(id) => {
+                let response = match self.handle_request(method, &params) {
+                    Ok(result) => make_response(id.clone(), result),
+                    Err((code, error_message)) => {
+                        make_error_response(id.clone(), code, &error_message)
+                    },
+                };
+
+                vec![response]
+            },
+            None => self.handle_notification(method, &params),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    fn handle(server: &mut LanguageServer, message: &str) -> Vec<JsonValue> {
+        server.handle_message(&JsonValue::parse(message).unwrap())
+    }
+
+    #[test]
+    fn initializes_and_shuts_down() {
+        let mut server = LanguageServer::new();
+
+        let responses = handle(
+            &mut server,
+            r#"{"jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {}}"#,
+        );
+
+        nia_assert_equal(1, responses.len());
+        nia_assert_equal(
+            Some(&JsonValue::Boolean(true)),
+            responses[0].get_path(&["result", "capabilities", "hoverProvider"]),
+        );
+
+        nia_assert_equal(
+            Vec::<JsonValue>::new(),
+            handle(
+                &mut server,
+                r#"{"jsonrpc": "2.0", "method": "initialized"}"#,
+            ),
+        );
+
+        let responses = handle(
+            &mut server,
+            r#"{"jsonrpc": "2.0", "id": "a", "method": "shutdown"}"#,
+        );
+
+        nia_assert_equal(
+            String::from(r#"{"jsonrpc":"2.0","id":"a","result":null}"#),
+            responses[0].to_string(),
+        );
+        nia_assert(server.is_shutdown_requested());
+
+        let responses = handle(
+            &mut server,
+            r#"{"jsonrpc": "2.0", "id": 2, "method": "textDocument/hover"}"#,
+        );
+
+        nia_assert_equal(
+            Some(&JsonValue::Number(ERROR_INVALID_REQUEST)),
+            responses[0].get_path(&["error", "code"]),
+        );
+
+        nia_assert(!server.is_exited());
+        handle(&mut server, r#"{"jsonrpc": "2.0", "method": "exit"}"#);
+        nia_assert(server.is_exited());
+    }
+
+    #[test]
+    fn publishes_diagnostics_of_documents() {
+        let mut server = LanguageServer::new();
+
+        let notifications = handle(
+            &mut server,
+            r#"{"jsonrpc": "2.0", "method": "textDocument/didOpen", "params": {"textDocument": {"uri": "file:///a.nia", "languageId": "nia", "version": 1, "text": "(print a)"}}}"#,
+        );
+
+        nia_assert_equal(
+            Some("textDocument/publishDiagnostics"),
+            notifications[0].get("method").and_then(JsonValue::as_str),
+        );
+        nia_assert_equal(
+            Some(1),
+            notifications[0]
+                .get_path(&["params", "diagnostics"])
+                .and_then(JsonValue::as_array)
+                .map(Vec::len),
+        );
+
+        let notifications = handle(
+            &mut server,
+            r#"{"jsonrpc": "2.0", "method": "textDocument/didChange", "params": {"textDocument": {"uri": "file:///a.nia", "version": 2}, "contentChanges": [{"text": "(print 1)"}]}}"#,
+        );
+
+        nia_assert_equal(
+            Some(0),
+            notifications[0]
+                .get_path(&["params", "diagnostics"])
+                .and_then(JsonValue::as_array)
+                .map(Vec::len),
+        );
+
+        let notifications = handle(
+            &mut server,
+            r#"{"jsonrpc": "2.0", "method": "textDocument/didClose", "params": {"textDocument": {"uri": "file:///a.nia"}}}"#,
+        );
+
+        nia_assert_equal(1, notifications.len());
+        nia_assert(server.documents.is_empty());
+    }
+
+    #[test]
+    fn answers_requests_about_documents() {
+        let mut server = LanguageServer::new();
+
+        handle(
+            &mut server,
+            r#"{"jsonrpc": "2.0", "method": "textDocument/didOpen", "params": {"textDocument": {"uri": "file:///a.nia", "text": "(defn f (a) a)\n(f 1)\n(list:"}}}"#,
+        );
+
+        let requests = vec![
+            (
+                r#"{"jsonrpc": "2.0", "id": 1, "method": "textDocument/hover", "params": {"textDocument": {"uri": "file:///a.nia"}, "position": {"line": 1, "character": 1}}}"#,
+                vec!["result", "contents", "value"],
+            ),
+            (
+                r#"{"jsonrpc": "2.0", "id": 2, "method": "textDocument/definition", "params": {"textDocument": {"uri": "file:///a.nia"}, "position": {"line": 1, "character": 1}}}"#,
+                vec!["result", "uri"],
+            ),
+        ];
+
+        for (request, path) in requests {
+            let responses = handle(&mut server, request);
+
+            nia_assert(responses[0].get_path(&path).is_some());
+        }
+
+        let responses = handle(
+            &mut server,
+            r#"{"jsonrpc": "2.0", "id": 3, "method": "textDocument/completion", "params": {"textDocument": {"uri": "file:///a.nia"}, "position": {"line": 2, "character": 6}}}"#,
+        );
+
+        nia_assert(
+            responses[0]
+                .get("result")
+                .and_then(JsonValue::as_array)
+                .map(|items| items.len() > 1)
+                .unwrap_or(false),
+        );
+
+        let responses = handle(
+            &mut server,
+            r#"{"jsonrpc": "2.0", "id": 4, "method": "textDocument/documentSymbol", "params": {"textDocument": {"uri": "file:///a.nia"}}}"#,
+        );
+
+        nia_assert_equal(
+            Some(1),
+            responses[0]
+                .get("result")
+                .and_then(JsonValue::as_array)
+                .map(Vec::len),
+        );
+
+        let responses = handle(
+            &mut server,
+            r#"{"jsonrpc": "2.0", "id": 5, "method": "textDocument/formatting", "params": {}}"#,
+        );
+
+        nia_assert_equal(
+            Some(&JsonValue::Number(ERROR_METHOD_NOT_FOUND)),
+            responses[0].get_path(&["error", "code"]),
+        );
+    }
+}
@@ -0,0 +1,25 @@
+mod completion;
+mod definition;
+mod diagnostics;
+mod document_definitions;
+mod document_symbols;
+mod hover;
+mod json;
+mod language_server;
+mod run_language_server;
+mod text_position;
+mod transport;
+mod uri;
+
+pub use completion::*;
+pub use definition::*;
+pub use diagnostics::*;
+pub use document_definitions::*;
+pub use document_symbols::*;
+pub use hover::*;
+pub use json::*;
+pub use language_server::*;
+pub use run_language_server::*;
+pub use text_position::*;
+pub use transport::*;
+pub use uri::*;
@@ -0,0 +1,121 @@
+use std::io::BufRead;
+use std::io::Write;
+
+use super::make_error_response;
+use super::read_message;
+use super::write_message;
+use super::JsonValue;
+use super::LanguageServer;
+
+const ERROR_PARSE: f64 = -32700.0;
+
+// serves messages until the client sends `exit', or closes the input; per
+// the protocol, exiting without the `shutdown' request is an error
+pub fn run_language_server<R: BufRead, W: Write>(
+    mut input: R,
+    mut output: W,
+) -> Result<(), String> {
+    let mut server = LanguageServer::new();
+
+    while let Some(content) = read_message(&mut input)? {
+        let responses = match JsonValue::parse(&content) {
+            Ok(message) => server.handle_message(&message),
+            Err(error) => vec![make_error_response(
+                JsonValue::Null,
+                ERROR_PARSE,
+                &format!("Error parsing message: {}", error),
+            )],
+        };
+
+        for response in responses {
+            write_message(&mut output, &response.to_string())?;
+        }
+
+        if server.is_exited() {
+            break;
+        }
+    }
+
+    if server.is_shutdown_requested() {
+        Ok(())
+    } else {
+        Err(String::from("Language server exited without shutdown."))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    use std::io::Cursor;
+
+    fn make_input(messages: Vec<&str>) -> Cursor<Vec<u8>> {
+        let mut input = Vec::new();
+
+        for message in messages {
+            write_message(&mut input, message).unwrap();
+        }
+
+        Cursor::new(input)
+    }
+
+    fn read_output(output: Vec<u8>) -> Vec<JsonValue> {
+        let mut output = Cursor::new(output);
+        let mut messages = Vec::new();
+
+        while let Some(content) = read_message(&mut output).unwrap() {
+            messages.push(JsonValue::parse(&content).unwrap());
+        }
+
+        messages
+    }
+
+    #[test]
+    fn serves_a_session() {
+        let input = make_input(vec![
+            r#"{"jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {}}"#,
+            r#"{"jsonrpc": "2.0", "method": "initialized", "params": {}}"#,
+            r#"{"jsonrpc": "2.0", "method": "textDocument/didOpen", "params": {"textDocument": {"uri": "file:///a.nia", "text": "(defv a 1)"}}}"#,
+            "{",
+            r#"{"jsonrpc": "2.0", "id": 2, "method": "shutdown"}"#,
+            r#"{"jsonrpc": "2.0", "method": "exit"}"#,
+            r#"{"jsonrpc": "2.0", "id": 3, "method": "shutdown"}"#,
+        ]);
+        let mut output = Vec::new();
+
+        nia_assert_equal(Ok(()), run_language_server(input, &mut output));
+
+        let messages = read_output(output);
+        let ids = messages
+            .iter()
+            .map(|message| message.get("id").cloned())
+            .collect::<Vec<Option<JsonValue>>>();
+
+        nia_assert_equal(
+            vec![
+                Some(JsonValue::number(1)),
+                None,
+                Some(JsonValue::Null),
+                Some(JsonValue::number(2)),
+            ],
+            ids,
+        );
+        nia_assert_equal(
+            Some(&JsonValue::Number(ERROR_PARSE)),
+            messages[2].get_path(&["error", "code"]),
+        );
+    }
+
+    #[test]
+    fn fails_without_shutdown() {
+        let input = make_input(vec![r#"{"jsonrpc": "2.0", "method": "exit"}"#]);
+
+        nia_assert(run_language_server(input, Vec::new()).is_err());
+        nia_assert(
+            run_language_server(Cursor::new(Vec::new()), Vec::new()).is_err(),
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use crate::Span;
+use crate::SyntaxNode;
+use crate::TokenKind;
+
+use super::JsonValue;
+
+// positions of the protocol count lines from zero and characters in UTF-16
+// code units, while the parser and the linter work with byte offsets
+
+pub fn offset_to_position(text: &str, offset: usize) -> JsonValue {
+    let mut offset = offset.min(text.len());
+
+    while !text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+
+    let before = &text[..offset];
+    let line_start = before.rfind('\n').map(|index| index + 1).unwrap_or(0);
+    let line = before.matches('\n').count();
+    let character = before[line_start..].encode_utf16().count();
+
+    JsonValue::object(vec![
+        ("line", JsonValue::number(line)),
+        ("character", JsonValue::number(character)),
+    ])
+}
+
+// positions past the end of a line are clamped to it
+pub fn position_to_offset(text: &str, position: &JsonValue) -> Option<usize> {
+    let line = position.get("line")?.as_usize()?;
+    let character = position.get("character")?.as_usize()?;
+
+    let mut line_start = 0;
+
+    for _ in 0..line {
+        line_start += text[line_start..].find('\n')? + 1;
+    }
+
+    let line_end = text[line_start..]
+        .find('\n')
+        .map(|index| line_start + index)
+        .unwrap_or(text.len());
+    let mut units = 0;
+
+    for (index, c) in text[line_start..line_end].char_indices() {
+        if units >= character {
+            return Some(line_start + index);
+        }
+
+        units += c.len_utf16();
+    }
+
+    Some(line_end)
+}
+
+pub fn make_range(text: &str, start: usize, end: usize) -> JsonValue {
+    JsonValue::object(vec![
+        ("start", offset_to_position(text, start)),
+        ("end", offset_to_position(text, end)),
+    ])
+}
+
+// the symbol under the cursor, or right before it
+pub fn find_symbol_at(
+    nodes: &[SyntaxNode],
+    offset: usize,
+) -> Option<(&str, Span)> {
+    nodes.iter().find_map(|node| match node {
+        SyntaxNode::Atom(TokenKind::Symbol, name, (start, end))
+            if *start <= offset && offset <= *end =>
+        {
+            Some((name.as_str(), (*start, *end)))
+        },
+        SyntaxNode::Prefixed(_, node, _) => {
+            find_symbol_at(std::slice::from_ref(node.as_ref()), offset)
+        },
+        SyntaxNode::Sequence(_, children, _, _) => {
+            find_symbol_at(children, offset)
+        },
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    use crate::parse_partial_syntax_tree;
+
+    fn make_position(line: usize, character: usize) -> JsonValue {
+        JsonValue::object(vec![
+            ("line", JsonValue::number(line)),
+            ("character", JsonValue::number(character)),
+        ])
+    }
+
+    #[test]
+    fn converts_offsets_to_positions_and_back() {
+        let text = "(a)\n(😀 é b)";
+
+        let specs =
+            vec![(0, 0, 0), (3, 0, 3), (4, 1, 0), (9, 1, 3), (12, 1, 5)];
+
+        for (offset, line, character) in specs {
+            let position = make_position(line, character);
+
+            nia_assert_equal(
+                position.clone(),
+                offset_to_position(text, offset),
+            );
+            nia_assert_equal(Some(offset), position_to_offset(text, &position));
+        }
+    }
+
+    #[test]
+    fn clamps_positions() {
+        let text = "(a)\n(b)";
+
+        nia_assert_equal(
+            Some(3),
+            position_to_offset(text, &make_position(0, 10)),
+        );
+        nia_assert_equal(
+            Some(7),
+            position_to_offset(text, &make_position(1, 10)),
+        );
+        nia_assert_equal(None, position_to_offset(text, &make_position(2, 0)));
+        nia_assert_equal(make_position(1, 3), offset_to_position(text, 100));
+    }
+
+    #[test]
+    fn finds_symbols_at_offsets() {
+        let code = "(list:map f '(g)";
+        let nodes = parse_partial_syntax_tree(code);
+
+        let specs = vec![
+            (0, None),
+            (1, Some(("list:map", (1, 9)))),
+            (9, Some(("list:map", (1, 9)))),
+            (11, Some(("f", (10, 11)))),
+            (14, Some(("g", (14, 15)))),
+        ];
+
+        for (offset, expected) in specs {
+            nia_assert_equal(expected, find_symbol_at(&nodes, offset));
+        }
+    }
+}
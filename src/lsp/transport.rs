@@ -0,0 +1,141 @@
+use std::io::BufRead;
+use std::io::Write;
+
+const CONTENT_LENGTH_HEADER: &'static str = "content-length:";
+
+// far more than any document of a configuration, the content is allocated
+// before it is read, so the length that a client claims is limited
+const MAX_CONTENT_LENGTH: usize = 64 * 1024 * 1024;
+
+// reads one message of the base protocol, that is headers separated from
+// the content by an empty line; returns None at the end of input
+pub fn read_message<R: BufRead>(
+    reader: &mut R,
+) -> Result<Option<String>, String> {
+    let mut content_length = None;
+    let mut is_first_line = true;
+
+    loop {
+        let mut line = String::new();
+
+        let read = reader
+            .read_line(&mut line)
+            .map_err(|error| format!("Error reading header: {}.", error))?;
+
+        if read == 0 {
+            return if is_first_line {
+                Ok(None)
+            } else {
+                Err(String::from("Unexpected end of input in headers."))
+            };
+        }
+
+        is_first_line = false;
+        let line = line.trim_end_matches(|c| c == '\r' || c == '\n');
+
+        if line.is_empty() {
+            break;
+        }
+
+        if line.to_lowercase().starts_with(CONTENT_LENGTH_HEADER) {
+            let length = line[CONTENT_LENGTH_HEADER.len()..]
+                .trim()
+                .parse::<usize>()
+                .map_err(|_| format!("Invalid header: {}.", line))?;
+
+            content_length = Some(length);
+        }
+    }
+
+    let content_length = content_length
+        .ok_or_else(|| String::from("Missing Content-Length header."))?;
+
+    if content_length > MAX_CONTENT_LENGTH {
+        return Err(format!(
+            "Content-Length {} exceeds the limit of {} bytes.",
+            content_length, MAX_CONTENT_LENGTH
+        ));
+    }
+
+    let mut content = vec![0; content_length];
+
+    reader
+        .read_exact(&mut content)
+        .map_err(|error| format!("Error reading content: {}.", error))?;
+
+    String::from_utf8(content)
+        .map(Some)
+        .map_err(|_| String::from("Content is not valid UTF-8."))
+}
+
+pub fn write_message<W: Write>(
+    writer: &mut W,
+    content: &str,
+) -> Result<(), String> {
+    write!(
+        writer,
+        "Content-Length: {}\r\n\r\n{}",
+        content.len(),
+        content
+    )
+    .and_then(|_| writer.flush())
+    .map_err(|error| format!("Error writing message: {}.", error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    use std::io::Cursor;
+
+    #[test]
+    fn reads_messages() {
+        let mut input = Cursor::new(
+            "Content-Length: 2\r\n\r\n{}content-length: 4\r\nContent-Type: \
+             application/vscode-jsonrpc; charset=utf-8\r\n\r\n\"é\""
+                .as_bytes(),
+        );
+
+        nia_assert_equal(
+            Ok(Some(String::from("{}"))),
+            read_message(&mut input),
+        );
+        nia_assert_equal(
+            Ok(Some(String::from("\"é\""))),
+            read_message(&mut input),
+        );
+        nia_assert_equal(Ok(None), read_message(&mut input));
+    }
+
+    #[test]
+    fn rejects_malformed_messages() {
+        let specs = vec![
+            "Content-Type: a\r\n\r\n{}",
+            "Content-Length: a\r\n\r\n{}",
+            "Content-Length: 10\r\n\r\n{}",
+            "Content-Length: 2\r\n",
+            "Content-Length: 18446744073709551615\r\n\r\n{}",
+        ];
+
+        for spec in specs {
+            let mut input = Cursor::new(spec.as_bytes());
+
+            nia_assert(read_message(&mut input).is_err());
+        }
+    }
+
+    #[test]
+    fn writes_messages() {
+        let mut output = Vec::new();
+
+        write_message(&mut output, "\"é\"").unwrap();
+
+        nia_assert_equal(
+            String::from("Content-Length: 4\r\n\r\n\"é\""),
+            String::from_utf8(output).unwrap(),
+        );
+    }
+}
@@ -0,0 +1,81 @@
+const FILE_SCHEME: &'static str = "file://";
+
+fn is_unreserved(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || b"-._~/".contains(&byte)
+}
+
+fn decode_percent_escapes(string: &str) -> Option<String> {
+    let bytes = string.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+
+    while index < bytes.len() {
+        if bytes[index] == b'%' {
+            let hex = string.get(index + 1..index + 3)?;
+
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            index += 3;
+        } else {
+            decoded.push(bytes[index]);
+            index += 1;
+        }
+    }
+
+    String::from_utf8(decoded).ok()
+}
+
+// only `file' uris name modules that can be imported
+pub fn uri_to_path(uri: &str) -> Option<String> {
+    let rest = uri.strip_prefix(FILE_SCHEME)?;
+    let path = match rest.find('/') {
+        Some(index) => &rest[index..],
+        None => return None,
+    };
+
+    decode_percent_escapes(path)
+}
+
+pub fn path_to_uri(path: &str) -> String {
+    let mut uri = String::from(FILE_SCHEME);
+
+    for byte in path.bytes() {
+        if is_unreserved(byte) {
+            uri.push(byte as char);
+        } else {
+            uri.push_str(&format!("%{:02X}", byte));
+        }
+    }
+
+    uri
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[test]
+    fn converts_uris_to_paths() {
+        let specs = vec![
+            ("file:///home/user/a.nia", Some("/home/user/a.nia")),
+            ("file://localhost/a%20b/%C3%A9.nia", Some("/a b/é.nia")),
+            ("file:///a%2", None),
+            ("untitled:Untitled-1", None),
+        ];
+
+        for (uri, expected) in specs {
+            nia_assert_equal(expected.map(String::from), uri_to_path(uri));
+        }
+    }
+
+    #[test]
+    fn converts_paths_to_uris() {
+        let path = "/a b/é.nia";
+        let uri = path_to_uri(path);
+
+        nia_assert_equal(String::from("file:///a%20b/%C3%A9.nia"), uri.clone());
+        nia_assert_equal(Some(String::from(path)), uri_to_path(&uri));
+    }
+}
@@ -1,14 +1,14 @@
-use std::collections::hash_map::DefaultHasher;
-pub mod utils;
-
-pub mod interpreter;
-pub mod repl;
-
-pub use interpreter::*;
-use nia_state_machine::{StateMachine, StateMachineResult};
-
-fn main() -> std::io::Result<()> {
-    repl::run()?;
-
-    Ok(())
-}
+pub mod utils;
+
+pub mod cli;
+pub mod interpreter;
+pub mod lsp;
+pub mod repl;
+
+pub use interpreter::*;
+
+fn main() {
+    let arguments = std::env::args().skip(1).collect();
+
+    std::process::exit(cli::run(arguments));
+}
@@ -0,0 +1,28 @@
+use std::io::IsTerminal;
+
+pub const SPECIAL_FORM_COLOR: &'static str = "\x1b[1;35m";
+pub const BUILTIN_OBJECT_COLOR: &'static str = "\x1b[36m";
+pub const STRING_COLOR: &'static str = "\x1b[32m";
+pub const KEYWORD_COLOR: &'static str = "\x1b[33m";
+pub const NUMBER_COLOR: &'static str = "\x1b[34m";
+pub const COMMENT_COLOR: &'static str = "\x1b[90m";
+pub const MATCHING_BRACKET_COLOR: &'static str = "\x1b[1;7m";
+
+pub const RESULT_COLOR: &'static str = "\x1b[32m";
+pub const ERROR_COLOR: &'static str = "\x1b[31m";
+pub const FAILURE_COLOR: &'static str = "\x1b[1;31m";
+
+const RESET: &'static str = "\x1b[0m";
+
+// see https://no-color.org
+pub fn is_color_enabled() -> bool {
+    let no_color = std::env::var_os("NO_COLOR")
+        .map(|value| !value.is_empty())
+        .unwrap_or(false);
+
+    !no_color && std::io::stdout().is_terminal()
+}
+
+pub fn paint(text: &str, color: &str) -> String {
+    format!("{}{}{}", color, text, RESET)
+}
@@ -0,0 +1,297 @@
+use crate::EventLoopHandle;
+use crate::Key;
+use crate::KeyChord;
+use crate::NiaCollectGarbageCommandResult;
+use crate::NiaExecuteCodeCommandResult;
+use crate::NiaGetDefinedDevicesCommandResult;
+use crate::NiaGetDefinedModifiersCommandResult;
+use crate::NiaGetReadableMappingsCommandResult;
+use crate::NiaInterpreterCommand;
+use crate::NiaInterpreterCommandResult;
+use crate::NiaLoadFileCommandResult;
+use crate::NiaReloadCommandResult;
+use crate::NiaStartListeningCommandResult;
+use crate::NiaStopListeningCommandResult;
+
+use super::format_table;
+use super::MetaCommand;
+use super::META_COMMANDS_HELP;
+
+fn send_command(
+    event_loop_handle: &EventLoopHandle,
+    command: NiaInterpreterCommand,
+) -> Result<NiaInterpreterCommandResult, String> {
+    event_loop_handle
+        .send_command(command)
+        .map_err(|_| String::from("Error sending command."))?;
+
+    event_loop_handle
+        .receive_result()
+        .map_err(|_| String::from("Error receiving result."))
+}
+
+fn unexpected_result(result: NiaInterpreterCommandResult) -> String {
+    format!("Unexpected result: {}.", result)
+}
+
+fn execute_code(
+    event_loop_handle: &EventLoopHandle,
+    code: &str,
+) -> Result<String, String> {
+    let command = NiaInterpreterCommand::make_execute_code_command(code);
+
+    match send_command(event_loop_handle, command)? {
+        NiaInterpreterCommandResult::ExecuteCode(
+            NiaExecuteCodeCommandResult::Success(value),
+        ) => Ok(value),
+        NiaInterpreterCommandResult::ExecuteCode(result) => {
+            Err(result.to_string())
+        },
+        result => Err(unexpected_result(result)),
+    }
+}
+
+fn format_key(key: Key) -> String {
+    match key.get_device_id() {
+        Some(device_id) => format!("{}:{}", device_id, key.get_key_id()),
+        None => key.get_key_id().to_string(),
+    }
+}
+
+fn format_key_chord(key_chord: &KeyChord) -> String {
+    key_chord
+        .get_modifiers()
+        .iter()
+        .copied()
+        .chain(std::iter::once(key_chord.get_key()))
+        .map(format_key)
+        .collect::<Vec<String>>()
+        .join("+")
+}
+
+fn load(
+    event_loop_handle: &EventLoopHandle,
+    file_path: String,
+) -> Result<String, String> {
+    let command = NiaInterpreterCommand::make_load_file_command(file_path);
+
+    match send_command(event_loop_handle, command)? {
+        NiaInterpreterCommandResult::LoadFile(
+            NiaLoadFileCommandResult::Success(value),
+        ) => Ok(value),
+        NiaInterpreterCommandResult::LoadFile(result) => {
+            Err(result.to_string())
+        },
+        result => Err(unexpected_result(result)),
+    }
+}
+
+fn reload(
+    event_loop_handle: &EventLoopHandle,
+    module_path: Option<String>,
+) -> Result<String, String> {
+    let command = NiaInterpreterCommand::make_reload_command(module_path);
+
+    match send_command(event_loop_handle, command)? {
+        NiaInterpreterCommandResult::Reload(
+            NiaReloadCommandResult::Success(module_paths),
+        ) => {
+            if module_paths.is_empty() {
+                Ok(String::from("No modules were reloaded."))
+            } else {
+                Ok(format!("Reloaded: {}.", module_paths.join(", ")))
+            }
+        },
+        NiaInterpreterCommandResult::Reload(result) => Err(result.to_string()),
+        result => Err(unexpected_result(result)),
+    }
+}
+
+fn time(
+    event_loop_handle: &EventLoopHandle,
+    code: &str,
+) -> Result<String, String> {
+    let command = NiaInterpreterCommand::make_time_code_command(code);
+
+    let (result, elapsed) = match send_command(event_loop_handle, command)? {
+        NiaInterpreterCommandResult::TimeCode(result) => result.take(),
+        result => return Err(unexpected_result(result)),
+    };
+
+    let elapsed_message = format!(
+        "Elapsed: {}.{:03} ms.",
+        elapsed.as_millis(),
+        elapsed.as_micros() % 1000
+    );
+
+    match result {
+        NiaExecuteCodeCommandResult::Success(value) => {
+            Ok(format!("{}\n{}", value, elapsed_message))
+        },
+        result => Err(format!("{}\n{}", result, elapsed_message)),
+    }
+}
+
+fn collect_garbage(
+    event_loop_handle: &EventLoopHandle,
+) -> Result<String, String> {
+    let command = NiaInterpreterCommand::make_collect_garbage_command();
+
+    match send_command(event_loop_handle, command)? {
+        NiaInterpreterCommandResult::CollectGarbage(
+            NiaCollectGarbageCommandResult::Success(statistics),
+        ) => Ok(statistics.to_string()),
+        NiaInterpreterCommandResult::CollectGarbage(result) => {
+            Err(result.to_string())
+        },
+        result => Err(unexpected_result(result)),
+    }
+}
+
+fn list_environment(
+    event_loop_handle: &EventLoopHandle,
+) -> Result<String, String> {
+    let variables = execute_code(event_loop_handle, "(env:variables :main)")?;
+    let functions = execute_code(event_loop_handle, "(env:functions :main)")?;
+
+    Ok(format!(
+        "Variables: {}\nFunctions: {}",
+        variables, functions
+    ))
+}
+
+fn list_mappings(
+    event_loop_handle: &EventLoopHandle,
+) -> Result<String, String> {
+    let command = NiaInterpreterCommand::make_get_readable_mappings_command();
+
+    let mappings = match send_command(event_loop_handle, command)? {
+        NiaInterpreterCommandResult::GetReadableMappings(
+            NiaGetReadableMappingsCommandResult::Success(mappings),
+        ) => mappings,
+        NiaInterpreterCommandResult::GetReadableMappings(result) => {
+            return Err(result.to_string());
+        },
+        result => return Err(unexpected_result(result)),
+    };
+
+    let rows = mappings
+        .into_iter()
+        .map(|(key_chords, action)| {
+            let key_chords = key_chords
+                .iter()
+                .map(format_key_chord)
+                .collect::<Vec<String>>()
+                .join(" ");
+
+            vec![key_chords, action]
+        })
+        .collect();
+
+    Ok(format_table(&["Key chords", "Action"], rows))
+}
+
+fn list_devices(event_loop_handle: &EventLoopHandle) -> Result<String, String> {
+    let command = NiaInterpreterCommand::make_get_defined_devices_command();
+
+    let devices = match send_command(event_loop_handle, command)? {
+        NiaInterpreterCommandResult::GetDefinedDevices(
+            NiaGetDefinedDevicesCommandResult::Success(devices),
+        ) => devices,
+        NiaInterpreterCommandResult::GetDefinedDevices(result) => {
+            return Err(result.to_string());
+        },
+        result => return Err(unexpected_result(result)),
+    };
+
+    let rows = devices
+        .iter()
+        .map(|device| {
+            vec![
+                device.get_id().to_string(),
+                device.get_path().clone(),
+                device.get_name().clone(),
+            ]
+        })
+        .collect();
+
+    Ok(format_table(&["Id", "Path", "Name"], rows))
+}
+
+fn list_modifiers(
+    event_loop_handle: &EventLoopHandle,
+) -> Result<String, String> {
+    let command = NiaInterpreterCommand::make_get_defined_modifiers();
+
+    let modifiers = match send_command(event_loop_handle, command)? {
+        NiaInterpreterCommandResult::GetDefinedModifiers(
+            NiaGetDefinedModifiersCommandResult::Success(modifiers),
+        ) => modifiers,
+        NiaInterpreterCommandResult::GetDefinedModifiers(result) => {
+            return Err(result.to_string());
+        },
+        result => return Err(unexpected_result(result)),
+    };
+
+    let rows = modifiers
+        .iter()
+        .map(|modifier| {
+            vec![format_key(modifier.get_key()), modifier.get_alias().clone()]
+        })
+        .collect();
+
+    Ok(format_table(&["Key", "Alias"], rows))
+}
+
+fn start_listening(
+    event_loop_handle: &EventLoopHandle,
+) -> Result<String, String> {
+    let command = NiaInterpreterCommand::make_start_listening_command();
+
+    match send_command(event_loop_handle, command)? {
+        NiaInterpreterCommandResult::StartListening(
+            NiaStartListeningCommandResult::Success(),
+        ) => Ok(String::from("Listening.")),
+        NiaInterpreterCommandResult::StartListening(result) => {
+            Err(result.to_string())
+        },
+        result => Err(unexpected_result(result)),
+    }
+}
+
+fn stop_listening(
+    event_loop_handle: &EventLoopHandle,
+) -> Result<String, String> {
+    let command = NiaInterpreterCommand::make_stop_listening_command();
+
+    match send_command(event_loop_handle, command)? {
+        NiaInterpreterCommandResult::StopListening(
+            NiaStopListeningCommandResult::Success(),
+        ) => Ok(String::from("Stopped listening.")),
+        NiaInterpreterCommandResult::StopListening(result) => {
+            Err(result.to_string())
+        },
+        result => Err(unexpected_result(result)),
+    }
+}
+
+pub fn execute_meta_command(
+    event_loop_handle: &EventLoopHandle,
+    meta_command: MetaCommand,
+) -> Result<String, String> {
+    match meta_command {
+        MetaCommand::Load(file_path) => load(event_loop_handle, file_path),
+        MetaCommand::Reload(module_path) => {
+            reload(event_loop_handle, module_path)
+        },
+        MetaCommand::Time(code) => time(event_loop_handle, &code),
+        MetaCommand::Gc => collect_garbage(event_loop_handle),
+        MetaCommand::Env => list_environment(event_loop_handle),
+        MetaCommand::Mappings => list_mappings(event_loop_handle),
+        MetaCommand::Devices => list_devices(event_loop_handle),
+        MetaCommand::Modifiers => list_modifiers(event_loop_handle),
+        MetaCommand::Listen => start_listening(event_loop_handle),
+        MetaCommand::Unlisten => stop_listening(event_loop_handle),
+        MetaCommand::Help => Ok(String::from(META_COMMANDS_HELP)),
+    }
+}
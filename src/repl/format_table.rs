@@ -0,0 +1,77 @@
+fn format_row(cells: &[String], widths: &[usize]) -> String {
+    let row = cells
+        .iter()
+        .zip(widths.iter())
+        .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+        .collect::<Vec<String>>()
+        .join("  ");
+
+    String::from(row.trim_end())
+}
+
+pub fn format_table(headers: &[&str], rows: Vec<Vec<String>>) -> String {
+    let headers = headers
+        .iter()
+        .map(|header| String::from(*header))
+        .collect::<Vec<String>>();
+
+    let mut widths = headers
+        .iter()
+        .map(|header| header.chars().count())
+        .collect::<Vec<usize>>();
+
+    for row in rows.iter() {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = std::cmp::max(*width, cell.chars().count());
+        }
+    }
+
+    let separators = widths
+        .iter()
+        .map(|width| "-".repeat(*width))
+        .collect::<Vec<String>>();
+
+    let mut lines = vec![
+        format_row(&headers, &widths),
+        format_row(&separators, &widths),
+    ];
+
+    lines.extend(rows.iter().map(|row| format_row(row, &widths)));
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[test]
+    fn aligns_columns() {
+        let rows = vec![
+            vec![String::from("1"), String::from("/dev/input/event1")],
+            vec![String::from("12"), String::from("/dev/a")],
+        ];
+
+        let expected = "\
+Id  Path
+--  -----------------
+1   /dev/input/event1
+12  /dev/a";
+
+        nia_assert_equal(
+            String::from(expected),
+            format_table(&["Id", "Path"], rows),
+        );
+    }
+
+    #[test]
+    fn prints_only_headers_when_there_are_no_rows() {
+        nia_assert_equal(
+            String::from("Key\n---"),
+            format_table(&["Key"], Vec::new()),
+        );
+    }
+}
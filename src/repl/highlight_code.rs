@@ -0,0 +1,156 @@
+use crate::is_builtin_object_name;
+use crate::is_special_form_name;
+use crate::tokenize;
+use crate::Token;
+use crate::TokenKind;
+
+use super::color::*;
+
+fn find_bracket_at_cursor(tokens: &Vec<Token>, cursor: usize) -> Option<usize> {
+    let is_bracket = |token: &Token| match token.get_kind() {
+        TokenKind::OpeningBracket | TokenKind::ClosingBracket => true,
+        _ => false,
+    };
+
+    // the bracket under the cursor wins over the one right before it
+    tokens
+        .iter()
+        .position(|token| token.get_start() == cursor && is_bracket(token))
+        .or_else(|| {
+            tokens.iter().position(|token| {
+                token.get_end() == cursor && is_bracket(token)
+            })
+        })
+}
+
+fn find_matching_bracket(tokens: &Vec<Token>, index: usize) -> Option<usize> {
+    let (step, own_kind): (isize, TokenKind) =
+        match tokens[index].get_kind() {
+            TokenKind::OpeningBracket => (1, TokenKind::OpeningBracket),
+            TokenKind::ClosingBracket => (-1, TokenKind::ClosingBracket),
+            _ => return None,
+        };
+
+    let mut depth = 0;
+    let mut current = index as isize;
+
+    while current >= 0 && (current as usize) < tokens.len() {
+        match tokens[current as usize].get_kind() {
+            TokenKind::OpeningBracket | TokenKind::ClosingBracket => {
+                if tokens[current as usize].get_kind() == own_kind {
+                    depth += 1;
+                } else {
+                    depth -= 1;
+                }
+
+                if depth == 0 {
+                    return Some(current as usize);
+                }
+            },
+            _ => {},
+        }
+
+        current += step;
+    }
+
+    None
+}
+
+fn get_symbol_color(symbol: &str) -> Option<&'static str> {
+    let object_name = symbol.split(':').next().unwrap_or(symbol);
+
+    if is_special_form_name(symbol) {
+        Some(SPECIAL_FORM_COLOR)
+    } else if object_name.len() < symbol.len()
+        && is_builtin_object_name(object_name)
+    {
+        Some(BUILTIN_OBJECT_COLOR)
+    } else {
+        None
+    }
+}
+
+pub fn highlight_code(code: &str, cursor: usize) -> String {
+    let tokens = tokenize(code);
+
+    let matching_brackets =
+        find_bracket_at_cursor(&tokens, cursor).and_then(|index| {
+            find_matching_bracket(&tokens, index).map(|other| (index, other))
+        });
+    let is_matching_bracket = |index: usize| match matching_brackets {
+        Some((first, second)) => index == first || index == second,
+        None => false,
+    };
+
+    let mut result = String::new();
+
+    for (index, token) in tokens.iter().enumerate() {
+        let text = token.get_text(code);
+
+        let color = match token.get_kind() {
+            TokenKind::OpeningBracket | TokenKind::ClosingBracket
+                if is_matching_bracket(index) =>
+            {
+                Some(MATCHING_BRACKET_COLOR)
+            },
+            TokenKind::Symbol => get_symbol_color(text),
+            TokenKind::String => Some(STRING_COLOR),
+            TokenKind::Keyword => Some(KEYWORD_COLOR),
+            TokenKind::Boolean | TokenKind::Number => Some(NUMBER_COLOR),
+            TokenKind::Comment => Some(COMMENT_COLOR),
+            _ => None,
+        };
+
+        match color {
+            Some(color) => result.push_str(&paint(text, color)),
+            None => result.push_str(text),
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[test]
+    fn colors_tokens_by_kind() {
+        let expected = format!(
+            "({} {} {} {} {} {}) {}",
+            paint("defn", SPECIAL_FORM_COLOR),
+            paint("list:map", BUILTIN_OBJECT_COLOR),
+            paint("\"s\"", STRING_COLOR),
+            paint(":k", KEYWORD_COLOR),
+            paint("1", NUMBER_COLOR),
+            "sym",
+            paint("; c", COMMENT_COLOR),
+        );
+
+        nia_assert_equal(
+            expected,
+            highlight_code("(defn list:map \"s\" :k 1 sym) ; c", 3),
+        );
+    }
+
+    #[test]
+    fn highlights_bracket_matching_the_cursor() {
+        let code = "(a (b))";
+        let expected = format!(
+            "(a {}b){}",
+            paint("(", MATCHING_BRACKET_COLOR),
+            paint(")", MATCHING_BRACKET_COLOR),
+        );
+
+        nia_assert_equal(expected.clone(), highlight_code(code, 3));
+        nia_assert_equal(expected, highlight_code(code, 5));
+    }
+
+    #[test]
+    fn does_not_highlight_unmatched_brackets() {
+        nia_assert_equal(String::from("(a (b)"), highlight_code("(a (b)", 0));
+    }
+}
@@ -0,0 +1,150 @@
+pub const META_COMMANDS_HELP: &'static str = "\
+:load FILE       Execute FILE in the main environment
+:reload [MODULE] Reload MODULE, or every changed module
+:time CODE       Evaluate CODE and print how long it took
+:gc              Collect garbage and print what was freed
+:env             List bindings of the main environment
+:mappings        Print defined mappings
+:devices         Print defined devices
+:modifiers       Print defined modifiers
+:listen          Start listening to devices
+:unlisten        Stop listening to devices
+:help            Print this message";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MetaCommand {
+    Load(String),
+    Reload(Option<String>),
+    Time(String),
+    Gc,
+    Env,
+    Mappings,
+    Devices,
+    Modifiers,
+    Listen,
+    Unlisten,
+    Help,
+}
+
+fn strip_quotes(argument: &str) -> &str {
+    if argument.len() >= 2
+        && argument.starts_with('"')
+        && argument.ends_with('"')
+    {
+        &argument[1..argument.len() - 1]
+    } else {
+        argument
+    }
+}
+
+impl MetaCommand {
+    // returns None for input that is not a meta-command, so `:keyword' is
+    // still evaluated as code
+    pub fn parse(input: &str) -> Option<Result<MetaCommand, String>> {
+        let input = input.trim();
+
+        let (name, argument) = match input.find(char::is_whitespace) {
+            Some(index) => (&input[..index], input[index..].trim()),
+            None => (input, ""),
+        };
+
+        let without_argument = |meta_command: MetaCommand| {
+            if argument.is_empty() {
+                Ok(meta_command)
+            } else {
+                Err(format!("Meta-command `{}' takes no arguments.", name))
+            }
+        };
+
+        let with_argument = |make: fn(String) -> MetaCommand| {
+            if argument.is_empty() {
+                Err(format!("Meta-command `{}' needs an argument.", name))
+            } else {
+                Ok(make(String::from(argument)))
+            }
+        };
+
+        let result = match name {
+            ":load" => with_argument(|argument| {
+                MetaCommand::Load(String::from(strip_quotes(&argument)))
+            }),
+            ":reload" if argument.is_empty() => Ok(MetaCommand::Reload(None)),
+            ":reload" => Ok(MetaCommand::Reload(Some(String::from(
+                strip_quotes(argument),
+            )))),
+            ":time" => with_argument(MetaCommand::Time),
+            ":gc" => without_argument(MetaCommand::Gc),
+            ":env" => without_argument(MetaCommand::Env),
+            ":mappings" => without_argument(MetaCommand::Mappings),
+            ":devices" => without_argument(MetaCommand::Devices),
+            ":modifiers" => without_argument(MetaCommand::Modifiers),
+            ":listen" => without_argument(MetaCommand::Listen),
+            ":unlisten" => without_argument(MetaCommand::Unlisten),
+            ":help" => without_argument(MetaCommand::Help),
+            _ => return None,
+        };
+
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(unused_imports)]
+    use nia_basic_assertions::*;
+
+    #[test]
+    fn parses_meta_commands() {
+        let specs = vec![
+            (
+                ":load file.nia",
+                MetaCommand::Load(String::from("file.nia")),
+            ),
+            (
+                ":load \"file name.nia\"",
+                MetaCommand::Load(String::from("file name.nia")),
+            ),
+            (":reload", MetaCommand::Reload(None)),
+            (
+                ":reload config.nia",
+                MetaCommand::Reload(Some(String::from("config.nia"))),
+            ),
+            (":time (+ 1 2)", MetaCommand::Time(String::from("(+ 1 2)"))),
+            (":gc", MetaCommand::Gc),
+            (":env", MetaCommand::Env),
+            (":mappings", MetaCommand::Mappings),
+            (":devices", MetaCommand::Devices),
+            (":modifiers", MetaCommand::Modifiers),
+            ("  :listen  ", MetaCommand::Listen),
+            (":unlisten", MetaCommand::Unlisten),
+            (":help", MetaCommand::Help),
+        ];
+
+        for (input, expected) in specs {
+            nia_assert_equal(Some(Ok(expected)), MetaCommand::parse(input));
+        }
+    }
+
+    #[test]
+    fn returns_none_for_code() {
+        let specs = vec!["(+ 1 2)", ":keyword", ":loaded", "load file.nia"];
+
+        for input in specs {
+            nia_assert_equal(None, MetaCommand::parse(input));
+        }
+    }
+
+    #[test]
+    fn returns_error_for_invalid_arguments() {
+        let specs = vec![":load", ":time", ":gc now", ":help me"];
+
+        for input in specs {
+            nia_assert(match MetaCommand::parse(input) {
+                Some(Err(_)) => true,
+                _ => false,
+            });
+        }
+    }
+}
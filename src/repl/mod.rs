@@ -1,3 +1,19 @@
+mod color;
+mod execute_meta_command;
+mod format_table;
+mod highlight_code;
+mod meta_command;
+mod nia_helper;
+
+pub use color::*;
+pub use execute_meta_command::*;
+pub use format_table::*;
+pub use highlight_code::*;
+pub use meta_command::*;
+pub use nia_helper::*;
+
+use std::rc::Rc;
+
 use rustyline::error::ReadlineError;
 use rustyline::Editor;
 
@@ -9,6 +25,7 @@ use crate::NiaInterpreterCommand;
 use crate::NiaInterpreterCommandResult;
 
 const HISTORY_FILE_NAME: &'static str = ".nia-interpreter.history";
+const PROMPT: &'static str = ">> ";
 
 fn get_history_file_path() -> Option<String> {
     match dirs::home_dir() {
@@ -20,13 +37,31 @@ fn get_history_file_path() -> Option<String> {
     }
 }
 
+fn print_colored(text: String, color: &str, color_enabled: bool) {
+    if color_enabled {
+        println!("{}", paint(&text, color))
+    } else {
+        println!("{}", text)
+    }
+}
+
 pub fn run() -> Result<(), std::io::Error> {
-    let interpreter = Interpreter::with_default_config();
+    run_with_interpreter(Interpreter::with_default_config())
+}
+
+pub fn run_with_interpreter(
+    interpreter: Interpreter,
+) -> Result<(), std::io::Error> {
     let history_file = get_history_file_path();
 
-    let event_loop_handle = EventLoop::run_event_loop(interpreter);
+    let event_loop_handle = Rc::new(EventLoop::run_event_loop(interpreter));
 
-    let mut rl = Editor::<()>::new();
+    let mut rl = Editor::<NiaHelper>::new();
+    let color_enabled = is_color_enabled();
+    rl.set_helper(Some(NiaHelper::new(
+        event_loop_handle.clone(),
+        color_enabled,
+    )));
 
     if let Some(history) = &history_file {
         rl.load_history(history)
@@ -36,18 +71,41 @@ pub fn run() -> Result<(), std::io::Error> {
     }
 
     loop {
-        let readline = rl.readline(">> ");
+        let readline = rl.readline(PROMPT);
 
         match readline {
-            Ok(line) => {
-                rl.add_history_entry(line.as_str());
+            Ok(code) => {
+                rl.add_history_entry(code.as_str());
+
+                if let Some(meta_command) = MetaCommand::parse(&code) {
+                    let result = meta_command.and_then(|meta_command| {
+                        execute_meta_command(&event_loop_handle, meta_command)
+                    });
+
+                    match result {
+                        Ok(output) => {
+                            print_colored(output, RESULT_COLOR, color_enabled)
+                        }
+                        Err(message) => {
+                            print_colored(message, ERROR_COLOR, color_enabled)
+                        }
+                    }
+
+                    if event_loop_handle.is_quitting() {
+                        break;
+                    }
+
+                    continue;
+                }
 
                 match event_loop_handle.send_command(
                     NiaInterpreterCommand::ExecuteCode(
-                        NiaExecuteCodeCommand::new(line),
+                        NiaExecuteCodeCommand::new(code),
                     ),
                 ) {
                     Ok(_) => {}
+                    // an action may have called `nia:quit' meanwhile
+                    Err(_) if event_loop_handle.is_quitting() => break,
                     Err(_) => println!("Error sending command"),
                 }
 
@@ -60,20 +118,30 @@ pub fn run() -> Result<(), std::io::Error> {
                 {
                     match result {
                         NiaExecuteCodeCommandResult::Success(success) => {
-                            println!("{}", success)
+                            print_colored(success, RESULT_COLOR, color_enabled)
                         }
                         NiaExecuteCodeCommandResult::Error(error) => {
-                            println!("Error: {}", error)
+                            print_colored(
+                                format!("Error: {}", error),
+                                ERROR_COLOR,
+                                color_enabled,
+                            )
                         }
                         NiaExecuteCodeCommandResult::Failure(failure) => {
-                            println!("Failure: {}", failure)
+                            print_colored(
+                                format!("Failure: {}", failure),
+                                FAILURE_COLOR,
+                                color_enabled,
+                            )
                         }
                     }
                 }
+
+                if event_loop_handle.is_quitting() {
+                    break;
+                }
             }
-            Err(ReadlineError::Interrupted) => {
-                // break;
-            }
+            Err(ReadlineError::Interrupted) => {}
             Err(ReadlineError::Eof) => {
                 break;
             }
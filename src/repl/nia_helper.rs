@@ -0,0 +1,94 @@
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use rustyline::completion::Completer;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::validate::ValidationContext;
+use rustyline::validate::ValidationResult;
+use rustyline::validate::Validator;
+use rustyline::Context;
+use rustyline::Helper;
+
+use crate::get_code_status;
+use crate::CodeStatus;
+use crate::EventLoopHandle;
+use crate::NiaCompleteCodeCommandResult;
+use crate::NiaInterpreterCommand;
+use crate::NiaInterpreterCommandResult;
+
+use super::highlight_code;
+
+pub struct NiaHelper {
+    event_loop_handle: Rc<EventLoopHandle>,
+    color_enabled: bool,
+}
+
+impl NiaHelper {
+    pub fn new(
+        event_loop_handle: Rc<EventLoopHandle>,
+        color_enabled: bool,
+    ) -> NiaHelper {
+        NiaHelper {
+            event_loop_handle,
+            color_enabled,
+        }
+    }
+}
+
+impl Completer for NiaHelper {
+    type Candidate = String;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<String>)> {
+        let command =
+            NiaInterpreterCommand::make_complete_code_command(line, pos);
+
+        if self.event_loop_handle.send_command(command).is_err() {
+            return Ok((pos, Vec::new()));
+        }
+
+        match self.event_loop_handle.receive_result() {
+            Ok(NiaInterpreterCommandResult::CompleteCode(
+                NiaCompleteCodeCommandResult::Success(completion),
+            )) => Ok((completion.get_start(), completion.take_candidates())),
+            _ => Ok((pos, Vec::new())),
+        }
+    }
+}
+
+impl Hinter for NiaHelper {}
+
+impl Highlighter for NiaHelper {
+    fn highlight<'l>(&self, line: &'l str, pos: usize) -> Cow<'l, str> {
+        if self.color_enabled {
+            Cow::Owned(highlight_code(line, pos))
+        } else {
+            Cow::Borrowed(line)
+        }
+    }
+
+    // the matching bracket changes whenever the cursor moves
+    fn highlight_char(&self, _line: &str, _pos: usize) -> bool {
+        self.color_enabled
+    }
+}
+
+// enter inserts a new line until the code is complete
+impl Validator for NiaHelper {
+    fn validate(
+        &self,
+        ctx: &mut ValidationContext,
+    ) -> rustyline::Result<ValidationResult> {
+        match get_code_status(ctx.input()) {
+            CodeStatus::Incomplete => Ok(ValidationResult::Incomplete),
+            _ => Ok(ValidationResult::Valid(None)),
+        }
+    }
+}
+
+impl Helper for NiaHelper {}
@@ -13,6 +13,7 @@ use crate::SYMBOL_NAME_GENERIC_EXECUTION_ERROR;
 use crate::SYMBOL_NAME_INVALID_ARGUMENT_COUNT_ERROR;
 use crate::SYMBOL_NAME_INVALID_ARGUMENT_ERROR;
 use crate::SYMBOL_NAME_OVERFLOW_ERROR;
+use crate::SYMBOL_NAME_PERMISSION_DENIED_ERROR;
 use crate::SYMBOL_NAME_ZERO_DIVISION_ERROR;
 
 use crate::library;
@@ -107,6 +108,10 @@ make_assertion_function!(assert_overflow_error, ErrorKind::Overflow);
 make_assertion_function!(assert_zero_division_error, ErrorKind::ZeroDivision);
 make_assertion_function!(assert_assertion_error, ErrorKind::Assertion);
 make_assertion_function!(assert_stack_overflow_error, ErrorKind::StackOverflow);
+make_assertion_function!(
+    assert_permission_denied_error,
+    ErrorKind::PermissionDenied
+);
 
 pub fn assert_is_function(param: Value) {
     nia_assert(match param {
@@ -259,6 +264,18 @@ pub fn assert_results_are_zero_division_errors(
     )
 }
 
+pub fn assert_results_are_permission_denied_errors(
+    interpreter: &mut Interpreter,
+    code_vector: Vec<&str>,
+) {
+    assert_results_are_errors(
+        interpreter,
+        code_vector,
+        ErrorKind::PermissionDenied,
+        SYMBOL_NAME_PERMISSION_DENIED_ERROR,
+    )
+}
+
 pub fn assert_results_are_overflow_errors(
     interpreter: &mut Interpreter,
     code_vector: Vec<&str>,
@@ -123,6 +123,45 @@ pub fn get_default_configuration_file_path() -> Option<PathBuf> {
         .map(|p| p.clone())
 }
 
+pub const MODULE_SEARCH_PATH_ENVIRONMENT_VARIABLE_NAME: &'static str =
+    "NIA_PATH";
+
+// Module specifiers that are neither absolute nor explicitly relative, like
+// "std/keys", are looked up in the module search path as well.
+pub fn is_bare_module_specifier(path: &str) -> bool {
+    !(path.starts_with("/") || path.starts_with(".") || path.starts_with("~"))
+}
+
+pub fn get_default_module_search_path() -> Vec<String> {
+    let mut paths = Vec::new();
+
+    if let Some(search_path) =
+        std::env::var_os(MODULE_SEARCH_PATH_ENVIRONMENT_VARIABLE_NAME)
+    {
+        paths.extend(std::env::split_paths(&search_path));
+    }
+
+    if let Some(home_dir) = dirs::home_dir() {
+        let mut path = home_dir.clone();
+        path.push(".nia.d");
+        path.push("modules");
+        paths.push(path);
+    }
+
+    if let Some(config_dir) = dirs::config_dir() {
+        let mut path = config_dir.clone();
+        path.push("nia.d");
+        path.push("modules");
+        paths.push(path);
+    }
+
+    paths
+        .into_iter()
+        .filter_map(|path| path.to_str().map(String::from))
+        .filter(|path| path.len() > 0)
+        .collect()
+}
+
 pub fn stub_function(
     _interpreter: &mut Interpreter,
     _environment_id: EnvironmentId,